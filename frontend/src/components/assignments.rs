@@ -2,9 +2,10 @@ use leptos::*;
 use log::{debug, error};
 use restinterface::{
     commit_assignments, fetch_assignments, fetch_fixtures, fetch_referees,
-    remove_committed_assignment, remove_staged_assignment, stage_assignment, AssignmentDTO,
-    AssignmentRefereeRoleDTO, AssignmentStagingDTO, AssignmentStatusDTO, FixtureDTO, FixtureIdDTO,
-    RefereeDTO, RefereeIdDTO,
+    fetch_unconfirmed_assignments, remove_committed_assignment, remove_staged_assignment,
+    stage_assignment, validate_assignments, AssignmentDTO, AssignmentRefereeRoleDTO,
+    AssignmentStagingDTO, AssignmentStatusDTO, AssignmentValidationViolationDTO, FixtureDTO,
+    FixtureIdDTO, RefereeDTO, RefereeIdDTO, UnconfirmedAssignmentsFilterDTO, UnconfirmedFixtureDTO,
 };
 
 #[component]
@@ -12,16 +13,25 @@ pub fn Assignments() -> impl IntoView {
     let (assignments, set_assignments) = create_signal(Vec::<AssignmentDTO>::new());
     let (referees, set_referees) = create_signal(Vec::<RefereeDTO>::new());
     let (fixtures, set_fixtures) = create_signal(Vec::<FixtureDTO>::new());
+    let (violations, set_violations) =
+        create_signal(Vec::<AssignmentValidationViolationDTO>::new());
+    let (unconfirmed, set_unconfirmed) = create_signal(Vec::<UnconfirmedFixtureDTO>::new());
 
     create_effect(move |_| {
         spawn_local(async move {
             let assignments = fetch_assignments().await;
             let referees = fetch_referees().await;
             let fixtures = fetch_fixtures().await;
+            let unconfirmed =
+                fetch_unconfirmed_assignments(&UnconfirmedAssignmentsFilterDTO::default()).await;
 
             set_assignments(assignments);
             set_referees(referees);
             set_fixtures(fixtures);
+            match unconfirmed {
+                Ok(unconfirmed) => set_unconfirmed(unconfirmed),
+                Err(e) => error!("Failed to fetch unconfirmed assignments: {}", e),
+            }
         });
     });
 
@@ -54,6 +64,15 @@ pub fn Assignments() -> impl IntoView {
         <div>
             <h1>Assignments</h1>
 
+            <button on:click=move |_| {
+                spawn_local(async move {
+                    let result = validate_assignments().await;
+                    match result {
+                        Ok(report) => set_violations(report.violations),
+                        Err(e) => error!("Failed to validate assignments: {}", e),
+                    }
+                });
+            }>Validate Assignments</button>
             <button on:click=move |_| {
                 spawn_local(async move {
                     let result = commit_assignments().await;
@@ -65,17 +84,33 @@ pub fn Assignments() -> impl IntoView {
                     }
                 });
             }>Commit Assignments</button>
+            {move || violations.get().into_iter().map(|v| {
+                view! {
+                    <p>{format!("{:?} ({:?}): {}", v.severity, v.rule, v.message)}</p>
+                }
+            }).collect::<Vec<_>>()}
+            <hr/>
+            <h2>Unconfirmed close to game day</h2>
+            {move || unconfirmed.get().into_iter().map(|u| {
+                let unconfirmed_referees = u.unconfirmed_assignments.iter().map(|a| {
+                    let referee_name = referees.get().iter().find(|r| r.id == a.referee_id).map_or(String::from("Unknown"), |r| r.name.clone());
+                    format!("{} ({:?})", referee_name, a.referee_role)
+                }).collect::<Vec<_>>().join(", ");
+                view! {
+                    <p>{format!("{} {} vs. {}: {}", u.fixture.date, u.fixture.team_home.name, u.fixture.team_away.name, unconfirmed_referees)}</p>
+                }
+            }).collect::<Vec<_>>()}
             <hr/>
         </div>
 
         {move || fixtures.get().into_iter().map(|f| {
-            let first_referee_assignment = assignments.get().into_iter().find(|a| a.fixture_id == f.id && a.referee_role == AssignmentRefereeRoleDTO::First);
-            let second_referee_assignment = assignments.get().into_iter().find(|a| a.fixture_id == f.id && a.referee_role == AssignmentRefereeRoleDTO::Second);
+            let first_referee_assignment = assignments.get().into_iter().find(|a| a.fixture_id == f.id && a.referee_role == AssignmentRefereeRoleDTO::First && a.status != AssignmentStatusDTO::Declined);
+            let second_referee_assignment = assignments.get().into_iter().find(|a| a.fixture_id == f.id && a.referee_role == AssignmentRefereeRoleDTO::Second && a.status != AssignmentStatusDTO::Declined);
 
             let first_referee_assignment_view = match first_referee_assignment {
                 Some(a) =>
                     match a.status {
-                        AssignmentStatusDTO::Staged => view! {
+                        AssignmentStatusDTO::Staged | AssignmentStatusDTO::Suspended => view! {
                         <div>
                             <p>First Referee: {referees.get().iter().find(|r| r.id == a.referee_id).unwrap().name.clone()}</p>
                             <select name="first_referee" id="first_referee" on:change=move |ev| assign_referee(f.id, AssignmentRefereeRoleDTO::First, RefereeIdDTO(event_target_value(&ev).parse().unwrap()))>
@@ -99,9 +134,9 @@ pub fn Assignments() -> impl IntoView {
                         }>Unassign First Referee</button>
                         </div>
                         },
-                        AssignmentStatusDTO::Committed => view! {
+                        AssignmentStatusDTO::Committed | AssignmentStatusDTO::Confirmed => view! {
                             <div>
-                                <p>First Referee: {referees.get().iter().find(|r| r.id == a.referee_id).unwrap().name.clone()}</p>
+                                <p>First Referee: {referees.get().iter().find(|r| r.id == a.referee_id).unwrap().name.clone()} {confirmation_label(&a.status)}</p>
                                 <button on:click=move |_| {
                                     let a = a.clone();
                                     spawn_local(async move {
@@ -116,6 +151,8 @@ pub fn Assignments() -> impl IntoView {
                                 }>Remove First Referee</button>
                             </div>
                         },
+                        // NOTE: declined assignments are filtered out above
+                        AssignmentStatusDTO::Declined => unreachable!(),
                 },
                 None => view! {
                     <div>
@@ -135,7 +172,7 @@ pub fn Assignments() -> impl IntoView {
             let second_referee_assignment_view = match second_referee_assignment {
                 Some(a) =>
                     match a.status {
-                        AssignmentStatusDTO::Staged => view! {
+                        AssignmentStatusDTO::Staged | AssignmentStatusDTO::Suspended => view! {
                         <div>
                             <p>Second Referee: {referees.get().iter().find(|r| r.id == a.referee_id).unwrap().name.clone()}</p>
                             <select name="second_referee" id="second_referee" on:change=move |ev| assign_referee(f.id, AssignmentRefereeRoleDTO::Second, RefereeIdDTO(event_target_value(&ev).parse().unwrap()))>
@@ -159,9 +196,9 @@ pub fn Assignments() -> impl IntoView {
                         }>Unassign Second Referee</button>
                         </div>
                         },
-                        AssignmentStatusDTO::Committed => view! {
+                        AssignmentStatusDTO::Committed | AssignmentStatusDTO::Confirmed => view! {
                             <div>
-                                <p>Second Referee: {referees.get().iter().find(|r| r.id == a.referee_id).unwrap().name.clone()}</p>
+                                <p>Second Referee: {referees.get().iter().find(|r| r.id == a.referee_id).unwrap().name.clone()} {confirmation_label(&a.status)}</p>
                                 <button on:click=move |_| {
                                     let a = a.clone();
                                     spawn_local(async move {
@@ -176,6 +213,8 @@ pub fn Assignments() -> impl IntoView {
                                 }>Remove Second Referee</button>
                             </div>
                         },
+                        // NOTE: declined assignments are filtered out above
+                        AssignmentStatusDTO::Declined => unreachable!(),
                 },
                 None => view! {
                     <div>
@@ -208,3 +247,11 @@ pub fn Assignments() -> impl IntoView {
         }).collect::<Vec<_>>()}
     }
 }
+
+fn confirmation_label(status: &AssignmentStatusDTO) -> &'static str {
+    if *status == AssignmentStatusDTO::Confirmed {
+        "(confirmed)"
+    } else {
+        "(unconfirmed)"
+    }
+}
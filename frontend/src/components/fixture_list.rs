@@ -3,8 +3,9 @@ use leptos::*;
 use log::{debug, error};
 
 use restinterface::{
-    create_fixture, fetch_fixtures, fetch_teams, fetch_venues, FixtureCreationDTO, FixtureDTO,
-    FixtureStatusDTO, TeamDTO, TeamIdDTO, VenueDTO, VenueIdDTO,
+    create_fixture, fetch_fixtures, fetch_leagues, fetch_seasons, fetch_teams, fetch_venues,
+    FixtureCreationDTO, FixtureDTO, FixtureStatusDTO, LeagueDTO, LeagueIdDTO, SeasonDTO,
+    SeasonIdDTO, TeamDTO, TeamIdDTO, VenueDTO, VenueIdDTO,
 };
 use uuid::Uuid;
 
@@ -13,20 +14,39 @@ pub fn FixtureList() -> impl IntoView {
     let (fixtures, set_fixtures) = create_signal(Vec::<FixtureDTO>::new());
     let (venues, set_venues) = create_signal(Vec::<VenueDTO>::new());
     let (teams, set_teams) = create_signal(Vec::<TeamDTO>::new());
+    let (leagues, set_leagues) = create_signal(Vec::<LeagueDTO>::new());
+    let (seasons, set_seasons) = create_signal(Vec::<SeasonDTO>::new());
 
     let (new_fixture_date, set_new_fixture_date) = create_signal(Utc::now());
+    let (new_fixture_league_id, set_new_fixture_league_id) =
+        create_signal(LeagueIdDTO(Uuid::new_v4()));
+    let (new_fixture_season_id, set_new_fixture_season_id) =
+        create_signal(SeasonIdDTO(Uuid::new_v4()));
     let (new_fixture_venue_id, set_new_fixture_venue_id) =
         create_signal(VenueIdDTO(Uuid::new_v4()));
     let (new_fixture_home_team_id, set_new_fixture_home_team_id) =
         create_signal(TeamIdDTO(Uuid::new_v4()));
     let (new_fixture_away_team_id, set_new_fixture_away_team_id) =
         create_signal(TeamIdDTO(Uuid::new_v4()));
+    let (new_fixture_first_referee_min_level, set_new_fixture_first_referee_min_level) =
+        create_signal(0);
+    let (new_fixture_second_referee_min_level, set_new_fixture_second_referee_min_level) =
+        create_signal(0);
 
     create_effect(move |_| {
         spawn_local(async move {
             let fixtures = fetch_fixtures().await;
             let venues = fetch_venues().await;
             let teams = fetch_teams().await;
+            let leagues = fetch_leagues().await;
+            let seasons = fetch_seasons().await;
+
+            if let Some(league) = leagues.first() {
+                set_new_fixture_league_id(league.id.clone());
+            }
+            if let Some(season) = seasons.iter().find(|s| !s.closed) {
+                set_new_fixture_season_id(season.id.clone());
+            }
 
             set_new_fixture_away_team_id(teams[0].id.clone().into());
             set_new_fixture_home_team_id(teams[0].id.clone().into());
@@ -35,6 +55,8 @@ pub fn FixtureList() -> impl IntoView {
             set_fixtures(fixtures);
             set_venues(venues);
             set_teams(teams);
+            set_leagues(leagues);
+            set_seasons(seasons);
         });
     });
 
@@ -44,10 +66,14 @@ pub fn FixtureList() -> impl IntoView {
         // TODO: check if Team home and Team away are different
 
         let fixture = FixtureCreationDTO {
+            league_id: new_fixture_league_id.get(),
+            season_id: new_fixture_season_id.get(),
             date: new_fixture_date.get(),
             team_home_id: new_fixture_home_team_id.get().into(),
             team_away_id: new_fixture_away_team_id.get().into(),
             venue_id: new_fixture_venue_id.get().into(),
+            first_referee_min_level: new_fixture_first_referee_min_level.get(),
+            second_referee_min_level: new_fixture_second_referee_min_level.get(),
         };
 
         let mut fixtures_previous = fixtures.get();
@@ -67,6 +93,18 @@ pub fn FixtureList() -> impl IntoView {
         });
     };
 
+    let select_new_fixture_league_id = move |ev: ev::Event| {
+        let league_id_str = event_target_value(&ev);
+        debug!("league_id_str: {}", league_id_str);
+        set_new_fixture_league_id(league_id_str.into());
+    };
+
+    let select_new_fixture_season_id = move |ev: ev::Event| {
+        let season_id_str = event_target_value(&ev);
+        debug!("season_id_str: {}", season_id_str);
+        set_new_fixture_season_id(season_id_str.into());
+    };
+
     let select_new_fixture_venue_id = move |ev: ev::Event| {
         let venue_id_str = event_target_value(&ev);
         debug!("venue_id_str: {}", venue_id_str);
@@ -100,6 +138,16 @@ pub fn FixtureList() -> impl IntoView {
 
             <form>
                 <input type="datetime-local" on:change=select_new_fixture_date />
+                <select name="league" id="leagues" on:change=select_new_fixture_league_id>
+                    {move || leagues.get().into_iter().map(|l| view! {
+                        <option value={l.id.0.to_string()}>{l.name}</option>
+                    }).collect::<Vec<_>>()}
+                </select>
+                <select name="season" id="seasons" on:change=select_new_fixture_season_id>
+                    {move || seasons.get().into_iter().filter(|s| !s.closed).map(|s| view! {
+                        <option value={s.id.0.to_string()}>{s.name}</option>
+                    }).collect::<Vec<_>>()}
+                </select>
                 <select name="venue" id="venues" on:change=select_new_fixture_venue_id>
                     {move || venues.get().into_iter().map(|v| view! {
                         <option value={v.id.0.to_string()}>{v.name}</option>
@@ -116,6 +164,21 @@ pub fn FixtureList() -> impl IntoView {
                     }).collect::<Vec<_>>()}
                 </select>
 
+                <input
+                    type="number"
+                    min="0"
+                    placeholder="First referee min level"
+                    prop:value=new_fixture_first_referee_min_level
+                    on:input=move |ev| set_new_fixture_first_referee_min_level.set(event_target_value(&ev).parse().unwrap_or(0))
+                />
+                <input
+                    type="number"
+                    min="0"
+                    placeholder="Second referee min level"
+                    prop:value=new_fixture_second_referee_min_level
+                    on:input=move |ev| set_new_fixture_second_referee_min_level.set(event_target_value(&ev).parse().unwrap_or(0))
+                />
+
                 <button on:click=add_fixture>"Add Fixture"</button>
             </form>
 
@@ -125,15 +188,17 @@ pub fn FixtureList() -> impl IntoView {
                 {move || fixtures.get().into_iter().map(|f| view! {
                     <div>
                         <b>{f.date.to_string()}</b>
+                        <p>{f.league.name} " - " {f.season.name}</p>
                         <p>{f.venue.name}</p>
                         <p>{format!("{:?}", f.status)}</p>
                         <p>"Home: " {f.team_home.name}</p>
                         <p>"Away: " {f.team_away.name}</p>
+                        <p>"Result: " {f.result.map(|r| r.sets.iter().map(|s| format!("{}:{}", s.home, s.away)).collect::<Vec<_>>().join(", ")).unwrap_or("-".to_string())}</p>
                         <p>"First Referee: " {f.first_referee.map(|r| r.name).unwrap_or("Unassigned".to_string())}</p>
                         <p>"Second Referee: " {f.second_referee.map(|r| r.name).unwrap_or("Unassigned".to_string())}</p>
-                        <button disabled=f.status != FixtureStatusDTO::Scheduled>"Change Venue"</button>
-                        <button disabled=f.status != FixtureStatusDTO::Scheduled>"Change Date"</button>
-                        <button disabled=f.status != FixtureStatusDTO::Scheduled>"Cancel Fixture"</button>
+                        <button disabled=f.status != FixtureStatusDTO::Scheduled || f.season.closed>"Change Venue"</button>
+                        <button disabled=f.status != FixtureStatusDTO::Scheduled || f.season.closed>"Change Date"</button>
+                        <button disabled=f.status != FixtureStatusDTO::Scheduled || f.season.closed>"Cancel Fixture"</button>
                         <p/>
                         <hr/>
                     </div>
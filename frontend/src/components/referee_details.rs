@@ -62,6 +62,7 @@ pub fn RefereeDetails() -> impl IntoView {
                 <div>
                     <p>"Name: " {r.name}</p>
                     <p>"Club: " {r.club}</p>
+                    <p>"Qualification level: " {r.qualification_level}</p>
                 </div>
             })}
 
@@ -7,6 +7,7 @@ pub fn RefereeList() -> impl IntoView {
     let (referees, set_referees) = create_signal(Vec::<RefereeDTO>::new());
     let (referee_name, set_referee_name) = create_signal(String::new());
     let (referee_club, set_referee_club) = create_signal(String::new());
+    let (referee_level, set_referee_level) = create_signal(0);
 
     create_effect(move |_| {
         // set referees to "use" the signal, so that leptos knows to rerun the effect when it changes after fetching
@@ -22,7 +23,12 @@ pub fn RefereeList() -> impl IntoView {
         // get the values from the form fields outside of the async block, otherwise leptos complains in browser console
         let name = referee_name.get();
         let club = referee_club.get();
-        let ref_creation = RefereeCreationDTO { name, club };
+        let qualification_level = referee_level.get();
+        let ref_creation = RefereeCreationDTO {
+            name,
+            club,
+            qualification_level,
+        };
         let mut referees_previous = referees.get();
         spawn_local(async move {
             let res = create_referee(&ref_creation).await;
@@ -42,6 +48,7 @@ pub fn RefereeList() -> impl IntoView {
         // reset the form fields
         set_referee_name(String::new());
         set_referee_club(String::new());
+        set_referee_level(0);
     };
 
     view! {
@@ -62,6 +69,14 @@ pub fn RefereeList() -> impl IntoView {
                     prop:value=referee_club
                     on:input=move |ev| set_referee_club.set(event_target_value(&ev))
                 />
+                <input
+                    id="referee-level"
+                    type="number"
+                    min="0"
+                    placeholder="Qualification level"
+                    prop:value=referee_level
+                    on:input=move |ev| set_referee_level.set(event_target_value(&ev).parse().unwrap_or(0))
+                />
                 <button type="submit">"Add Referee"</button>
             </form>
 
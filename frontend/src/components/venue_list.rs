@@ -46,6 +46,7 @@ pub fn VenueList() -> impl IntoView {
             } else {
                 Some(venue_email)
             },
+            location: None,
         };
 
         let mut venues_previous = venues.get();
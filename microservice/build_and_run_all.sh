@@ -10,6 +10,10 @@ cd ../fixtures
 sh ./run_instance_1.sh &
 sh ./run_instance_2.sh &
 
+cd ../notifications
+sh ./run_instance_1.sh &
+sh ./run_instance_2.sh &
+
 cd ../referees
 sh ./run_instance_1.sh &
 sh ./run_instance_2.sh &
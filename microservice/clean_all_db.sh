@@ -7,6 +7,9 @@ sh ./clean_db.sh
 cd ../fixtures
 sh ./clean_db.sh 
 
+cd ../notifications
+sh ./clean_db.sh 
+
 cd ../referees
 sh ./clean_db.sh 
 
@@ -0,0 +1,2 @@
+docker stop rustddd-microservices-smtp
+docker rm rustddd-microservices-smtp
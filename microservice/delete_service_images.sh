@@ -1,6 +1,7 @@
 docker rmi rustddd-assignments
 docker rmi rustddd-availabilities
 docker rmi rustddd-fixtures
+docker rmi rustddd-notifications
 docker rmi rustddd-referees
 docker rmi rustddd-teams
 docker rmi rustddd-venues
\ No newline at end of file
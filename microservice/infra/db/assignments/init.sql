@@ -1,7 +1,7 @@
 CREATE SCHEMA IF NOT EXISTS rustddd;
 ALTER SYSTEM SET wal_level = logical;
 
-CREATE TYPE rustddd.assignment_status AS ENUM ('committed', 'staged');
+CREATE TYPE rustddd.assignment_status AS ENUM ('committed', 'staged', 'suspended', 'confirmed', 'declined');
 CREATE TYPE rustddd.assignment_referee_role AS ENUM ('first', 'second');
 
 CREATE TABLE IF NOT EXISTS rustddd.assignments (
@@ -9,10 +9,30 @@ CREATE TABLE IF NOT EXISTS rustddd.assignments (
     referee_role rustddd.assignment_referee_role NOT NULL,
     fixture_id UUID NOT NULL,
     referee_id UUID NOT NULL,
+    review_reason VARCHAR,
     UNIQUE (fixture_id, referee_id)
 );
 ALTER TABLE rustddd.assignments REPLICA IDENTITY FULL;
 
+CREATE TYPE rustddd.commit_saga_status AS ENUM ('running', 'completed', 'compensating', 'compensated');
+CREATE TYPE rustddd.commit_saga_step_status AS ENUM ('pending', 'accepted', 'rejected');
+
+CREATE TABLE IF NOT EXISTS rustddd.commit_sagas (
+    id UUID PRIMARY KEY,
+    status rustddd.commit_saga_status NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS rustddd.commit_saga_steps (
+    saga_id UUID NOT NULL REFERENCES rustddd.commit_sagas (id) ON DELETE CASCADE,
+    fixture_id UUID NOT NULL,
+    referee_id UUID NOT NULL,
+    referee_role rustddd.assignment_referee_role NOT NULL,
+    status rustddd.commit_saga_step_status NOT NULL,
+    reason VARCHAR,
+    UNIQUE (saga_id, fixture_id, referee_id)
+);
+
 CREATE TABLE rustddd.domain_events_outbox (
     id UUID NOT NULL,
     payload JSONB NOT NULL,
@@ -28,6 +48,15 @@ CREATE TABLE rustddd.domain_events_inbox (
 );
 ALTER TABLE rustddd.domain_events_inbox REPLICA IDENTITY FULL;
 
+CREATE TABLE rustddd.domain_events_dead_letter (
+    id UUID NOT NULL PRIMARY KEY,
+    payload TEXT NOT NULL,
+    error VARCHAR NOT NULL,
+    attempts INTEGER NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    redrive_requested_at TIMESTAMP WITH TIME ZONE
+);
+
 
 INSERT INTO rustddd.assignments (status, fixture_id, referee_id, referee_role) VALUES
 ('committed', 'ba045e60-1ae2-4902-8293-02b04747a888'::UUID, '2ef28cf5-6471-4051-ae11-0f419aef3234'::UUID, 'first'),
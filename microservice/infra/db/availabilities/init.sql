@@ -4,10 +4,34 @@ ALTER SYSTEM SET wal_level = logical;
 CREATE TABLE IF NOT EXISTS rustddd.availabilities (
     fixture_id UUID NOT NULL,
     referee_id UUID NOT NULL,
+    suspended BOOLEAN NOT NULL DEFAULT FALSE,
     UNIQUE (fixture_id, referee_id)
 );
 ALTER TABLE rustddd.availabilities REPLICA IDENTITY FULL;
 
+CREATE TABLE IF NOT EXISTS rustddd.availability_locks (
+    id UUID PRIMARY KEY,
+    fixture_id UUID,
+    from_date TIMESTAMP WITH TIME ZONE,
+    to_date TIMESTAMP WITH TIME ZONE
+);
+ALTER TABLE rustddd.availability_locks REPLICA IDENTITY FULL;
+
+CREATE TABLE IF NOT EXISTS rustddd.declaration_deadlines (
+    fixture_id UUID PRIMARY KEY,
+    deadline TIMESTAMP WITH TIME ZONE NOT NULL,
+    closed BOOLEAN NOT NULL DEFAULT FALSE
+);
+ALTER TABLE rustddd.declaration_deadlines REPLICA IDENTITY FULL;
+
+CREATE TABLE IF NOT EXISTS rustddd.blackouts (
+    id UUID PRIMARY KEY,
+    referee_id UUID NOT NULL,
+    from_date TIMESTAMP WITH TIME ZONE NOT NULL,
+    to_date TIMESTAMP WITH TIME ZONE NOT NULL
+);
+ALTER TABLE rustddd.blackouts REPLICA IDENTITY FULL;
+
 CREATE TABLE rustddd.domain_events_outbox (
     id UUID NOT NULL,
     payload JSONB NOT NULL,
@@ -23,6 +47,15 @@ CREATE TABLE rustddd.domain_events_inbox (
 );
 ALTER TABLE rustddd.domain_events_inbox REPLICA IDENTITY FULL;
 
+CREATE TABLE rustddd.domain_events_dead_letter (
+    id UUID NOT NULL PRIMARY KEY,
+    payload TEXT NOT NULL,
+    error VARCHAR NOT NULL,
+    attempts INTEGER NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    redrive_requested_at TIMESTAMP WITH TIME ZONE
+);
+
 INSERT INTO rustddd.availabilities (fixture_id, referee_id) VALUES
 ('ba045e60-1ae2-4902-8293-02b04747a888'::UUID, '2ef28cf5-6471-4051-ae11-0f419aef3234'::UUID),
 ('ba045e60-1ae2-4902-8293-02b04747a888'::UUID, 'e1214a09-42e1-4194-9acc-d310172d001a'::UUID),
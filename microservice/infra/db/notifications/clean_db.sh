@@ -0,0 +1,4 @@
+docker stop rustddd-notifications-db
+docker rm rustddd-notifications-db
+
+sudo rm data -rf
@@ -0,0 +1,42 @@
+CREATE SCHEMA IF NOT EXISTS rustddd;
+ALTER SYSTEM SET wal_level = logical;
+
+CREATE TYPE rustddd.notification_kind AS ENUM ('first_referee_assigned', 'second_referee_assigned', 'assignment_removed', 'fixture_date_changed', 'fixture_venue_changed', 'fixture_cancelled');
+CREATE TYPE rustddd.delivery_status AS ENUM ('pending', 'sent', 'failed');
+
+CREATE TABLE IF NOT EXISTS rustddd.notification_recipients (
+    referee_id UUID PRIMARY KEY,
+    email VARCHAR NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS rustddd.notifications (
+    id UUID PRIMARY KEY,
+    referee_id UUID NOT NULL,
+    fixture_id UUID NOT NULL,
+    kind rustddd.notification_kind NOT NULL,
+    recipient VARCHAR NOT NULL,
+    subject VARCHAR NOT NULL,
+    body VARCHAR NOT NULL,
+    status rustddd.delivery_status NOT NULL,
+    attempts INTEGER NOT NULL,
+    last_error VARCHAR,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    next_attempt_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    sent_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE TABLE rustddd.domain_events_inbox (
+    id UUID NOT NULL,
+    payload JSONB NOT NULL,
+    processed_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE TABLE rustddd.domain_events_dead_letter (
+    id UUID NOT NULL PRIMARY KEY,
+    payload TEXT NOT NULL,
+    error VARCHAR NOT NULL,
+    attempts INTEGER NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    redrive_requested_at TIMESTAMP WITH TIME ZONE
+);
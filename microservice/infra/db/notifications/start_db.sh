@@ -0,0 +1 @@
+docker compose -f docker-compose.yaml up
\ No newline at end of file
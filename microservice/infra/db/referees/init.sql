@@ -4,7 +4,11 @@ ALTER SYSTEM SET wal_level = logical;
 CREATE TABLE IF NOT EXISTS rustddd.referees (
     referee_id UUID NOT NULL PRIMARY KEY,
     name VARCHAR NOT NULL,
-    club VARCHAR NOT NULL
+    club VARCHAR NOT NULL,
+    qualification_level INTEGER NOT NULL DEFAULT 0,
+    home_latitude DOUBLE PRECISION,
+    home_longitude DOUBLE PRECISION,
+    archived BOOLEAN NOT NULL DEFAULT FALSE
 );
 ALTER TABLE rustddd.referees REPLICA IDENTITY FULL;
 
@@ -23,6 +27,15 @@ CREATE TABLE rustddd.domain_events_inbox (
 );
 ALTER TABLE rustddd.domain_events_inbox REPLICA IDENTITY FULL;
 
+CREATE TABLE rustddd.domain_events_dead_letter (
+    id UUID NOT NULL PRIMARY KEY,
+    payload TEXT NOT NULL,
+    error VARCHAR NOT NULL,
+    attempts INTEGER NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    redrive_requested_at TIMESTAMP WITH TIME ZONE
+);
+
 INSERT INTO rustddd.referees (referee_id, name, club) VALUES
 ('2ef28cf5-6471-4051-ae11-0f419aef3234'::UUID, 'John Doe', 'Club A'),
 ('e1214a09-42e1-4194-9acc-d310172d001a'::UUID, 'Jane Smith', 'Club B'),
@@ -4,7 +4,8 @@ ALTER SYSTEM SET wal_level = logical;
 CREATE TABLE IF NOT EXISTS rustddd.teams (
     team_id UUID NOT NULL PRIMARY KEY,
     name VARCHAR NOT NULL,
-    club VARCHAR NOT NULL
+    club VARCHAR NOT NULL,
+    archived BOOLEAN NOT NULL DEFAULT FALSE
 );
 ALTER TABLE rustddd.teams REPLICA IDENTITY FULL;
 
@@ -23,6 +24,15 @@ CREATE TABLE rustddd.domain_events_inbox (
 );
 ALTER TABLE rustddd.domain_events_inbox REPLICA IDENTITY FULL;
 
+CREATE TABLE rustddd.domain_events_dead_letter (
+    id UUID NOT NULL PRIMARY KEY,
+    payload TEXT NOT NULL,
+    error VARCHAR NOT NULL,
+    attempts INTEGER NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    redrive_requested_at TIMESTAMP WITH TIME ZONE
+);
+
 INSERT INTO rustddd.teams (team_id, name, club) VALUES
 ('def7f2ca-58a1-44ed-8f2b-78386c9746cf'::UUID, 'Team A', 'Club A'),
 ('9b93e265-deb3-4139-a9b8-e261d7985a05'::UUID, 'Team B', 'Club B'),
@@ -8,7 +8,10 @@ CREATE TABLE IF NOT EXISTS rustddd.venues (
     zip VARCHAR NOT NULL,
     city VARCHAR NOT NULL,
     telephone VARCHAR,
-    email VARCHAR
+    email VARCHAR,
+    latitude DOUBLE PRECISION,
+    longitude DOUBLE PRECISION,
+    archived BOOLEAN NOT NULL DEFAULT FALSE
 );
 ALTER TABLE rustddd.venues REPLICA IDENTITY FULL;
 
@@ -27,6 +30,15 @@ CREATE TABLE rustddd.domain_events_inbox (
 );
 ALTER TABLE rustddd.domain_events_inbox REPLICA IDENTITY FULL;
 
+CREATE TABLE rustddd.domain_events_dead_letter (
+    id UUID NOT NULL PRIMARY KEY,
+    payload TEXT NOT NULL,
+    error VARCHAR NOT NULL,
+    attempts INTEGER NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    redrive_requested_at TIMESTAMP WITH TIME ZONE
+);
+
 INSERT INTO rustddd.venues (venue_id, name, street, zip, city, telephone, email) VALUES
 ('6ee926bc-3728-4cdb-8efb-98d350a07854'::UUID, 'Venue A', 'Street A', '12345', 'City A', '1234567890', 'venuea@example.com'),
 ('cf49df42-cf40-48fa-b2e7-d31b4c796ce1'::UUID, 'Venue B', 'Street B', '23456', 'City B', '2345678901', 'venueb@example.com'),
@@ -1,6 +1,7 @@
 killall assignments
 killall availabilities
 killall fixtures
+killall notifications
 killall referees
 killall teams
 killall venues
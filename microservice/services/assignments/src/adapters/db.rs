@@ -1 +1,2 @@
 pub mod assignment_repo_pg;
+pub mod commit_saga_repo_pg;
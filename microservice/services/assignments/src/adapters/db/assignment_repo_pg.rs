@@ -12,7 +12,7 @@ use crate::domain::{
     type_name = "rustddd.assignment_referee_role",
     rename_all = "lowercase"
 )]
-enum AssignmentRefereeRoleDb {
+pub(crate) enum AssignmentRefereeRoleDb {
     First,
     Second,
 }
@@ -22,6 +22,9 @@ enum AssignmentRefereeRoleDb {
 enum AssignmentStatusDb {
     Committed,
     Staged,
+    Suspended,
+    Confirmed,
+    Declined,
 }
 
 #[derive(sqlx::FromRow, Debug)]
@@ -30,6 +33,7 @@ struct AssignmentDb {
     pub fixture_id: Uuid,
     pub referee_id: Uuid,
     pub referee_role: AssignmentRefereeRoleDb,
+    pub review_reason: Option<String>,
 }
 
 pub struct AssignmentRepositoryPg();
@@ -45,6 +49,9 @@ impl From<AssignmentStatusDb> for AssignmentStatus {
         match status {
             AssignmentStatusDb::Committed => AssignmentStatus::Committed,
             AssignmentStatusDb::Staged => AssignmentStatus::Staged,
+            AssignmentStatusDb::Suspended => AssignmentStatus::Suspended,
+            AssignmentStatusDb::Confirmed => AssignmentStatus::Confirmed,
+            AssignmentStatusDb::Declined => AssignmentStatus::Declined,
         }
     }
 }
@@ -54,6 +61,9 @@ impl From<AssignmentStatus> for AssignmentStatusDb {
         match status {
             AssignmentStatus::Committed => AssignmentStatusDb::Committed,
             AssignmentStatus::Staged => AssignmentStatusDb::Staged,
+            AssignmentStatus::Suspended => AssignmentStatusDb::Suspended,
+            AssignmentStatus::Confirmed => AssignmentStatusDb::Confirmed,
+            AssignmentStatus::Declined => AssignmentStatusDb::Declined,
         }
     }
 }
@@ -83,6 +93,7 @@ impl From<AssignmentDb> for Assignment {
             assignment.referee_id.into(),
             assignment.referee_role.into(),
             assignment.status.into(),
+            assignment.review_reason,
         )
     }
 }
@@ -94,7 +105,7 @@ impl AssignmentRepository for AssignmentRepositoryPg {
     async fn get_all(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<Assignment>, Self::Error> {
         let assignments: Vec<AssignmentDb> = sqlx::query_as!(
             AssignmentDb,
-            "SELECT status as \"status: AssignmentStatusDb\", fixture_id, referee_id, referee_role as \"referee_role: AssignmentRefereeRoleDb\" 
+            "SELECT status as \"status: AssignmentStatusDb\", fixture_id, referee_id, referee_role as \"referee_role: AssignmentRefereeRoleDb\", review_reason 
             FROM rustddd.assignments"
         )
         .fetch_all(&mut **tx_ctx)
@@ -110,7 +121,7 @@ impl AssignmentRepository for AssignmentRepositoryPg {
     ) -> Result<Vec<Assignment>, Self::Error> {
         let assignments: Vec<AssignmentDb> = sqlx::query_as!(
             AssignmentDb,
-            "SELECT status as \"status: AssignmentStatusDb\", fixture_id, referee_id, referee_role as \"referee_role: AssignmentRefereeRoleDb\" 
+            "SELECT status as \"status: AssignmentStatusDb\", fixture_id, referee_id, referee_role as \"referee_role: AssignmentRefereeRoleDb\", review_reason 
             FROM rustddd.assignments WHERE status = 'staged'"
         )
         .fetch_all(&mut **tx_ctx)
@@ -120,6 +131,24 @@ impl AssignmentRepository for AssignmentRepositoryPg {
         Ok(assignments.into_iter().map(|a| a.into()).collect())
     }
 
+    async fn find_all_committed_by_referee(
+        &self,
+        referee_id: RefereeId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<Assignment>, Self::Error> {
+        let assignments: Vec<AssignmentDb> = sqlx::query_as!(
+            AssignmentDb,
+            "SELECT status as \"status: AssignmentStatusDb\", fixture_id, referee_id, referee_role as \"referee_role: AssignmentRefereeRoleDb\", review_reason 
+            FROM rustddd.assignments WHERE status IN ('committed', 'confirmed') AND referee_id = $1",
+            referee_id.0
+        )
+        .fetch_all(&mut **tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(assignments.into_iter().map(|a| a.into()).collect())
+    }
+
     async fn find_by_fixture_and_referee(
         &self,
         fixture_id: FixtureId,
@@ -128,7 +157,7 @@ impl AssignmentRepository for AssignmentRepositoryPg {
     ) -> Result<Option<Assignment>, Self::Error> {
         let assignment: Option<AssignmentDb> = sqlx::query_as!(
             AssignmentDb,
-            "SELECT status as \"status: AssignmentStatusDb\", fixture_id, referee_id, referee_role as \"referee_role: AssignmentRefereeRoleDb\" 
+            "SELECT status as \"status: AssignmentStatusDb\", fixture_id, referee_id, referee_role as \"referee_role: AssignmentRefereeRoleDb\", review_reason 
             FROM rustddd.assignments WHERE fixture_id = $1 AND referee_id = $2",
             fixture_id.0,
             referee_id.0
@@ -165,14 +194,15 @@ impl AssignmentRepository for AssignmentRepositoryPg {
         let referee_role: AssignmentRefereeRoleDb = assignment.referee_role().into();
         let status: AssignmentStatusDb = assignment.status().into();
         sqlx::query!(
-            "INSERT INTO rustddd.assignments (status, fixture_id, referee_id, referee_role) 
-            VALUES ($1, $2, $3, $4)
+            "INSERT INTO rustddd.assignments (status, fixture_id, referee_id, referee_role, review_reason) 
+            VALUES ($1, $2, $3, $4, $5)
             ON CONFLICT (fixture_id, referee_id) 
-            DO UPDATE SET referee_role = $4, status = $1",
+            DO UPDATE SET referee_role = $4, status = $1, review_reason = $5",
             status as AssignmentStatusDb,
             assignment.fixture_id().0,
             assignment.referee_id().0,
-            referee_role as AssignmentRefereeRoleDb
+            referee_role as AssignmentRefereeRoleDb,
+            assignment.review_reason()
         )
         .execute(&mut **tx_ctx)
         .await
@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use microservices_shared::domain_ids::CommitSagaId;
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::{
+    adapters::db::assignment_repo_pg::AssignmentRefereeRoleDb,
+    domain::{
+        aggregates::commit_saga::{
+            CommitSaga, CommitSagaStatus, CommitSagaStep, CommitSagaStepStatus,
+        },
+        repositories::commit_saga_repo::CommitSagaRepository,
+    },
+};
+
+#[derive(sqlx::Type, Debug)]
+#[sqlx(type_name = "rustddd.commit_saga_status", rename_all = "lowercase")]
+enum CommitSagaStatusDb {
+    Running,
+    Completed,
+    Compensating,
+    Compensated,
+}
+
+#[derive(sqlx::Type, Debug)]
+#[sqlx(
+    type_name = "rustddd.commit_saga_step_status",
+    rename_all = "lowercase"
+)]
+enum CommitSagaStepStatusDb {
+    Pending,
+    Accepted,
+    Rejected,
+}
+
+#[derive(sqlx::FromRow, Debug)]
+struct CommitSagaDb {
+    pub id: Uuid,
+    pub status: CommitSagaStatusDb,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(sqlx::FromRow, Debug)]
+struct CommitSagaStepDb {
+    pub saga_id: Uuid,
+    pub fixture_id: Uuid,
+    pub referee_id: Uuid,
+    pub referee_role: AssignmentRefereeRoleDb,
+    pub status: CommitSagaStepStatusDb,
+    pub reason: Option<String>,
+}
+
+pub struct CommitSagaRepositoryPg();
+
+impl CommitSagaRepositoryPg {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl From<CommitSagaStatusDb> for CommitSagaStatus {
+    fn from(status: CommitSagaStatusDb) -> Self {
+        match status {
+            CommitSagaStatusDb::Running => CommitSagaStatus::Running,
+            CommitSagaStatusDb::Completed => CommitSagaStatus::Completed,
+            CommitSagaStatusDb::Compensating => CommitSagaStatus::Compensating,
+            CommitSagaStatusDb::Compensated => CommitSagaStatus::Compensated,
+        }
+    }
+}
+
+impl From<CommitSagaStatus> for CommitSagaStatusDb {
+    fn from(status: CommitSagaStatus) -> Self {
+        match status {
+            CommitSagaStatus::Running => CommitSagaStatusDb::Running,
+            CommitSagaStatus::Completed => CommitSagaStatusDb::Completed,
+            CommitSagaStatus::Compensating => CommitSagaStatusDb::Compensating,
+            CommitSagaStatus::Compensated => CommitSagaStatusDb::Compensated,
+        }
+    }
+}
+
+impl From<CommitSagaStepStatusDb> for CommitSagaStepStatus {
+    fn from(status: CommitSagaStepStatusDb) -> Self {
+        match status {
+            CommitSagaStepStatusDb::Pending => CommitSagaStepStatus::Pending,
+            CommitSagaStepStatusDb::Accepted => CommitSagaStepStatus::Accepted,
+            CommitSagaStepStatusDb::Rejected => CommitSagaStepStatus::Rejected,
+        }
+    }
+}
+
+impl From<CommitSagaStepStatus> for CommitSagaStepStatusDb {
+    fn from(status: CommitSagaStepStatus) -> Self {
+        match status {
+            CommitSagaStepStatus::Pending => CommitSagaStepStatusDb::Pending,
+            CommitSagaStepStatus::Accepted => CommitSagaStepStatusDb::Accepted,
+            CommitSagaStepStatus::Rejected => CommitSagaStepStatusDb::Rejected,
+        }
+    }
+}
+
+impl From<CommitSagaStepDb> for CommitSagaStep {
+    fn from(step: CommitSagaStepDb) -> Self {
+        CommitSagaStep::new(
+            step.fixture_id.into(),
+            step.referee_id.into(),
+            step.referee_role.into(),
+            step.status.into(),
+            step.reason,
+        )
+    }
+}
+
+impl CommitSagaDb {
+    // NOTE: the steps are stored in a separate table, therefore they are loaded separately
+    fn into_commit_saga(self, steps: Vec<CommitSagaStep>) -> CommitSaga {
+        CommitSaga::from_id(
+            CommitSagaId::from(self.id),
+            self.status.into(),
+            self.created_at,
+            steps,
+        )
+    }
+}
+
+impl CommitSagaRepository for CommitSagaRepositoryPg {
+    type Error = String;
+    type TxCtx = Transaction<'static, Postgres>;
+
+    async fn find_by_id(
+        &self,
+        saga_id: CommitSagaId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<CommitSaga>, Self::Error> {
+        let saga: Option<CommitSagaDb> = sqlx::query_as!(
+            CommitSagaDb,
+            "SELECT id, status as \"status: CommitSagaStatusDb\", created_at
+            FROM rustddd.commit_sagas WHERE id = $1",
+            saga_id.0
+        )
+        .fetch_optional(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("CommitSagaRepositoryPg::find_by_id failed with {}", e))?;
+
+        let Some(saga) = saga else {
+            return Ok(None);
+        };
+
+        let steps: Vec<CommitSagaStepDb> = sqlx::query_as!(
+            CommitSagaStepDb,
+            "SELECT saga_id, fixture_id, referee_id, referee_role as \"referee_role: AssignmentRefereeRoleDb\", status as \"status: CommitSagaStepStatusDb\", reason
+            FROM rustddd.commit_saga_steps WHERE saga_id = $1",
+            saga_id.0
+        )
+        .fetch_all(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("CommitSagaRepositoryPg::find_by_id failed with {}", e))?;
+
+        Ok(Some(saga.into_commit_saga(
+            steps.into_iter().map(|s| s.into()).collect(),
+        )))
+    }
+
+    async fn get_all(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<CommitSaga>, Self::Error> {
+        let sagas: Vec<CommitSagaDb> = sqlx::query_as!(
+            CommitSagaDb,
+            "SELECT id, status as \"status: CommitSagaStatusDb\", created_at
+            FROM rustddd.commit_sagas ORDER BY created_at DESC"
+        )
+        .fetch_all(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("CommitSagaRepositoryPg::get_all failed with {}", e))?;
+
+        let steps: Vec<CommitSagaStepDb> = sqlx::query_as!(
+            CommitSagaStepDb,
+            "SELECT saga_id, fixture_id, referee_id, referee_role as \"referee_role: AssignmentRefereeRoleDb\", status as \"status: CommitSagaStepStatusDb\", reason
+            FROM rustddd.commit_saga_steps"
+        )
+        .fetch_all(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("CommitSagaRepositoryPg::get_all failed with {}", e))?;
+
+        let mut steps_by_saga: HashMap<Uuid, Vec<CommitSagaStep>> = HashMap::new();
+        for step in steps {
+            steps_by_saga
+                .entry(step.saga_id)
+                .or_default()
+                .push(step.into());
+        }
+
+        Ok(sagas
+            .into_iter()
+            .map(|saga| {
+                let steps = steps_by_saga.remove(&saga.id).unwrap_or_default();
+                saga.into_commit_saga(steps)
+            })
+            .collect())
+    }
+
+    async fn save(&self, saga: &CommitSaga, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error> {
+        let status: CommitSagaStatusDb = saga.status().into();
+        sqlx::query!(
+            "INSERT INTO rustddd.commit_sagas (id, status, created_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (id)
+            DO UPDATE SET status = $2",
+            saga.id().0,
+            status as CommitSagaStatusDb,
+            saga.created_at()
+        )
+        .execute(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("CommitSagaRepositoryPg::save failed with {}", e))?;
+
+        for step in saga.steps() {
+            let referee_role: AssignmentRefereeRoleDb = step.referee_role().into();
+            let status: CommitSagaStepStatusDb = step.status().into();
+            sqlx::query!(
+                "INSERT INTO rustddd.commit_saga_steps (saga_id, fixture_id, referee_id, referee_role, status, reason)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT (saga_id, fixture_id, referee_id)
+                DO UPDATE SET status = $5, reason = $6",
+                saga.id().0,
+                step.fixture_id().0,
+                step.referee_id().0,
+                referee_role as AssignmentRefereeRoleDb,
+                status as CommitSagaStepStatusDb,
+                step.reason()
+            )
+            .execute(&mut **tx_ctx)
+            .await
+            .map_err(|e| format!("CommitSagaRepositoryPg::save failed with {}", e))?;
+        }
+
+        Ok(())
+    }
+}
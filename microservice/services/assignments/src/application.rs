@@ -1 +1,4 @@
 pub mod assignment_services;
+pub mod calendar_services;
+pub mod commit_saga_services;
+pub mod expense_services;
@@ -1,16 +1,37 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
 use microservices_shared::{
     domain_event_repo::DomainEventOutboxRepository,
     domain_events::DomainEvent,
     domain_ids::{FixtureId, RefereeId},
-    resolvers::traits::{FixtureResolver, RefereeResolver},
+    resolvers::traits::{AvailabilityResolver, FixtureResolver, RefereeResolver},
+};
+use restinterface::{
+    AssignmentDTO, AssignmentStagingDTO, AssignmentValidationReportDTO,
+    AssignmentValidationRuleDTO, AssignmentValidationSeverityDTO, AssignmentValidationViolationDTO,
+    FixtureDTO, FixtureIdDTO, FixtureStatusDTO, RefereeDTO, UnconfirmedFixtureDTO,
 };
-use restinterface::{AssignmentDTO, AssignmentStagingDTO};
+use shared::booking::{booking_violations, FixtureSlot, RefereeBooking};
 
 use crate::domain::{
-    aggregates::assignment::{Assignment, AssignmentRefereeRole},
-    repositories::assignment_repo::AssignmentRepository,
+    aggregates::{
+        assignment::{Assignment, AssignmentRefereeRole},
+        commit_saga::CommitSaga,
+    },
+    repositories::{assignment_repo::AssignmentRepository, commit_saga_repo::CommitSagaRepository},
 };
 
+/// Committed assignments of fixtures within this many hours from now are close to game day and should be confirmed
+pub const UNCONFIRMED_WINDOW_DEFAULT_HOURS: i64 = 72;
+
+/// The change of a fixture which might invalidate its committed assignments
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixtureChange {
+    Date,
+    Venue,
+}
+
 pub async fn remove_staged_assignment<TxCtx>(
     fixture_id: FixtureId,
     referee_id: RefereeId,
@@ -61,69 +82,121 @@ pub async fn remove_committed_assignment<TxCtx>(
 
     let assignment = assignment.unwrap();
 
-    if assignment.is_staged() {
+    if !assignment.is_committed() {
         return Err(format!(
             "Assignment with fixture_id {} and referee_id {} not committed",
             fixture_id.0, referee_id.0
         ));
     }
 
-    let fixture = fixture_resolver
-        .resolve(&assignment.fixture_id())
-        .await
-        .expect(&format!(
-            "Fixture {:?} not found when removing committed assignment",
-            assignment.fixture_id().0
+    unassign_referee(&assignment, fixture_resolver, domain_event_repo, tx_ctx).await?;
+    assignment_repo.delete(&assignment, tx_ctx).await
+}
+
+pub async fn confirm_assignment<TxCtx>(
+    fixture_id: FixtureId,
+    referee_id: RefereeId,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<AssignmentDTO, String> {
+    let mut assignment = assignment_repo
+        .find_by_fixture_and_referee(fixture_id, referee_id, tx_ctx)
+        .await?
+        .ok_or(format!(
+            "Assignment with fixture_id {} and referee_id {} not found",
+            fixture_id.0, referee_id.0
+        ))?;
+
+    if !assignment.is_unconfirmed() {
+        return Err(format!(
+            "Assignment with fixture_id {} and referee_id {} not committed or already confirmed",
+            fixture_id.0, referee_id.0
         ));
+    }
 
-    match assignment.referee_role() {
-        AssignmentRefereeRole::First => {
-            if fixture.first_referee.is_none() {
-                return Err(format!(
-                    "First referee not assigned for fixture {}",
-                    fixture.id.0
-                ));
-            }
+    assignment.confirm();
+    assignment_repo.save(&assignment, tx_ctx).await?;
 
-            if fixture.first_referee.unwrap().id != assignment.referee_id().into() {
-                return Err(format!(
-                    "First referee not assigned for fixture {}",
-                    fixture.id.0
-                ));
-            }
+    Ok(assignment.into())
+}
 
-            let event = DomainEvent::FirstRefereeAssignmentRemoved {
-                fixture_id: fixture.id.0.into(),
-                referee_id: assignment.referee_id().into(),
-            };
+pub async fn decline_assignment<TxCtx>(
+    fixture_id: FixtureId,
+    referee_id: RefereeId,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    fixture_resolver: &impl FixtureResolver<Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<AssignmentDTO, String> {
+    let mut assignment = assignment_repo
+        .find_by_fixture_and_referee(fixture_id, referee_id, tx_ctx)
+        .await?
+        .ok_or(format!(
+            "Assignment with fixture_id {} and referee_id {} not found",
+            fixture_id.0, referee_id.0
+        ))?;
 
-            domain_event_repo.store(event.clone(), tx_ctx).await?;
-        }
-        AssignmentRefereeRole::Second => {
-            if fixture.second_referee.is_none() {
-                return Err(format!(
-                    "Second referee not assigned for fixture {}",
-                    fixture.id.0
-                ));
-            }
+    if !assignment.is_committed() {
+        return Err(format!(
+            "Assignment with fixture_id {} and referee_id {} not committed",
+            fixture_id.0, referee_id.0
+        ));
+    }
 
-            if fixture.second_referee.unwrap().id != assignment.referee_id().into() {
-                return Err(format!(
-                    "Second referee not assigned for fixture {}",
-                    fixture.id.0
-                ));
-            }
+    // NOTE: declining frees the fixture just like removing the committed assignment, but the declined
+    // assignment is kept, so that the admin can see who turned the fixture down
+    unassign_referee(&assignment, fixture_resolver, domain_event_repo, tx_ctx).await?;
 
-            let event = DomainEvent::SecondRefereeAssignmentRemoved {
-                fixture_id: fixture.id.0.into(),
-                referee_id: assignment.referee_id().into(),
-            };
+    assignment.decline();
+    assignment_repo.save(&assignment, tx_ctx).await?;
+
+    Ok(assignment.into())
+}
+
+/// Returns the scheduled fixtures taking place within the given window from now on, which
+/// have committed assignments that are not confirmed by their referees yet, ordered by date.
+pub async fn get_unconfirmed_assignments<TxCtx>(
+    now: DateTime<Utc>,
+    within: Duration,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    fixture_resolver: &impl FixtureResolver<Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<UnconfirmedFixtureDTO>, String> {
+    let assignments = assignment_repo.get_all(tx_ctx).await?;
+
+    let mut unconfirmed: HashMap<FixtureId, Vec<Assignment>> = HashMap::new();
+    for assignment in assignments.into_iter().filter(|a| a.is_unconfirmed()) {
+        unconfirmed
+            .entry(assignment.fixture_id())
+            .or_default()
+            .push(assignment);
+    }
+
+    let mut unconfirmed_fixtures = Vec::new();
+    for (fixture_id, assignments) in unconfirmed {
+        let fixture = fixture_resolver.resolve(&fixture_id).await.expect(&format!(
+            "Fixture {} not found when fetching unconfirmed assignments",
+            fixture_id.0
+        ));
 
-            domain_event_repo.store(event.clone(), tx_ctx).await?;
+        if fixture.status != FixtureStatusDTO::Scheduled
+            || fixture.date < now
+            || fixture.date > now + within
+        {
+            continue;
         }
+
+        unconfirmed_fixtures.push((fixture, assignments));
     }
+    unconfirmed_fixtures.sort_by_key(|(f, _)| f.date);
 
-    assignment_repo.delete(&assignment, tx_ctx).await
+    Ok(unconfirmed_fixtures
+        .into_iter()
+        .map(|(fixture, assignments)| UnconfirmedFixtureDTO {
+            fixture,
+            unconfirmed_assignments: assignments.into_iter().map(|a| a.into()).collect(),
+        })
+        .collect())
 }
 
 pub async fn stage_assignment<TxCtx>(
@@ -148,6 +221,28 @@ pub async fn stage_assignment<TxCtx>(
             assignment_staging.referee_id.0
         ));
 
+    if fixture.season.closed {
+        return Err(format!(
+            "Fixture {} is read-only because its season is closed",
+            fixture.id.0
+        ));
+    }
+
+    let referee_role: AssignmentRefereeRole = assignment_staging.referee_role.into();
+    if !is_qualified(&fixture, &referee, referee_role) {
+        return Err(format!(
+            "Referee {} with qualification level {} is under-qualified for the {:?} referee role in fixture {}",
+            referee.id.0, referee.qualification_level, referee_role, fixture.id.0
+        ));
+    }
+
+    if is_conflict_of_interest(&fixture, &referee.club) {
+        return Err(format!(
+            "Referee {} cannot be assigned to fixture {} due to a conflict of interest: their club {} is playing",
+            referee.id.0, fixture.id.0, referee.club
+        ));
+    }
+
     let assignment_lookup = assignment_repo
         .find_by_fixture_and_referee(fixture.id.0.into(), referee.id.0.into(), tx_ctx)
         .await?;
@@ -172,18 +267,47 @@ pub async fn stage_assignment<TxCtx>(
 
 pub async fn commit_assignments<TxCtx>(
     assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    commit_saga_repo: &impl CommitSagaRepository<TxCtx = TxCtx, Error = String>,
     fixture_resolver: &impl FixtureResolver<Error = String>,
     referee_resolver: &impl RefereeResolver<Error = String>,
+    availability_resolver: &impl AvailabilityResolver<Error = String>,
     domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
     tx_ctx: &mut TxCtx,
 ) -> Result<String, String> {
-    // NOTE: committing assignments also validates them and rejects if any invalid
-    validate_assignments(assignment_repo, tx_ctx).await?;
+    // NOTE: committing assignments also validates them and rejects if the report contains any errors,
+    // therefore no referee assigned events are written to the outbox in that case
+    let report = validate_assignments(
+        assignment_repo,
+        fixture_resolver,
+        referee_resolver,
+        availability_resolver,
+        tx_ctx,
+    )
+    .await?;
+
+    if report.has_errors() {
+        return Err(format!(
+            "Assignments invalid: {}",
+            report
+                .violations
+                .iter()
+                .filter(|v| v.severity == AssignmentValidationSeverityDTO::Error)
+                .map(|v| v.message.clone())
+                .collect::<Vec<String>>()
+                .join("; ")
+        ));
+    }
 
     // NOTE: committing assignments fetches all staged assignments and assigns the referees to the fixtures according to their roles
-    // and changes the assignment status to committed
+    // and changes the assignment status to committed. Assigning the referees happens in the fixtures service, therefore
+    // a commit saga tracks its replies and compensates the commit in case the fixtures service rejects an assignment
 
     let assignments = assignment_repo.find_all_staged(tx_ctx).await?;
+    if assignments.is_empty() {
+        return Ok("Assignments committed".to_string());
+    }
+
+    let saga = CommitSaga::new(&assignments, Utc::now());
 
     for mut assignment in assignments {
         let fixture = fixture_resolver
@@ -204,10 +328,12 @@ pub async fn commit_assignments<TxCtx>(
 
         let event = match role {
             AssignmentRefereeRole::First => DomainEvent::FirstRefereeAssigned {
+                saga_id: saga.id(),
                 fixture_id: fixture.id.0.into(),
                 referee_id: referee.id.0.into(),
             },
             AssignmentRefereeRole::Second => DomainEvent::SecondRefereeAssigned {
+                saga_id: saga.id(),
                 fixture_id: fixture.id.0.into(),
                 referee_id: referee.id.0.into(),
             },
@@ -220,21 +346,992 @@ pub async fn commit_assignments<TxCtx>(
         assignment_repo.save(&assignment, tx_ctx).await?;
     }
 
+    commit_saga_repo.save(&saga, tx_ctx).await?;
+
     Ok("Assignments committed".to_string())
 }
 
 pub async fn validate_assignments<TxCtx>(
-    _assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
-    _tx_ctx: &mut TxCtx,
-) -> Result<String, String> {
-    // NOTE: this is not fully implemented, just a placeholder because its
-    // complex domain logic, requiring additional domain functions in the
-    // aggregates and the domain service layer.
-    // An assignment is valid if:
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    fixture_resolver: &impl FixtureResolver<Error = String>,
+    referee_resolver: &impl RefereeResolver<Error = String>,
+    availability_resolver: &impl AvailabilityResolver<Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<AssignmentValidationReportDTO, String> {
+    // NOTE: a staged assignment is valid if:
     //  - the fixture is in a playable state (i.e. not postponed, cancelled, etc.)
+    //  - the fixture is not read-only, i.e. its season is not closed
     //  - the referee is available for the fixture
     //  - the referee is not already assigned to another fixture at the same time
     //  - the referee is not assigned to a fixture that is "close" to the fixture (i.e. same field, same game-hour), except when its at the same venue
+    //  - the referee has no conflict of interest, i.e. their club is not playing in the fixture
+    // Fixtures and referees are owned by other services, therefore we resolve them, the availabilities
+    // are fetched from the availabilities service.
+    // Declined and suspended assignments are ignored as they do not book the referee (anymore).
+    // Violations are not returned as an error but collected into a report, so that the caller can
+    // see which assignment violates which rule.
+
+    let assignments = assignment_repo.get_all(tx_ctx).await?;
+
+    // NOTE: the same fixture is referenced by multiple assignments, therefore we resolve each only once
+    let mut fixtures: HashMap<FixtureId, FixtureDTO> = HashMap::new();
+    for assignment in assignments.iter() {
+        if fixtures.contains_key(&assignment.fixture_id()) {
+            continue;
+        }
+
+        let fixture = fixture_resolver
+            .resolve(&assignment.fixture_id())
+            .await
+            .expect(&format!(
+                "Fixture {} not found when validating assignments",
+                assignment.fixture_id().0
+            ));
+        fixtures.insert(assignment.fixture_id(), fixture);
+    }
 
-    Ok("Assignments validated".to_string())
+    let mut referees: HashMap<RefereeId, RefereeDTO> = HashMap::new();
+    let mut availabilities: HashMap<RefereeId, Vec<FixtureIdDTO>> = HashMap::new();
+    for assignment in assignments.iter().filter(|a| a.is_staged()) {
+        if availabilities.contains_key(&assignment.referee_id()) {
+            continue;
+        }
+
+        let referee = referee_resolver
+            .resolve(&assignment.referee_id())
+            .await
+            .expect(&format!(
+                "Referee {} not found when validating assignments",
+                assignment.referee_id().0
+            ));
+        let referee_availabilities = availability_resolver
+            .resolve(&assignment.referee_id())
+            .await?;
+        availabilities.insert(assignment.referee_id(), referee_availabilities);
+        referees.insert(assignment.referee_id(), referee);
+    }
+
+    let mut violations: Vec<AssignmentValidationViolationDTO> = Vec::new();
+
+    for assignment in assignments.iter() {
+        if !assignment.is_staged() {
+            continue;
+        }
+
+        let fixture = fixtures.get(&assignment.fixture_id()).unwrap();
+
+        if fixture.status != FixtureStatusDTO::Scheduled {
+            violations.push(violation(
+                assignment,
+                AssignmentValidationRuleDTO::FixtureNotScheduled,
+                AssignmentValidationSeverityDTO::Error,
+                format!(
+                    "Fixture {} is not scheduled for referee {}",
+                    fixture.id.0,
+                    assignment.referee_id().0
+                ),
+            ));
+        }
+
+        if fixture.season.closed {
+            violations.push(violation(
+                assignment,
+                AssignmentValidationRuleDTO::FixtureReadOnly,
+                AssignmentValidationSeverityDTO::Error,
+                format!(
+                    "Fixture {} is read-only because its season is closed",
+                    fixture.id.0
+                ),
+            ));
+        }
+
+        let referee = referees.get(&assignment.referee_id()).unwrap();
+        if is_conflict_of_interest(fixture, &referee.club) {
+            violations.push(violation(
+                assignment,
+                AssignmentValidationRuleDTO::RefereeConflictOfInterest,
+                AssignmentValidationSeverityDTO::Error,
+                format!(
+                    "Referee {} has a conflict of interest for fixture {}: their club {} is playing",
+                    referee.id.0, fixture.id.0, referee.club
+                ),
+            ));
+        }
+
+        if !availabilities
+            .get(&assignment.referee_id())
+            .unwrap()
+            .contains(&fixture.id)
+        {
+            violations.push(violation(
+                assignment,
+                AssignmentValidationRuleDTO::RefereeNotAvailable,
+                AssignmentValidationSeverityDTO::Error,
+                format!(
+                    "Referee {} is not available for fixture {}",
+                    assignment.referee_id().0,
+                    fixture.id.0
+                ),
+            ));
+        }
+    }
+
+    let bookings: Vec<RefereeBooking> = assignments
+        .iter()
+        .filter(|a| !a.is_declined() && !a.is_suspended())
+        .map(|a| RefereeBooking {
+            fixture_id: a.fixture_id().into(),
+            referee_id: a.referee_id().into(),
+            slot: fixture_slot(fixtures.get(&a.fixture_id()).unwrap()),
+            staged: a.is_staged(),
+        })
+        .collect();
+    violations.extend(booking_violations(&bookings));
+
+    Ok(AssignmentValidationReportDTO { violations })
+}
+
+pub async fn propose_assignments<TxCtx>(
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    fixtures: Vec<FixtureDTO>,
+    referees: Vec<RefereeDTO>,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    availability_resolver: &impl AvailabilityResolver<Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<AssignmentStagingDTO>, String> {
+    // NOTE: this is a simple greedy solver and not an optimal one: it goes through the scheduled fixtures
+    // in the date range ordered by date and fills the open referee roles with the available referee who
+    // has the fewest assignments so far, skipping referees that would end up in a same-time or travel
+    // conflict, that are under-qualified for the role, or that have a conflict of interest. Existing
+    // staged and committed assignments are left untouched and are taken into account.
+    // Fixtures and referees are owned by other services, therefore the caller passes them in.
+
+    let fixtures: HashMap<FixtureId, FixtureDTO> =
+        fixtures.into_iter().map(|f| (f.id.into(), f)).collect();
+    let assignments = assignment_repo.get_all(tx_ctx).await?;
+
+    let mut availabilities: HashMap<RefereeId, Vec<FixtureIdDTO>> = HashMap::new();
+    for referee in referees.iter() {
+        let referee_availabilities = availability_resolver.resolve(&referee.id.into()).await?;
+        availabilities.insert(referee.id.into(), referee_availabilities);
+    }
+
+    // NOTE: the fixtures each referee is booked for, both by existing and by proposed assignments
+    let mut bookings: HashMap<RefereeId, Vec<FixtureId>> = HashMap::new();
+    for assignment in assignments
+        .iter()
+        .filter(|a| !a.is_declined() && !a.is_suspended())
+    {
+        bookings
+            .entry(assignment.referee_id())
+            .or_default()
+            .push(assignment.fixture_id());
+    }
+
+    let mut fixtures_in_range: Vec<&FixtureDTO> = fixtures
+        .values()
+        .filter(|f| {
+            f.status == FixtureStatusDTO::Scheduled
+                && !f.season.closed
+                && f.date >= from
+                && f.date <= to
+        })
+        .collect();
+    fixtures_in_range.sort_by_key(|f| f.date);
+
+    let mut proposal: Vec<AssignmentStagingDTO> = Vec::new();
+
+    for fixture in fixtures_in_range {
+        for role in [AssignmentRefereeRole::First, AssignmentRefereeRole::Second] {
+            let role_filled = match role {
+                AssignmentRefereeRole::First => fixture.first_referee.is_some(),
+                AssignmentRefereeRole::Second => fixture.second_referee.is_some(),
+            } || assignments.iter().any(|a| {
+                a.fixture_id() == fixture.id.into()
+                    && a.referee_role() == role
+                    && !a.is_declined()
+                    && !a.is_suspended()
+            });
+            if role_filled {
+                continue;
+            }
+
+            let candidate = referees
+                .iter()
+                .filter(|r| {
+                    availabilities
+                        .get(&r.id.into())
+                        .unwrap()
+                        .contains(&fixture.id)
+                })
+                .filter(|r| is_qualified(fixture, r, role))
+                .filter(|r| !is_conflict_of_interest(fixture, &r.club))
+                // NOTE: a referee who declined the fixture is not proposed for it again, neither is one
+                // whose assignment to it is suspended, as it is staged again once the fixture is rescheduled
+                .filter(|r| {
+                    !assignments.iter().any(|a| {
+                        (a.is_declined() || a.is_suspended())
+                            && a.fixture_id() == fixture.id.into()
+                            && a.referee_id() == r.id.into()
+                    })
+                })
+                .filter(|r| {
+                    !bookings.get(&r.id.into()).is_some_and(|booked| {
+                        booked.iter().any(|booked_fixture_id| {
+                            *booked_fixture_id == fixture.id.into()
+                                || fixtures
+                                    .get(booked_fixture_id)
+                                    .is_some_and(|f| is_in_conflict(fixture, f))
+                        })
+                    })
+                })
+                .min_by_key(|r| bookings.get(&r.id.into()).map_or(0, |booked| booked.len()));
+
+            if let Some(referee) = candidate {
+                proposal.push(AssignmentStagingDTO {
+                    fixture_id: fixture.id,
+                    referee_id: referee.id,
+                    referee_role: role.into(),
+                });
+                bookings
+                    .entry(referee.id.into())
+                    .or_default()
+                    .push(fixture.id.into());
+            }
+        }
+    }
+
+    Ok(proposal)
+}
+
+pub async fn accept_assignment_proposal<TxCtx>(
+    proposal: &[AssignmentStagingDTO],
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    fixture_resolver: &impl FixtureResolver<Error = String>,
+    referee_resolver: &impl RefereeResolver<Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<AssignmentDTO>, String> {
+    // NOTE: the proposal is accepted wholesale, that is all assignments are staged within the same TX
+    let mut assignments = Vec::new();
+    for assignment_staging in proposal {
+        let assignment = stage_assignment(
+            assignment_staging,
+            assignment_repo,
+            fixture_resolver,
+            referee_resolver,
+            tx_ctx,
+        )
+        .await?;
+        assignments.push(assignment);
+    }
+
+    Ok(assignments)
+}
+
+pub async fn flag_conflicts_of_interest<TxCtx>(
+    referee_id: RefereeId,
+    club: &str,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    fixture_resolver: &impl FixtureResolver<Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<AssignmentDTO>, String> {
+    // NOTE: when a referee changes their club, committed assignments might now be in a conflict of interest.
+    // We do not remove these assignments automatically but flag them, so that the admin can review them.
+    // The new club is carried by the RefereeClubChanged event, therefore we do not need to resolve the referee.
+    let assignments = assignment_repo.get_all(tx_ctx).await?;
+
+    let mut flagged_assignments = Vec::new();
+    for mut assignment in assignments {
+        if assignment.referee_id() != referee_id || !assignment.is_committed() {
+            continue;
+        }
+
+        let fixture = fixture_resolver
+            .resolve(&assignment.fixture_id())
+            .await
+            .expect(&format!(
+                "Fixture {} not found when flagging conflicts of interest",
+                assignment.fixture_id().0
+            ));
+
+        if is_conflict_of_interest(&fixture, club) {
+            assignment.flag_for_review(&format!(
+                "Conflict of interest: club {} of the referee is playing",
+                club
+            ));
+            assignment_repo.save(&assignment, tx_ctx).await?;
+            flagged_assignments.push(assignment.into());
+        }
+    }
+
+    Ok(flagged_assignments)
+}
+
+/// Re-validates the committed assignments of a fixture whose date or venue changed. Assignments which
+/// became invalid are flagged for review instead of being removed, so that the admin can resolve them.
+/// Returns the flagged assignments, which might include assignments of other fixtures the referee is now
+/// double-booked with.
+pub async fn review_assignments_of_changed_fixture<TxCtx>(
+    fixture_id: FixtureId,
+    change: FixtureChange,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    fixture_resolver: &impl FixtureResolver<Error = String>,
+    availability_resolver: &impl AvailabilityResolver<Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<AssignmentDTO>, String> {
+    let assignments = assignment_repo.get_all(tx_ctx).await?;
+    let fixture = fixture_resolver.resolve(&fixture_id).await?;
+
+    // NOTE: an assignment might be flagged more than once, e.g. the other assignment of a double-booked pair,
+    // therefore only the latest copy per fixture and referee is kept, saved and returned
+    let mut flagged_assignments = Vec::new();
+    for assignment in assignments
+        .iter()
+        .filter(|a| a.fixture_id() == fixture_id && a.is_committed())
+    {
+        let mut reasons = Vec::new();
+
+        // NOTE: availabilities are declared per fixture, therefore after a date change we cannot tell
+        // whether the referee is still available and the admin has to check back with them
+        if change == FixtureChange::Date {
+            reasons.push(format!(
+                "Fixture was moved to {}, the referee declared their availability for the old date",
+                fixture.date.format("%Y-%m-%d %H:%M")
+            ));
+        }
+
+        let availabilities = availability_resolver
+            .resolve(&assignment.referee_id())
+            .await?;
+        if !availabilities.contains(&fixture.id) {
+            reasons.push("Referee is not available for the fixture anymore".to_string());
+        }
+
+        for other_assignment in assignments.iter().filter(|a| {
+            a.referee_id() == assignment.referee_id()
+                && a.fixture_id() != fixture_id
+                && a.is_committed()
+        }) {
+            let other_fixture = fixture_resolver
+                .resolve(&other_assignment.fixture_id())
+                .await?;
+            if !is_in_conflict(&fixture, &other_fixture) {
+                continue;
+            }
+
+            reasons.push(format!(
+                "Referee is double-booked with fixture {}",
+                other_fixture.id.0
+            ));
+
+            flag_for_review(
+                other_assignment,
+                &format!("Referee is double-booked with fixture {}", fixture.id.0),
+                &mut flagged_assignments,
+            );
+        }
+
+        for reason in reasons.iter() {
+            flag_for_review(assignment, reason, &mut flagged_assignments);
+        }
+    }
+
+    for assignment in flagged_assignments.iter() {
+        assignment_repo.save(assignment, tx_ctx).await?;
+    }
+
+    Ok(flagged_assignments.into_iter().map(|a| a.into()).collect())
+}
+
+/// Flags the flagged copy of the assignment if there is one already, otherwise adds a flagged copy
+fn flag_for_review(
+    assignment: &Assignment,
+    reason: &str,
+    flagged_assignments: &mut Vec<Assignment>,
+) {
+    match flagged_assignments.iter_mut().find(|a| {
+        a.fixture_id() == assignment.fixture_id() && a.referee_id() == assignment.referee_id()
+    }) {
+        Some(flagged_assignment) => flagged_assignment.flag_for_review(reason),
+        None => {
+            let mut flagged_assignment = assignment.clone();
+            flagged_assignment.flag_for_review(reason);
+            flagged_assignments.push(flagged_assignment);
+        }
+    }
+}
+
+/// Returns the committed assignments which were flagged for review and need the attention of the admin
+pub async fn get_assignments_for_review<TxCtx>(
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<AssignmentDTO>, String> {
+    let assignments = assignment_repo.get_all(tx_ctx).await?;
+
+    Ok(assignments
+        .into_iter()
+        .filter(|a| a.is_committed() && a.needs_review())
+        .map(|a| a.into())
+        .collect())
+}
+
+pub async fn suspend_staged_assignments_for_fixture<TxCtx>(
+    fixture_id: FixtureId,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<AssignmentDTO>, String> {
+    // NOTE: when a fixture is postponed, its staged assignments must not be committed anymore until
+    // the fixture is rescheduled and the assignments are staged again. Committed assignments are left untouched.
+    let staged_assignments = assignment_repo.find_all_staged(tx_ctx).await?;
+
+    let mut suspended_assignments = Vec::new();
+    for mut assignment in staged_assignments {
+        if assignment.fixture_id() != fixture_id {
+            continue;
+        }
+
+        assignment.suspend();
+        assignment_repo.save(&assignment, tx_ctx).await?;
+        suspended_assignments.push(assignment.into());
+    }
+
+    Ok(suspended_assignments)
+}
+
+pub async fn resume_suspended_assignments_for_fixture<TxCtx>(
+    fixture_id: FixtureId,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<AssignmentDTO>, String> {
+    // NOTE: the availabilities service resumes the suspended availabilities of the fixture on the same event,
+    // so the resumed assignments are validated against the availabilities declared for the original date.
+    let assignments = assignment_repo.get_all(tx_ctx).await?;
+
+    let mut resumed_assignments = Vec::new();
+    for mut assignment in assignments
+        .into_iter()
+        .filter(|a| a.fixture_id() == fixture_id && a.is_suspended())
+    {
+        assignment.resume();
+        assignment_repo.save(&assignment, tx_ctx).await?;
+        resumed_assignments.push(assignment.into());
+    }
+
+    Ok(resumed_assignments)
+}
+
+/// Voids all assignments of a cancelled fixture: staged and suspended assignments are removed and
+/// committed ones are released by unassigning their referees. Declined assignments are kept, so that
+/// the history of the fixture stays visible. Returns the voided assignments.
+pub async fn void_assignments_for_fixture<TxCtx>(
+    fixture_id: FixtureId,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<AssignmentDTO>, String> {
+    let assignments = assignment_repo.get_all(tx_ctx).await?;
+
+    let mut voided_assignments = Vec::new();
+    for assignment in assignments
+        .into_iter()
+        .filter(|a| a.fixture_id() == fixture_id && !a.is_declined())
+    {
+        if assignment.is_committed() {
+            // NOTE: the fixture is not validated, the cached one might be stale or its commit saga step
+            // still pending, and the cancellation has to void all of its assignments anyway
+            remove_referee_from_fixture(&assignment, domain_event_repo, tx_ctx).await?;
+        } else {
+            domain_event_repo
+                .store(
+                    DomainEvent::StagedAssignmentRemoved {
+                        fixture_id,
+                        referee_id: assignment.referee_id(),
+                    },
+                    tx_ctx,
+                )
+                .await?;
+        }
+
+        assignment_repo.delete(&assignment, tx_ctx).await?;
+        voided_assignments.push(assignment.into());
+    }
+
+    Ok(voided_assignments)
+}
+
+pub async fn remove_staged_assignments_of_referee<TxCtx>(
+    referee_id: RefereeId,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<AssignmentDTO>, String> {
+    // NOTE: an archived referee can no longer be assigned, therefore their staged assignments are removed.
+    // Committed assignments are left untouched, they are kept for the history of the fixtures.
+    let staged_assignments = assignment_repo.find_all_staged(tx_ctx).await?;
+
+    let mut removed_assignments = Vec::new();
+    for assignment in staged_assignments {
+        if assignment.referee_id() != referee_id {
+            continue;
+        }
+
+        assignment_repo.delete(&assignment, tx_ctx).await?;
+        removed_assignments.push(assignment.into());
+    }
+
+    Ok(removed_assignments)
+}
+
+/// Frees the role of the referee of a committed assignment in its fixture. The fixture is owned by the
+/// fixtures service, therefore this is done by emitting the corresponding domain event.
+async fn unassign_referee<TxCtx>(
+    assignment: &Assignment,
+    fixture_resolver: &impl FixtureResolver<Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    let fixture = fixture_resolver
+        .resolve(&assignment.fixture_id())
+        .await
+        .expect(&format!(
+            "Fixture {:?} not found when unassigning referee",
+            assignment.fixture_id().0
+        ));
+
+    if fixture.season.closed {
+        return Err(format!(
+            "Fixture {} is read-only because its season is closed",
+            fixture.id.0
+        ));
+    }
+
+    match assignment.referee_role() {
+        AssignmentRefereeRole::First => {
+            if fixture.first_referee.is_none() {
+                return Err(format!(
+                    "First referee not assigned for fixture {}",
+                    fixture.id.0
+                ));
+            }
+
+            if fixture.first_referee.unwrap().id != assignment.referee_id().into() {
+                return Err(format!(
+                    "First referee not assigned for fixture {}",
+                    fixture.id.0
+                ));
+            }
+        }
+        AssignmentRefereeRole::Second => {
+            if fixture.second_referee.is_none() {
+                return Err(format!(
+                    "Second referee not assigned for fixture {}",
+                    fixture.id.0
+                ));
+            }
+
+            if fixture.second_referee.unwrap().id != assignment.referee_id().into() {
+                return Err(format!(
+                    "Second referee not assigned for fixture {}",
+                    fixture.id.0
+                ));
+            }
+        }
+    }
+
+    remove_referee_from_fixture(assignment, domain_event_repo, tx_ctx).await
+}
+
+/// Emits the domain event which frees the role of the referee of the assignment in its fixture
+async fn remove_referee_from_fixture<TxCtx>(
+    assignment: &Assignment,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    let event = match assignment.referee_role() {
+        AssignmentRefereeRole::First => DomainEvent::FirstRefereeAssignmentRemoved {
+            fixture_id: assignment.fixture_id(),
+            referee_id: assignment.referee_id(),
+        },
+        AssignmentRefereeRole::Second => DomainEvent::SecondRefereeAssignmentRemoved {
+            fixture_id: assignment.fixture_id(),
+            referee_id: assignment.referee_id(),
+        },
+    };
+
+    domain_event_repo.store(event, tx_ctx).await
+}
+
+/// A referee is qualified for a role in a fixture if their level is at least the level required for it
+fn is_qualified(fixture: &FixtureDTO, referee: &RefereeDTO, role: AssignmentRefereeRole) -> bool {
+    let min_level = match role {
+        AssignmentRefereeRole::First => fixture.first_referee_min_level,
+        AssignmentRefereeRole::Second => fixture.second_referee_min_level,
+    };
+    referee.qualification_level >= min_level
+}
+
+/// A referee has a conflict of interest if their club is playing in the fixture
+fn is_conflict_of_interest(fixture: &FixtureDTO, club: &str) -> bool {
+    fixture.team_home.club == club || fixture.team_away.club == club
+}
+
+/// A referee cannot officiate two fixtures which are at the same time, or close to each other at different venues
+fn is_in_conflict(fixture: &FixtureDTO, other: &FixtureDTO) -> bool {
+    fixture_slot(fixture).is_in_conflict_with(&fixture_slot(other))
+}
+
+fn fixture_slot(fixture: &FixtureDTO) -> FixtureSlot {
+    FixtureSlot {
+        date: fixture.date,
+        venue_id: fixture.venue.id,
+    }
+}
+
+fn violation(
+    assignment: &Assignment,
+    rule: AssignmentValidationRuleDTO,
+    severity: AssignmentValidationSeverityDTO,
+    message: String,
+) -> AssignmentValidationViolationDTO {
+    AssignmentValidationViolationDTO {
+        fixture_id: assignment.fixture_id().into(),
+        referee_id: assignment.referee_id().into(),
+        rule,
+        severity,
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, Utc};
+    use microservices_shared::{
+        domain_ids::{FixtureId, RefereeId},
+        resolvers::traits::{MockAvailabilityResolver, MockFixtureResolver, MockRefereeResolver},
+    };
+    use restinterface::{
+        AssignmentValidationReportDTO, AssignmentValidationRuleDTO,
+        AssignmentValidationSeverityDTO, FixtureDTO, FixtureIdDTO, FixtureStatusDTO, LeagueDTO,
+        LeagueIdDTO, RefereeDTO, SeasonDTO, SeasonIdDTO, TeamDTO, TeamIdDTO, VenueDTO, VenueIdDTO,
+    };
+    use uuid::Uuid;
+
+    use crate::{
+        application::assignment_services::{
+            review_assignments_of_changed_fixture, validate_assignments, FixtureChange,
+        },
+        domain::{
+            aggregates::assignment::{Assignment, AssignmentRefereeRole, AssignmentStatus},
+            repositories::assignment_repo::MockAssignmentRepository,
+        },
+    };
+
+    #[tokio::test]
+    async fn test_given_staged_assignment_when_referee_not_available_then_violation_reported() {
+        let fixture = test_fixture(Utc::now(), VenueIdDTO(Uuid::new_v4()));
+        let referee_id = RefereeId::from(Uuid::new_v4());
+        let assignment =
+            Assignment::staged(fixture.id.into(), referee_id, AssignmentRefereeRole::First);
+
+        // NOTE: the referee declared their availability for another fixture only
+        let report = run_validation(
+            vec![fixture],
+            referee_id,
+            vec![assignment],
+            vec![FixtureIdDTO(Uuid::new_v4())],
+        )
+        .await;
+
+        assert!(report.has_errors());
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(
+            report.violations[0].rule,
+            AssignmentValidationRuleDTO::RefereeNotAvailable
+        );
+    }
+
+    #[tokio::test]
+    async fn test_given_committed_assignment_at_same_time_when_staging_then_double_booking_reported(
+    ) {
+        let now = Utc::now();
+        let fixture = test_fixture(now, VenueIdDTO(Uuid::new_v4()));
+        let other_fixture = test_fixture(now, VenueIdDTO(Uuid::new_v4()));
+        let referee_id = RefereeId::from(Uuid::new_v4());
+        let assignment =
+            Assignment::staged(fixture.id.into(), referee_id, AssignmentRefereeRole::First);
+        let other_assignment = Assignment::new(
+            other_fixture.id.into(),
+            referee_id,
+            AssignmentRefereeRole::Second,
+            AssignmentStatus::Committed,
+            None,
+        );
+        let available = vec![fixture.id, other_fixture.id];
+
+        let report = run_validation(
+            vec![fixture.clone(), other_fixture],
+            referee_id,
+            vec![assignment, other_assignment],
+            available,
+        )
+        .await;
+
+        assert!(report.has_errors());
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(
+            report.violations[0].rule,
+            AssignmentValidationRuleDTO::RefereeAssignedAtSameTime
+        );
+        assert_eq!(report.violations[0].fixture_id, fixture.id);
+    }
+
+    #[tokio::test]
+    async fn test_given_staged_assignments_at_close_fixtures_when_validating_then_travel_conflict_reported_once(
+    ) {
+        let now = Utc::now();
+        let venue_id = VenueIdDTO(Uuid::new_v4());
+        let fixture = test_fixture(now, venue_id);
+        let same_venue_fixture = test_fixture(now + Duration::minutes(30), venue_id);
+        let other_venue_fixture =
+            test_fixture(now + Duration::minutes(30), VenueIdDTO(Uuid::new_v4()));
+        let referee_id = RefereeId::from(Uuid::new_v4());
+
+        // a close fixture at the same venue does not require travelling, but is reported as a warning
+        let report = run_validation(
+            vec![fixture.clone(), same_venue_fixture.clone()],
+            referee_id,
+            vec![
+                Assignment::staged(fixture.id.into(), referee_id, AssignmentRefereeRole::First),
+                Assignment::staged(
+                    same_venue_fixture.id.into(),
+                    referee_id,
+                    AssignmentRefereeRole::First,
+                ),
+            ],
+            vec![fixture.id, same_venue_fixture.id],
+        )
+        .await;
+        assert!(!report.has_errors());
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(
+            report.violations[0].severity,
+            AssignmentValidationSeverityDTO::Warning
+        );
+
+        // a close fixture at a different venue cannot be reached in time
+        let report = run_validation(
+            vec![fixture.clone(), other_venue_fixture.clone()],
+            referee_id,
+            vec![
+                Assignment::staged(fixture.id.into(), referee_id, AssignmentRefereeRole::First),
+                Assignment::staged(
+                    other_venue_fixture.id.into(),
+                    referee_id,
+                    AssignmentRefereeRole::First,
+                ),
+            ],
+            vec![fixture.id, other_venue_fixture.id],
+        )
+        .await;
+        assert!(report.has_errors());
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(
+            report.violations[0].rule,
+            AssignmentValidationRuleDTO::RefereeAssignedToCloseFixture
+        );
+    }
+
+    #[tokio::test]
+    async fn test_given_referee_double_booked_twice_when_fixture_changed_then_each_assignment_flagged_once(
+    ) {
+        let now = Utc::now();
+        let fixture = test_fixture(now, VenueIdDTO(Uuid::new_v4()));
+        let same_time_fixture = test_fixture(now, VenueIdDTO(Uuid::new_v4()));
+        let close_fixture = test_fixture(now + Duration::minutes(30), VenueIdDTO(Uuid::new_v4()));
+        let referee_id = RefereeId::from(Uuid::new_v4());
+
+        let conflict_of_interest = "Conflict of interest: club Club C of the referee is playing";
+        let assignments = vec![
+            Assignment::new(
+                fixture.id.into(),
+                referee_id,
+                AssignmentRefereeRole::First,
+                AssignmentStatus::Committed,
+                None,
+            ),
+            Assignment::new(
+                same_time_fixture.id.into(),
+                referee_id,
+                AssignmentRefereeRole::First,
+                AssignmentStatus::Committed,
+                Some(conflict_of_interest.to_string()),
+            ),
+            Assignment::new(
+                close_fixture.id.into(),
+                referee_id,
+                AssignmentRefereeRole::Second,
+                AssignmentStatus::Committed,
+                None,
+            ),
+        ];
+        let fixtures = [
+            fixture.clone(),
+            same_time_fixture.clone(),
+            close_fixture.clone(),
+        ];
+        let available = vec![fixture.id, same_time_fixture.id, close_fixture.id];
+
+        let mut assignment_repo = MockAssignmentRepository::new();
+        let mut fixture_resolver = MockFixtureResolver::new();
+        let mut availability_resolver = MockAvailabilityResolver::new();
+
+        assignment_repo
+            .expect_get_all()
+            .return_const(Ok(assignments));
+        assignment_repo.expect_save().times(3).return_const(Ok(()));
+        fixture_resolver
+            .expect_resolve()
+            .returning(move |fixture_id: &FixtureId| {
+                fixtures
+                    .iter()
+                    .find(|f| f.id == (*fixture_id).into())
+                    .cloned()
+                    .ok_or("Fixture not found".to_string())
+            });
+        availability_resolver
+            .expect_resolve()
+            .return_const(Ok(available));
+
+        let flagged = review_assignments_of_changed_fixture(
+            fixture.id.into(),
+            FixtureChange::Venue,
+            &assignment_repo,
+            &fixture_resolver,
+            &availability_resolver,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(flagged.len(), 3, "Each assignment should be flagged once");
+        let review_reason = |fixture_id: FixtureIdDTO| {
+            flagged
+                .iter()
+                .find(|a| a.fixture_id == fixture_id)
+                .and_then(|a| a.review_reason.clone())
+                .unwrap()
+        };
+        assert_eq!(
+            review_reason(fixture.id),
+            format!(
+                "Referee is double-booked with fixture {}; Referee is double-booked with fixture {}",
+                same_time_fixture.id.0, close_fixture.id.0
+            )
+        );
+        // the existing reason of the assignment is kept
+        assert_eq!(
+            review_reason(same_time_fixture.id),
+            format!(
+                "{}; Referee is double-booked with fixture {}",
+                conflict_of_interest, fixture.id.0
+            )
+        );
+        assert_eq!(
+            review_reason(close_fixture.id),
+            format!("Referee is double-booked with fixture {}", fixture.id.0)
+        );
+    }
+
+    async fn run_validation(
+        fixtures: Vec<FixtureDTO>,
+        referee_id: RefereeId,
+        assignments: Vec<Assignment>,
+        available: Vec<FixtureIdDTO>,
+    ) -> AssignmentValidationReportDTO {
+        let mut assignment_repo = MockAssignmentRepository::new();
+        let mut fixture_resolver = MockFixtureResolver::new();
+        let mut referee_resolver = MockRefereeResolver::new();
+        let mut availability_resolver = MockAvailabilityResolver::new();
+
+        assignment_repo
+            .expect_get_all()
+            .return_const(Ok(assignments));
+        fixture_resolver
+            .expect_resolve()
+            .returning(move |fixture_id: &FixtureId| {
+                fixtures
+                    .iter()
+                    .find(|f| f.id == (*fixture_id).into())
+                    .cloned()
+                    .ok_or("Fixture not found".to_string())
+            });
+        referee_resolver
+            .expect_resolve()
+            .return_const(Ok(RefereeDTO {
+                id: referee_id.into(),
+                name: "John Doe".to_string(),
+                club: "Club C".to_string(),
+                qualification_level: 0,
+                home: None,
+                archived: false,
+            }));
+        availability_resolver
+            .expect_resolve()
+            .return_const(Ok(available));
+
+        validate_assignments(
+            &assignment_repo,
+            &fixture_resolver,
+            &referee_resolver,
+            &availability_resolver,
+            &mut (),
+        )
+        .await
+        .unwrap()
+    }
+
+    fn test_fixture(date: DateTime<Utc>, venue_id: VenueIdDTO) -> FixtureDTO {
+        FixtureDTO {
+            id: FixtureIdDTO(Uuid::new_v4()),
+            league: LeagueDTO {
+                id: LeagueIdDTO(Uuid::new_v4()),
+                name: "League A".to_string(),
+            },
+            season: SeasonDTO {
+                id: SeasonIdDTO(Uuid::new_v4()),
+                name: "Season 2024/25".to_string(),
+                closed: false,
+            },
+            team_home: TeamDTO {
+                id: TeamIdDTO(Uuid::new_v4()),
+                name: "Team A".to_string(),
+                club: "Club A".to_string(),
+                archived: false,
+            },
+            team_away: TeamDTO {
+                id: TeamIdDTO(Uuid::new_v4()),
+                name: "Team B".to_string(),
+                club: "Club B".to_string(),
+                archived: false,
+            },
+            venue: VenueDTO {
+                id: venue_id,
+                name: "Venue A".to_string(),
+                street: "Street".to_string(),
+                zip: "12345".to_string(),
+                city: "City".to_string(),
+                telephone: None,
+                email: None,
+                location: None,
+                archived: false,
+            },
+            date,
+            status: FixtureStatusDTO::Scheduled,
+            result: None,
+            first_referee: None,
+            second_referee: None,
+            first_referee_min_level: 0,
+            second_referee_min_level: 0,
+        }
+    }
 }
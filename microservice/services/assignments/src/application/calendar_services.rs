@@ -0,0 +1,357 @@
+use chrono::{DateTime, Duration, Utc};
+use microservices_shared::{
+    domain_ids::RefereeId,
+    resolvers::traits::{FixtureResolver, RefereeResolver},
+};
+use restinterface::{FixtureDTO, FixtureStatusDTO};
+
+use crate::domain::{
+    aggregates::assignment::{Assignment, AssignmentRefereeRole},
+    repositories::assignment_repo::AssignmentRepository,
+};
+
+// NOTE: fixtures only have a start, we assume a volleyball match takes two hours
+const FIXTURE_DURATION_HOURS: i64 = 2;
+
+/// Renders all committed assignments of the referee as an RFC 5545 calendar.
+/// The UID of an event is derived from the fixture and the referee only, so a
+/// calendar client updates the existing event when the date of a fixture changes.
+pub async fn get_referee_calendar<TxCtx>(
+    referee_id: RefereeId,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    fixture_resolver: &impl FixtureResolver<Error = String>,
+    referee_resolver: &impl RefereeResolver<Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<String, String> {
+    let referee = referee_resolver.resolve(&referee_id).await?;
+
+    let assignments = assignment_repo
+        .find_all_committed_by_referee(referee_id, tx_ctx)
+        .await?;
+
+    let now = Utc::now();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//rust-ddd//referee assignments//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!(
+            "X-WR-CALNAME:{}",
+            escape_text(&format!("Assignments {}", referee.name))
+        ),
+    ];
+
+    for assignment in assignments.iter() {
+        let fixture = fixture_resolver.resolve(&assignment.fixture_id()).await?;
+
+        lines.extend(render_event(assignment, &fixture, now));
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    Ok(lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<String>>()
+        .join(""))
+}
+
+fn render_event(assignment: &Assignment, fixture: &FixtureDTO, now: DateTime<Utc>) -> Vec<String> {
+    let role = match assignment.referee_role() {
+        AssignmentRefereeRole::First => "First referee",
+        AssignmentRefereeRole::Second => "Second referee",
+    };
+    let venue = &fixture.venue;
+    let status = if fixture.status == FixtureStatusDTO::Cancelled {
+        "CANCELLED"
+    } else {
+        "CONFIRMED"
+    };
+
+    vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}", event_uid(assignment)),
+        format!("DTSTAMP:{}", format_date_time(&now)),
+        format!("DTSTART:{}", format_date_time(&fixture.date)),
+        format!(
+            "DTEND:{}",
+            format_date_time(&(fixture.date + Duration::hours(FIXTURE_DURATION_HOURS)))
+        ),
+        format!(
+            "SUMMARY:{}",
+            escape_text(&format!(
+                "{} vs {} ({})",
+                fixture.team_home.name, fixture.team_away.name, role
+            ))
+        ),
+        format!(
+            "LOCATION:{}",
+            escape_text(&format!(
+                "{}, {}, {} {}",
+                venue.name, venue.street, venue.zip, venue.city
+            ))
+        ),
+        format!(
+            "DESCRIPTION:{}",
+            escape_text(&format!(
+                "{} {}\n{}",
+                fixture.league.name, fixture.season.name, role
+            ))
+        ),
+        format!("STATUS:{}", status),
+        "END:VEVENT".to_string(),
+    ]
+}
+
+fn event_uid(assignment: &Assignment) -> String {
+    format!(
+        "{}-{}@rust-ddd",
+        assignment.fixture_id().0,
+        assignment.referee_id().0
+    )
+}
+
+fn format_date_time(date: &DateTime<Utc>) -> String {
+    date.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escapes the characters which have a special meaning in TEXT values
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Content lines must not be longer than 75 octets, longer lines continue on
+/// the next line starting with a space. Every line ends with CRLF.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut line_length = 0;
+
+    for c in line.chars() {
+        if line_length + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            line_length = 1;
+        }
+        folded.push(c);
+        line_length += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use microservices_shared::{
+        domain_ids::{FixtureId, RefereeId},
+        resolvers::traits::{MockFixtureResolver, MockRefereeResolver},
+    };
+    use mockall::predicate::eq;
+    use restinterface::{
+        FixtureDTO, FixtureIdDTO, FixtureStatusDTO, LeagueDTO, LeagueIdDTO, RefereeDTO, SeasonDTO,
+        SeasonIdDTO, TeamDTO, TeamIdDTO, VenueDTO, VenueIdDTO,
+    };
+    use uuid::Uuid;
+
+    use crate::{
+        application::calendar_services::{escape_text, fold_line, get_referee_calendar},
+        domain::{
+            aggregates::assignment::{Assignment, AssignmentRefereeRole, AssignmentStatus},
+            repositories::assignment_repo::MockAssignmentRepository,
+        },
+    };
+
+    #[test]
+    fn test_given_long_line_when_fold_then_continuation_lines_start_with_space() {
+        let line = format!("DESCRIPTION:{}", "a".repeat(100));
+
+        let folded = fold_line(&line);
+
+        let folded_lines: Vec<&str> = folded.trim_end_matches("\r\n").split("\r\n").collect();
+        assert_eq!(folded_lines.len(), 2);
+        assert_eq!(folded_lines[0].len(), 75);
+        assert!(folded_lines[1].starts_with(' '));
+        assert_eq!(folded.replace("\r\n ", ""), format!("{}\r\n", line));
+    }
+
+    #[test]
+    fn test_given_multibyte_characters_when_fold_then_no_character_is_split() {
+        let line = format!("SUMMARY:{}", "ä".repeat(50));
+
+        let folded = fold_line(&line);
+
+        let folded_lines: Vec<&str> = folded.trim_end_matches("\r\n").split("\r\n").collect();
+        assert!(folded_lines.iter().all(|line| line.len() <= 75));
+        assert_eq!(folded.replace("\r\n ", ""), format!("{}\r\n", line));
+    }
+
+    #[test]
+    fn test_given_short_line_when_fold_then_only_crlf_appended() {
+        assert_eq!(fold_line("BEGIN:VEVENT"), "BEGIN:VEVENT\r\n");
+    }
+
+    #[test]
+    fn test_given_special_characters_when_escape_text_then_escaped() {
+        assert_eq!(
+            escape_text("Hall A, Court 1; back\\side\nEntrance"),
+            "Hall A\\, Court 1\\; back\\\\side\\nEntrance"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_given_committed_assignment_when_get_referee_calendar_then_event_rendered() {
+        let mut referee_resolver = MockRefereeResolver::new();
+        let mut assignment_repo = MockAssignmentRepository::new();
+        let mut fixture_resolver = MockFixtureResolver::new();
+
+        let referee_id = RefereeId::from(Uuid::new_v4());
+        let fixture = test_fixture(FixtureStatusDTO::Scheduled);
+        let assignment = Assignment::new(
+            fixture.id.into(),
+            referee_id,
+            AssignmentRefereeRole::Second,
+            AssignmentStatus::Committed,
+            None,
+        );
+
+        referee_resolver
+            .expect_resolve()
+            .with(eq(referee_id))
+            .return_const(Ok(test_referee(referee_id)));
+        assignment_repo
+            .expect_find_all_committed_by_referee()
+            .with(eq(referee_id), eq(&()))
+            .return_const(Ok(vec![assignment]));
+        fixture_resolver
+            .expect_resolve()
+            .with(eq(FixtureId::from(fixture.id)))
+            .return_const(Ok(fixture.clone()));
+
+        let calendar = get_referee_calendar(
+            referee_id,
+            &assignment_repo,
+            &fixture_resolver,
+            &referee_resolver,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        // the UID line is longer than 75 octets and therefore folded
+        let calendar = calendar.replace("\r\n ", "");
+        let lines: Vec<&str> = calendar.trim_end_matches("\r\n").split("\r\n").collect();
+        assert_eq!(lines.first(), Some(&"BEGIN:VCALENDAR"));
+        assert_eq!(lines.last(), Some(&"END:VCALENDAR"));
+        assert!(lines.contains(&"X-WR-CALNAME:Assignments John Doe"));
+        assert!(lines.contains(&"BEGIN:VEVENT"));
+        assert!(lines.contains(&"END:VEVENT"));
+        assert!(lines.contains(&format!("UID:{}-{}@rust-ddd", fixture.id.0, referee_id.0).as_str()));
+        assert!(lines.contains(&"DTSTART:20241005T183000Z"));
+        assert!(lines.contains(&"DTEND:20241005T203000Z"));
+        assert!(lines.contains(&"SUMMARY:Team A vs Team B (Second referee)"));
+        assert!(lines.contains(&"LOCATION:Sporthalle\\, Nord\\, Street A\\, 12345 City A"));
+        assert!(lines.contains(&"DESCRIPTION:League A Season 2024/25\\nSecond referee"));
+        assert!(lines.contains(&"STATUS:CONFIRMED"));
+    }
+
+    #[tokio::test]
+    async fn test_given_cancelled_fixture_when_get_referee_calendar_then_event_cancelled() {
+        let mut referee_resolver = MockRefereeResolver::new();
+        let mut assignment_repo = MockAssignmentRepository::new();
+        let mut fixture_resolver = MockFixtureResolver::new();
+
+        let referee_id = RefereeId::from(Uuid::new_v4());
+        let fixture = test_fixture(FixtureStatusDTO::Cancelled);
+        let assignment = Assignment::new(
+            fixture.id.into(),
+            referee_id,
+            AssignmentRefereeRole::First,
+            AssignmentStatus::Committed,
+            None,
+        );
+
+        referee_resolver
+            .expect_resolve()
+            .return_const(Ok(test_referee(referee_id)));
+        assignment_repo
+            .expect_find_all_committed_by_referee()
+            .return_const(Ok(vec![assignment]));
+        fixture_resolver
+            .expect_resolve()
+            .return_const(Ok(fixture.clone()));
+
+        let calendar = get_referee_calendar(
+            referee_id,
+            &assignment_repo,
+            &fixture_resolver,
+            &referee_resolver,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        let calendar = calendar.replace("\r\n ", "");
+        let lines: Vec<&str> = calendar.trim_end_matches("\r\n").split("\r\n").collect();
+        assert!(lines.contains(&"SUMMARY:Team A vs Team B (First referee)"));
+        assert!(lines.contains(&"STATUS:CANCELLED"));
+    }
+
+    fn test_referee(referee_id: RefereeId) -> RefereeDTO {
+        RefereeDTO {
+            id: referee_id.into(),
+            name: "John Doe".to_string(),
+            club: "Club C".to_string(),
+            qualification_level: 0,
+            home: None,
+            archived: false,
+        }
+    }
+
+    fn test_fixture(status: FixtureStatusDTO) -> FixtureDTO {
+        FixtureDTO {
+            id: FixtureIdDTO(Uuid::new_v4()),
+            league: LeagueDTO {
+                id: LeagueIdDTO(Uuid::new_v4()),
+                name: "League A".to_string(),
+            },
+            season: SeasonDTO {
+                id: SeasonIdDTO(Uuid::new_v4()),
+                name: "Season 2024/25".to_string(),
+                closed: false,
+            },
+            team_home: TeamDTO {
+                id: TeamIdDTO(Uuid::new_v4()),
+                name: "Team A".to_string(),
+                club: "Club A".to_string(),
+                archived: false,
+            },
+            team_away: TeamDTO {
+                id: TeamIdDTO(Uuid::new_v4()),
+                name: "Team B".to_string(),
+                club: "Club B".to_string(),
+                archived: false,
+            },
+            venue: VenueDTO {
+                id: VenueIdDTO(Uuid::new_v4()),
+                name: "Sporthalle, Nord".to_string(),
+                street: "Street A".to_string(),
+                zip: "12345".to_string(),
+                city: "City A".to_string(),
+                telephone: None,
+                email: None,
+                location: None,
+                archived: false,
+            },
+            date: Utc.with_ymd_and_hms(2024, 10, 5, 18, 30, 0).unwrap(),
+            status,
+            result: None,
+            first_referee: None,
+            second_referee: None,
+            first_referee_min_level: 0,
+            second_referee_min_level: 0,
+        }
+    }
+}
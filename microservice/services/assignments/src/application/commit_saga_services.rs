@@ -0,0 +1,142 @@
+use microservices_shared::{
+    domain_event_repo::DomainEventOutboxRepository,
+    domain_events::DomainEvent,
+    domain_ids::{CommitSagaId, FixtureId, RefereeId},
+};
+use restinterface::CommitSagaDTO;
+
+use crate::domain::{
+    aggregates::{
+        assignment::AssignmentRefereeRole,
+        commit_saga::{CommitSaga, CommitSagaStep},
+    },
+    repositories::{assignment_repo::AssignmentRepository, commit_saga_repo::CommitSagaRepository},
+};
+
+pub async fn get_commit_sagas<TxCtx>(
+    commit_saga_repo: &impl CommitSagaRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<CommitSagaDTO>, String> {
+    let sagas = commit_saga_repo.get_all(tx_ctx).await?;
+    Ok(sagas.into_iter().map(|s| s.into()).collect())
+}
+
+pub async fn get_commit_saga<TxCtx>(
+    saga_id: CommitSagaId,
+    commit_saga_repo: &impl CommitSagaRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Option<CommitSagaDTO>, String> {
+    let saga = commit_saga_repo.find_by_id(saga_id, tx_ctx).await?;
+    Ok(saga.map(|s| s.into()))
+}
+
+/// The fixtures service has assigned the referee to the fixture. If the saga is already
+/// compensating, the referee is removed from the fixture again right away.
+pub async fn accept_referee_assignment<TxCtx>(
+    saga_id: CommitSagaId,
+    fixture_id: FixtureId,
+    referee_id: RefereeId,
+    commit_saga_repo: &impl CommitSagaRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<CommitSagaDTO, String> {
+    let mut saga = find_commit_saga(saga_id, commit_saga_repo, tx_ctx).await?;
+
+    saga.accept_step(fixture_id, referee_id)?;
+
+    if saga.is_compensating() {
+        // NOTE: the assignment itself was already reverted to staged when the saga started compensating
+        let step = saga
+            .step(fixture_id, referee_id)
+            .expect("Accepted step not found in commit saga");
+        remove_referee_from_fixture(step, domain_event_repo, tx_ctx).await?;
+    }
+
+    commit_saga_repo.save(&saga, tx_ctx).await?;
+
+    Ok(saga.into())
+}
+
+/// The fixtures service has refused to assign the referee to the fixture. The first rejection
+/// compensates the whole saga: all of its assignments are reverted to staged and referees of
+/// already accepted steps are removed from their fixtures again.
+pub async fn reject_referee_assignment<TxCtx>(
+    saga_id: CommitSagaId,
+    fixture_id: FixtureId,
+    referee_id: RefereeId,
+    reason: &str,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    commit_saga_repo: &impl CommitSagaRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<CommitSagaDTO, String> {
+    let mut saga = find_commit_saga(saga_id, commit_saga_repo, tx_ctx).await?;
+    let was_running = saga.is_running();
+
+    saga.reject_step(fixture_id, referee_id, reason)?;
+
+    if was_running {
+        for step in saga.steps() {
+            let reverted = revert_assignment_to_staged(step, assignment_repo, tx_ctx).await?;
+
+            // NOTE: removing or declining an assignment already removes the referee from the fixture
+            if reverted && step.is_accepted() {
+                remove_referee_from_fixture(step, domain_event_repo, tx_ctx).await?;
+            }
+        }
+    }
+
+    commit_saga_repo.save(&saga, tx_ctx).await?;
+
+    Ok(saga.into())
+}
+
+async fn find_commit_saga<TxCtx>(
+    saga_id: CommitSagaId,
+    commit_saga_repo: &impl CommitSagaRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<CommitSaga, String> {
+    commit_saga_repo
+        .find_by_id(saga_id, tx_ctx)
+        .await?
+        .ok_or(format!("Commit saga {} not found", saga_id.0))
+}
+
+/// Returns false if the assignment was removed or declined in the meantime and therefore not reverted
+async fn revert_assignment_to_staged<TxCtx>(
+    step: &CommitSagaStep,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<bool, String> {
+    let assignment = assignment_repo
+        .find_by_fixture_and_referee(step.fixture_id(), step.referee_id(), tx_ctx)
+        .await?;
+
+    match assignment.filter(|a| a.is_committed()) {
+        None => Ok(false),
+        Some(mut assignment) => {
+            assignment.revert_to_staged();
+            assignment_repo.save(&assignment, tx_ctx).await?;
+            Ok(true)
+        }
+    }
+}
+
+async fn remove_referee_from_fixture<TxCtx>(
+    step: &CommitSagaStep,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    let event = match step.referee_role() {
+        AssignmentRefereeRole::First => DomainEvent::FirstRefereeAssignmentRemoved {
+            fixture_id: step.fixture_id(),
+            referee_id: step.referee_id(),
+        },
+        AssignmentRefereeRole::Second => DomainEvent::SecondRefereeAssignmentRemoved {
+            fixture_id: step.fixture_id(),
+            referee_id: step.referee_id(),
+        },
+    };
+
+    domain_event_repo.store(event, tx_ctx).await
+}
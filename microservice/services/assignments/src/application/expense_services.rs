@@ -0,0 +1,170 @@
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use microservices_shared::{
+    domain_ids::RefereeId,
+    geo_location::GeoLocation,
+    resolvers::traits::{FixtureResolver, RefereeResolver},
+};
+use restinterface::{ExpenseItemDTO, ExpenseStatementDTO, FixtureDTO, FixtureStatusDTO};
+
+use crate::domain::{
+    aggregates::assignment::{Assignment, AssignmentRefereeRole},
+    repositories::assignment_repo::AssignmentRepository,
+};
+
+/// The rates with which referees are reimbursed, all amounts are in cents
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpenseRates {
+    pub per_km_cents: i64,
+    pub first_referee_fee_cents: i64,
+    pub second_referee_fee_cents: i64,
+}
+
+/// Computes the travel expenses of a referee for all their committed assignments of fixtures
+/// taking place in the given month (UTC). The travel is reimbursed per km for the way from the
+/// home of the referee to the venue and back, plus a flat fee depending on the referee role.
+pub async fn get_expense_statement<TxCtx>(
+    referee_id: RefereeId,
+    year: i32,
+    month: u32,
+    rates: &ExpenseRates,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    fixture_resolver: &impl FixtureResolver<Error = String>,
+    referee_resolver: &impl RefereeResolver<Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<ExpenseStatementDTO, String> {
+    let (from, to) = month_range(year, month)?;
+
+    let referee = referee_resolver.resolve(&referee_id).await?;
+    let home = referee
+        .home
+        .and_then(|home| GeoLocation::try_from(home).ok());
+
+    let assignments = assignment_repo
+        .find_all_committed_by_referee(referee_id, tx_ctx)
+        .await?;
+
+    let mut items = Vec::new();
+    for assignment in assignments.iter() {
+        let fixture = fixture_resolver.resolve(&assignment.fixture_id()).await?;
+
+        // NOTE: referees only travel to fixtures which take place, therefore cancelled and postponed ones are not reimbursed
+        if fixture.date < from
+            || fixture.date >= to
+            || fixture.status == FixtureStatusDTO::Cancelled
+            || fixture.status == FixtureStatusDTO::Postponed
+        {
+            continue;
+        }
+
+        items.push(expense_item(assignment, &fixture, home, rates));
+    }
+    items.sort_by_key(|i| i.date);
+
+    Ok(ExpenseStatementDTO {
+        total_cents: items.iter().map(|i| i.total_cents).sum(),
+        referee,
+        year,
+        month,
+        items,
+    })
+}
+
+/// Renders the expense statement as CSV with one row per assignment and a final row with the total,
+/// amounts are given in the currency unit with two decimals
+pub fn render_expense_statement_csv(statement: &ExpenseStatementDTO) -> String {
+    let mut lines = vec!["date,home,away,venue,role,distance_km,travel,fee,total".to_string()];
+
+    for item in statement.items.iter() {
+        let columns = [
+            item.date.format("%Y-%m-%d %H:%M").to_string(),
+            item.team_home_name.clone(),
+            item.team_away_name.clone(),
+            item.venue_name.clone(),
+            format!("{:?}", item.referee_role),
+            item.distance_km
+                .map_or(String::new(), |d| format!("{:.1}", d)),
+            format_cents(item.travel_cents),
+            format_cents(item.fee_cents),
+            format_cents(item.total_cents),
+        ];
+
+        lines.push(
+            columns
+                .iter()
+                .map(|c| escape_csv_field(c))
+                .collect::<Vec<String>>()
+                .join(","),
+        );
+    }
+
+    lines.push(format!(
+        ",,,,,,,Total,{}",
+        format_cents(statement.total_cents)
+    ));
+
+    lines.iter().map(|line| format!("{}\r\n", line)).collect()
+}
+
+fn expense_item(
+    assignment: &Assignment,
+    fixture: &FixtureDTO,
+    home: Option<GeoLocation>,
+    rates: &ExpenseRates,
+) -> ExpenseItemDTO {
+    let venue_location = fixture
+        .venue
+        .location
+        .and_then(|location| GeoLocation::try_from(location).ok());
+
+    // NOTE: the distance is rounded to 100m, so that the amounts can be retraced from the statement
+    let distance_km = home
+        .zip(venue_location)
+        .map(|(home, venue)| (home.distance_km(&venue) * 10.0).round() / 10.0);
+    let travel_cents =
+        distance_km.map_or(0, |d| (2.0 * d * rates.per_km_cents as f64).round() as i64);
+    let fee_cents = match assignment.referee_role() {
+        AssignmentRefereeRole::First => rates.first_referee_fee_cents,
+        AssignmentRefereeRole::Second => rates.second_referee_fee_cents,
+    };
+
+    ExpenseItemDTO {
+        fixture_id: fixture.id,
+        date: fixture.date,
+        team_home_name: fixture.team_home.name.clone(),
+        team_away_name: fixture.team_away.name.clone(),
+        venue_name: fixture.venue.name.clone(),
+        referee_role: assignment.referee_role().into(),
+        distance_km,
+        travel_cents,
+        fee_cents,
+        total_cents: travel_cents + fee_cents,
+    }
+}
+
+fn month_range(year: i32, month: u32) -> Result<(DateTime<Utc>, DateTime<Utc>), String> {
+    let first_day = NaiveDate::from_ymd_opt(year, month, 1)
+        .ok_or(format!("Invalid month {}-{}", year, month))?;
+    let next_first_day = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    }
+    .ok_or(format!("Invalid month {}-{}", year, month))?;
+
+    Ok((
+        Utc.from_utc_datetime(&first_day.and_hms_opt(0, 0, 0).unwrap()),
+        Utc.from_utc_datetime(&next_first_day.and_hms_opt(0, 0, 0).unwrap()),
+    ))
+}
+
+fn format_cents(cents: i64) -> String {
+    format!("{}.{:02}", cents / 100, cents % 100)
+}
+
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
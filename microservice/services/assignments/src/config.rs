@@ -7,6 +7,11 @@ pub struct AppConfig {
     pub kafka_domain_events_topics: Vec<String>,
     pub kafka_consumer_group: String,
     pub otlp_endpoint: String,
+    pub idp_host: String,
+    pub idp_realm: String,
+    pub expense_rate_per_km_cents: i64,
+    pub expense_fee_first_referee_cents: i64,
+    pub expense_fee_second_referee_cents: i64,
 }
 
 impl AppConfig {
@@ -25,6 +30,15 @@ impl AppConfig {
             kafka_domain_events_topics,
             kafka_consumer_group: get_from_env_or_panic("KAFKA_CONSUMER_GROUP"),
             otlp_endpoint: get_from_env_or_panic("OTLP_ENDPOINT"),
+            idp_host: get_from_env_or_panic("IDP_HOST"),
+            idp_realm: get_from_env_or_panic("IDP_REALM"),
+            expense_rate_per_km_cents: parse_from_env_or_panic("EXPENSE_RATE_PER_KM_CENTS"),
+            expense_fee_first_referee_cents: parse_from_env_or_panic(
+                "EXPENSE_FEE_FIRST_REFEREE_CENTS",
+            ),
+            expense_fee_second_referee_cents: parse_from_env_or_panic(
+                "EXPENSE_FEE_SECOND_REFEREE_CENTS",
+            ),
         }
     }
 }
@@ -32,3 +46,10 @@ impl AppConfig {
 pub fn get_from_env_or_panic(key: &str) -> String {
     std::env::var(key).unwrap_or_else(|err| panic!("Cannot find {} in env: {}", key, err))
 }
+
+fn parse_from_env_or_panic(key: &str) -> i64 {
+    let value = get_from_env_or_panic(key);
+    value
+        .parse()
+        .unwrap_or_else(|err| panic!("Invalid {} {}: {}", key, value, err))
+}
@@ -1 +1,2 @@
 pub mod assignment;
+pub mod commit_saga;
@@ -10,6 +10,9 @@ pub enum AssignmentRefereeRole {
 pub enum AssignmentStatus {
     Committed,
     Staged,
+    Suspended,
+    Confirmed,
+    Declined,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -18,6 +21,7 @@ pub struct Assignment {
     referee_role: AssignmentRefereeRole,
     fixture_id: FixtureId,
     referee_id: RefereeId,
+    review_reason: Option<String>,
 }
 
 impl Assignment {
@@ -31,6 +35,7 @@ impl Assignment {
             fixture_id,
             referee_id,
             referee_role,
+            review_reason: None,
         }
     }
 
@@ -39,12 +44,14 @@ impl Assignment {
         referee_id: RefereeId,
         referee_role: AssignmentRefereeRole,
         status: AssignmentStatus,
+        review_reason: Option<String>,
     ) -> Self {
         Self {
             status,
             fixture_id,
             referee_id,
             referee_role,
+            review_reason,
         }
     }
 
@@ -64,18 +71,96 @@ impl Assignment {
         self.status
     }
 
+    pub fn review_reason(&self) -> Option<&str> {
+        self.review_reason.as_deref()
+    }
+
+    pub fn needs_review(&self) -> bool {
+        self.review_reason.is_some()
+    }
+
     pub fn is_staged(&self) -> bool {
         self.status == AssignmentStatus::Staged
     }
 
+    /// A confirmed assignment is still committed, only the referee has accepted it
     pub fn is_committed(&self) -> bool {
+        self.status == AssignmentStatus::Committed || self.status == AssignmentStatus::Confirmed
+    }
+
+    pub fn is_unconfirmed(&self) -> bool {
         self.status == AssignmentStatus::Committed
     }
 
+    pub fn is_declined(&self) -> bool {
+        self.status == AssignmentStatus::Declined
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.status == AssignmentStatus::Suspended
+    }
+
     pub fn change_referee_role(&mut self, referee_role: AssignmentRefereeRole) {
         self.referee_role = referee_role;
     }
 
+    /// Flags a committed assignment which became invalid due to changes outside of the assignment itself
+    pub fn flag_for_review(&mut self, reason: &str) {
+        if !self.is_committed() {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("Assignment not committed");
+        }
+
+        // NOTE: an assignment can become invalid for several reasons, each of them is kept for the admin
+        self.review_reason = match self.review_reason.take() {
+            Some(review_reason) if review_reason.split("; ").any(|r| r == reason) => {
+                Some(review_reason)
+            }
+            Some(review_reason) => Some(format!("{}; {}", review_reason, reason)),
+            None => Some(reason.to_string()),
+        };
+    }
+
+    /// Suspends a staged assignment, e.g. because its fixture was postponed; it can be staged again
+    pub fn suspend(&mut self) {
+        if self.status != AssignmentStatus::Staged {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("Assignment not staged");
+        }
+
+        self.status = AssignmentStatus::Suspended;
+    }
+
+    /// Stages a suspended assignment again, e.g. because its fixture was rescheduled
+    pub fn resume(&mut self) {
+        if self.status != AssignmentStatus::Suspended {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("Assignment not suspended");
+        }
+
+        self.status = AssignmentStatus::Staged;
+    }
+
+    /// The referee accepts a committed assignment
+    pub fn confirm(&mut self) {
+        if self.status != AssignmentStatus::Committed {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("Assignment not committed or already confirmed");
+        }
+
+        self.status = AssignmentStatus::Confirmed;
+    }
+
+    /// The referee turns down a committed assignment, also after having confirmed it
+    pub fn decline(&mut self) {
+        if !self.is_committed() {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("Assignment not committed");
+        }
+
+        self.status = AssignmentStatus::Declined;
+    }
+
     pub fn commit(&mut self) {
         if self.status == AssignmentStatus::Staged {
             self.status = AssignmentStatus::Committed;
@@ -83,4 +168,15 @@ impl Assignment {
             panic!("Assignment already committed")
         }
     }
+
+    /// Reverts a committed assignment to staged, e.g. because its commit saga was compensated
+    pub fn revert_to_staged(&mut self) {
+        if !self.is_committed() {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("Assignment not committed");
+        }
+
+        self.status = AssignmentStatus::Staged;
+        self.review_reason = None;
+    }
 }
@@ -0,0 +1,304 @@
+use chrono::{DateTime, Utc};
+use microservices_shared::domain_ids::{CommitSagaId, FixtureId, RefereeId};
+use uuid::Uuid;
+
+use super::assignment::{Assignment, AssignmentRefereeRole};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitSagaStatus {
+    Running,
+    Completed,
+    Compensating,
+    Compensated,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitSagaStepStatus {
+    Pending,
+    Accepted,
+    Rejected,
+}
+
+/// A step assigns the referee of a committed assignment to its fixture in the fixtures service
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitSagaStep {
+    fixture_id: FixtureId,
+    referee_id: RefereeId,
+    referee_role: AssignmentRefereeRole,
+    status: CommitSagaStepStatus,
+    reason: Option<String>,
+}
+
+impl CommitSagaStep {
+    pub fn new(
+        fixture_id: FixtureId,
+        referee_id: RefereeId,
+        referee_role: AssignmentRefereeRole,
+        status: CommitSagaStepStatus,
+        reason: Option<String>,
+    ) -> Self {
+        Self {
+            fixture_id,
+            referee_id,
+            referee_role,
+            status,
+            reason,
+        }
+    }
+
+    pub fn fixture_id(&self) -> FixtureId {
+        self.fixture_id
+    }
+
+    pub fn referee_id(&self) -> RefereeId {
+        self.referee_id
+    }
+
+    pub fn referee_role(&self) -> AssignmentRefereeRole {
+        self.referee_role
+    }
+
+    pub fn status(&self) -> CommitSagaStepStatus {
+        self.status
+    }
+
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    pub fn is_accepted(&self) -> bool {
+        self.status == CommitSagaStepStatus::Accepted
+    }
+}
+
+/// Tracks the commit of assignments across the assignments and the fixtures service. As soon as
+/// one step is rejected, the saga compensates, i.e. all its assignments are reverted to staged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitSaga {
+    id: CommitSagaId,
+    status: CommitSagaStatus,
+    created_at: DateTime<Utc>,
+    steps: Vec<CommitSagaStep>,
+}
+
+impl CommitSaga {
+    pub fn new(assignments: &[Assignment], created_at: DateTime<Utc>) -> Self {
+        let steps = assignments
+            .iter()
+            .map(|a| {
+                CommitSagaStep::new(
+                    a.fixture_id(),
+                    a.referee_id(),
+                    a.referee_role(),
+                    CommitSagaStepStatus::Pending,
+                    None,
+                )
+            })
+            .collect();
+
+        let mut saga = Self {
+            id: CommitSagaId(Uuid::new_v4()),
+            status: CommitSagaStatus::Running,
+            created_at,
+            steps,
+        };
+        saga.update_status();
+        saga
+    }
+
+    pub fn from_id(
+        id: CommitSagaId,
+        status: CommitSagaStatus,
+        created_at: DateTime<Utc>,
+        steps: Vec<CommitSagaStep>,
+    ) -> Self {
+        Self {
+            id,
+            status,
+            created_at,
+            steps,
+        }
+    }
+
+    pub fn id(&self) -> CommitSagaId {
+        self.id
+    }
+
+    pub fn status(&self) -> CommitSagaStatus {
+        self.status
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn steps(&self) -> &[CommitSagaStep] {
+        &self.steps
+    }
+
+    pub fn step(&self, fixture_id: FixtureId, referee_id: RefereeId) -> Option<&CommitSagaStep> {
+        self.steps
+            .iter()
+            .find(|s| s.fixture_id == fixture_id && s.referee_id == referee_id)
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.status == CommitSagaStatus::Running
+    }
+
+    /// A saga which is compensating or compensated has at least one rejected step
+    pub fn is_compensating(&self) -> bool {
+        self.status == CommitSagaStatus::Compensating
+            || self.status == CommitSagaStatus::Compensated
+    }
+
+    pub fn accept_step(
+        &mut self,
+        fixture_id: FixtureId,
+        referee_id: RefereeId,
+    ) -> Result<(), String> {
+        let step = self.pending_step_mut(fixture_id, referee_id)?;
+        step.status = CommitSagaStepStatus::Accepted;
+
+        self.update_status();
+        Ok(())
+    }
+
+    pub fn reject_step(
+        &mut self,
+        fixture_id: FixtureId,
+        referee_id: RefereeId,
+        reason: &str,
+    ) -> Result<(), String> {
+        let step = self.pending_step_mut(fixture_id, referee_id)?;
+        step.status = CommitSagaStepStatus::Rejected;
+        step.reason = Some(reason.to_string());
+
+        self.update_status();
+        Ok(())
+    }
+
+    fn pending_step_mut(
+        &mut self,
+        fixture_id: FixtureId,
+        referee_id: RefereeId,
+    ) -> Result<&mut CommitSagaStep, String> {
+        let saga_id = self.id;
+        let step = self
+            .steps
+            .iter_mut()
+            .find(|s| s.fixture_id == fixture_id && s.referee_id == referee_id)
+            .ok_or(format!(
+                "Commit saga {} has no step for fixture {} and referee {}",
+                saga_id.0, fixture_id.0, referee_id.0
+            ))?;
+
+        if step.status != CommitSagaStepStatus::Pending {
+            return Err(format!(
+                "Step of commit saga {} for fixture {} and referee {} already {:?}",
+                saga_id.0, fixture_id.0, referee_id.0, step.status
+            ));
+        }
+
+        Ok(step)
+    }
+
+    fn update_status(&mut self) {
+        let any_pending = self
+            .steps
+            .iter()
+            .any(|s| s.status == CommitSagaStepStatus::Pending);
+        let any_rejected = self
+            .steps
+            .iter()
+            .any(|s| s.status == CommitSagaStepStatus::Rejected);
+
+        self.status = match (any_rejected, any_pending) {
+            (false, true) => CommitSagaStatus::Running,
+            (false, false) => CommitSagaStatus::Completed,
+            (true, true) => CommitSagaStatus::Compensating,
+            (true, false) => CommitSagaStatus::Compensated,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use microservices_shared::domain_ids::{FixtureId, RefereeId};
+    use uuid::Uuid;
+
+    use crate::domain::aggregates::assignment::{Assignment, AssignmentRefereeRole};
+
+    use super::{CommitSaga, CommitSagaStatus, CommitSagaStepStatus};
+
+    #[test]
+    fn test_given_running_saga_when_all_steps_accepted_then_completed() {
+        let fixture_id = FixtureId(Uuid::new_v4());
+        let first = Assignment::staged(
+            fixture_id,
+            RefereeId(Uuid::new_v4()),
+            AssignmentRefereeRole::First,
+        );
+        let second = Assignment::staged(
+            fixture_id,
+            RefereeId(Uuid::new_v4()),
+            AssignmentRefereeRole::Second,
+        );
+        let mut saga = CommitSaga::new(&[first.clone(), second.clone()], Utc::now());
+        assert_eq!(saga.status(), CommitSagaStatus::Running);
+
+        saga.accept_step(fixture_id, first.referee_id()).unwrap();
+        assert_eq!(saga.status(), CommitSagaStatus::Running);
+
+        saga.accept_step(fixture_id, second.referee_id()).unwrap();
+        assert_eq!(saga.status(), CommitSagaStatus::Completed);
+
+        assert!(
+            saga.accept_step(fixture_id, second.referee_id()).is_err(),
+            "A step can only be replied to once"
+        );
+    }
+
+    #[test]
+    fn test_given_running_saga_when_step_rejected_then_compensating_until_all_replied() {
+        let fixture_id = FixtureId(Uuid::new_v4());
+        let first = Assignment::staged(
+            fixture_id,
+            RefereeId(Uuid::new_v4()),
+            AssignmentRefereeRole::First,
+        );
+        let second = Assignment::staged(
+            fixture_id,
+            RefereeId(Uuid::new_v4()),
+            AssignmentRefereeRole::Second,
+        );
+        let mut saga = CommitSaga::new(&[first.clone(), second.clone()], Utc::now());
+
+        saga.reject_step(
+            fixture_id,
+            first.referee_id(),
+            "First referee already assigned",
+        )
+        .unwrap();
+        assert_eq!(saga.status(), CommitSagaStatus::Compensating);
+        assert!(saga.is_compensating());
+
+        let step = saga.step(fixture_id, first.referee_id()).unwrap();
+        assert_eq!(step.status(), CommitSagaStepStatus::Rejected);
+        assert_eq!(step.reason(), Some("First referee already assigned"));
+
+        saga.accept_step(fixture_id, second.referee_id()).unwrap();
+        assert_eq!(saga.status(), CommitSagaStatus::Compensated);
+    }
+
+    #[test]
+    fn test_given_unknown_step_when_accept_then_error() {
+        let mut saga = CommitSaga::new(&[], Utc::now());
+        assert_eq!(saga.status(), CommitSagaStatus::Completed);
+
+        assert!(saga
+            .accept_step(FixtureId(Uuid::new_v4()), RefereeId(Uuid::new_v4()))
+            .is_err());
+    }
+}
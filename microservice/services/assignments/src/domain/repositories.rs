@@ -1 +1,2 @@
 pub mod assignment_repo;
+pub mod commit_saga_repo;
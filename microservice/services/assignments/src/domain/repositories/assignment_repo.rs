@@ -1,8 +1,10 @@
 use microservices_shared::domain_ids::{FixtureId, RefereeId};
+use mockall::automock;
 
 use crate::domain::aggregates::assignment::Assignment;
 
 #[allow(async_fn_in_trait)]
+#[automock(type Error = String; type TxCtx = ();)]
 pub trait AssignmentRepository {
     type Error;
     type TxCtx;
@@ -30,4 +32,10 @@ pub trait AssignmentRepository {
         &self,
         tx_ctx: &mut Self::TxCtx,
     ) -> Result<Vec<Assignment>, Self::Error>;
+
+    async fn find_all_committed_by_referee(
+        &self,
+        referee_id: RefereeId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<Assignment>, Self::Error>;
 }
@@ -0,0 +1,17 @@
+use microservices_shared::domain_ids::CommitSagaId;
+
+use crate::domain::aggregates::commit_saga::CommitSaga;
+
+#[allow(async_fn_in_trait)]
+pub trait CommitSagaRepository {
+    type Error;
+    type TxCtx;
+
+    async fn find_by_id(
+        &self,
+        saga_id: CommitSagaId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<CommitSaga>, Self::Error>;
+    async fn get_all(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<CommitSaga>, Self::Error>;
+    async fn save(&self, saga: &CommitSaga, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error>;
+}
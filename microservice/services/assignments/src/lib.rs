@@ -1,8 +1,16 @@
 use std::sync::Arc;
 
-use domain::aggregates::assignment::{Assignment, AssignmentRefereeRole, AssignmentStatus};
+use application::expense_services::ExpenseRates;
+use domain::aggregates::{
+    assignment::{Assignment, AssignmentRefereeRole, AssignmentStatus},
+    commit_saga::{CommitSaga, CommitSagaStatus, CommitSagaStep, CommitSagaStepStatus},
+};
+use microservices_shared::token::AccessTokenVerifier;
 use opentelemetry::global::BoxedTracer;
-use restinterface::{AssignmentDTO, AssignmentRefereeRoleDTO, AssignmentStatusDTO};
+use restinterface::{
+    AssignmentDTO, AssignmentRefereeRoleDTO, AssignmentStatusDTO, CommitSagaDTO,
+    CommitSagaStatusDTO, CommitSagaStepDTO, CommitSagaStepStatusDTO,
+};
 use sqlx::PgPool;
 
 pub mod adapters;
@@ -15,6 +23,8 @@ pub struct AppState {
     pub connection_pool: PgPool,
     pub redis_client: redis::Client,
     pub tracer: Arc<BoxedTracer>,
+    pub expense_rates: ExpenseRates,
+    pub token_verifier: AccessTokenVerifier,
 }
 
 // NOTE: put here because REST and Application layers need them
@@ -42,6 +52,9 @@ impl From<AssignmentStatus> for AssignmentStatusDTO {
         match status {
             AssignmentStatus::Committed => AssignmentStatusDTO::Committed,
             AssignmentStatus::Staged => AssignmentStatusDTO::Staged,
+            AssignmentStatus::Suspended => AssignmentStatusDTO::Suspended,
+            AssignmentStatus::Confirmed => AssignmentStatusDTO::Confirmed,
+            AssignmentStatus::Declined => AssignmentStatusDTO::Declined,
         }
     }
 }
@@ -53,6 +66,51 @@ impl From<Assignment> for AssignmentDTO {
             fixture_id: assignment.fixture_id().into(),
             referee_id: assignment.referee_id().into(),
             referee_role: assignment.referee_role().into(),
+            review_reason: assignment.review_reason().map(|r| r.to_string()),
+        }
+    }
+}
+
+impl From<CommitSagaStatus> for CommitSagaStatusDTO {
+    fn from(status: CommitSagaStatus) -> Self {
+        match status {
+            CommitSagaStatus::Running => CommitSagaStatusDTO::Running,
+            CommitSagaStatus::Completed => CommitSagaStatusDTO::Completed,
+            CommitSagaStatus::Compensating => CommitSagaStatusDTO::Compensating,
+            CommitSagaStatus::Compensated => CommitSagaStatusDTO::Compensated,
+        }
+    }
+}
+
+impl From<CommitSagaStepStatus> for CommitSagaStepStatusDTO {
+    fn from(status: CommitSagaStepStatus) -> Self {
+        match status {
+            CommitSagaStepStatus::Pending => CommitSagaStepStatusDTO::Pending,
+            CommitSagaStepStatus::Accepted => CommitSagaStepStatusDTO::Accepted,
+            CommitSagaStepStatus::Rejected => CommitSagaStepStatusDTO::Rejected,
+        }
+    }
+}
+
+impl From<&CommitSagaStep> for CommitSagaStepDTO {
+    fn from(step: &CommitSagaStep) -> Self {
+        CommitSagaStepDTO {
+            fixture_id: step.fixture_id().into(),
+            referee_id: step.referee_id().into(),
+            referee_role: step.referee_role().into(),
+            status: step.status().into(),
+            reason: step.reason().map(|r| r.to_string()),
+        }
+    }
+}
+
+impl From<CommitSaga> for CommitSagaDTO {
+    fn from(saga: CommitSaga) -> Self {
+        CommitSagaDTO {
+            id: saga.id().into(),
+            status: saga.status().into(),
+            created_at: *saga.created_at(),
+            steps: saga.steps().iter().map(|s| s.into()).collect(),
         }
     }
 }
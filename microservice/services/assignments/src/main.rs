@@ -1,8 +1,19 @@
+use assignments::application::expense_services::ExpenseRates;
 use assignments::config::AppConfig;
+use assignments::ports::kafka::domain_events_handler::DomainEventHandlers;
 use assignments::ports::rest::assignments::{
-    commit_assignments_handler, fetch_assignments_handler, remove_committed_assignment_handler,
+    accept_assignment_proposal_handler, commit_assignments_handler, confirm_assignment_handler,
+    decline_assignment_handler, fetch_assignments_for_review_handler, fetch_assignments_handler,
+    fetch_unconfirmed_assignments_handler, get_referee_calendar_handler,
+    propose_assignments_handler, remove_committed_assignment_handler,
     remove_staged_assignment_handler, stage_assignment_handler, validate_assignments_handler,
 };
+use assignments::ports::rest::commit_sagas::{
+    fetch_commit_saga_handler, fetch_commit_sagas_handler,
+};
+use assignments::ports::rest::expenses::{
+    get_expense_statement_csv_handler, get_expense_statement_handler,
+};
 
 use assignments::AppState;
 use axum::http::Method;
@@ -12,7 +23,8 @@ use axum::{
     Router,
 };
 
-use microservices_shared::domain_events::{DomainEventCallbacksLoggerImpl, DomainEventConsumer};
+use microservices_shared::domain_events::DomainEventConsumer;
+use microservices_shared::token::AccessTokenVerifier;
 use opentelemetry::{
     trace::{Span, Tracer},
     KeyValue,
@@ -35,19 +47,31 @@ async fn main() {
     let connection_pool = PgPool::connect(&config.db_url).await.unwrap();
     let redis_client = redis::Client::open(config.redis_url).unwrap();
 
-    let domain_event_callbacks = Box::new(DomainEventCallbacksLoggerImpl::new(tracer_arc.clone()));
+    let domain_event_handlers =
+        DomainEventHandlers::new(redis_client.get_connection().unwrap(), tracer_arc.clone())
+            .into_registry();
     let mut domain_event_consumer = DomainEventConsumer::new(
         &config.kafka_consumer_group,
         &config.kafka_url,
         &config.kafka_domain_events_topics,
         connection_pool.clone(),
-        domain_event_callbacks,
+        domain_event_handlers,
     );
 
+    let token_verifier = AccessTokenVerifier::new(&config.idp_host, &config.idp_realm)
+        .await
+        .unwrap();
+
     let app_state = AppState {
         connection_pool: connection_pool.clone(),
         redis_client,
         tracer: tracer_arc,
+        expense_rates: ExpenseRates {
+            per_km_cents: config.expense_rate_per_km_cents,
+            first_referee_fee_cents: config.expense_fee_first_referee_cents,
+            second_referee_fee_cents: config.expense_fee_second_referee_cents,
+        },
+        token_verifier,
     };
     let state_arc = Arc::new(app_state);
 
@@ -73,8 +97,44 @@ async fn main() {
             "/assignments/committed/:fixture_id/:referee_id",
             delete(remove_committed_assignment_handler),
         )
+        .route(
+            "/assignments/committed/:fixture_id/:referee_id/confirm",
+            post(confirm_assignment_handler),
+        )
+        .route(
+            "/assignments/committed/:fixture_id/:referee_id/decline",
+            post(decline_assignment_handler),
+        )
+        .route(
+            "/assignments/unconfirmed",
+            get(fetch_unconfirmed_assignments_handler),
+        )
+        .route(
+            "/assignments/review",
+            get(fetch_assignments_for_review_handler),
+        )
         .route("/assignments/validate", post(validate_assignments_handler))
         .route("/assignments/commit", post(commit_assignments_handler))
+        .route("/assignments/sagas", get(fetch_commit_sagas_handler))
+        .route("/assignments/sagas/:id", get(fetch_commit_saga_handler))
+        .route("/assignments/proposal", post(propose_assignments_handler))
+        .route(
+            "/assignments/proposal/accept",
+            post(accept_assignment_proposal_handler),
+        )
+        // NOTE: the calendar lives under /referees for the client, the gateway routes it here
+        .route(
+            "/referees/:id/assignments.ics",
+            get(get_referee_calendar_handler),
+        )
+        .route(
+            "/expenses/referee/:referee_id/:year/:month",
+            get(get_expense_statement_handler),
+        )
+        .route(
+            "/expenses/referee/:referee_id/:year/:month/statement.csv",
+            get(get_expense_statement_csv_handler),
+        )
         .layer(cors)
         .with_state(state_arc);
 
@@ -0,0 +1,464 @@
+use std::sync::Arc;
+
+use crate::{
+    adapters::db::{
+        assignment_repo_pg::AssignmentRepositoryPg, commit_saga_repo_pg::CommitSagaRepositoryPg,
+    },
+    application::{
+        assignment_services::{
+            flag_conflicts_of_interest, remove_staged_assignments_of_referee,
+            resume_suspended_assignments_for_fixture, review_assignments_of_changed_fixture,
+            suspend_staged_assignments_for_fixture, void_assignments_for_fixture, FixtureChange,
+        },
+        commit_saga_services::{accept_referee_assignment, reject_referee_assignment},
+    },
+};
+use log::info;
+use microservices_shared::{
+    domain_event_repo::DomainEventRepositoryPg,
+    domain_events::{AvailabilityLockScope, DomainEventHandlerRegistry},
+    domain_ids::{AvailabilityLockId, CommitSagaId, FixtureId, RefereeId, VenueId},
+    resolvers::impls::{AvailabilityResolverImpl, FixtureResolverImpl},
+};
+use opentelemetry::global::BoxedTracer;
+use opentelemetry::{
+    trace::{Span, Tracer},
+    KeyValue,
+};
+use redis::Commands;
+use sqlx::types::chrono::{DateTime, Utc};
+use tokio::sync::Mutex;
+
+pub struct DomainEventHandlers {
+    redis_conn: Arc<Mutex<redis::Connection>>,
+    tracer: Arc<BoxedTracer>,
+}
+
+impl DomainEventHandlers {
+    pub fn new(redis_conn: redis::Connection, tracer: Arc<BoxedTracer>) -> Self {
+        Self {
+            // NOTE: the connection is shared with the resolvers, therefore it is wrapped in Arc<Mutex>
+            redis_conn: Arc::new(Mutex::new(redis_conn)),
+            tracer,
+        }
+    }
+
+    async fn review_assignments(
+        &mut self,
+        fixture_id: FixtureId,
+        change: FixtureChange,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        // NOTE: the review needs the changed fixture, not the one from before the change
+        invalidate_fixture_cache_entry(&self.redis_conn, fixture_id).await?;
+
+        let assignment_repo = AssignmentRepositoryPg::new();
+        let fixture_resolver = FixtureResolverImpl::new(self.redis_conn.clone());
+        let availability_resolver = AvailabilityResolverImpl::new();
+
+        let flagged_assignments = review_assignments_of_changed_fixture(
+            fixture_id,
+            change,
+            &assignment_repo,
+            &fixture_resolver,
+            &availability_resolver,
+            tx_ctx,
+        )
+        .await?;
+
+        info!(
+            "Assignments flagged for review due to changed fixture: {:?}",
+            flagged_assignments
+        );
+
+        Ok(())
+    }
+
+    /// Registers the handlers for the domain events this service reacts to
+    pub fn into_registry(
+        self,
+    ) -> DomainEventHandlerRegistry<Self, sqlx::Transaction<'static, sqlx::Postgres>> {
+        let tracer = self.tracer.clone();
+        DomainEventHandlerRegistry::new(self, tracer)
+            .on_referee_club_changed(|handlers, referee_id, club_name, tx_ctx| {
+                Box::pin(handlers.on_referee_club_changed(referee_id, club_name, tx_ctx))
+            })
+            .on_referee_home_changed(|handlers, referee_id, tx_ctx| {
+                Box::pin(handlers.on_referee_home_changed(referee_id, tx_ctx))
+            })
+            .on_referee_archived(|handlers, referee_id, tx_ctx| {
+                Box::pin(handlers.on_referee_archived(referee_id, tx_ctx))
+            })
+            .on_fixture_date_changed(|handlers, fixture_id, date, tx_ctx| {
+                Box::pin(handlers.on_fixture_date_changed(fixture_id, date, tx_ctx))
+            })
+            .on_fixture_venue_changed(|handlers, fixture_id, venue_id, tx_ctx| {
+                Box::pin(handlers.on_fixture_venue_changed(fixture_id, venue_id, tx_ctx))
+            })
+            .on_fixture_cancelled(|handlers, fixture_id, tx_ctx| {
+                Box::pin(handlers.on_fixture_cancelled(fixture_id, tx_ctx))
+            })
+            .on_fixture_postponed(|handlers, fixture_id, tx_ctx| {
+                Box::pin(handlers.on_fixture_postponed(fixture_id, tx_ctx))
+            })
+            .on_fixture_rescheduled(|handlers, fixture_id, tx_ctx| {
+                Box::pin(handlers.on_fixture_rescheduled(fixture_id, tx_ctx))
+            })
+            .on_availabilities_locked(|handlers, lock_id, scope, tx_ctx| {
+                Box::pin(handlers.on_availabilities_locked(lock_id, scope, tx_ctx))
+            })
+            .on_availabilities_unlocked(|handlers, lock_id, scope, tx_ctx| {
+                Box::pin(handlers.on_availabilities_unlocked(lock_id, scope, tx_ctx))
+            })
+            .on_referee_assignment_accepted(|handlers, saga_id, fixture_id, referee_id, tx_ctx| {
+                Box::pin(
+                    handlers
+                        .on_referee_assignment_accepted(saga_id, fixture_id, referee_id, tx_ctx),
+                )
+            })
+            .on_referee_assignment_rejected(
+                |handlers, saga_id, fixture_id, referee_id, reason, tx_ctx| {
+                    Box::pin(handlers.on_referee_assignment_rejected(
+                        saga_id, fixture_id, referee_id, reason, tx_ctx,
+                    ))
+                },
+            )
+    }
+
+    async fn on_referee_club_changed(
+        &mut self,
+        referee_id: RefereeId,
+        club_name: String,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Assignments: Referee club changed: {:?} -> {}",
+            referee_id, club_name
+        );
+
+        let mut span = self.tracer.start("on_referee_club_changed");
+        span.set_attribute(KeyValue::new("referee_id", referee_id.to_string()));
+        span.set_attribute(KeyValue::new("club_name", club_name.clone()));
+
+        invalidate_referee_cache_entry(&self.redis_conn, referee_id).await?;
+
+        let assignment_repo = AssignmentRepositoryPg::new();
+        let fixture_resolver = FixtureResolverImpl::new(self.redis_conn.clone());
+
+        let flagged_assignments = flag_conflicts_of_interest(
+            referee_id,
+            &club_name,
+            &assignment_repo,
+            &fixture_resolver,
+            tx_ctx,
+        )
+        .await?;
+
+        info!(
+            "Assignments flagged for conflict of interest: {:?}",
+            flagged_assignments
+        );
+
+        Ok(())
+    }
+
+    async fn on_referee_home_changed(
+        &mut self,
+        referee_id: RefereeId,
+        _tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Assignments: Referee home changed: {:?}",
+            referee_id
+        );
+
+        let mut span = self.tracer.start("on_referee_home_changed");
+        span.set_attribute(KeyValue::new("referee_id", referee_id.to_string()));
+
+        // NOTE: the expense statements resolve the referee, therefore they need to see the new home
+        invalidate_referee_cache_entry(&self.redis_conn, referee_id).await
+    }
+
+    async fn on_referee_archived(
+        &mut self,
+        referee_id: RefereeId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Assignments: Referee archived: {:?}",
+            referee_id
+        );
+
+        let mut span = self.tracer.start("on_referee_archived");
+        span.set_attribute(KeyValue::new("referee_id", referee_id.to_string()));
+
+        invalidate_referee_cache_entry(&self.redis_conn, referee_id).await?;
+
+        let assignment_repo = AssignmentRepositoryPg::new();
+
+        let removed_assignments =
+            remove_staged_assignments_of_referee(referee_id, &assignment_repo, tx_ctx).await?;
+
+        info!(
+            "Staged assignments removed due to archived referee: {:?}",
+            removed_assignments
+        );
+
+        Ok(())
+    }
+
+    async fn on_fixture_date_changed(
+        &mut self,
+        fixture_id: FixtureId,
+        date: DateTime<Utc>,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Assignments: Fixture date changed: {:?} -> {}",
+            fixture_id, date
+        );
+
+        let mut span = self.tracer.start("on_fixture_date_changed");
+        span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
+        span.set_attribute(KeyValue::new("date", date.to_string()));
+
+        self.review_assignments(fixture_id, FixtureChange::Date, tx_ctx)
+            .await
+    }
+
+    async fn on_fixture_venue_changed(
+        &mut self,
+        fixture_id: FixtureId,
+        venue_id: VenueId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Assignments: Fixture venue changed: {:?} -> {:?}",
+            fixture_id, venue_id
+        );
+
+        let mut span = self.tracer.start("on_fixture_venue_changed");
+        span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
+        span.set_attribute(KeyValue::new("venue_id", venue_id.to_string()));
+
+        self.review_assignments(fixture_id, FixtureChange::Venue, tx_ctx)
+            .await
+    }
+
+    async fn on_fixture_cancelled(
+        &mut self,
+        fixture_id: FixtureId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Assignments: Fixture cancelled: {:?}",
+            fixture_id
+        );
+
+        let mut span = self.tracer.start("on_fixture_cancelled");
+        span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
+
+        let assignment_repo = AssignmentRepositoryPg::new();
+        let domain_event_repo = DomainEventRepositoryPg::new();
+
+        let voided_assignments =
+            void_assignments_for_fixture(fixture_id, &assignment_repo, &domain_event_repo, tx_ctx)
+                .await?;
+
+        info!(
+            "Assignments voided due to cancelled fixture: {:?}",
+            voided_assignments
+        );
+
+        Ok(())
+    }
+
+    async fn on_fixture_postponed(
+        &mut self,
+        fixture_id: FixtureId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Assignments: Fixture postponed: {:?}",
+            fixture_id
+        );
+
+        let mut span = self.tracer.start("on_fixture_postponed");
+        span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
+
+        let assignment_repo = AssignmentRepositoryPg::new();
+
+        let suspended_assignments =
+            suspend_staged_assignments_for_fixture(fixture_id, &assignment_repo, tx_ctx).await?;
+
+        info!(
+            "Staged assignments suspended due to postponed fixture: {:?}",
+            suspended_assignments
+        );
+
+        Ok(())
+    }
+
+    async fn on_fixture_rescheduled(
+        &mut self,
+        fixture_id: FixtureId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Assignments: Fixture rescheduled: {:?}",
+            fixture_id
+        );
+
+        let mut span = self.tracer.start("on_fixture_rescheduled");
+        span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
+
+        let assignment_repo = AssignmentRepositoryPg::new();
+
+        let resumed_assignments =
+            resume_suspended_assignments_for_fixture(fixture_id, &assignment_repo, tx_ctx).await?;
+
+        info!(
+            "Suspended assignments staged again due to rescheduled fixture: {:?}",
+            resumed_assignments
+        );
+
+        Ok(())
+    }
+
+    async fn on_availabilities_locked(
+        &mut self,
+        lock_id: AvailabilityLockId,
+        scope: AvailabilityLockScope,
+        _tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        // NOTE: declarations for the locked fixtures are final from now on, assignments
+        // can be planned on the availabilities without them being withdrawn anymore
+        info!(
+            "Received Domain Event in Assignments: Availability data finalised: {:?} -> {:?}",
+            lock_id, scope
+        );
+
+        let mut span = self.tracer.start("on_availabilities_locked");
+        span.set_attribute(KeyValue::new("lock_id", lock_id.to_string()));
+
+        Ok(())
+    }
+
+    async fn on_availabilities_unlocked(
+        &mut self,
+        lock_id: AvailabilityLockId,
+        scope: AvailabilityLockScope,
+        _tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Assignments: Availability data reopened: {:?} -> {:?}",
+            lock_id, scope
+        );
+
+        let mut span = self.tracer.start("on_availabilities_unlocked");
+        span.set_attribute(KeyValue::new("lock_id", lock_id.to_string()));
+
+        Ok(())
+    }
+
+    async fn on_referee_assignment_accepted(
+        &mut self,
+        saga_id: CommitSagaId,
+        fixture_id: FixtureId,
+        referee_id: RefereeId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Assignments: Referee assignment accepted: {:?} -> {:?} (saga {:?})",
+            fixture_id, referee_id, saga_id
+        );
+
+        let mut span = self.tracer.start("on_referee_assignment_accepted");
+        span.set_attribute(KeyValue::new("saga_id", saga_id.to_string()));
+        span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
+        span.set_attribute(KeyValue::new("referee_id", referee_id.to_string()));
+
+        let commit_saga_repo = CommitSagaRepositoryPg::new();
+        let domain_event_repo = DomainEventRepositoryPg::new();
+
+        let saga = accept_referee_assignment(
+            saga_id,
+            fixture_id,
+            referee_id,
+            &commit_saga_repo,
+            &domain_event_repo,
+            tx_ctx,
+        )
+        .await?;
+
+        info!("Commit saga {:?} is {:?}", saga.id, saga.status);
+
+        Ok(())
+    }
+
+    async fn on_referee_assignment_rejected(
+        &mut self,
+        saga_id: CommitSagaId,
+        fixture_id: FixtureId,
+        referee_id: RefereeId,
+        reason: String,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Assignments: Referee assignment rejected: {:?} -> {:?} (saga {:?}): {}",
+            fixture_id, referee_id, saga_id, reason
+        );
+
+        let mut span = self.tracer.start("on_referee_assignment_rejected");
+        span.set_attribute(KeyValue::new("saga_id", saga_id.to_string()));
+        span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
+        span.set_attribute(KeyValue::new("referee_id", referee_id.to_string()));
+        span.set_attribute(KeyValue::new("reason", reason.clone()));
+
+        let assignment_repo = AssignmentRepositoryPg::new();
+        let commit_saga_repo = CommitSagaRepositoryPg::new();
+        let domain_event_repo = DomainEventRepositoryPg::new();
+
+        let saga = reject_referee_assignment(
+            saga_id,
+            fixture_id,
+            referee_id,
+            &reason,
+            &assignment_repo,
+            &commit_saga_repo,
+            &domain_event_repo,
+            tx_ctx,
+        )
+        .await?;
+
+        info!("Commit saga {:?} is {:?}", saga.id, saga.status);
+
+        Ok(())
+    }
+}
+
+async fn invalidate_referee_cache_entry(
+    redis_conn: &Arc<Mutex<redis::Connection>>,
+    referee_id: RefereeId,
+) -> Result<(), String> {
+    info!("Invalidating cache entry for referee: {:?}", referee_id);
+
+    // NOTE: the event might arrive here before the referees service has invalidated the entry
+    let key = format!("referee_{}", referee_id.0);
+    let _result: Result<(), redis::RedisError> = redis_conn.lock().await.del(key);
+
+    _result.map_err(|e| e.to_string())
+}
+
+async fn invalidate_fixture_cache_entry(
+    redis_conn: &Arc<Mutex<redis::Connection>>,
+    fixture_id: FixtureId,
+) -> Result<(), String> {
+    info!("Invalidating cache entry for fixture: {:?}", fixture_id);
+
+    // NOTE: the event might arrive here before the fixtures service has invalidated the entry
+    let key = format!("fixture_{}", fixture_id.0);
+    let _result: Result<(), redis::RedisError> = redis_conn.lock().await.del(key);
+
+    _result.map_err(|e| e.to_string())
+}
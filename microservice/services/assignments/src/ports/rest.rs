@@ -1 +1,3 @@
 pub mod assignments;
+pub mod commit_sagas;
+pub mod expenses;
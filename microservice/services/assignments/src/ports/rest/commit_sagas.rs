@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use log::info;
+use opentelemetry::{
+    trace::{Span, Tracer},
+    KeyValue,
+};
+use restinterface::{CommitSagaDTO, CommitSagaIdDTO};
+use shared::app_error::AppError;
+
+use crate::{
+    adapters::db::commit_saga_repo_pg::CommitSagaRepositoryPg,
+    application::commit_saga_services::{get_commit_saga, get_commit_sagas},
+    AppState,
+};
+
+pub async fn fetch_commit_sagas_handler(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<CommitSagaDTO>>, AppError> {
+    info!("Fetching commit sagas");
+    let _span = state.tracer.start("fetch_commit_sagas");
+
+    let mut tx = state
+        .connection_pool
+        .begin()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    let commit_saga_repo = CommitSagaRepositoryPg::new();
+
+    let sagas = get_commit_sagas(&commit_saga_repo, &mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e))?;
+
+    // NOTE: read-only, therefore dont commit TX
+
+    Ok(Json(sagas))
+}
+
+pub async fn fetch_commit_saga_handler(
+    State(state): State<Arc<AppState>>,
+    Path(saga_id): Path<CommitSagaIdDTO>,
+) -> Result<Json<Option<CommitSagaDTO>>, AppError> {
+    info!("Fetching commit saga {:?}", saga_id);
+    let mut span = state.tracer.start("fetch_commit_saga");
+    span.set_attribute(KeyValue::new("saga_id", saga_id.to_string()));
+
+    let mut tx = state
+        .connection_pool
+        .begin()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    let commit_saga_repo = CommitSagaRepositoryPg::new();
+
+    let saga = get_commit_saga(saga_id.into(), &commit_saga_repo, &mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e))?;
+
+    // NOTE: read-only, therefore dont commit TX
+
+    Ok(Json(saga))
+}
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::IntoResponse,
+    Json,
+};
+use log::info;
+use microservices_shared::resolvers::impls::{FixtureResolverImpl, RefereeResolverImpl};
+use opentelemetry::{
+    trace::{Span, Tracer},
+    KeyValue,
+};
+use restinterface::{ExpenseStatementDTO, RefereeIdDTO};
+use shared::app_error::AppError;
+use tokio::sync::Mutex;
+
+use crate::{
+    adapters::db::assignment_repo_pg::AssignmentRepositoryPg,
+    application::expense_services::{get_expense_statement, render_expense_statement_csv},
+    AppState,
+};
+
+pub async fn get_expense_statement_handler(
+    State(state): State<Arc<AppState>>,
+    Path((referee_id, year, month)): Path<(RefereeIdDTO, i32, u32)>,
+) -> Result<Json<ExpenseStatementDTO>, AppError> {
+    info!(
+        "Getting expense statement of referee {} for {}-{}",
+        referee_id.0, year, month
+    );
+    let mut span = state.tracer.start("get_expense_statement");
+    span.set_attribute(KeyValue::new("referee_id", referee_id.to_string()));
+    span.set_attribute(KeyValue::new("month", format!("{}-{}", year, month)));
+
+    let statement = fetch_expense_statement(&state, referee_id, year, month).await?;
+
+    Ok(Json(statement))
+}
+
+pub async fn get_expense_statement_csv_handler(
+    State(state): State<Arc<AppState>>,
+    Path((referee_id, year, month)): Path<(RefereeIdDTO, i32, u32)>,
+) -> Result<impl IntoResponse, AppError> {
+    info!(
+        "Exporting expense statement of referee {} for {}-{} as CSV",
+        referee_id.0, year, month
+    );
+    let mut span = state.tracer.start("get_expense_statement_csv");
+    span.set_attribute(KeyValue::new("referee_id", referee_id.to_string()));
+    span.set_attribute(KeyValue::new("month", format!("{}-{}", year, month)));
+
+    let statement = fetch_expense_statement(&state, referee_id, year, month).await?;
+    let csv = render_expense_statement_csv(&statement);
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"expenses_{}_{}-{:02}.csv\"",
+                    referee_id.0, year, month
+                ),
+            ),
+        ],
+        csv,
+    ))
+}
+
+async fn fetch_expense_statement(
+    state: &AppState,
+    referee_id: RefereeIdDTO,
+    year: i32,
+    month: u32,
+) -> Result<ExpenseStatementDTO, AppError> {
+    let mut tx = state
+        .connection_pool
+        .begin()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    let redis_conn = state
+        .redis_client
+        .get_connection()
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+    let redis_conn_arc_mutex = Arc::new(Mutex::new(redis_conn));
+
+    let assignment_repo = AssignmentRepositoryPg::new();
+    let fixture_resolver = FixtureResolverImpl::new(redis_conn_arc_mutex.clone());
+    let referee_resolver = RefereeResolverImpl::new(redis_conn_arc_mutex.clone());
+
+    let statement = get_expense_statement(
+        referee_id.into(),
+        year,
+        month,
+        &state.expense_rates,
+        &assignment_repo,
+        &fixture_resolver,
+        &referee_resolver,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e))?;
+
+    // NOTE: read-only, therefore dont commit TX
+
+    Ok(statement)
+}
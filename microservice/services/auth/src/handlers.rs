@@ -7,7 +7,9 @@ use axum::{
     response::IntoResponse,
 };
 use log::info;
-use microservices_shared::token::{AccessToken, Tokens};
+use microservices_shared::token::{
+    ACCESS_TOKEN_COOKIE, AccessToken, Tokens, extract_access_token_from_cookie_header,
+};
 use opentelemetry::trace::Tracer;
 use redis::Commands;
 use serde::{Deserialize, Serialize};
@@ -15,8 +17,6 @@ use shared::app_error::AppError;
 
 use crate::AppState;
 
-static ACCESS_TOKEN_COOKIE: &str = "rust-ddd-access";
-
 #[derive(Debug, Serialize, Deserialize)]
 pub struct LoginDTO {
     pub username: String,
@@ -131,17 +131,5 @@ pub async fn status_handler(
 
 fn extract_access_token_from_cookie(headers: &HeaderMap) -> Option<String> {
     let cookie_header = headers.get("cookie")?;
-    let cookie_str = cookie_header.to_str().ok()?.to_string();
-    let cookies: Vec<&str> = cookie_str.split("; ").collect();
-
-    for cookie in cookies.iter() {
-        let cookie_split: Vec<&str> = cookie.split("=").collect();
-        if cookie_split.len() == 2 {
-            if cookie_split[0] == ACCESS_TOKEN_COOKIE {
-                return Some(cookie_split[1].to_string());
-            }
-        }
-    }
-
-    None
+    extract_access_token_from_cookie_header(cookie_header.to_str().ok()?)
 }
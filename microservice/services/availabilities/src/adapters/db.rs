@@ -1 +1,4 @@
+pub mod availability_lock_repo_pg;
 pub mod availability_repo_pg;
+pub mod blackout_repo_pg;
+pub mod declaration_deadline_repo_pg;
@@ -9,6 +9,21 @@ struct AvailabilityDb {
     pub fixture_id: Uuid,
 }
 
+#[derive(FromRow)]
+struct SuspendedAvailabilityDb {
+    pub referee_id: Uuid,
+}
+
+#[derive(FromRow)]
+struct ResumedAvailabilityDb {
+    pub referee_id: Uuid,
+}
+
+#[derive(FromRow)]
+struct WithdrawnAvailabilityDb {
+    pub referee_id: Uuid,
+}
+
 pub struct AvailabilityRepositoryPg();
 
 impl AvailabilityRepositoryPg {
@@ -27,8 +42,11 @@ impl AvailabilityRepository for AvailabilityRepositoryPg {
         referee_id: &RefereeId,
         tx_ctx: &mut Self::TxCtx,
     ) -> Result<(), Self::Error> {
+        // NOTE: declaring a suspended availability again lifts its suspension
         sqlx::query!(
-            "INSERT INTO rustddd.availabilities (fixture_id, referee_id) VALUES ($1, $2)",
+            "INSERT INTO rustddd.availabilities (fixture_id, referee_id) VALUES ($1, $2)
+            ON CONFLICT (fixture_id, referee_id)
+            DO UPDATE SET suspended = FALSE",
             fixture_id.0,
             referee_id.0
         )
@@ -57,6 +75,50 @@ impl AvailabilityRepository for AvailabilityRepositoryPg {
         Ok(())
     }
 
+    async fn declare_availabilities(
+        &self,
+        fixture_ids: &[FixtureId],
+        referee_id: &RefereeId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<(), Self::Error> {
+        let fixture_ids: Vec<Uuid> = fixture_ids.iter().map(|f| f.0).collect();
+
+        // NOTE: declaring a suspended availability again lifts its suspension
+        sqlx::query!(
+            "INSERT INTO rustddd.availabilities (fixture_id, referee_id)
+            SELECT fixture_id, $2 FROM UNNEST($1::UUID[]) AS fixture_id
+            ON CONFLICT (fixture_id, referee_id)
+            DO UPDATE SET suspended = FALSE",
+            &fixture_ids,
+            referee_id.0
+        )
+        .execute(&mut **tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    async fn withdraw_availabilities(
+        &self,
+        fixture_ids: &[FixtureId],
+        referee_id: &RefereeId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<(), Self::Error> {
+        let fixture_ids: Vec<Uuid> = fixture_ids.iter().map(|f| f.0).collect();
+
+        sqlx::query!(
+            "DELETE FROM rustddd.availabilities WHERE fixture_id = ANY($1) AND referee_id = $2",
+            &fixture_ids,
+            referee_id.0
+        )
+        .execute(&mut **tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
     async fn get_availabilities_for_referee(
         &self,
         referee_id: &RefereeId,
@@ -64,7 +126,7 @@ impl AvailabilityRepository for AvailabilityRepositoryPg {
     ) -> Result<Vec<FixtureId>, Self::Error> {
         let availabilities: Vec<AvailabilityDb> = sqlx::query_as!(
             AvailabilityDb,
-            "SELECT fixture_id FROM rustddd.availabilities WHERE referee_id = $1",
+            "SELECT fixture_id FROM rustddd.availabilities WHERE referee_id = $1 AND suspended = FALSE",
             referee_id.0
         )
         .fetch_all(&mut **tx_ctx)
@@ -84,7 +146,7 @@ impl AvailabilityRepository for AvailabilityRepositoryPg {
         tx_ctx: &mut Self::TxCtx,
     ) -> Result<bool, Self::Error> {
         let count: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM rustddd.availabilities WHERE fixture_id = $1 AND referee_id = $2",
+            "SELECT COUNT(*) FROM rustddd.availabilities WHERE fixture_id = $1 AND referee_id = $2 AND suspended = FALSE",
         )
         .bind(fixture_id.0)
         .bind(referee_id.0)
@@ -94,4 +156,64 @@ impl AvailabilityRepository for AvailabilityRepositoryPg {
 
         Ok(count > 0)
     }
+
+    async fn suspend_availabilities_for_fixture(
+        &self,
+        fixture_id: &FixtureId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<RefereeId>, Self::Error> {
+        let suspended_availabilities: Vec<SuspendedAvailabilityDb> = sqlx::query_as!(
+            SuspendedAvailabilityDb,
+            "UPDATE rustddd.availabilities SET suspended = TRUE WHERE fixture_id = $1 AND suspended = FALSE RETURNING referee_id",
+            fixture_id.0
+        )
+        .fetch_all(&mut **tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(suspended_availabilities
+            .into_iter()
+            .map(|a| a.referee_id.into())
+            .collect())
+    }
+
+    async fn resume_availabilities_for_fixture(
+        &self,
+        fixture_id: &FixtureId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<RefereeId>, Self::Error> {
+        let resumed_availabilities: Vec<ResumedAvailabilityDb> = sqlx::query_as!(
+            ResumedAvailabilityDb,
+            "UPDATE rustddd.availabilities SET suspended = FALSE WHERE fixture_id = $1 AND suspended = TRUE RETURNING referee_id",
+            fixture_id.0
+        )
+        .fetch_all(&mut **tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(resumed_availabilities
+            .into_iter()
+            .map(|a| a.referee_id.into())
+            .collect())
+    }
+
+    async fn withdraw_availabilities_for_fixture(
+        &self,
+        fixture_id: &FixtureId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<RefereeId>, Self::Error> {
+        let withdrawn_availabilities: Vec<WithdrawnAvailabilityDb> = sqlx::query_as!(
+            WithdrawnAvailabilityDb,
+            "DELETE FROM rustddd.availabilities WHERE fixture_id = $1 RETURNING referee_id",
+            fixture_id.0
+        )
+        .fetch_all(&mut **tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(withdrawn_availabilities
+            .into_iter()
+            .map(|a| a.referee_id.into())
+            .collect())
+    }
 }
@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+use microservices_shared::domain_ids::{BlackoutId, RefereeId};
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::domain::{
+    aggregates::blackout::Blackout, repositories::blackout_repo::BlackoutRepository,
+};
+
+pub struct BlackoutRepositoryPg();
+
+struct BlackoutDb {
+    pub id: Uuid,
+    pub referee_id: Uuid,
+    pub from_date: DateTime<Utc>,
+    pub to_date: DateTime<Utc>,
+}
+
+impl From<BlackoutDb> for Blackout {
+    fn from(blackout: BlackoutDb) -> Self {
+        Blackout::from_id(
+            BlackoutId::from(blackout.id),
+            RefereeId::from(blackout.referee_id),
+            blackout.from_date,
+            blackout.to_date,
+        )
+    }
+}
+
+impl BlackoutRepositoryPg {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl BlackoutRepository for BlackoutRepositoryPg {
+    type Error = String;
+    type TxCtx = Transaction<'static, Postgres>;
+
+    async fn find_by_id(
+        &self,
+        blackout_id: BlackoutId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<Blackout>, Self::Error> {
+        let blackout: Option<BlackoutDb> = sqlx::query_as!(
+            BlackoutDb,
+            "SELECT id, referee_id, from_date, to_date
+            FROM rustddd.blackouts
+            WHERE id = $1",
+            blackout_id.0
+        )
+        .fetch_optional(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("BlackoutRepositoryPg::find_by_id failed with {}", e))?;
+
+        Ok(blackout.map(|b| b.into()))
+    }
+
+    async fn find_by_referee(
+        &self,
+        referee_id: RefereeId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<Blackout>, Self::Error> {
+        let blackouts: Vec<BlackoutDb> = sqlx::query_as!(
+            BlackoutDb,
+            "SELECT id, referee_id, from_date, to_date
+            FROM rustddd.blackouts
+            WHERE referee_id = $1
+            ORDER BY from_date ASC",
+            referee_id.0
+        )
+        .fetch_all(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("BlackoutRepositoryPg::find_by_referee failed with {}", e))?;
+
+        Ok(blackouts.into_iter().map(|b| b.into()).collect())
+    }
+
+    async fn save(&self, blackout: &Blackout, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error> {
+        // NOTE: a blackout never changes, it is only created and deleted
+        sqlx::query!(
+            "INSERT INTO rustddd.blackouts (id, referee_id, from_date, to_date)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (id) DO NOTHING",
+            blackout.id().0,
+            blackout.referee_id().0,
+            blackout.from(),
+            blackout.to()
+        )
+        .execute(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("BlackoutRepositoryPg::save failed with {}", e))?;
+
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        blackout_id: BlackoutId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<(), Self::Error> {
+        sqlx::query!("DELETE FROM rustddd.blackouts WHERE id = $1", blackout_id.0)
+            .execute(&mut **tx_ctx)
+            .await
+            .map_err(|e| format!("BlackoutRepositoryPg::delete failed with {}", e))?;
+
+        Ok(())
+    }
+}
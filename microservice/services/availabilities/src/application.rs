@@ -1 +1,3 @@
+pub mod availability_lock_services;
 pub mod availability_services;
+pub mod blackout_services;
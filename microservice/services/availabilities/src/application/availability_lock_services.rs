@@ -0,0 +1,359 @@
+use chrono::{DateTime, Utc};
+use microservices_shared::{
+    domain_event_repo::DomainEventOutboxRepository,
+    domain_events::{AvailabilityLockScope, DomainEvent},
+    domain_ids::{AvailabilityLockId, FixtureId},
+    resolvers::traits::FixtureResolver,
+};
+
+use crate::domain::{
+    aggregates::{availability_lock::AvailabilityLock, declaration_deadline::DeclarationDeadline},
+    repositories::{
+        availability_lock_repo::AvailabilityLockRepository,
+        declaration_deadline_repo::DeclarationDeadlineRepository,
+    },
+};
+
+pub async fn lock_availabilities<TxCtx>(
+    scope: AvailabilityLockScope,
+    availability_lock_repo: &impl AvailabilityLockRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<AvailabilityLock, String> {
+    if let AvailabilityLockScope::DateRange { from, to } = scope {
+        if from > to {
+            return Err(
+                "Lock date range ends before it starts - cannot lock availabilities".to_string(),
+            );
+        }
+    }
+
+    let lock = AvailabilityLock::new(scope);
+    availability_lock_repo.save(&lock, tx_ctx).await?;
+
+    domain_event_repo
+        .store(
+            DomainEvent::AvailabilitiesLocked {
+                lock_id: lock.id(),
+                scope: lock.scope(),
+            },
+            tx_ctx,
+        )
+        .await?;
+
+    Ok(lock)
+}
+
+pub async fn unlock_availabilities<TxCtx>(
+    lock_id: AvailabilityLockId,
+    availability_lock_repo: &impl AvailabilityLockRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    let lock = availability_lock_repo
+        .find_by_id(lock_id, tx_ctx)
+        .await?
+        .ok_or(format!("Availability lock {:?} not found", lock_id))?;
+
+    availability_lock_repo.delete(lock.id(), tx_ctx).await?;
+
+    domain_event_repo
+        .store(
+            DomainEvent::AvailabilitiesUnlocked {
+                lock_id: lock.id(),
+                scope: lock.scope(),
+            },
+            tx_ctx,
+        )
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_availability_locks<TxCtx>(
+    availability_lock_repo: &impl AvailabilityLockRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<AvailabilityLock>, String> {
+    availability_lock_repo.get_all(tx_ctx).await
+}
+
+/// Sets or moves the declaration deadline of a fixture, moving it reopens a
+/// deadline which was closed already, however the lock created when closing it
+/// has to be unlocked separately.
+pub async fn set_declaration_deadline<TxCtx>(
+    fixture_id: FixtureId,
+    deadline: DateTime<Utc>,
+    fixture_resolver: &impl FixtureResolver<Error = String>,
+    declaration_deadline_repo: &impl DeclarationDeadlineRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<DeclarationDeadline, String> {
+    let fixture = fixture_resolver
+        .resolve(&fixture_id)
+        .await
+        .expect(&format!("Fixture {:?} not resolved", fixture_id));
+
+    if deadline > fixture.date {
+        return Err(
+            "Declaration deadline is after the fixture date - cannot set deadline".to_string(),
+        );
+    }
+
+    let declaration_deadline = DeclarationDeadline::new(fixture_id, deadline);
+    declaration_deadline_repo
+        .save(&declaration_deadline, tx_ctx)
+        .await?;
+
+    Ok(declaration_deadline)
+}
+
+/// Closes all declaration deadlines which have passed by locking the
+/// availabilities of their fixtures, returns the fixtures which got locked.
+pub async fn close_due_declaration_deadlines<TxCtx>(
+    now: DateTime<Utc>,
+    declaration_deadline_repo: &impl DeclarationDeadlineRepository<TxCtx = TxCtx, Error = String>,
+    availability_lock_repo: &impl AvailabilityLockRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<FixtureId>, String> {
+    let mut locked_fixtures = Vec::new();
+
+    for mut deadline in declaration_deadline_repo.find_due(now, tx_ctx).await? {
+        deadline.close();
+        declaration_deadline_repo.save(&deadline, tx_ctx).await?;
+
+        lock_availabilities(
+            AvailabilityLockScope::Fixture(deadline.fixture_id()),
+            availability_lock_repo,
+            domain_event_repo,
+            tx_ctx,
+        )
+        .await?;
+
+        locked_fixtures.push(deadline.fixture_id());
+    }
+
+    Ok(locked_fixtures)
+}
+
+/// Availabilities of a fixture cannot be changed while a lock covers it or
+/// when its declaration deadline has passed but was not closed yet.
+pub async fn ensure_availabilities_changeable<TxCtx>(
+    fixture_id: FixtureId,
+    fixture_date: &DateTime<Utc>,
+    now: DateTime<Utc>,
+    availability_lock_repo: &impl AvailabilityLockRepository<TxCtx = TxCtx, Error = String>,
+    declaration_deadline_repo: &impl DeclarationDeadlineRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    let locks = availability_lock_repo.get_all(tx_ctx).await?;
+    if let Some(lock) = locks.iter().find(|l| l.covers(fixture_id, fixture_date)) {
+        return Err(format!(
+            "Availabilities for this fixture are locked by {:?} - cannot change availability",
+            lock.id()
+        ));
+    }
+
+    // NOTE: a closed deadline is represented by its lock, which an admin may have lifted again
+    if let Some(deadline) = declaration_deadline_repo
+        .find_by_fixture(fixture_id, tx_ctx)
+        .await?
+    {
+        if !deadline.is_closed() && deadline.has_passed(&now) {
+            return Err(
+                "Declaration deadline for this fixture has passed - cannot change availability"
+                    .to_string(),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+    use microservices_shared::{
+        domain_event_repo::MockDomainEventOutboxRepository,
+        domain_events::{AvailabilityLockScope, DomainEvent},
+        domain_ids::FixtureId,
+    };
+    use mockall::predicate::{eq, function};
+    use uuid::Uuid;
+
+    use crate::{
+        application::availability_lock_services::{
+            close_due_declaration_deadlines, ensure_availabilities_changeable, lock_availabilities,
+        },
+        domain::{
+            aggregates::{
+                availability_lock::AvailabilityLock, declaration_deadline::DeclarationDeadline,
+            },
+            repositories::{
+                availability_lock_repo::MockAvailabilityLockRepository,
+                declaration_deadline_repo::MockDeclarationDeadlineRepository,
+            },
+        },
+    };
+
+    #[tokio::test]
+    async fn test_given_date_range_lock_covering_fixture_when_ensure_changeable_then_error() {
+        let mut availability_lock_repo = MockAvailabilityLockRepository::new();
+        let declaration_deadline_repo = MockDeclarationDeadlineRepository::new();
+
+        let fixture_id = FixtureId::from(Uuid::new_v4());
+        let fixture_date = Utc.with_ymd_and_hms(2024, 10, 5, 18, 30, 0).unwrap();
+        let lock = AvailabilityLock::new(AvailabilityLockScope::DateRange {
+            from: fixture_date - Duration::days(1),
+            to: fixture_date + Duration::days(1),
+        });
+
+        availability_lock_repo
+            .expect_get_all()
+            .return_const(Ok(vec![lock]));
+
+        let result = ensure_availabilities_changeable(
+            fixture_id,
+            &fixture_date,
+            fixture_date - Duration::days(7),
+            &availability_lock_repo,
+            &declaration_deadline_repo,
+            &mut (),
+        )
+        .await;
+
+        assert!(result
+            .unwrap_err()
+            .starts_with("Availabilities for this fixture are locked"));
+    }
+
+    #[tokio::test]
+    async fn test_given_passed_deadline_when_ensure_changeable_then_error_unless_closed() {
+        let mut availability_lock_repo = MockAvailabilityLockRepository::new();
+        let mut declaration_deadline_repo = MockDeclarationDeadlineRepository::new();
+
+        let fixture_id = FixtureId::from(Uuid::new_v4());
+        let fixture_date = Utc.with_ymd_and_hms(2024, 10, 5, 18, 30, 0).unwrap();
+        let deadline = fixture_date - Duration::days(2);
+
+        availability_lock_repo
+            .expect_get_all()
+            .return_const(Ok(vec![]));
+        declaration_deadline_repo
+            .expect_find_by_fixture()
+            .with(eq(fixture_id), eq(&()))
+            .times(1)
+            .return_const(Ok(Some(DeclarationDeadline::new(fixture_id, deadline))));
+        declaration_deadline_repo
+            .expect_find_by_fixture()
+            .with(eq(fixture_id), eq(&()))
+            .times(1)
+            .return_const(Ok(Some(DeclarationDeadline::from_values(
+                fixture_id, deadline, true,
+            ))));
+
+        let open_result = ensure_availabilities_changeable(
+            fixture_id,
+            &fixture_date,
+            deadline + Duration::hours(1),
+            &availability_lock_repo,
+            &declaration_deadline_repo,
+            &mut (),
+        )
+        .await;
+        let closed_result = ensure_availabilities_changeable(
+            fixture_id,
+            &fixture_date,
+            deadline + Duration::hours(1),
+            &availability_lock_repo,
+            &declaration_deadline_repo,
+            &mut (),
+        )
+        .await;
+
+        assert_eq!(
+            open_result.unwrap_err(),
+            "Declaration deadline for this fixture has passed - cannot change availability"
+        );
+        assert!(closed_result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_given_due_deadline_when_close_due_deadlines_then_fixture_locked() {
+        let mut declaration_deadline_repo = MockDeclarationDeadlineRepository::new();
+        let mut availability_lock_repo = MockAvailabilityLockRepository::new();
+        let mut domain_event_repo = MockDomainEventOutboxRepository::new();
+
+        let fixture_id = FixtureId::from(Uuid::new_v4());
+        let now = Utc.with_ymd_and_hms(2024, 10, 3, 0, 0, 0).unwrap();
+
+        declaration_deadline_repo
+            .expect_find_due()
+            .with(eq(now), eq(&()))
+            .return_const(Ok(vec![DeclarationDeadline::new(
+                fixture_id,
+                now - Duration::hours(1),
+            )]));
+        declaration_deadline_repo
+            .expect_save()
+            .with(
+                function(move |d: &DeclarationDeadline| d.is_closed()),
+                eq(&()),
+            )
+            .times(1)
+            .return_const(Ok(()));
+        availability_lock_repo
+            .expect_save()
+            .with(
+                function(move |l: &AvailabilityLock| {
+                    l.scope() == AvailabilityLockScope::Fixture(fixture_id)
+                }),
+                eq(&()),
+            )
+            .times(1)
+            .return_const(Ok(()));
+        domain_event_repo
+            .expect_store()
+            .with(
+                function(move |e: &DomainEvent| {
+                    matches!(e, DomainEvent::AvailabilitiesLocked { scope, .. }
+                        if *scope == AvailabilityLockScope::Fixture(fixture_id))
+                }),
+                eq(&()),
+            )
+            .times(1)
+            .return_const(Ok(()));
+
+        let locked_fixtures = close_due_declaration_deadlines(
+            now,
+            &declaration_deadline_repo,
+            &availability_lock_repo,
+            &domain_event_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(locked_fixtures, vec![fixture_id]);
+    }
+
+    #[tokio::test]
+    async fn test_given_inverted_date_range_when_lock_availabilities_then_error() {
+        let availability_lock_repo = MockAvailabilityLockRepository::new();
+        let domain_event_repo = MockDomainEventOutboxRepository::new();
+
+        let from = Utc.with_ymd_and_hms(2024, 10, 5, 0, 0, 0).unwrap();
+
+        let result = lock_availabilities(
+            AvailabilityLockScope::DateRange {
+                from,
+                to: from - Duration::days(1),
+            },
+            &availability_lock_repo,
+            &domain_event_repo,
+            &mut (),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -1,11 +1,42 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Datelike, Utc, Weekday};
 use microservices_shared::{
     domain_event_repo::DomainEventOutboxRepository,
     domain_events::DomainEvent,
-    domain_ids::{FixtureId, RefereeId},
+    domain_ids::{FixtureId, RefereeId, VenueId},
     resolvers::traits::{FixtureResolver, RefereeResolver},
 };
+use restinterface::{FixtureDTO, FixtureStatusDTO};
+
+use crate::{
+    application::availability_lock_services::ensure_availabilities_changeable,
+    domain::repositories::{
+        availability_lock_repo::AvailabilityLockRepository,
+        availability_repo::AvailabilityRepository, blackout_repo::BlackoutRepository,
+        declaration_deadline_repo::DeclarationDeadlineRepository,
+    },
+};
+
+/// Selects the fixtures of a bulk declaration, all given criteria have to match
+/// and an empty list of venues or weekdays matches every venue or weekday
+#[derive(Debug, Clone, Default)]
+pub struct AvailabilityBulkDeclaration {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub venue_ids: Vec<VenueId>,
+    pub weekdays: Vec<Weekday>,
+}
 
-use crate::domain::repositories::availability_repo::AvailabilityRepository;
+impl AvailabilityBulkDeclaration {
+    pub fn matches(&self, fixture: &FixtureDTO) -> bool {
+        self.from.is_none_or(|from| from <= fixture.date)
+            && self.to.is_none_or(|to| fixture.date <= to)
+            && (self.venue_ids.is_empty()
+                || self.venue_ids.contains(&VenueId::from(fixture.venue.id)))
+            && (self.weekdays.is_empty() || self.weekdays.contains(&fixture.date.weekday()))
+    }
+}
 
 pub async fn declare_availability<TxCtx>(
     fixture_id: FixtureId,
@@ -13,10 +44,13 @@ pub async fn declare_availability<TxCtx>(
     fixture_resolver: &impl FixtureResolver<Error = String>,
     referee_resolver: &impl RefereeResolver<Error = String>,
     availability_repo: &impl AvailabilityRepository<TxCtx = TxCtx, Error = String>,
+    availability_lock_repo: &impl AvailabilityLockRepository<TxCtx = TxCtx, Error = String>,
+    declaration_deadline_repo: &impl DeclarationDeadlineRepository<TxCtx = TxCtx, Error = String>,
+    blackout_repo: &impl BlackoutRepository<TxCtx = TxCtx, Error = String>,
     domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
     tx_ctx: &mut TxCtx,
 ) -> Result<(), String> {
-    let _fixture = fixture_resolver
+    let fixture = fixture_resolver
         .resolve(&fixture_id)
         .await
         .expect(&format!("Fixture {:?} not resolved", fixture_id));
@@ -25,6 +59,24 @@ pub async fn declare_availability<TxCtx>(
         .await
         .expect(&format!("Referee {:?} not resolved", referee_id));
 
+    ensure_availabilities_changeable(
+        fixture_id,
+        &fixture.date,
+        Utc::now(),
+        availability_lock_repo,
+        declaration_deadline_repo,
+        tx_ctx,
+    )
+    .await?;
+
+    let blackouts = blackout_repo.find_by_referee(referee_id, tx_ctx).await?;
+    if blackouts.iter().any(|b| b.covers(&fixture.date)) {
+        return Err(
+            "Referee has a blackout on the date of this fixture - cannot declare availability"
+                .to_string(),
+        );
+    }
+
     if availability_repo
         .is_available(&fixture_id, &referee_id, tx_ctx)
         .await?
@@ -52,16 +104,98 @@ pub async fn declare_availability<TxCtx>(
     Ok(())
 }
 
+/// Declares the availability of the referee for all scheduled fixtures matching
+/// the bulk declaration and returns the fixtures which got declared. Fixtures
+/// the referee is available for already, which fall into a blackout of the
+/// referee or whose availabilities cannot be changed anymore are skipped.
+pub async fn declare_availabilities_bulk<TxCtx>(
+    referee_id: RefereeId,
+    bulk_declaration: &AvailabilityBulkDeclaration,
+    fixtures: &[FixtureDTO],
+    referee_resolver: &impl RefereeResolver<Error = String>,
+    availability_repo: &impl AvailabilityRepository<TxCtx = TxCtx, Error = String>,
+    availability_lock_repo: &impl AvailabilityLockRepository<TxCtx = TxCtx, Error = String>,
+    declaration_deadline_repo: &impl DeclarationDeadlineRepository<TxCtx = TxCtx, Error = String>,
+    blackout_repo: &impl BlackoutRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<FixtureId>, String> {
+    let _referee = referee_resolver
+        .resolve(&referee_id)
+        .await
+        .expect(&format!("Referee {:?} not resolved", referee_id));
+
+    let declared: HashSet<FixtureId> = availability_repo
+        .get_availabilities_for_referee(&referee_id, tx_ctx)
+        .await?
+        .into_iter()
+        .collect();
+    let blackouts = blackout_repo.find_by_referee(referee_id, tx_ctx).await?;
+
+    let now = Utc::now();
+    let mut fixture_ids = Vec::new();
+    for fixture in fixtures
+        .iter()
+        .filter(|f| f.status == FixtureStatusDTO::Scheduled && bulk_declaration.matches(f))
+    {
+        let fixture_id = FixtureId::from(fixture.id);
+        if declared.contains(&fixture_id) || blackouts.iter().any(|b| b.covers(&fixture.date)) {
+            continue;
+        }
+
+        // NOTE: locked fixtures are skipped instead of failing the whole declaration
+        if ensure_availabilities_changeable(
+            fixture_id,
+            &fixture.date,
+            now,
+            availability_lock_repo,
+            declaration_deadline_repo,
+            tx_ctx,
+        )
+        .await
+        .is_err()
+        {
+            continue;
+        }
+
+        fixture_ids.push(fixture_id);
+    }
+
+    if fixture_ids.is_empty() {
+        return Ok(fixture_ids);
+    }
+
+    availability_repo
+        .declare_availabilities(&fixture_ids, &referee_id, tx_ctx)
+        .await?;
+
+    for fixture_id in fixture_ids.iter() {
+        domain_event_repo
+            .store(
+                DomainEvent::AvailabilityDeclared {
+                    fixture_id: *fixture_id,
+                    referee_id,
+                },
+                tx_ctx,
+            )
+            .await?;
+    }
+
+    Ok(fixture_ids)
+}
+
 pub async fn withdraw_availability<TxCtx>(
     fixture_id: FixtureId,
     referee_id: RefereeId,
     fixture_resolver: &impl FixtureResolver<Error = String>,
     referee_resolver: &impl RefereeResolver<Error = String>,
     availability_repo: &impl AvailabilityRepository<TxCtx = TxCtx, Error = String>,
+    availability_lock_repo: &impl AvailabilityLockRepository<TxCtx = TxCtx, Error = String>,
+    declaration_deadline_repo: &impl DeclarationDeadlineRepository<TxCtx = TxCtx, Error = String>,
     domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
     tx_ctx: &mut TxCtx,
 ) -> Result<(), String> {
-    let _fixture = fixture_resolver
+    let fixture = fixture_resolver
         .resolve(&fixture_id)
         .await
         .expect(&format!("Fixture {:?} not resolved", fixture_id));
@@ -70,6 +204,16 @@ pub async fn withdraw_availability<TxCtx>(
         .await
         .expect(&format!("Referee {:?} not resolved", referee_id));
 
+    ensure_availabilities_changeable(
+        fixture_id,
+        &fixture.date,
+        Utc::now(),
+        availability_lock_repo,
+        declaration_deadline_repo,
+        tx_ctx,
+    )
+    .await?;
+
     if !availability_repo
         .is_available(&fixture_id, &referee_id, tx_ctx)
         .await?
@@ -96,6 +240,88 @@ pub async fn withdraw_availability<TxCtx>(
     Ok(())
 }
 
+pub async fn suspend_availabilities_for_fixture<TxCtx>(
+    fixture_id: FixtureId,
+    availability_repo: &impl AvailabilityRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<RefereeId>, String> {
+    // NOTE: when a fixture is postponed, availabilities declared for its original date are not valid anymore.
+    // They are suspended instead of deleted, so that they can be resumed once the fixture is rescheduled.
+    availability_repo
+        .suspend_availabilities_for_fixture(&fixture_id, tx_ctx)
+        .await
+}
+
+pub async fn resume_availabilities_for_fixture<TxCtx>(
+    fixture_id: FixtureId,
+    availability_repo: &impl AvailabilityRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<RefereeId>, String> {
+    // NOTE: once a postponed fixture is rescheduled, the suspended availabilities count again, just as the
+    // assignments service stages its suspended assignments again. Referees who cannot make the new date withdraw them.
+    availability_repo
+        .resume_availabilities_for_fixture(&fixture_id, tx_ctx)
+        .await
+}
+
+pub async fn withdraw_availabilities_for_fixture<TxCtx>(
+    fixture_id: FixtureId,
+    availability_repo: &impl AvailabilityRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<RefereeId>, String> {
+    // NOTE: a cancelled fixture will never take place, therefore all its availabilities are withdrawn,
+    // including the suspended ones. Locks and deadlines do not apply, as this is not a decision of the referee.
+    let referee_ids = availability_repo
+        .withdraw_availabilities_for_fixture(&fixture_id, tx_ctx)
+        .await?;
+
+    for referee_id in referee_ids.iter() {
+        domain_event_repo
+            .store(
+                DomainEvent::AvailabilityWithdrawn {
+                    fixture_id,
+                    referee_id: *referee_id,
+                },
+                tx_ctx,
+            )
+            .await?;
+    }
+
+    Ok(referee_ids)
+}
+
+pub async fn withdraw_availabilities_of_referee<TxCtx>(
+    referee_id: RefereeId,
+    availability_repo: &impl AvailabilityRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<FixtureId>, String> {
+    // NOTE: an archived referee can no longer be assigned, therefore their availabilities are withdrawn.
+    // This is a cleanup after the fact, so neither locks nor deadlines apply.
+    let fixtures = availability_repo
+        .get_availabilities_for_referee(&referee_id, tx_ctx)
+        .await?;
+
+    availability_repo
+        .withdraw_availabilities(&fixtures, &referee_id, tx_ctx)
+        .await?;
+
+    for fixture_id in fixtures.iter() {
+        domain_event_repo
+            .store(
+                DomainEvent::AvailabilityWithdrawn {
+                    fixture_id: *fixture_id,
+                    referee_id,
+                },
+                tx_ctx,
+            )
+            .await?;
+    }
+
+    Ok(fixtures)
+}
+
 pub async fn get_availabilities_for_referee<TxCtx>(
     referee_id: RefereeId,
     referee_resolver: &impl RefereeResolver<Error = String>,
@@ -113,3 +339,274 @@ pub async fn get_availabilities_for_referee<TxCtx>(
 
     Ok(availabilities)
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone, Utc, Weekday};
+    use microservices_shared::{
+        domain_event_repo::MockDomainEventOutboxRepository,
+        domain_events::DomainEvent,
+        domain_ids::{FixtureId, RefereeId, VenueId},
+        resolvers::traits::MockRefereeResolver,
+    };
+    use mockall::predicate::eq;
+    use restinterface::{
+        FixtureDTO, FixtureIdDTO, FixtureStatusDTO, LeagueDTO, LeagueIdDTO, RefereeDTO, SeasonDTO,
+        SeasonIdDTO, TeamDTO, TeamIdDTO, VenueDTO, VenueIdDTO,
+    };
+    use uuid::Uuid;
+
+    use crate::{
+        application::availability_services::{
+            declare_availabilities_bulk, resume_availabilities_for_fixture,
+            withdraw_availabilities_for_fixture, withdraw_availabilities_of_referee,
+            AvailabilityBulkDeclaration,
+        },
+        domain::repositories::{
+            availability_lock_repo::MockAvailabilityLockRepository,
+            availability_repo::MockAvailabilityRepository, blackout_repo::MockBlackoutRepository,
+            declaration_deadline_repo::MockDeclarationDeadlineRepository,
+        },
+    };
+
+    fn fixture_dto(
+        date: DateTime<Utc>,
+        venue_id: VenueIdDTO,
+        status: FixtureStatusDTO,
+    ) -> FixtureDTO {
+        FixtureDTO {
+            id: FixtureIdDTO(Uuid::new_v4()),
+            league: LeagueDTO {
+                id: LeagueIdDTO(Uuid::new_v4()),
+                name: "League A".to_string(),
+            },
+            season: SeasonDTO {
+                id: SeasonIdDTO(Uuid::new_v4()),
+                name: "Season 2024/25".to_string(),
+                closed: false,
+            },
+            team_home: TeamDTO {
+                id: TeamIdDTO(Uuid::new_v4()),
+                name: "Team A".to_string(),
+                club: "Club A".to_string(),
+                archived: false,
+            },
+            team_away: TeamDTO {
+                id: TeamIdDTO(Uuid::new_v4()),
+                name: "Team B".to_string(),
+                club: "Club B".to_string(),
+                archived: false,
+            },
+            venue: VenueDTO {
+                id: venue_id,
+                name: "Venue".to_string(),
+                street: "Street".to_string(),
+                zip: "12345".to_string(),
+                city: "City".to_string(),
+                telephone: None,
+                email: None,
+                location: None,
+                archived: false,
+            },
+            date,
+            status,
+            result: None,
+            first_referee: None,
+            second_referee: None,
+            first_referee_min_level: 0,
+            second_referee_min_level: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_given_fixtures_when_declare_bulk_by_venue_and_weekday_then_only_matching_declared(
+    ) {
+        let mut referee_resolver = MockRefereeResolver::new();
+        let mut availability_repo = MockAvailabilityRepository::new();
+        let mut availability_lock_repo = MockAvailabilityLockRepository::new();
+        let mut declaration_deadline_repo = MockDeclarationDeadlineRepository::new();
+        let mut blackout_repo = MockBlackoutRepository::new();
+        let mut domain_event_repo = MockDomainEventOutboxRepository::new();
+
+        let referee_id = RefereeId::from(Uuid::new_v4());
+        let venue_a = VenueIdDTO(Uuid::new_v4());
+        let venue_b = VenueIdDTO(Uuid::new_v4());
+        let saturday = Utc.with_ymd_and_hms(2024, 10, 5, 18, 30, 0).unwrap();
+        let sunday = Utc.with_ymd_and_hms(2024, 10, 6, 18, 30, 0).unwrap();
+        let next_saturday = Utc.with_ymd_and_hms(2024, 10, 12, 18, 30, 0).unwrap();
+
+        let matching = fixture_dto(saturday, venue_a, FixtureStatusDTO::Scheduled);
+        let fixtures = vec![
+            matching.clone(),
+            fixture_dto(sunday, venue_a, FixtureStatusDTO::Scheduled),
+            fixture_dto(saturday, venue_b, FixtureStatusDTO::Scheduled),
+            fixture_dto(next_saturday, venue_a, FixtureStatusDTO::Cancelled),
+        ];
+        let already_declared = fixture_dto(next_saturday, venue_a, FixtureStatusDTO::Scheduled);
+        let fixtures = [fixtures, vec![already_declared.clone()]].concat();
+
+        referee_resolver
+            .expect_resolve()
+            .return_const(Ok(RefereeDTO {
+                id: referee_id.into(),
+                name: "Referee A".to_string(),
+                club: "Club A".to_string(),
+                qualification_level: 1,
+                home: None,
+                archived: false,
+            }));
+        availability_repo
+            .expect_get_availabilities_for_referee()
+            .return_const(Ok(vec![FixtureId::from(already_declared.id)]));
+        blackout_repo
+            .expect_find_by_referee()
+            .return_const(Ok(vec![]));
+        availability_lock_repo
+            .expect_get_all()
+            .return_const(Ok(vec![]));
+        declaration_deadline_repo
+            .expect_find_by_fixture()
+            .return_const(Ok(None));
+        availability_repo
+            .expect_declare_availabilities()
+            .with(
+                eq(vec![FixtureId::from(matching.id)]),
+                eq(referee_id),
+                eq(()),
+            )
+            .times(1)
+            .return_const(Ok(()));
+        domain_event_repo
+            .expect_store()
+            .times(1)
+            .return_const(Ok(()));
+
+        let bulk_declaration = AvailabilityBulkDeclaration {
+            from: None,
+            to: None,
+            venue_ids: vec![VenueId::from(venue_a)],
+            weekdays: vec![Weekday::Sat],
+        };
+
+        let declared = declare_availabilities_bulk(
+            referee_id,
+            &bulk_declaration,
+            &fixtures,
+            &referee_resolver,
+            &availability_repo,
+            &availability_lock_repo,
+            &declaration_deadline_repo,
+            &blackout_repo,
+            &domain_event_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(declared, vec![FixtureId::from(matching.id)]);
+    }
+
+    #[tokio::test]
+    async fn test_given_declared_availabilities_when_fixture_cancelled_then_all_are_withdrawn() {
+        let mut availability_repo = MockAvailabilityRepository::new();
+        let mut domain_event_repo = MockDomainEventOutboxRepository::new();
+
+        let fixture_id = FixtureId::from(Uuid::new_v4());
+        let referee_a = RefereeId::from(Uuid::new_v4());
+        let referee_b = RefereeId::from(Uuid::new_v4());
+
+        availability_repo
+            .expect_withdraw_availabilities_for_fixture()
+            .with(eq(fixture_id), eq(()))
+            .times(1)
+            .return_const(Ok(vec![referee_a, referee_b]));
+        domain_event_repo
+            .expect_store()
+            .withf(move |event, _| {
+                matches!(
+                    event,
+                    DomainEvent::AvailabilityWithdrawn { fixture_id: f, .. } if *f == fixture_id
+                )
+            })
+            .times(2)
+            .return_const(Ok(()));
+
+        let withdrawn = withdraw_availabilities_for_fixture(
+            fixture_id,
+            &availability_repo,
+            &domain_event_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(withdrawn, vec![referee_a, referee_b]);
+    }
+
+    #[tokio::test]
+    async fn test_given_suspended_availabilities_when_fixture_rescheduled_then_they_are_resumed() {
+        let mut availability_repo = MockAvailabilityRepository::new();
+
+        let fixture_id = FixtureId::from(Uuid::new_v4());
+        let referee_id = RefereeId::from(Uuid::new_v4());
+
+        availability_repo
+            .expect_resume_availabilities_for_fixture()
+            .with(eq(fixture_id), eq(()))
+            .times(1)
+            .return_const(Ok(vec![referee_id]));
+
+        let resumed = resume_availabilities_for_fixture(fixture_id, &availability_repo, &mut ())
+            .await
+            .unwrap();
+
+        assert_eq!(resumed, vec![referee_id]);
+    }
+
+    #[tokio::test]
+    async fn test_given_declared_availabilities_when_referee_archived_then_all_are_withdrawn() {
+        let mut availability_repo = MockAvailabilityRepository::new();
+        let mut domain_event_repo = MockDomainEventOutboxRepository::new();
+
+        let referee_id = RefereeId::from(Uuid::new_v4());
+        let fixture_a = FixtureId::from(Uuid::new_v4());
+        let fixture_b = FixtureId::from(Uuid::new_v4());
+
+        availability_repo
+            .expect_get_availabilities_for_referee()
+            .with(eq(referee_id), eq(()))
+            .times(1)
+            .return_const(Ok(vec![fixture_a, fixture_b]));
+        availability_repo
+            .expect_withdraw_availabilities()
+            .withf(move |fixtures, referee, _| {
+                fixtures == [fixture_a, fixture_b] && *referee == referee_id
+            })
+            .times(1)
+            .return_const(Ok(()));
+        for fixture_id in [fixture_a, fixture_b] {
+            domain_event_repo
+                .expect_store()
+                .withf(move |event, _| {
+                    matches!(
+                        event,
+                        DomainEvent::AvailabilityWithdrawn { fixture_id: f, referee_id: r }
+                            if *f == fixture_id && *r == referee_id
+                    )
+                })
+                .times(1)
+                .return_const(Ok(()));
+        }
+
+        let withdrawn = withdraw_availabilities_of_referee(
+            referee_id,
+            &availability_repo,
+            &domain_event_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(withdrawn, vec![fixture_a, fixture_b]);
+    }
+}
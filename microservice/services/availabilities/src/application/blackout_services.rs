@@ -0,0 +1,260 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use microservices_shared::{
+    domain_event_repo::DomainEventOutboxRepository,
+    domain_events::DomainEvent,
+    domain_ids::{BlackoutId, FixtureId, RefereeId},
+    resolvers::traits::RefereeResolver,
+};
+use restinterface::FixtureDTO;
+
+use crate::{
+    application::availability_lock_services::ensure_availabilities_changeable,
+    domain::{
+        aggregates::blackout::Blackout,
+        repositories::{
+            availability_lock_repo::AvailabilityLockRepository,
+            availability_repo::AvailabilityRepository, blackout_repo::BlackoutRepository,
+            declaration_deadline_repo::DeclarationDeadlineRepository,
+        },
+    },
+};
+
+/// Declares a blackout for the referee and withdraws the availabilities of the
+/// referee for all fixtures within it. Availabilities of fixtures which cannot
+/// be changed anymore are kept, as they have been finalised already.
+pub async fn declare_blackout<TxCtx>(
+    referee_id: RefereeId,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    fixtures: &[FixtureDTO],
+    referee_resolver: &impl RefereeResolver<Error = String>,
+    availability_repo: &impl AvailabilityRepository<TxCtx = TxCtx, Error = String>,
+    availability_lock_repo: &impl AvailabilityLockRepository<TxCtx = TxCtx, Error = String>,
+    declaration_deadline_repo: &impl DeclarationDeadlineRepository<TxCtx = TxCtx, Error = String>,
+    blackout_repo: &impl BlackoutRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Blackout, String> {
+    if from > to {
+        return Err("Blackout ends before it starts - cannot declare blackout".to_string());
+    }
+
+    let _referee = referee_resolver
+        .resolve(&referee_id)
+        .await
+        .expect(&format!("Referee {:?} not resolved", referee_id));
+
+    let blackout = Blackout::new(referee_id, from, to);
+    blackout_repo.save(&blackout, tx_ctx).await?;
+
+    let declared: HashSet<FixtureId> = availability_repo
+        .get_availabilities_for_referee(&referee_id, tx_ctx)
+        .await?
+        .into_iter()
+        .collect();
+
+    let now = Utc::now();
+    let mut fixture_ids = Vec::new();
+    for fixture in fixtures.iter().filter(|f| blackout.covers(&f.date)) {
+        let fixture_id = FixtureId::from(fixture.id);
+        if !declared.contains(&fixture_id) {
+            continue;
+        }
+
+        if ensure_availabilities_changeable(
+            fixture_id,
+            &fixture.date,
+            now,
+            availability_lock_repo,
+            declaration_deadline_repo,
+            tx_ctx,
+        )
+        .await
+        .is_err()
+        {
+            continue;
+        }
+
+        fixture_ids.push(fixture_id);
+    }
+
+    if fixture_ids.is_empty() {
+        return Ok(blackout);
+    }
+
+    availability_repo
+        .withdraw_availabilities(&fixture_ids, &referee_id, tx_ctx)
+        .await?;
+
+    for fixture_id in fixture_ids {
+        domain_event_repo
+            .store(
+                DomainEvent::AvailabilityWithdrawn {
+                    fixture_id,
+                    referee_id,
+                },
+                tx_ctx,
+            )
+            .await?;
+    }
+
+    Ok(blackout)
+}
+
+/// Removes the blackout, withdrawn availabilities are not declared again
+pub async fn remove_blackout<TxCtx>(
+    blackout_id: BlackoutId,
+    blackout_repo: &impl BlackoutRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    let blackout = blackout_repo
+        .find_by_id(blackout_id, tx_ctx)
+        .await?
+        .ok_or(format!("Blackout {:?} not found", blackout_id))?;
+
+    blackout_repo.delete(blackout.id(), tx_ctx).await
+}
+
+pub async fn get_blackouts_for_referee<TxCtx>(
+    referee_id: RefereeId,
+    blackout_repo: &impl BlackoutRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<Blackout>, String> {
+    blackout_repo.find_by_referee(referee_id, tx_ctx).await
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone, Utc};
+    use microservices_shared::{
+        domain_event_repo::MockDomainEventOutboxRepository,
+        domain_ids::{FixtureId, RefereeId},
+        resolvers::traits::MockRefereeResolver,
+    };
+    use mockall::predicate::eq;
+    use restinterface::{
+        FixtureDTO, FixtureIdDTO, FixtureStatusDTO, LeagueDTO, LeagueIdDTO, RefereeDTO, SeasonDTO,
+        SeasonIdDTO, TeamDTO, TeamIdDTO, VenueDTO, VenueIdDTO,
+    };
+    use uuid::Uuid;
+
+    use crate::{
+        application::blackout_services::declare_blackout,
+        domain::repositories::{
+            availability_lock_repo::MockAvailabilityLockRepository,
+            availability_repo::MockAvailabilityRepository, blackout_repo::MockBlackoutRepository,
+            declaration_deadline_repo::MockDeclarationDeadlineRepository,
+        },
+    };
+
+    fn fixture_dto(date: DateTime<Utc>) -> FixtureDTO {
+        let team = TeamDTO {
+            id: TeamIdDTO(Uuid::new_v4()),
+            name: "Team A".to_string(),
+            club: "Club A".to_string(),
+            archived: false,
+        };
+        FixtureDTO {
+            id: FixtureIdDTO(Uuid::new_v4()),
+            league: LeagueDTO {
+                id: LeagueIdDTO(Uuid::new_v4()),
+                name: "League A".to_string(),
+            },
+            season: SeasonDTO {
+                id: SeasonIdDTO(Uuid::new_v4()),
+                name: "Season 2024/25".to_string(),
+                closed: false,
+            },
+            team_home: team.clone(),
+            team_away: team,
+            venue: VenueDTO {
+                id: VenueIdDTO(Uuid::new_v4()),
+                name: "Venue".to_string(),
+                street: "Street".to_string(),
+                zip: "12345".to_string(),
+                city: "City".to_string(),
+                telephone: None,
+                email: None,
+                location: None,
+                archived: false,
+            },
+            date,
+            status: FixtureStatusDTO::Scheduled,
+            result: None,
+            first_referee: None,
+            second_referee: None,
+            first_referee_min_level: 0,
+            second_referee_min_level: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_given_declared_availabilities_when_declare_blackout_then_covered_are_withdrawn() {
+        let mut referee_resolver = MockRefereeResolver::new();
+        let mut availability_repo = MockAvailabilityRepository::new();
+        let mut availability_lock_repo = MockAvailabilityLockRepository::new();
+        let mut declaration_deadline_repo = MockDeclarationDeadlineRepository::new();
+        let mut blackout_repo = MockBlackoutRepository::new();
+        let mut domain_event_repo = MockDomainEventOutboxRepository::new();
+
+        let referee_id = RefereeId::from(Uuid::new_v4());
+        let within = fixture_dto(Utc.with_ymd_and_hms(2024, 12, 27, 18, 30, 0).unwrap());
+        let within_not_declared =
+            fixture_dto(Utc.with_ymd_and_hms(2024, 12, 28, 18, 30, 0).unwrap());
+        let outside = fixture_dto(Utc.with_ymd_and_hms(2025, 1, 11, 18, 30, 0).unwrap());
+        let fixtures = vec![within.clone(), within_not_declared, outside.clone()];
+
+        referee_resolver
+            .expect_resolve()
+            .return_const(Ok(RefereeDTO {
+                id: referee_id.into(),
+                name: "Referee A".to_string(),
+                club: "Club A".to_string(),
+                qualification_level: 1,
+                home: None,
+                archived: false,
+            }));
+        blackout_repo.expect_save().times(1).return_const(Ok(()));
+        availability_repo
+            .expect_get_availabilities_for_referee()
+            .return_const(Ok(vec![
+                FixtureId::from(within.id),
+                FixtureId::from(outside.id),
+            ]));
+        availability_lock_repo
+            .expect_get_all()
+            .return_const(Ok(vec![]));
+        declaration_deadline_repo
+            .expect_find_by_fixture()
+            .return_const(Ok(None));
+        availability_repo
+            .expect_withdraw_availabilities()
+            .with(eq(vec![FixtureId::from(within.id)]), eq(referee_id), eq(()))
+            .times(1)
+            .return_const(Ok(()));
+        domain_event_repo
+            .expect_store()
+            .times(1)
+            .return_const(Ok(()));
+
+        let blackout = declare_blackout(
+            referee_id,
+            Utc.with_ymd_and_hms(2024, 12, 24, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 1, 6, 23, 59, 59).unwrap(),
+            &fixtures,
+            &referee_resolver,
+            &availability_repo,
+            &availability_lock_repo,
+            &declaration_deadline_repo,
+            &blackout_repo,
+            &domain_event_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(blackout.referee_id(), referee_id);
+    }
+}
@@ -7,6 +7,8 @@ pub struct AppConfig {
     pub kafka_domain_events_topics: Vec<String>,
     pub kafka_consumer_group: String,
     pub otlp_endpoint: String,
+    pub idp_host: String,
+    pub idp_realm: String,
 }
 
 impl AppConfig {
@@ -25,6 +27,8 @@ impl AppConfig {
             kafka_domain_events_topics,
             kafka_consumer_group: get_from_env_or_panic("KAFKA_CONSUMER_GROUP"),
             otlp_endpoint: get_from_env_or_panic("OTLP_ENDPOINT"),
+            idp_host: get_from_env_or_panic("IDP_HOST"),
+            idp_realm: get_from_env_or_panic("IDP_REALM"),
         }
     }
 }
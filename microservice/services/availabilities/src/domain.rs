@@ -1 +1,2 @@
+pub mod aggregates;
 pub mod repositories;
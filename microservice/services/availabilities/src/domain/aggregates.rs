@@ -0,0 +1,3 @@
+pub mod availability_lock;
+pub mod blackout;
+pub mod declaration_deadline;
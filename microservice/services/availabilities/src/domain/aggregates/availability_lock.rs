@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use microservices_shared::{
+    domain_events::AvailabilityLockScope,
+    domain_ids::{AvailabilityLockId, FixtureId},
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct AvailabilityLock {
+    id: AvailabilityLockId,
+    // NOTE: as long as the lock exists, availabilities of the covered fixtures cannot be changed
+    scope: AvailabilityLockScope,
+}
+
+impl AvailabilityLock {
+    pub fn new(scope: AvailabilityLockScope) -> Self {
+        if let AvailabilityLockScope::DateRange { from, to } = scope {
+            if from > to {
+                // NOTE: this is not how we would like to handle this in a real application
+                panic!("Lock date range ends before it starts");
+            }
+        }
+
+        Self {
+            id: AvailabilityLockId(Uuid::new_v4()),
+            scope,
+        }
+    }
+
+    pub fn from_id(id: AvailabilityLockId, scope: AvailabilityLockScope) -> Self {
+        Self { id, scope }
+    }
+
+    pub fn id(&self) -> AvailabilityLockId {
+        self.id
+    }
+
+    pub fn scope(&self) -> AvailabilityLockScope {
+        self.scope
+    }
+
+    pub fn covers(&self, fixture_id: FixtureId, fixture_date: &DateTime<Utc>) -> bool {
+        match self.scope {
+            AvailabilityLockScope::Fixture(locked_fixture_id) => locked_fixture_id == fixture_id,
+            AvailabilityLockScope::DateRange { from, to } => {
+                from <= *fixture_date && *fixture_date <= to
+            }
+        }
+    }
+}
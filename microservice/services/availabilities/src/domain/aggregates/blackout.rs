@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use microservices_shared::domain_ids::{BlackoutId, RefereeId};
+use uuid::Uuid;
+
+/// A period in which a referee is not available at all, e.g. holidays
+#[derive(Debug, Clone)]
+pub struct Blackout {
+    id: BlackoutId,
+    referee_id: RefereeId,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+}
+
+impl Blackout {
+    pub fn new(referee_id: RefereeId, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        if from > to {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("Blackout ends before it starts");
+        }
+
+        Self {
+            id: BlackoutId(Uuid::new_v4()),
+            referee_id,
+            from,
+            to,
+        }
+    }
+
+    pub fn from_id(
+        id: BlackoutId,
+        referee_id: RefereeId,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            referee_id,
+            from,
+            to,
+        }
+    }
+
+    pub fn id(&self) -> BlackoutId {
+        self.id
+    }
+
+    pub fn referee_id(&self) -> RefereeId {
+        self.referee_id
+    }
+
+    pub fn from(&self) -> &DateTime<Utc> {
+        &self.from
+    }
+
+    pub fn to(&self) -> &DateTime<Utc> {
+        &self.to
+    }
+
+    pub fn covers(&self, date: &DateTime<Utc>) -> bool {
+        self.from <= *date && *date <= self.to
+    }
+}
@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use microservices_shared::domain_ids::FixtureId;
+
+#[derive(Debug, Clone)]
+pub struct DeclarationDeadline {
+    fixture_id: FixtureId,
+    deadline: DateTime<Utc>,
+    // NOTE: once the deadline has passed, it is closed by locking the availabilities of its fixture
+    closed: bool,
+}
+
+impl DeclarationDeadline {
+    pub fn new(fixture_id: FixtureId, deadline: DateTime<Utc>) -> Self {
+        Self {
+            fixture_id,
+            deadline,
+            closed: false,
+        }
+    }
+
+    pub fn from_values(fixture_id: FixtureId, deadline: DateTime<Utc>, closed: bool) -> Self {
+        Self {
+            fixture_id,
+            deadline,
+            closed,
+        }
+    }
+
+    pub fn fixture_id(&self) -> FixtureId {
+        self.fixture_id
+    }
+
+    pub fn deadline(&self) -> &DateTime<Utc> {
+        &self.deadline
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn has_passed(&self, now: &DateTime<Utc>) -> bool {
+        self.deadline <= *now
+    }
+
+    pub fn close(&mut self) {
+        if self.closed {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("Declaration deadline already closed");
+        }
+
+        self.closed = true;
+    }
+}
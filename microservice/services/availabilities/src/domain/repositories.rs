@@ -1 +1,4 @@
+pub mod availability_lock_repo;
 pub mod availability_repo;
+pub mod blackout_repo;
+pub mod declaration_deadline_repo;
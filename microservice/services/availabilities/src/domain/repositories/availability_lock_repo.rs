@@ -0,0 +1,29 @@
+use microservices_shared::domain_ids::AvailabilityLockId;
+use mockall::automock;
+
+use crate::domain::aggregates::availability_lock::AvailabilityLock;
+
+#[allow(async_fn_in_trait)]
+#[automock(type Error = String; type TxCtx = ();)]
+pub trait AvailabilityLockRepository {
+    type Error;
+    type TxCtx;
+
+    async fn find_by_id(
+        &self,
+        lock_id: AvailabilityLockId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<AvailabilityLock>, Self::Error>;
+    async fn get_all(&self, tx_ctx: &mut Self::TxCtx)
+        -> Result<Vec<AvailabilityLock>, Self::Error>;
+    async fn save(
+        &self,
+        lock: &AvailabilityLock,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<(), Self::Error>;
+    async fn delete(
+        &self,
+        lock_id: AvailabilityLockId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<(), Self::Error>;
+}
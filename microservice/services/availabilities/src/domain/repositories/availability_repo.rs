@@ -19,6 +19,18 @@ pub trait AvailabilityRepository {
         referee: &RefereeId,
         tx_ctx: &mut Self::TxCtx,
     ) -> Result<(), Self::Error>;
+    async fn declare_availabilities(
+        &self,
+        fixtures: &[FixtureId],
+        referee: &RefereeId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<(), Self::Error>;
+    async fn withdraw_availabilities(
+        &self,
+        fixtures: &[FixtureId],
+        referee: &RefereeId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<(), Self::Error>;
     async fn get_availabilities_for_referee(
         &self,
         referee: &RefereeId,
@@ -30,4 +42,19 @@ pub trait AvailabilityRepository {
         referee: &RefereeId,
         tx_ctx: &mut Self::TxCtx,
     ) -> Result<bool, Self::Error>;
+    async fn suspend_availabilities_for_fixture(
+        &self,
+        fixture: &FixtureId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<RefereeId>, Self::Error>;
+    async fn resume_availabilities_for_fixture(
+        &self,
+        fixture: &FixtureId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<RefereeId>, Self::Error>;
+    async fn withdraw_availabilities_for_fixture(
+        &self,
+        fixture: &FixtureId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<RefereeId>, Self::Error>;
 }
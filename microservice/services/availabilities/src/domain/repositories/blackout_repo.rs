@@ -0,0 +1,28 @@
+use microservices_shared::domain_ids::{BlackoutId, RefereeId};
+use mockall::automock;
+
+use crate::domain::aggregates::blackout::Blackout;
+
+#[allow(async_fn_in_trait)]
+#[automock(type Error = String; type TxCtx = ();)]
+pub trait BlackoutRepository {
+    type Error;
+    type TxCtx;
+
+    async fn find_by_id(
+        &self,
+        blackout_id: BlackoutId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<Blackout>, Self::Error>;
+    async fn find_by_referee(
+        &self,
+        referee_id: RefereeId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<Blackout>, Self::Error>;
+    async fn save(&self, blackout: &Blackout, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error>;
+    async fn delete(
+        &self,
+        blackout_id: BlackoutId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<(), Self::Error>;
+}
@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use microservices_shared::domain_ids::FixtureId;
+use mockall::automock;
+
+use crate::domain::aggregates::declaration_deadline::DeclarationDeadline;
+
+#[allow(async_fn_in_trait)]
+#[automock(type Error = String; type TxCtx = ();)]
+pub trait DeclarationDeadlineRepository {
+    type Error;
+    type TxCtx;
+
+    async fn find_by_fixture(
+        &self,
+        fixture_id: FixtureId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<DeclarationDeadline>, Self::Error>;
+    /// All deadlines which have passed at the given time but are not closed yet
+    async fn find_due(
+        &self,
+        now: DateTime<Utc>,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<DeclarationDeadline>, Self::Error>;
+    async fn save(
+        &self,
+        deadline: &DeclarationDeadline,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<(), Self::Error>;
+}
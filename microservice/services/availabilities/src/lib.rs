@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use microservices_shared::token::AccessTokenVerifier;
 use opentelemetry::global::BoxedTracer;
 use sqlx::PgPool;
 
@@ -13,4 +14,5 @@ pub struct AppState {
     pub connection_pool: PgPool,
     pub redis_client: redis::Client,
     pub tracer: Arc<BoxedTracer>,
+    pub token_verifier: AccessTokenVerifier,
 }
@@ -1,15 +1,25 @@
 use availabilities::config::AppConfig;
+use availabilities::ports::kafka::domain_events_handler::DomainEventHandlers;
 use availabilities::ports::rest::availabilities::{
-    declare_availability_handler, fetch_availabilities_for_referee_handler,
-    withdraw_availability_handler,
+    declare_availabilities_bulk_handler, declare_availability_handler,
+    fetch_availabilities_for_referee_handler, withdraw_availability_handler,
 };
+use availabilities::ports::rest::availability_locks::{
+    fetch_availability_locks_handler, lock_availabilities_handler,
+    set_declaration_deadline_handler, unlock_availabilities_handler,
+};
+use availabilities::ports::rest::blackouts::{
+    declare_blackout_handler, fetch_blackouts_for_referee_handler, remove_blackout_handler,
+};
+use availabilities::ports::scheduler::run_declaration_deadline_closer;
 use axum::http::Method;
 use axum::{
-    routing::{get, post},
+    routing::{delete, get, post, put},
     Router,
 };
 
-use microservices_shared::domain_events::{DomainEventCallbacksLoggerImpl, DomainEventConsumer};
+use microservices_shared::domain_events::DomainEventConsumer;
+use microservices_shared::token::AccessTokenVerifier;
 use sqlx::PgPool;
 use std::sync::Arc;
 
@@ -34,19 +44,27 @@ async fn main() {
     let connection_pool = PgPool::connect(&config.db_url).await.unwrap();
     let redis_client = redis::Client::open(config.redis_url).unwrap();
 
-    let domain_event_callbacks = Box::new(DomainEventCallbacksLoggerImpl::new(tracer_arc.clone()));
+    let domain_event_handlers = DomainEventHandlers::new(tracer_arc.clone()).into_registry();
     let mut domain_event_consumer = DomainEventConsumer::new(
         &config.kafka_consumer_group,
         &config.kafka_url,
         &config.kafka_domain_events_topics,
         connection_pool.clone(),
-        domain_event_callbacks,
+        domain_event_handlers,
     );
 
+    let deadline_closer_pool = connection_pool.clone();
+    let deadline_closer_tracer = tracer_arc.clone();
+
+    let token_verifier = AccessTokenVerifier::new(&config.idp_host, &config.idp_realm)
+        .await
+        .unwrap();
+
     let app_state = AppState {
         tracer: tracer_arc,
         connection_pool: connection_pool.clone(),
         redis_client,
+        token_verifier,
     };
     let state_arc = Arc::new(app_state);
 
@@ -66,6 +84,10 @@ async fn main() {
             "/availabilities/declare/fixture/:fixture_id/referee/:referee_id",
             post(declare_availability_handler),
         )
+        .route(
+            "/availabilities/declare/referee/:referee_id",
+            post(declare_availabilities_bulk_handler),
+        )
         .route(
             "/availabilities/withdraw/fixture/:fixture_id/referee/:referee_id",
             post(withdraw_availability_handler),
@@ -74,6 +96,26 @@ async fn main() {
             "/availabilities/referee/:referee_id",
             get(fetch_availabilities_for_referee_handler),
         )
+        .route(
+            "/availabilities/locks",
+            get(fetch_availability_locks_handler).post(lock_availabilities_handler),
+        )
+        .route(
+            "/availabilities/locks/:lock_id",
+            delete(unlock_availabilities_handler),
+        )
+        .route(
+            "/availabilities/deadlines/fixture/:fixture_id",
+            put(set_declaration_deadline_handler),
+        )
+        .route(
+            "/availabilities/blackouts/referee/:referee_id",
+            get(fetch_blackouts_for_referee_handler).post(declare_blackout_handler),
+        )
+        .route(
+            "/availabilities/blackouts/:blackout_id",
+            delete(remove_blackout_handler),
+        )
         .layer(cors)
         .with_state(state_arc);
 
@@ -85,6 +127,10 @@ async fn main() {
         domain_event_consumer.run().await;
     });
 
+    tokio::spawn(async move {
+        run_declaration_deadline_closer(deadline_closer_pool, deadline_closer_tracer).await;
+    });
+
     span.end();
     axum::serve(listener, app).await.unwrap();
 }
@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use crate::{
+    adapters::db::availability_repo_pg::AvailabilityRepositoryPg,
+    application::availability_services::{
+        resume_availabilities_for_fixture, suspend_availabilities_for_fixture,
+        withdraw_availabilities_for_fixture, withdraw_availabilities_of_referee,
+    },
+};
+use log::info;
+use microservices_shared::{
+    domain_event_repo::DomainEventRepositoryPg,
+    domain_events::DomainEventHandlerRegistry,
+    domain_ids::{FixtureId, RefereeId},
+};
+use opentelemetry::global::BoxedTracer;
+use opentelemetry::{
+    trace::{Span, Tracer},
+    KeyValue,
+};
+
+pub struct DomainEventHandlers {
+    tracer: Arc<BoxedTracer>,
+}
+
+impl DomainEventHandlers {
+    pub fn new(tracer: Arc<BoxedTracer>) -> Self {
+        Self { tracer }
+    }
+
+    /// Registers the handlers for the domain events this service reacts to
+    pub fn into_registry(
+        self,
+    ) -> DomainEventHandlerRegistry<Self, sqlx::Transaction<'static, sqlx::Postgres>> {
+        let tracer = self.tracer.clone();
+        DomainEventHandlerRegistry::new(self, tracer)
+            .on_referee_archived(|handlers, referee_id, tx_ctx| {
+                Box::pin(handlers.on_referee_archived(referee_id, tx_ctx))
+            })
+            .on_fixture_cancelled(|handlers, fixture_id, tx_ctx| {
+                Box::pin(handlers.on_fixture_cancelled(fixture_id, tx_ctx))
+            })
+            .on_fixture_postponed(|handlers, fixture_id, tx_ctx| {
+                Box::pin(handlers.on_fixture_postponed(fixture_id, tx_ctx))
+            })
+            .on_fixture_rescheduled(|handlers, fixture_id, tx_ctx| {
+                Box::pin(handlers.on_fixture_rescheduled(fixture_id, tx_ctx))
+            })
+    }
+
+    async fn on_referee_archived(
+        &mut self,
+        referee_id: RefereeId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Availabilities: Referee archived: {:?}",
+            referee_id
+        );
+
+        let mut span = self.tracer.start("on_referee_archived");
+        span.set_attribute(KeyValue::new("referee_id", referee_id.to_string()));
+
+        let availability_repo = AvailabilityRepositoryPg::new();
+        let domain_event_repo = DomainEventRepositoryPg::new();
+
+        let withdrawn_fixtures = withdraw_availabilities_of_referee(
+            referee_id,
+            &availability_repo,
+            &domain_event_repo,
+            tx_ctx,
+        )
+        .await?;
+
+        info!(
+            "Availabilities withdrawn due to archived referee for fixtures: {:?}",
+            withdrawn_fixtures
+        );
+
+        Ok(())
+    }
+
+    async fn on_fixture_cancelled(
+        &mut self,
+        fixture_id: FixtureId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Availabilities: Fixture cancelled: {:?}",
+            fixture_id
+        );
+
+        let mut span = self.tracer.start("on_fixture_cancelled");
+        span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
+
+        let availability_repo = AvailabilityRepositoryPg::new();
+        let domain_event_repo = DomainEventRepositoryPg::new();
+
+        let withdrawn_referees = withdraw_availabilities_for_fixture(
+            fixture_id,
+            &availability_repo,
+            &domain_event_repo,
+            tx_ctx,
+        )
+        .await?;
+
+        info!(
+            "Availabilities withdrawn due to cancelled fixture for referees: {:?}",
+            withdrawn_referees
+        );
+
+        Ok(())
+    }
+
+    async fn on_fixture_postponed(
+        &mut self,
+        fixture_id: FixtureId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Availabilities: Fixture postponed: {:?}",
+            fixture_id
+        );
+
+        let mut span = self.tracer.start("on_fixture_postponed");
+        span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
+
+        let availability_repo = AvailabilityRepositoryPg::new();
+
+        let suspended_referees =
+            suspend_availabilities_for_fixture(fixture_id, &availability_repo, tx_ctx).await?;
+
+        info!(
+            "Availabilities suspended due to postponed fixture for referees: {:?}",
+            suspended_referees
+        );
+
+        Ok(())
+    }
+
+    async fn on_fixture_rescheduled(
+        &mut self,
+        fixture_id: FixtureId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Availabilities: Fixture rescheduled: {:?}",
+            fixture_id
+        );
+
+        let mut span = self.tracer.start("on_fixture_rescheduled");
+        span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
+
+        let availability_repo = AvailabilityRepositoryPg::new();
+
+        let resumed_referees =
+            resume_availabilities_for_fixture(fixture_id, &availability_repo, tx_ctx).await?;
+
+        info!(
+            "Availabilities resumed due to rescheduled fixture for referees: {:?}",
+            resumed_referees
+        );
+
+        Ok(())
+    }
+}
@@ -1 +1,3 @@
 pub mod availabilities;
+pub mod availability_locks;
+pub mod blackouts;
@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::Json;
+use chrono::{DateTime, Utc};
+use log::{error, info};
+use microservices_shared::domain_event_repo::DomainEventRepositoryPg;
+use microservices_shared::resolvers::impls::FixtureResolverImpl;
+use microservices_shared::token::authorize_any_role;
+use opentelemetry::{
+    trace::{Span, Tracer},
+    KeyValue,
+};
+use restinterface::{
+    AvailabilityLockDTO, AvailabilityLockIdDTO, AvailabilityLockScopeDTO, DeclarationDeadlineDTO,
+    FixtureIdDTO,
+};
+use shared::app_error::AppError;
+use tokio::sync::Mutex;
+
+use crate::adapters::db::availability_lock_repo_pg::AvailabilityLockRepositoryPg;
+use crate::adapters::db::declaration_deadline_repo_pg::DeclarationDeadlineRepositoryPg;
+use crate::application::availability_lock_services::{
+    get_availability_locks, lock_availabilities, set_declaration_deadline, unlock_availabilities,
+};
+use crate::domain::aggregates::availability_lock::AvailabilityLock;
+use crate::domain::aggregates::declaration_deadline::DeclarationDeadline;
+use crate::AppState;
+
+impl From<AvailabilityLock> for AvailabilityLockDTO {
+    fn from(lock: AvailabilityLock) -> Self {
+        AvailabilityLockDTO {
+            id: lock.id().into(),
+            scope: lock.scope().into(),
+        }
+    }
+}
+
+impl From<DeclarationDeadline> for DeclarationDeadlineDTO {
+    fn from(deadline: DeclarationDeadline) -> Self {
+        DeclarationDeadlineDTO {
+            fixture_id: deadline.fixture_id().into(),
+            deadline: *deadline.deadline(),
+            closed: deadline.is_closed(),
+        }
+    }
+}
+
+pub async fn fetch_availability_locks_handler(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<AvailabilityLockDTO>>, AppError> {
+    info!("Fetching availability locks");
+    let _span = state.tracer.start("fetch_availability_locks");
+
+    let mut tx = state.connection_pool.begin().await.map_err(|e| {
+        error!("Error beginning transaction: {:?}", e);
+        AppError::from_error(&e.to_string())
+    })?;
+
+    let availability_lock_repo = AvailabilityLockRepositoryPg::new();
+
+    let locks = get_availability_locks(&availability_lock_repo, &mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(locks.into_iter().map(|l| l.into()).collect()))
+}
+
+pub async fn lock_availabilities_handler(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(scope): Json<AvailabilityLockScopeDTO>,
+) -> Result<Json<AvailabilityLockDTO>, AppError> {
+    info!("Locking availabilities: {:?}", scope);
+    let mut span = state.tracer.start("lock_availabilities");
+    span.set_attribute(KeyValue::new("scope", format!("{:?}", scope)));
+
+    authorize_any_role(&headers, &state.token_verifier, &vec!["admin"])
+        .map_err(|e| AppError::from_error_unauthorized(&e))?;
+
+    let mut tx = state.connection_pool.begin().await.map_err(|e| {
+        error!("Error beginning transaction: {:?}", e);
+        AppError::from_error(&e.to_string())
+    })?;
+
+    let availability_lock_repo = AvailabilityLockRepositoryPg::new();
+    let domain_event_repo = DomainEventRepositoryPg::new();
+
+    let lock = lock_availabilities(
+        scope.into(),
+        &availability_lock_repo,
+        &domain_event_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(lock.into()))
+}
+
+pub async fn unlock_availabilities_handler(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(lock_id): Path<AvailabilityLockIdDTO>,
+) -> Result<Json<()>, AppError> {
+    info!("Unlocking availabilities: {:?}", lock_id);
+    let mut span = state.tracer.start("unlock_availabilities");
+    span.set_attribute(KeyValue::new("lock_id", lock_id.to_string()));
+
+    authorize_any_role(&headers, &state.token_verifier, &vec!["admin"])
+        .map_err(|e| AppError::from_error_unauthorized(&e))?;
+
+    let mut tx = state.connection_pool.begin().await.map_err(|e| {
+        error!("Error beginning transaction: {:?}", e);
+        AppError::from_error(&e.to_string())
+    })?;
+
+    let availability_lock_repo = AvailabilityLockRepositoryPg::new();
+    let domain_event_repo = DomainEventRepositoryPg::new();
+
+    unlock_availabilities(
+        lock_id.into(),
+        &availability_lock_repo,
+        &domain_event_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(()))
+}
+
+pub async fn set_declaration_deadline_handler(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path(fixture_id): Path<FixtureIdDTO>,
+    Json(deadline): Json<DateTime<Utc>>,
+) -> Result<Json<DeclarationDeadlineDTO>, AppError> {
+    info!(
+        "Setting declaration deadline for fixture: {:?} to {:?}",
+        fixture_id, deadline
+    );
+    let mut span = state.tracer.start("set_declaration_deadline");
+    span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
+    span.set_attribute(KeyValue::new("deadline", deadline.to_string()));
+
+    authorize_any_role(&headers, &state.token_verifier, &vec!["admin"])
+        .map_err(|e| AppError::from_error_unauthorized(&e))?;
+
+    let mut tx = state.connection_pool.begin().await.map_err(|e| {
+        error!("Error beginning transaction: {:?}", e);
+        AppError::from_error(&e.to_string())
+    })?;
+
+    let redis_conn = state
+        .redis_client
+        .get_connection()
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+    let redis_conn_arc_mutex = Arc::new(Mutex::new(redis_conn));
+
+    let fixture_resolver = FixtureResolverImpl::new(redis_conn_arc_mutex.clone());
+    let declaration_deadline_repo = DeclarationDeadlineRepositoryPg::new();
+
+    let declaration_deadline = set_declaration_deadline(
+        fixture_id.into(),
+        deadline,
+        &fixture_resolver,
+        &declaration_deadline_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(declaration_deadline.into()))
+}
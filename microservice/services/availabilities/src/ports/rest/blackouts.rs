@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::Json;
+use log::{error, info};
+use microservices_shared::domain_event_repo::DomainEventRepositoryPg;
+use microservices_shared::resolvers::impls::RefereeResolverImpl;
+use opentelemetry::{
+    trace::{Span, Tracer},
+    KeyValue,
+};
+use restinterface::{
+    fetch_fixtures, BlackoutCreationDTO, BlackoutDTO, BlackoutIdDTO, RefereeIdDTO,
+};
+use shared::app_error::AppError;
+use tokio::sync::Mutex;
+
+use crate::adapters::db::availability_lock_repo_pg::AvailabilityLockRepositoryPg;
+use crate::adapters::db::availability_repo_pg::AvailabilityRepositoryPg;
+use crate::adapters::db::blackout_repo_pg::BlackoutRepositoryPg;
+use crate::adapters::db::declaration_deadline_repo_pg::DeclarationDeadlineRepositoryPg;
+use crate::application::blackout_services::{
+    declare_blackout, get_blackouts_for_referee, remove_blackout,
+};
+use crate::domain::aggregates::blackout::Blackout;
+use crate::AppState;
+
+impl From<Blackout> for BlackoutDTO {
+    fn from(blackout: Blackout) -> Self {
+        BlackoutDTO {
+            id: blackout.id().into(),
+            referee_id: blackout.referee_id().into(),
+            from: *blackout.from(),
+            to: *blackout.to(),
+        }
+    }
+}
+
+pub async fn fetch_blackouts_for_referee_handler(
+    State(state): State<Arc<AppState>>,
+    Path(referee_id): Path<RefereeIdDTO>,
+) -> Result<Json<Vec<BlackoutDTO>>, AppError> {
+    info!("Fetching blackouts for referee: {:?}", referee_id);
+    let mut span = state.tracer.start("fetch_blackouts_for_referee");
+    span.set_attribute(KeyValue::new("referee_id", referee_id.to_string()));
+
+    let mut tx = state.connection_pool.begin().await.map_err(|e| {
+        error!("Error beginning transaction: {:?}", e);
+        AppError::from_error(&e.to_string())
+    })?;
+
+    let blackout_repo = BlackoutRepositoryPg::new();
+
+    let blackouts = get_blackouts_for_referee(referee_id.into(), &blackout_repo, &mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(blackouts.into_iter().map(|b| b.into()).collect()))
+}
+
+pub async fn declare_blackout_handler(
+    State(state): State<Arc<AppState>>,
+    Path(referee_id): Path<RefereeIdDTO>,
+    Json(blackout_creation): Json<BlackoutCreationDTO>,
+) -> Result<Json<BlackoutDTO>, AppError> {
+    info!(
+        "Declaring blackout for referee: {:?} from {:?} to {:?}",
+        referee_id, blackout_creation.from, blackout_creation.to
+    );
+    let mut span = state.tracer.start("declare_blackout");
+    span.set_attribute(KeyValue::new("referee_id", referee_id.to_string()));
+
+    let mut tx = state.connection_pool.begin().await.map_err(|e| {
+        error!("Error beginning transaction: {:?}", e);
+        AppError::from_error(&e.to_string())
+    })?;
+
+    let redis_conn = state
+        .redis_client
+        .get_connection()
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+    let redis_conn_arc_mutex = Arc::new(Mutex::new(redis_conn));
+
+    // NOTE: fixtures are owned by the fixtures service, therefore we fetch them via its REST API
+    let fixtures = fetch_fixtures().await;
+
+    let referee_resolver = RefereeResolverImpl::new(redis_conn_arc_mutex.clone());
+    let availability_repo = AvailabilityRepositoryPg::new();
+    let availability_lock_repo = AvailabilityLockRepositoryPg::new();
+    let declaration_deadline_repo = DeclarationDeadlineRepositoryPg::new();
+    let blackout_repo = BlackoutRepositoryPg::new();
+    let domain_event_repo = DomainEventRepositoryPg::new();
+
+    let blackout = declare_blackout(
+        referee_id.into(),
+        blackout_creation.from,
+        blackout_creation.to,
+        &fixtures,
+        &referee_resolver,
+        &availability_repo,
+        &availability_lock_repo,
+        &declaration_deadline_repo,
+        &blackout_repo,
+        &domain_event_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(blackout.into()))
+}
+
+pub async fn remove_blackout_handler(
+    State(state): State<Arc<AppState>>,
+    Path(blackout_id): Path<BlackoutIdDTO>,
+) -> Result<Json<()>, AppError> {
+    info!("Removing blackout: {:?}", blackout_id);
+    let mut span = state.tracer.start("remove_blackout");
+    span.set_attribute(KeyValue::new("blackout_id", blackout_id.to_string()));
+
+    let mut tx = state.connection_pool.begin().await.map_err(|e| {
+        error!("Error beginning transaction: {:?}", e);
+        AppError::from_error(&e.to_string())
+    })?;
+
+    let blackout_repo = BlackoutRepositoryPg::new();
+
+    remove_blackout(blackout_id.into(), &blackout_repo, &mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(()))
+}
@@ -0,0 +1,75 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::Utc;
+use log::{info, warn};
+use microservices_shared::domain_event_repo::DomainEventRepositoryPg;
+use opentelemetry::{
+    global::BoxedTracer,
+    trace::{Span, Tracer},
+    KeyValue,
+};
+use sqlx::PgPool;
+
+use crate::{
+    adapters::db::{
+        availability_lock_repo_pg::AvailabilityLockRepositoryPg,
+        declaration_deadline_repo_pg::DeclarationDeadlineRepositoryPg,
+    },
+    application::availability_lock_services::close_due_declaration_deadlines,
+};
+
+const DECLARATION_DEADLINE_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Periodically closes passed declaration deadlines, the lock of a closed
+/// deadline is published as domain event through the outbox like any other.
+pub async fn run_declaration_deadline_closer(connection_pool: PgPool, tracer: Arc<BoxedTracer>) {
+    let mut interval = tokio::time::interval(DECLARATION_DEADLINE_CHECK_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        let mut span = tracer.start("close_due_declaration_deadlines");
+
+        let mut tx = match connection_pool.begin().await {
+            Ok(tx) => tx,
+            Err(e) => {
+                warn!("Error beginning transaction for closing deadlines: {}", e);
+                continue;
+            }
+        };
+
+        let declaration_deadline_repo = DeclarationDeadlineRepositoryPg::new();
+        let availability_lock_repo = AvailabilityLockRepositoryPg::new();
+        let domain_event_repo = DomainEventRepositoryPg::new();
+
+        let result = close_due_declaration_deadlines(
+            Utc::now(),
+            &declaration_deadline_repo,
+            &availability_lock_repo,
+            &domain_event_repo,
+            &mut tx,
+        )
+        .await;
+
+        match result {
+            Ok(locked_fixtures) => {
+                if let Err(e) = tx.commit().await {
+                    warn!("Error committing closed deadlines: {}", e);
+                    continue;
+                }
+
+                if !locked_fixtures.is_empty() {
+                    info!(
+                        "Declaration deadlines closed, fixtures locked: {:?}",
+                        locked_fixtures
+                    );
+                }
+                span.set_attribute(KeyValue::new(
+                    "locked_fixtures",
+                    locked_fixtures.len() as i64,
+                ));
+            }
+            Err(e) => warn!("Error closing declaration deadlines: {}", e),
+        }
+    }
+}
@@ -1 +1,4 @@
 pub mod fixture_repo_pg;
+pub mod league_repo_pg;
+pub mod season_repo_pg;
+pub mod standing_repo_pg;
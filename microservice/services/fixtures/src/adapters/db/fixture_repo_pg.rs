@@ -1,11 +1,11 @@
 use chrono::{DateTime, Datelike, TimeZone, Utc};
 use log::debug;
-use microservices_shared::domain_ids::{FixtureId, RefereeId, TeamId, VenueId};
+use microservices_shared::domain_ids::{FixtureId, LeagueId, RefereeId, SeasonId, TeamId, VenueId};
 use sqlx::{Postgres, Transaction};
 use uuid::Uuid;
 
 use crate::domain::{
-    aggregates::fixture::{Fixture, FixtureStatus},
+    aggregates::fixture::{Fixture, FixtureResult, FixtureStatus, SetScore},
     repositories::fixture_repo::FixtureRepository,
 };
 
@@ -15,6 +15,8 @@ pub struct FixtureRepositoryPg();
 #[sqlx(type_name = "rustddd.fixture_status", rename_all = "lowercase")]
 enum FixtureStatusDb {
     Scheduled,
+    Postponed,
+    Played,
     Cancelled,
 }
 
@@ -23,11 +25,18 @@ struct FixtureDb {
     pub id: Uuid,
     pub date: DateTime<Utc>,
     pub status: FixtureStatusDb,
+    pub league_id: Uuid,
+    pub season_id: Uuid,
     pub venue_id: Uuid,
     pub team_home_id: Uuid,
     pub team_away_id: Uuid,
     pub first_referee_id: Option<Uuid>,
     pub second_referee_id: Option<Uuid>,
+    pub first_referee_min_level: i32,
+    pub second_referee_min_level: i32,
+    pub result_home_points: Option<Vec<i32>>,
+    pub result_away_points: Option<Vec<i32>>,
+    pub result_winner_id: Option<Uuid>,
 }
 
 impl FixtureRepositoryPg {
@@ -40,6 +49,8 @@ impl From<FixtureStatusDb> for FixtureStatus {
     fn from(status: FixtureStatusDb) -> Self {
         match status {
             FixtureStatusDb::Scheduled => FixtureStatus::Scheduled,
+            FixtureStatusDb::Postponed => FixtureStatus::Postponed,
+            FixtureStatusDb::Played => FixtureStatus::Played,
             FixtureStatusDb::Cancelled => FixtureStatus::Cancelled,
         }
     }
@@ -49,6 +60,8 @@ impl From<FixtureStatus> for FixtureStatusDb {
     fn from(status: FixtureStatus) -> Self {
         match status {
             FixtureStatus::Scheduled => FixtureStatusDb::Scheduled,
+            FixtureStatus::Postponed => FixtureStatusDb::Postponed,
+            FixtureStatus::Played => FixtureStatusDb::Played,
             FixtureStatus::Cancelled => FixtureStatusDb::Cancelled,
         }
     }
@@ -56,15 +69,38 @@ impl From<FixtureStatus> for FixtureStatusDb {
 
 impl From<FixtureDb> for Fixture {
     fn from(fixture: FixtureDb) -> Self {
+        // NOTE: the set scores of a result are stored as two arrays of the same length, one per team
+        let result = fixture.result_winner_id.map(|winner_id| {
+            let home_points = fixture
+                .result_home_points
+                .expect("result_home_points is required");
+            let away_points = fixture
+                .result_away_points
+                .expect("result_away_points is required");
+            FixtureResult::new(
+                home_points
+                    .into_iter()
+                    .zip(away_points)
+                    .map(|(home, away)| SetScore { home, away })
+                    .collect(),
+                TeamId::from(winner_id),
+            )
+        });
+
         Fixture::from_id(
             FixtureId::from(fixture.id),
             fixture.date,
             fixture.status.into(),
+            LeagueId::from(fixture.league_id),
+            SeasonId::from(fixture.season_id),
             VenueId::from(fixture.venue_id),
             TeamId::from(fixture.team_home_id),
             TeamId::from(fixture.team_away_id),
             fixture.first_referee_id.map(|id| RefereeId::from(id)),
             fixture.second_referee_id.map(|id| RefereeId::from(id)),
+            fixture.first_referee_min_level,
+            fixture.second_referee_min_level,
+            result,
         )
     }
 }
@@ -81,7 +117,7 @@ impl FixtureRepository for FixtureRepositoryPg {
         // NOTE: need to force nullable for referees, see https://docs.rs/sqlx/0.4.2/sqlx/macro.query.html#force-nullable
         let fixture: Option<FixtureDb> = sqlx::query_as!(
             FixtureDb, 
-            r#"SELECT f.fixture_id as id, f.date, f.status as "status: FixtureStatusDb", f.venue_id, f.team_home_id, f.team_away_id, f.first_referee_id as "first_referee_id?", f.second_referee_id as "second_referee_id?"
+            r#"SELECT f.fixture_id as id, f.date, f.status as "status: FixtureStatusDb", f.league_id, f.season_id, f.venue_id, f.team_home_id, f.team_away_id, f.first_referee_id as "first_referee_id?", f.second_referee_id as "second_referee_id?", f.first_referee_min_level, f.second_referee_min_level, f.result_home_points, f.result_away_points, f.result_winner_id
             FROM rustddd.fixtures f
             WHERE f.fixture_id = $1"#,
             fixture_id.0
@@ -98,7 +134,7 @@ impl FixtureRepository for FixtureRepositoryPg {
     async fn get_all(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<Fixture>, Self::Error> {
         let fixtures: Vec<FixtureDb> = sqlx::query_as!(
             FixtureDb,
-            r#"SELECT f.fixture_id as id, f.date, f.status as "status: FixtureStatusDb", f.venue_id, f.team_home_id, f.team_away_id, f.first_referee_id as "first_referee_id?", f.second_referee_id as "second_referee_id?"
+            r#"SELECT f.fixture_id as id, f.date, f.status as "status: FixtureStatusDb", f.league_id, f.season_id, f.venue_id, f.team_home_id, f.team_away_id, f.first_referee_id as "first_referee_id?", f.second_referee_id as "second_referee_id?", f.first_referee_min_level, f.second_referee_min_level, f.result_home_points, f.result_away_points, f.result_winner_id
             FROM rustddd.fixtures f
             ORDER BY f.date ASC"#
         )
@@ -109,6 +145,28 @@ impl FixtureRepository for FixtureRepositoryPg {
         Ok(fixtures.into_iter().map(Fixture::from).collect())
     }
 
+    async fn find_by_league_and_season(
+        &self,
+        league_id: Option<LeagueId>,
+        season_id: Option<SeasonId>,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<Fixture>, Self::Error> {
+        let fixtures: Vec<FixtureDb> = sqlx::query_as!(
+            FixtureDb,
+            r#"SELECT f.fixture_id as id, f.date, f.status as "status: FixtureStatusDb", f.league_id, f.season_id, f.venue_id, f.team_home_id, f.team_away_id, f.first_referee_id as "first_referee_id?", f.second_referee_id as "second_referee_id?", f.first_referee_min_level, f.second_referee_min_level, f.result_home_points, f.result_away_points, f.result_winner_id
+            FROM rustddd.fixtures f
+            WHERE ($1::uuid IS NULL OR f.league_id = $1) AND ($2::uuid IS NULL OR f.season_id = $2)
+            ORDER BY f.date ASC"#,
+            league_id.map(|l| l.0),
+            season_id.map(|s| s.0)
+        )
+        .fetch_all(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("FixtureRepositoryPg::find_by_league_and_season failed with {}", e.to_string()))?;
+
+        Ok(fixtures.into_iter().map(Fixture::from).collect())
+    }
+
     async fn find_by_day_and_venue(
         &self,
         date: &DateTime<Utc>,
@@ -127,7 +185,7 @@ impl FixtureRepository for FixtureRepositoryPg {
 
         let fixtures: Vec<FixtureDb> = sqlx::query_as!(
             FixtureDb,
-            r#"SELECT f.fixture_id as id, f.date, f.status as "status: FixtureStatusDb", f.venue_id, f.team_home_id, f.team_away_id, f.first_referee_id as "first_referee_id?", f.second_referee_id as "second_referee_id?"
+            r#"SELECT f.fixture_id as id, f.date, f.status as "status: FixtureStatusDb", f.league_id, f.season_id, f.venue_id, f.team_home_id, f.team_away_id, f.first_referee_id as "first_referee_id?", f.second_referee_id as "second_referee_id?", f.first_referee_min_level, f.second_referee_min_level, f.result_home_points, f.result_away_points, f.result_winner_id
             FROM rustddd.fixtures f
             WHERE f.date BETWEEN $1 AND $2 AND f.venue_id = $3
             ORDER BY f.date ASC"#,
@@ -160,7 +218,7 @@ impl FixtureRepository for FixtureRepositoryPg {
 
         let fixtures: Vec<FixtureDb> = sqlx::query_as!(
             FixtureDb,
-            r#"SELECT f.fixture_id as id, f.date, f.status as "status: FixtureStatusDb", f.venue_id, f.team_home_id, f.team_away_id, f.first_referee_id as "first_referee_id?", f.second_referee_id as "second_referee_id?"
+            r#"SELECT f.fixture_id as id, f.date, f.status as "status: FixtureStatusDb", f.league_id, f.season_id, f.venue_id, f.team_home_id, f.team_away_id, f.first_referee_id as "first_referee_id?", f.second_referee_id as "second_referee_id?", f.first_referee_min_level, f.second_referee_min_level, f.result_home_points, f.result_away_points, f.result_winner_id
             FROM rustddd.fixtures f
             WHERE f.date BETWEEN $1 AND $2 AND (f.team_home_id = $3 OR f.team_away_id = $3)
             ORDER BY f.date ASC"#,
@@ -179,12 +237,19 @@ impl FixtureRepository for FixtureRepositoryPg {
         let status: FixtureStatusDb = FixtureStatusDb::from(fixture.status().clone());
         let first_referee_id = fixture.first_referee_id().map(|r| r.0);
         let second_referee_id = fixture.second_referee_id().map(|r| r.0);
-        // NOTE: we do an upsert that only updates the stuff that is allowed to change: cancelled, date, venue_id, first_referee_id, second_referee_id
+        let result_home_points: Option<Vec<i32>> = fixture
+            .result()
+            .map(|r| r.sets().iter().map(|s| s.home).collect());
+        let result_away_points: Option<Vec<i32>> = fixture
+            .result()
+            .map(|r| r.sets().iter().map(|s| s.away).collect());
+        let result_winner_id = fixture.result().map(|r| r.winner().0);
+        // NOTE: we do an upsert that only updates the stuff that is allowed to change: cancelled, date, venue_id, first_referee_id, second_referee_id, result
         sqlx::query!(
-            "INSERT INTO rustddd.fixtures (fixture_id, date, venue_id, team_home_id, team_away_id, status, first_referee_id, second_referee_id) 
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "INSERT INTO rustddd.fixtures (fixture_id, date, venue_id, team_home_id, team_away_id, status, first_referee_id, second_referee_id, first_referee_min_level, second_referee_min_level, league_id, season_id, result_home_points, result_away_points, result_winner_id) 
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
             ON CONFLICT (fixture_id) 
-            DO UPDATE SET date = $2, venue_id = $3, status = $6, first_referee_id = $7, second_referee_id = $8",
+            DO UPDATE SET date = $2, venue_id = $3, status = $6, first_referee_id = $7, second_referee_id = $8, result_home_points = $13, result_away_points = $14, result_winner_id = $15",
             fixture.id().0,
             fixture.date(),
             fixture.venue_id().0,
@@ -193,6 +258,13 @@ impl FixtureRepository for FixtureRepositoryPg {
             status as FixtureStatusDb,
             first_referee_id,
             second_referee_id,
+            fixture.first_referee_min_level(),
+            fixture.second_referee_min_level(),
+            fixture.league_id().0,
+            fixture.season_id().0,
+            result_home_points.as_deref(),
+            result_away_points.as_deref(),
+            result_winner_id,
         )
         .execute(&mut **tx_ctx)
         .await
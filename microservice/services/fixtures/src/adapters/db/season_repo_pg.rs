@@ -0,0 +1,80 @@
+use microservices_shared::domain_ids::SeasonId;
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::domain::{aggregates::season::Season, repositories::season_repo::SeasonRepository};
+
+pub struct SeasonRepositoryPg();
+
+struct SeasonDb {
+    pub id: Uuid,
+    pub name: String,
+    pub closed: bool,
+}
+
+impl From<SeasonDb> for Season {
+    fn from(season: SeasonDb) -> Self {
+        Season::from_id(SeasonId::from(season.id), season.name, season.closed)
+    }
+}
+
+impl SeasonRepositoryPg {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl SeasonRepository for SeasonRepositoryPg {
+    type Error = String;
+    type TxCtx = Transaction<'static, Postgres>;
+
+    async fn find_by_id(
+        &self,
+        season_id: SeasonId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<Season>, Self::Error> {
+        let season: Option<SeasonDb> = sqlx::query_as!(
+            SeasonDb,
+            "SELECT season_id as id, name, closed
+            FROM rustddd.seasons
+            WHERE season_id = $1",
+            season_id.0
+        )
+        .fetch_optional(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("SeasonRepositoryPg::find_by_id failed with {}", e))?;
+
+        Ok(season.map(|s| s.into()))
+    }
+
+    async fn get_all(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<Season>, Self::Error> {
+        let seasons: Vec<SeasonDb> = sqlx::query_as!(
+            SeasonDb,
+            "SELECT season_id as id, name, closed
+            FROM rustddd.seasons
+            ORDER BY name ASC"
+        )
+        .fetch_all(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("SeasonRepositoryPg::get_all failed with {}", e))?;
+
+        Ok(seasons.into_iter().map(|s| s.into()).collect())
+    }
+
+    async fn save(&self, season: &Season, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error> {
+        // NOTE: we do an upsert that only updates the stuff that is allowed to change: closed
+        sqlx::query!(
+            "INSERT INTO rustddd.seasons (season_id, name, closed) VALUES ($1, $2, $3)
+            ON CONFLICT (season_id)
+            DO UPDATE SET closed = $3",
+            season.id().0,
+            season.name(),
+            season.is_closed()
+        )
+        .execute(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("SeasonRepositoryPg::save failed with {}", e))?;
+
+        Ok(())
+    }
+}
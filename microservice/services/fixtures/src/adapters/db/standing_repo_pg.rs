@@ -0,0 +1,126 @@
+use microservices_shared::domain_ids::{LeagueId, SeasonId, TeamId};
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::domain::{
+    aggregates::standing::Standing, repositories::standing_repo::StandingRepository,
+};
+
+pub struct StandingRepositoryPg();
+
+struct StandingDb {
+    pub league_id: Uuid,
+    pub season_id: Uuid,
+    pub team_id: Uuid,
+    pub played: i32,
+    pub wins: i32,
+    pub losses: i32,
+    pub sets_won: i32,
+    pub sets_lost: i32,
+    pub points_won: i32,
+    pub points_lost: i32,
+    pub table_points: i32,
+}
+
+impl From<StandingDb> for Standing {
+    fn from(standing: StandingDb) -> Self {
+        Standing::from_values(
+            LeagueId::from(standing.league_id),
+            SeasonId::from(standing.season_id),
+            TeamId::from(standing.team_id),
+            standing.played,
+            standing.wins,
+            standing.losses,
+            standing.sets_won,
+            standing.sets_lost,
+            standing.points_won,
+            standing.points_lost,
+            standing.table_points,
+        )
+    }
+}
+
+impl StandingRepositoryPg {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl StandingRepository for StandingRepositoryPg {
+    type Error = String;
+    type TxCtx = Transaction<'static, Postgres>;
+
+    async fn find_by_team(
+        &self,
+        league_id: LeagueId,
+        season_id: SeasonId,
+        team_id: TeamId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<Standing>, Self::Error> {
+        let standing: Option<StandingDb> = sqlx::query_as!(
+            StandingDb,
+            "SELECT league_id, season_id, team_id, played, wins, losses, sets_won, sets_lost, points_won, points_lost, table_points
+            FROM rustddd.standings
+            WHERE league_id = $1 AND season_id = $2 AND team_id = $3",
+            league_id.0,
+            season_id.0,
+            team_id.0
+        )
+        .fetch_optional(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("StandingRepositoryPg::find_by_team failed with {}", e))?;
+
+        Ok(standing.map(|s| s.into()))
+    }
+
+    async fn find_by_league_and_season(
+        &self,
+        league_id: LeagueId,
+        season_id: SeasonId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<Standing>, Self::Error> {
+        let standings: Vec<StandingDb> = sqlx::query_as!(
+            StandingDb,
+            "SELECT league_id, season_id, team_id, played, wins, losses, sets_won, sets_lost, points_won, points_lost, table_points
+            FROM rustddd.standings
+            WHERE league_id = $1 AND season_id = $2",
+            league_id.0,
+            season_id.0
+        )
+        .fetch_all(&mut **tx_ctx)
+        .await
+        .map_err(|e| {
+            format!(
+                "StandingRepositoryPg::find_by_league_and_season failed with {}",
+                e
+            )
+        })?;
+
+        Ok(standings.into_iter().map(|s| s.into()).collect())
+    }
+
+    async fn save(&self, standing: &Standing, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error> {
+        sqlx::query!(
+            "INSERT INTO rustddd.standings (league_id, season_id, team_id, played, wins, losses, sets_won, sets_lost, points_won, points_lost, table_points)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            ON CONFLICT (league_id, season_id, team_id)
+            DO UPDATE SET played = $4, wins = $5, losses = $6, sets_won = $7, sets_lost = $8, points_won = $9, points_lost = $10, table_points = $11",
+            standing.league_id().0,
+            standing.season_id().0,
+            standing.team_id().0,
+            standing.played(),
+            standing.wins(),
+            standing.losses(),
+            standing.sets_won(),
+            standing.sets_lost(),
+            standing.points_won(),
+            standing.points_lost(),
+            standing.table_points()
+        )
+        .execute(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("StandingRepositoryPg::save failed with {}", e))?;
+
+        Ok(())
+    }
+}
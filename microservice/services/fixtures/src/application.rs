@@ -1 +1,5 @@
 pub mod fixture_services;
+pub mod league_services;
+pub mod schedule_services;
+pub mod season_services;
+pub mod standing_services;
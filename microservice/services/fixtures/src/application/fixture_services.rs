@@ -2,19 +2,31 @@ use chrono::{DateTime, Utc};
 use microservices_shared::{
     domain_event_repo::DomainEventOutboxRepository,
     domain_events::DomainEvent,
-    domain_ids::{FixtureId, RefereeId, TeamId, VenueId},
+    domain_ids::{CommitSagaId, FixtureId, LeagueId, RefereeId, SeasonId, TeamId, VenueId},
     resolvers::traits::{TeamResolver, VenueResolver},
 };
 use restinterface::FixtureDTO;
 
-use crate::domain::{aggregates::fixture::Fixture, repositories::fixture_repo::FixtureRepository};
+use crate::domain::{
+    aggregates::fixture::{Fixture, FixtureResult, SetScore},
+    repositories::{
+        fixture_repo::FixtureRepository, league_repo::LeagueRepository,
+        season_repo::SeasonRepository,
+    },
+};
 
 pub async fn create_fixture<TxCtx>(
     date: DateTime<Utc>,
+    league_id: LeagueId,
+    season_id: SeasonId,
     venue_id: VenueId,
     team_home_id: TeamId,
     team_away_id: TeamId,
+    first_referee_min_level: i32,
+    second_referee_min_level: i32,
     fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    league_repo: &impl LeagueRepository<TxCtx = TxCtx, Error = String>,
+    season_repo: &impl SeasonRepository<TxCtx = TxCtx, Error = String>,
     venue_resolver: &impl VenueResolver<Error = String>,
     team_resolver: &impl TeamResolver<Error = String>,
     domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
@@ -24,6 +36,23 @@ pub async fn create_fixture<TxCtx>(
         return Err("Team home and team away cannot be the same".to_string());
     }
 
+    let league = league_repo
+        .find_by_id(league_id, tx_ctx)
+        .await?
+        .ok_or(format!("League {:?} not found", league_id))?;
+    let season = season_repo
+        .find_by_id(season_id, tx_ctx)
+        .await?
+        .ok_or(format!("Season {:?} not found", season_id))?;
+
+    if season.is_closed() {
+        return Err("Cannot create a fixture in a closed season".to_string());
+    }
+
+    if first_referee_min_level < 0 || second_referee_min_level < 0 {
+        return Err("Minimum referee levels cannot be negative".to_string());
+    }
+
     let venue = venue_resolver
         .resolve(&venue_id)
         .await
@@ -59,7 +88,18 @@ pub async fn create_fixture<TxCtx>(
         return Err("There is already a fixture at the same day for the away team".to_string());
     }
 
-    let fixture = Fixture::new(date, venue_id, team_home_id, team_away_id, None, None);
+    let fixture = Fixture::new(
+        date,
+        league_id,
+        season_id,
+        venue_id,
+        team_home_id,
+        team_away_id,
+        None,
+        None,
+        first_referee_min_level,
+        second_referee_min_level,
+    );
 
     fixture_repo
         .save(&fixture, tx_ctx)
@@ -78,13 +118,18 @@ pub async fn create_fixture<TxCtx>(
 
     Ok(FixtureDTO {
         id: fixture.id().into(),
+        league: league.into(),
+        season: season.into(),
         date: fixture.date().clone(),
         status: fixture.status().clone().into(),
+        result: None,
         venue: venue.into(),
         team_home: team_home.into(),
         team_away: team_away.into(),
         first_referee: None,
         second_referee: None,
+        first_referee_min_level: fixture.first_referee_min_level(),
+        second_referee_min_level: fixture.second_referee_min_level(),
     })
 }
 
@@ -92,6 +137,7 @@ pub async fn update_fixture_date<TxCtx>(
     fixture_id: FixtureId,
     date: DateTime<Utc>,
     fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    season_repo: &impl SeasonRepository<TxCtx = TxCtx, Error = String>,
     domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
     tx_ctx: &mut TxCtx,
 ) -> Result<(), String> {
@@ -100,6 +146,8 @@ pub async fn update_fixture_date<TxCtx>(
         .await?
         .expect("Fixture not found");
 
+    ensure_not_read_only(&fixture, season_repo, tx_ctx).await?;
+
     fixture.change_date(date);
 
     fixture_repo
@@ -125,6 +173,7 @@ pub async fn update_fixture_venue<TxCtx>(
     fixture_id: FixtureId,
     venue_id: VenueId,
     fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    season_repo: &impl SeasonRepository<TxCtx = TxCtx, Error = String>,
     venue_resolver: &impl VenueResolver<Error = String>,
     domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
     tx_ctx: &mut TxCtx,
@@ -134,6 +183,8 @@ pub async fn update_fixture_venue<TxCtx>(
         .await?
         .expect("Fixture not found");
 
+    ensure_not_read_only(&fixture, season_repo, tx_ctx).await?;
+
     let _venue = venue_resolver
         .resolve(&venue_id)
         .await
@@ -163,6 +214,7 @@ pub async fn update_fixture_venue<TxCtx>(
 pub async fn cancel_fixture<TxCtx>(
     fixture_id: FixtureId,
     fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    season_repo: &impl SeasonRepository<TxCtx = TxCtx, Error = String>,
     domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
     tx_ctx: &mut TxCtx,
 ) -> Result<Fixture, String> {
@@ -171,10 +223,16 @@ pub async fn cancel_fixture<TxCtx>(
         .await?
         .expect("Fixture not found");
 
+    ensure_not_read_only(&fixture, season_repo, tx_ctx).await?;
+
     if fixture.is_cancelled() {
         return Err("Fixture is already cancelled".to_string());
     }
 
+    if fixture.is_played() {
+        return Err("Fixture has already been played".to_string());
+    }
+
     fixture.cancel();
 
     fixture_repo
@@ -195,48 +253,245 @@ pub async fn cancel_fixture<TxCtx>(
     Ok(fixture)
 }
 
-pub async fn assign_first_referee<TxCtx>(
+pub async fn postpone_fixture<TxCtx>(
     fixture_id: FixtureId,
-    referee_id: RefereeId,
     fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    season_repo: &impl SeasonRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
     tx_ctx: &mut TxCtx,
-) -> Result<(), String> {
+) -> Result<Fixture, String> {
     let mut fixture = fixture_repo
         .find_by_id(fixture_id, tx_ctx)
         .await?
-        .expect(format!("Fixture not found: {:?}", fixture_id).as_str());
+        .expect("Fixture not found");
+
+    ensure_not_read_only(&fixture, season_repo, tx_ctx).await?;
 
-    // TODO: handle if there is already a first referee assigned
-    fixture.assign_first_referee(referee_id);
+    if !fixture.is_scheduled() {
+        return Err("Only a scheduled fixture can be postponed".to_string());
+    }
+
+    fixture.postpone();
 
     fixture_repo
         .save(&fixture, tx_ctx)
         .await
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    domain_event_repo
+        .store(
+            DomainEvent::FixturePostponed {
+                fixture_id: fixture.id(),
+            },
+            tx_ctx,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(fixture)
 }
 
-pub async fn assign_second_referee<TxCtx>(
+pub async fn reschedule_fixture<TxCtx>(
     fixture_id: FixtureId,
-    referee_id: RefereeId,
+    date: DateTime<Utc>,
     fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    season_repo: &impl SeasonRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
     tx_ctx: &mut TxCtx,
-) -> Result<(), String> {
+) -> Result<Fixture, String> {
     let mut fixture = fixture_repo
         .find_by_id(fixture_id, tx_ctx)
         .await?
-        .expect(format!("Fixture not found: {:?}", fixture_id).as_str());
+        .expect("Fixture not found");
 
-    // TODO: handle if there is already a second referee assigned
-    fixture.assign_second_referee(referee_id);
+    ensure_not_read_only(&fixture, season_repo, tx_ctx).await?;
+
+    if !fixture.is_postponed() {
+        return Err("Only a postponed fixture can be rescheduled".to_string());
+    }
+
+    fixture.reschedule(date);
 
     fixture_repo
         .save(&fixture, tx_ctx)
         .await
         .map_err(|e| e.to_string())?;
 
-    Ok(())
+    // NOTE: a rescheduled fixture also changed its date, the other services handle this as for any other fixture
+    domain_event_repo
+        .store(
+            DomainEvent::FixtureDateChanged {
+                fixture_id: fixture.id(),
+                date,
+            },
+            tx_ctx,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    domain_event_repo
+        .store(
+            DomainEvent::FixtureRescheduled {
+                fixture_id: fixture.id(),
+            },
+            tx_ctx,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(fixture)
+}
+
+/// Who submits the result of a fixture: either the referee assigned as first
+/// referee or an admin.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResultSubmitter {
+    FirstReferee(RefereeId),
+    Admin,
+}
+
+pub async fn record_fixture_result<TxCtx>(
+    fixture_id: FixtureId,
+    sets: Vec<SetScore>,
+    submitted_by: ResultSubmitter,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    season_repo: &impl SeasonRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Fixture, String> {
+    let mut fixture = fixture_repo
+        .find_by_id(fixture_id, tx_ctx)
+        .await?
+        .expect("Fixture not found");
+
+    ensure_not_read_only(&fixture, season_repo, tx_ctx).await?;
+
+    if let ResultSubmitter::FirstReferee(referee_id) = submitted_by {
+        if fixture.first_referee_id() != Some(&referee_id) {
+            return Err(
+                "Only the assigned first referee or an admin may record the result".to_string(),
+            );
+        }
+    }
+
+    if !fixture.is_scheduled() {
+        return Err("Only a scheduled fixture can have its result recorded".to_string());
+    }
+
+    if *fixture.date() > Utc::now() {
+        return Err("Fixture has not been played yet".to_string());
+    }
+
+    let result = FixtureResult::from_sets(sets, *fixture.team_home_id(), *fixture.team_away_id())?;
+    let winner = result.winner();
+    fixture.record_result(result);
+
+    fixture_repo
+        .save(&fixture, tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    domain_event_repo
+        .store(
+            DomainEvent::FixtureResultRecorded {
+                fixture_id: fixture.id(),
+                winner,
+            },
+            tx_ctx,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(fixture)
+}
+
+/// Executes a step of a commit saga of the assignments service, the outcome is replied
+/// with a RefereeAssignmentAccepted or RefereeAssignmentRejected event
+pub async fn assign_first_referee<TxCtx>(
+    saga_id: CommitSagaId,
+    fixture_id: FixtureId,
+    referee_id: RefereeId,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    let result = match fixture_repo.find_by_id(fixture_id, tx_ctx).await? {
+        None => Err(format!("Fixture not found: {}", fixture_id.0)),
+        Some(mut fixture) => {
+            let result = fixture.assign_first_referee(referee_id);
+            if result.is_ok() {
+                fixture_repo.save(&fixture, tx_ctx).await?;
+            }
+            result
+        }
+    };
+
+    reply_referee_assignment(
+        saga_id,
+        fixture_id,
+        referee_id,
+        result,
+        domain_event_repo,
+        tx_ctx,
+    )
+    .await
+}
+
+/// Executes a step of a commit saga of the assignments service, the outcome is replied
+/// with a RefereeAssignmentAccepted or RefereeAssignmentRejected event
+pub async fn assign_second_referee<TxCtx>(
+    saga_id: CommitSagaId,
+    fixture_id: FixtureId,
+    referee_id: RefereeId,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    let result = match fixture_repo.find_by_id(fixture_id, tx_ctx).await? {
+        None => Err(format!("Fixture not found: {}", fixture_id.0)),
+        Some(mut fixture) => {
+            let result = fixture.assign_second_referee(referee_id);
+            if result.is_ok() {
+                fixture_repo.save(&fixture, tx_ctx).await?;
+            }
+            result
+        }
+    };
+
+    reply_referee_assignment(
+        saga_id,
+        fixture_id,
+        referee_id,
+        result,
+        domain_event_repo,
+        tx_ctx,
+    )
+    .await
+}
+
+async fn reply_referee_assignment<TxCtx>(
+    saga_id: CommitSagaId,
+    fixture_id: FixtureId,
+    referee_id: RefereeId,
+    result: Result<(), String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    let event = match result {
+        Ok(()) => DomainEvent::RefereeAssignmentAccepted {
+            saga_id,
+            fixture_id,
+            referee_id,
+        },
+        Err(reason) => DomainEvent::RefereeAssignmentRejected {
+            saga_id,
+            fixture_id,
+            referee_id,
+            reason,
+        },
+    };
+
+    domain_event_repo.store(event, tx_ctx).await
 }
 
 pub async fn unassign_first_referee<TxCtx>(
@@ -281,12 +536,34 @@ pub async fn unassign_second_referee<TxCtx>(
     Ok(())
 }
 
+/// A fixture becomes read-only once its season is closed
+async fn ensure_not_read_only<TxCtx>(
+    fixture: &Fixture,
+    season_repo: &impl SeasonRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    let season = season_repo
+        .find_by_id(*fixture.season_id(), tx_ctx)
+        .await?
+        .ok_or(format!("Season {:?} not found", fixture.season_id()))?;
+
+    if season.is_closed() {
+        return Err(format!(
+            "Fixture {:?} is read-only because its season is closed",
+            fixture.id()
+        ));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::Utc;
     use microservices_shared::{
         domain_event_repo::MockDomainEventOutboxRepository,
-        domain_ids::{FixtureId, TeamId, VenueId},
+        domain_events::DomainEvent,
+        domain_ids::{CommitSagaId, FixtureId, LeagueId, RefereeId, SeasonId, TeamId, VenueId},
         resolvers::traits::{MockTeamResolver, MockVenueResolver},
     };
     use mockall::predicate::eq;
@@ -294,10 +571,20 @@ mod tests {
     use uuid::Uuid;
 
     use crate::{
-        application::fixture_services::cancel_fixture,
+        application::fixture_services::{
+            assign_first_referee, assign_second_referee, cancel_fixture, postpone_fixture,
+            record_fixture_result, reschedule_fixture, ResultSubmitter,
+        },
         domain::{
-            aggregates::fixture::{Fixture, FixtureStatus},
-            repositories::fixture_repo::MockFixtureRepository,
+            aggregates::{
+                fixture::{Fixture, FixtureStatus, SetScore},
+                league::League,
+                season::Season,
+            },
+            repositories::{
+                fixture_repo::MockFixtureRepository, league_repo::MockLeagueRepository,
+                season_repo::MockSeasonRepository,
+            },
         },
     };
 
@@ -310,10 +597,24 @@ mod tests {
         let mut venue_resolver = MockVenueResolver::new();
         let mut team_resolver = MockTeamResolver::new();
         let mut domain_event_repo = MockDomainEventOutboxRepository::new();
+        let mut league_repo = MockLeagueRepository::new();
+        let mut season_repo = MockSeasonRepository::new();
 
         let venue_id = VenueId::from(Uuid::new_v4());
         let team_home_id = TeamId::from(Uuid::new_v4());
         let team_away_id = TeamId::from(Uuid::new_v4());
+        let league = League::new("League A");
+        let season = Season::new("Season 2024/25");
+
+        league_repo
+            .expect_find_by_id()
+            .with(eq(league.id()), eq(&()))
+            .return_const(Ok(Some(league.clone())));
+
+        season_repo
+            .expect_find_by_id()
+            .with(eq(season.id()), eq(&()))
+            .return_const(Ok(Some(season.clone())));
 
         let venue_dto = VenueDTO {
             id: venue_id.into(),
@@ -323,18 +624,22 @@ mod tests {
             city: "City A".to_string(),
             telephone: None,
             email: None,
+            location: None,
+            archived: false,
         };
 
         let team_home_dto = TeamDTO {
             id: team_home_id.into(),
             name: "Team A".to_string(),
             club: "Club A".to_string(),
+            archived: false,
         };
 
         let team_away_dto = TeamDTO {
             id: team_away_id.into(),
             name: "Team B".to_string(),
             club: "Club B".to_string(),
+            archived: false,
         };
 
         venue_resolver
@@ -366,10 +671,16 @@ mod tests {
 
         let fixture_created = create_fixture(
             now,
+            league.id(),
+            season.id(),
             venue_id,
             team_home_id,
             team_away_id,
+            1,
+            0,
             &fixture_repo,
+            &league_repo,
+            &season_repo,
             &venue_resolver,
             &team_resolver,
             &domain_event_repo,
@@ -380,13 +691,18 @@ mod tests {
 
         let fixture_expected = FixtureDTO {
             id: fixture_created.id.into(),
+            league: league.into(),
+            season: season.into(),
             date: now,
             status: FixtureStatusDTO::Scheduled,
+            result: None,
             venue: venue_dto.clone(),
             team_home: team_home_dto.clone(),
             team_away: team_away_dto.clone(),
             first_referee: None,
             second_referee: None,
+            first_referee_min_level: 1,
+            second_referee_min_level: 0,
         };
 
         assert_eq!(fixture_created, fixture_expected);
@@ -398,7 +714,10 @@ mod tests {
         let mut fixture_repo = MockFixtureRepository::new();
         let mut domain_event_repo = MockDomainEventOutboxRepository::new();
 
+        let mut season_repo = MockSeasonRepository::new();
+
         let fixture_id = FixtureId::from(Uuid::new_v4());
+        let season = Season::new("Season 2024/25");
         let venue_id = VenueId::from(Uuid::new_v4());
         let team_home_id = TeamId::from(Uuid::new_v4());
         let team_away_id = TeamId::from(Uuid::new_v4());
@@ -407,11 +726,16 @@ mod tests {
             fixture_id,
             now,
             FixtureStatus::Scheduled,
+            LeagueId::from(Uuid::new_v4()),
+            season.id(),
             venue_id,
             team_home_id,
             team_away_id,
             None,
             None,
+            0,
+            0,
+            None,
         );
 
         fixture_repo
@@ -420,16 +744,152 @@ mod tests {
             .return_const(Ok(Some(fixture.clone())));
         fixture_repo.expect_save().return_const(Ok(()));
 
+        season_repo
+            .expect_find_by_id()
+            .with(eq(season.id()), eq(&()))
+            .return_const(Ok(Some(season.clone())));
+
         domain_event_repo.expect_store().return_const(Ok(()));
 
-        let fixture_cancelled =
-            cancel_fixture(fixture_id, &fixture_repo, &domain_event_repo, &mut ())
-                .await
-                .unwrap();
+        let fixture_cancelled = cancel_fixture(
+            fixture_id,
+            &fixture_repo,
+            &season_repo,
+            &domain_event_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
 
         assert!(fixture_cancelled.is_cancelled());
     }
 
+    #[tokio::test]
+    async fn test_given_scheduled_fixture_when_postpone_then_postponed_and_event_stored() {
+        let now = Utc::now();
+        let mut fixture_repo = MockFixtureRepository::new();
+        let mut domain_event_repo = MockDomainEventOutboxRepository::new();
+        let mut season_repo = MockSeasonRepository::new();
+
+        let fixture_id = FixtureId::from(Uuid::new_v4());
+        let season = Season::new("Season 2024/25");
+
+        let fixture = Fixture::from_id(
+            fixture_id,
+            now,
+            FixtureStatus::Scheduled,
+            LeagueId::from(Uuid::new_v4()),
+            season.id(),
+            VenueId::from(Uuid::new_v4()),
+            TeamId::from(Uuid::new_v4()),
+            TeamId::from(Uuid::new_v4()),
+            None,
+            None,
+            0,
+            0,
+            None,
+        );
+
+        fixture_repo
+            .expect_find_by_id()
+            .with(eq(fixture_id), eq(&()))
+            .return_const(Ok(Some(fixture.clone())));
+        fixture_repo.expect_save().return_const(Ok(()));
+
+        season_repo
+            .expect_find_by_id()
+            .with(eq(season.id()), eq(&()))
+            .return_const(Ok(Some(season.clone())));
+
+        domain_event_repo
+            .expect_store()
+            .withf(move |event, _| {
+                matches!(event, DomainEvent::FixturePostponed { fixture_id: id } if *id == fixture_id)
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let fixture_postponed = postpone_fixture(
+            fixture_id,
+            &fixture_repo,
+            &season_repo,
+            &domain_event_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        assert!(fixture_postponed.is_postponed());
+    }
+
+    #[tokio::test]
+    async fn test_given_postponed_fixture_when_reschedule_then_scheduled_and_events_stored() {
+        let now = Utc::now();
+        let rescheduled_date = now + chrono::Duration::days(7);
+        let mut fixture_repo = MockFixtureRepository::new();
+        let mut domain_event_repo = MockDomainEventOutboxRepository::new();
+        let mut season_repo = MockSeasonRepository::new();
+
+        let fixture_id = FixtureId::from(Uuid::new_v4());
+        let season = Season::new("Season 2024/25");
+
+        let fixture = Fixture::from_id(
+            fixture_id,
+            now,
+            FixtureStatus::Postponed,
+            LeagueId::from(Uuid::new_v4()),
+            season.id(),
+            VenueId::from(Uuid::new_v4()),
+            TeamId::from(Uuid::new_v4()),
+            TeamId::from(Uuid::new_v4()),
+            None,
+            None,
+            0,
+            0,
+            None,
+        );
+
+        fixture_repo
+            .expect_find_by_id()
+            .with(eq(fixture_id), eq(&()))
+            .return_const(Ok(Some(fixture.clone())));
+        fixture_repo.expect_save().return_const(Ok(()));
+
+        season_repo
+            .expect_find_by_id()
+            .with(eq(season.id()), eq(&()))
+            .return_const(Ok(Some(season.clone())));
+
+        domain_event_repo
+            .expect_store()
+            .withf(move |event, _| {
+                matches!(event, DomainEvent::FixtureDateChanged { fixture_id: id, date } if *id == fixture_id && *date == rescheduled_date)
+            })
+            .times(1)
+            .return_const(Ok(()));
+        domain_event_repo
+            .expect_store()
+            .withf(move |event, _| {
+                matches!(event, DomainEvent::FixtureRescheduled { fixture_id: id } if *id == fixture_id)
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let fixture_rescheduled = reschedule_fixture(
+            fixture_id,
+            rescheduled_date,
+            &fixture_repo,
+            &season_repo,
+            &domain_event_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        assert!(fixture_rescheduled.is_scheduled());
+        assert_eq!(*fixture_rescheduled.date(), rescheduled_date);
+    }
+
     #[tokio::test]
     #[should_panic]
     async fn test_given_cancelled_fixture_when_cancel_then_panic() {
@@ -437,7 +897,10 @@ mod tests {
         let mut fixture_repo = MockFixtureRepository::new();
         let domain_event_repo = MockDomainEventOutboxRepository::new();
 
+        let mut season_repo = MockSeasonRepository::new();
+
         let fixture_id = FixtureId::from(Uuid::new_v4());
+        let season = Season::new("Season 2024/25");
         let venue_id = VenueId::from(Uuid::new_v4());
         let team_home_id = TeamId::from(Uuid::new_v4());
         let team_away_id = TeamId::from(Uuid::new_v4());
@@ -446,11 +909,16 @@ mod tests {
             fixture_id,
             now,
             FixtureStatus::Cancelled,
+            LeagueId::from(Uuid::new_v4()),
+            season.id(),
             venue_id,
             team_home_id,
             team_away_id,
             None,
             None,
+            0,
+            0,
+            None,
         );
 
         fixture_repo
@@ -459,8 +927,368 @@ mod tests {
             .return_const(Ok(Some(fixture.clone())));
         fixture_repo.expect_save().return_const(Ok(()));
 
-        cancel_fixture(fixture_id, &fixture_repo, &domain_event_repo, &mut ())
+        season_repo
+            .expect_find_by_id()
+            .with(eq(season.id()), eq(&()))
+            .return_const(Ok(Some(season.clone())));
+
+        cancel_fixture(
+            fixture_id,
+            &fixture_repo,
+            &season_repo,
+            &domain_event_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_given_fixture_in_closed_season_when_cancel_then_error() {
+        let now = Utc::now();
+        let mut fixture_repo = MockFixtureRepository::new();
+        let mut season_repo = MockSeasonRepository::new();
+        let domain_event_repo = MockDomainEventOutboxRepository::new();
+
+        let fixture_id = FixtureId::from(Uuid::new_v4());
+        let mut season = Season::new("Season 2023/24");
+        season.close();
+
+        let fixture = Fixture::from_id(
+            fixture_id,
+            now,
+            FixtureStatus::Scheduled,
+            LeagueId::from(Uuid::new_v4()),
+            season.id(),
+            VenueId::from(Uuid::new_v4()),
+            TeamId::from(Uuid::new_v4()),
+            TeamId::from(Uuid::new_v4()),
+            None,
+            None,
+            0,
+            0,
+            None,
+        );
+
+        fixture_repo
+            .expect_find_by_id()
+            .with(eq(fixture_id), eq(&()))
+            .return_const(Ok(Some(fixture.clone())));
+        fixture_repo.expect_save().never();
+
+        season_repo
+            .expect_find_by_id()
+            .with(eq(season.id()), eq(&()))
+            .return_const(Ok(Some(season.clone())));
+
+        let result = cancel_fixture(
+            fixture_id,
+            &fixture_repo,
+            &season_repo,
+            &domain_event_repo,
+            &mut (),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_given_first_referee_when_record_result_then_played_and_event_stored() {
+        let mut fixture_repo = MockFixtureRepository::new();
+        let mut domain_event_repo = MockDomainEventOutboxRepository::new();
+        let mut season_repo = MockSeasonRepository::new();
+
+        let fixture_id = FixtureId::from(Uuid::new_v4());
+        let team_home_id = TeamId::from(Uuid::new_v4());
+        let referee_id = RefereeId::from(Uuid::new_v4());
+        let season = Season::new("Season 2024/25");
+
+        let fixture = Fixture::from_id(
+            fixture_id,
+            Utc::now() - chrono::Duration::hours(2),
+            FixtureStatus::Scheduled,
+            LeagueId::from(Uuid::new_v4()),
+            season.id(),
+            VenueId::from(Uuid::new_v4()),
+            team_home_id,
+            TeamId::from(Uuid::new_v4()),
+            Some(referee_id),
+            None,
+            0,
+            0,
+            None,
+        );
+
+        fixture_repo
+            .expect_find_by_id()
+            .with(eq(fixture_id), eq(&()))
+            .return_const(Ok(Some(fixture.clone())));
+        fixture_repo.expect_save().return_const(Ok(()));
+
+        season_repo
+            .expect_find_by_id()
+            .with(eq(season.id()), eq(&()))
+            .return_const(Ok(Some(season.clone())));
+
+        domain_event_repo
+            .expect_store()
+            .withf(move |event, _| {
+                matches!(event, DomainEvent::FixtureResultRecorded { fixture_id: id, winner } if *id == fixture_id && *winner == team_home_id)
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        let fixture_played = record_fixture_result(
+            fixture_id,
+            vec![
+                SetScore { home: 25, away: 20 },
+                SetScore { home: 25, away: 23 },
+                SetScore { home: 25, away: 18 },
+            ],
+            ResultSubmitter::FirstReferee(referee_id),
+            &fixture_repo,
+            &season_repo,
+            &domain_event_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        assert!(fixture_played.is_played());
+        assert_eq!(fixture_played.result().unwrap().winner(), team_home_id);
+    }
+
+    #[tokio::test]
+    async fn test_given_result_which_is_not_best_of_five_when_record_result_then_error() {
+        let season = Season::new("Season 2024/25");
+        let fixture = Fixture::from_id(
+            FixtureId::from(Uuid::new_v4()),
+            Utc::now() - chrono::Duration::hours(2),
+            FixtureStatus::Scheduled,
+            LeagueId::from(Uuid::new_v4()),
+            season.id(),
+            VenueId::from(Uuid::new_v4()),
+            TeamId::from(Uuid::new_v4()),
+            TeamId::from(Uuid::new_v4()),
+            None,
+            None,
+            0,
+            0,
+            None,
+        );
+        let won = SetScore { home: 25, away: 20 };
+        let lost = SetScore { home: 20, away: 25 };
+
+        for sets in [
+            vec![won],
+            vec![won, won, lost, lost],
+            vec![won, won, won, lost],
+            vec![won, lost, won, lost, lost, won],
+        ] {
+            let mut fixture_repo = MockFixtureRepository::new();
+            let mut season_repo = MockSeasonRepository::new();
+            let mut domain_event_repo = MockDomainEventOutboxRepository::new();
+
+            fixture_repo
+                .expect_find_by_id()
+                .return_const(Ok(Some(fixture.clone())));
+            fixture_repo.expect_save().never();
+            season_repo
+                .expect_find_by_id()
+                .return_const(Ok(Some(season.clone())));
+            domain_event_repo.expect_store().never();
+
+            let result = record_fixture_result(
+                fixture.id(),
+                sets.clone(),
+                ResultSubmitter::Admin,
+                &fixture_repo,
+                &season_repo,
+                &domain_event_repo,
+                &mut (),
+            )
+            .await;
+
+            assert!(result.is_err(), "Result {:?} should be rejected", sets);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_given_first_referee_taken_when_assign_first_referee_then_rejected_and_not_saved()
+    {
+        let now = Utc::now();
+        let mut fixture_repo = MockFixtureRepository::new();
+        let mut domain_event_repo = MockDomainEventOutboxRepository::new();
+
+        let saga_id = CommitSagaId::from(Uuid::new_v4());
+        let fixture_id = FixtureId::from(Uuid::new_v4());
+        let assigned_referee_id = RefereeId::from(Uuid::new_v4());
+        let referee_id = RefereeId::from(Uuid::new_v4());
+
+        let fixture = Fixture::from_id(
+            fixture_id,
+            now,
+            FixtureStatus::Scheduled,
+            LeagueId::from(Uuid::new_v4()),
+            SeasonId::from(Uuid::new_v4()),
+            VenueId::from(Uuid::new_v4()),
+            TeamId::from(Uuid::new_v4()),
+            TeamId::from(Uuid::new_v4()),
+            Some(assigned_referee_id),
+            None,
+            0,
+            0,
+            None,
+        );
+
+        fixture_repo
+            .expect_find_by_id()
+            .with(eq(fixture_id), eq(&()))
+            .return_const(Ok(Some(fixture.clone())));
+        fixture_repo.expect_save().times(0);
+
+        domain_event_repo
+            .expect_store()
+            .withf(move |event, _| {
+                matches!(event, DomainEvent::RefereeAssignmentRejected { saga_id: s, fixture_id: f, referee_id: r, .. } if *s == saga_id && *f == fixture_id && *r == referee_id)
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        assign_first_referee(
+            saga_id,
+            fixture_id,
+            referee_id,
+            &fixture_repo,
+            &domain_event_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_given_first_referee_free_when_assign_first_referee_then_accepted_and_saved() {
+        let now = Utc::now();
+        let mut fixture_repo = MockFixtureRepository::new();
+        let mut domain_event_repo = MockDomainEventOutboxRepository::new();
+
+        let saga_id = CommitSagaId::from(Uuid::new_v4());
+        let fixture_id = FixtureId::from(Uuid::new_v4());
+        let referee_id = RefereeId::from(Uuid::new_v4());
+
+        let fixture = Fixture::from_id(
+            fixture_id,
+            now,
+            FixtureStatus::Scheduled,
+            LeagueId::from(Uuid::new_v4()),
+            SeasonId::from(Uuid::new_v4()),
+            VenueId::from(Uuid::new_v4()),
+            TeamId::from(Uuid::new_v4()),
+            TeamId::from(Uuid::new_v4()),
+            None,
+            None,
+            0,
+            0,
+            None,
+        );
+
+        fixture_repo
+            .expect_find_by_id()
+            .with(eq(fixture_id), eq(&()))
+            .return_const(Ok(Some(fixture.clone())));
+        fixture_repo
+            .expect_save()
+            .withf(move |fixture, _| fixture.first_referee_id() == Some(&referee_id))
+            .times(1)
+            .return_const(Ok(()));
+
+        domain_event_repo
+            .expect_store()
+            .withf(move |event, _| {
+                matches!(event, DomainEvent::RefereeAssignmentAccepted { saga_id: s, fixture_id: f, referee_id: r } if *s == saga_id && *f == fixture_id && *r == referee_id)
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        assign_first_referee(
+            saga_id,
+            fixture_id,
+            referee_id,
+            &fixture_repo,
+            &domain_event_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_given_fixture_not_scheduled_when_assign_referees_then_rejected_and_not_saved() {
+        for status in [
+            FixtureStatus::Cancelled,
+            FixtureStatus::Postponed,
+            FixtureStatus::Played,
+        ] {
+            let mut fixture_repo = MockFixtureRepository::new();
+            let mut domain_event_repo = MockDomainEventOutboxRepository::new();
+
+            let saga_id = CommitSagaId::from(Uuid::new_v4());
+            let fixture_id = FixtureId::from(Uuid::new_v4());
+            let referee_id = RefereeId::from(Uuid::new_v4());
+
+            let fixture = Fixture::from_id(
+                fixture_id,
+                Utc::now(),
+                status,
+                LeagueId::from(Uuid::new_v4()),
+                SeasonId::from(Uuid::new_v4()),
+                VenueId::from(Uuid::new_v4()),
+                TeamId::from(Uuid::new_v4()),
+                TeamId::from(Uuid::new_v4()),
+                None,
+                None,
+                0,
+                0,
+                None,
+            );
+
+            fixture_repo
+                .expect_find_by_id()
+                .with(eq(fixture_id), eq(&()))
+                .return_const(Ok(Some(fixture.clone())));
+            fixture_repo.expect_save().times(0);
+
+            domain_event_repo
+                .expect_store()
+                .withf(move |event, _| {
+                    matches!(event, DomainEvent::RefereeAssignmentRejected { saga_id: s, fixture_id: f, referee_id: r, .. } if *s == saga_id && *f == fixture_id && *r == referee_id)
+                })
+                .times(2)
+                .return_const(Ok(()));
+
+            assign_first_referee(
+                saga_id,
+                fixture_id,
+                referee_id,
+                &fixture_repo,
+                &domain_event_repo,
+                &mut (),
+            )
+            .await
+            .unwrap();
+
+            assign_second_referee(
+                saga_id,
+                fixture_id,
+                referee_id,
+                &fixture_repo,
+                &domain_event_repo,
+                &mut (),
+            )
             .await
             .unwrap();
+        }
     }
 }
@@ -0,0 +1,15 @@
+use restinterface::LeagueDTO;
+
+use crate::domain::{aggregates::league::League, repositories::league_repo::LeagueRepository};
+
+pub async fn create_league<TxCtx>(
+    name: &str,
+    league_repo: &impl LeagueRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<LeagueDTO, String> {
+    let league = League::new(name);
+
+    league_repo.save(&league, tx_ctx).await?;
+
+    Ok(league.into())
+}
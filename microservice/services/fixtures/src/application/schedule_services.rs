@@ -0,0 +1,374 @@
+use chrono::{DateTime, Datelike, Duration, Utc, Weekday};
+use microservices_shared::{
+    domain_event_repo::DomainEventOutboxRepository,
+    domain_ids::{LeagueId, SeasonId, TeamId, VenueId},
+    resolvers::traits::{TeamResolver, VenueResolver},
+};
+use restinterface::FixtureDTO;
+
+use crate::{
+    application::fixture_services::create_fixture,
+    domain::repositories::{
+        fixture_repo::FixtureRepository, league_repo::LeagueRepository,
+        season_repo::SeasonRepository,
+    },
+};
+
+// NOTE: if no free day is found within a year the schedule is considered impossible
+const MAX_SCHEDULING_DAYS: i64 = 366;
+
+/// A team taking part in a schedule together with the venue of its home fixtures
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScheduleTeam {
+    pub team_id: TeamId,
+    pub home_venue_id: VenueId,
+}
+
+/// A fixture of a generated schedule which has not been created yet
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScheduledFixture {
+    pub date: DateTime<Utc>,
+    pub team_home_id: TeamId,
+    pub team_away_id: TeamId,
+    pub venue_id: VenueId,
+}
+
+/// Plans a double round-robin where every team meets every other team once at
+/// home and once away. Each round is put on the next allowed weekday after the
+/// previous round, a fixture which clashes with an existing or already planned
+/// fixture on the same day (same team or same venue) is moved to a later day.
+pub async fn preview_schedule<TxCtx>(
+    season_id: SeasonId,
+    teams: &[ScheduleTeam],
+    start_date: DateTime<Utc>,
+    weekdays: &[Weekday],
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    season_repo: &impl SeasonRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<ScheduledFixture>, String> {
+    if teams.len() < 2 {
+        return Err("A schedule needs at least two teams".to_string());
+    }
+
+    if teams
+        .iter()
+        .enumerate()
+        .any(|(i, t)| teams[i + 1..].iter().any(|o| o.team_id == t.team_id))
+    {
+        return Err("A team can only take part once in a schedule".to_string());
+    }
+
+    if weekdays.is_empty() {
+        return Err("A schedule needs at least one allowed weekday".to_string());
+    }
+
+    let season = season_repo
+        .find_by_id(season_id, tx_ctx)
+        .await?
+        .ok_or(format!("Season {:?} not found", season_id))?;
+    if season.is_closed() {
+        return Err("Cannot schedule fixtures in a closed season".to_string());
+    }
+
+    let mut scheduled_fixtures: Vec<ScheduledFixture> = Vec::new();
+    let mut round_start = start_date;
+
+    for round in double_round_robin(teams) {
+        let round_day = next_match_day(round_start, weekdays);
+        let mut last_day = round_day;
+
+        for (home, away) in round {
+            let mut day = round_day;
+            while !is_day_free(
+                &day,
+                &home,
+                &away,
+                &scheduled_fixtures,
+                fixture_repo,
+                tx_ctx,
+            )
+            .await?
+            {
+                day = next_match_day(day + Duration::days(1), weekdays);
+                if day - start_date > Duration::days(MAX_SCHEDULING_DAYS) {
+                    return Err(format!(
+                        "No free day found for the fixture of team {:?} against team {:?}",
+                        home.team_id, away.team_id
+                    ));
+                }
+            }
+
+            last_day = last_day.max(day);
+            scheduled_fixtures.push(ScheduledFixture {
+                date: day,
+                team_home_id: home.team_id,
+                team_away_id: away.team_id,
+                venue_id: home.home_venue_id,
+            });
+        }
+
+        round_start = last_day + Duration::days(1);
+    }
+
+    Ok(scheduled_fixtures)
+}
+
+/// Creates all fixtures of the previewed schedule and stores a FixtureCreated
+/// event for each, as it runs in the transaction of the caller either all or
+/// none of the fixtures are created
+pub async fn generate_schedule<TxCtx>(
+    league_id: LeagueId,
+    season_id: SeasonId,
+    teams: &[ScheduleTeam],
+    start_date: DateTime<Utc>,
+    weekdays: &[Weekday],
+    first_referee_min_level: i32,
+    second_referee_min_level: i32,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    league_repo: &impl LeagueRepository<TxCtx = TxCtx, Error = String>,
+    season_repo: &impl SeasonRepository<TxCtx = TxCtx, Error = String>,
+    venue_resolver: &impl VenueResolver<Error = String>,
+    team_resolver: &impl TeamResolver<Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<FixtureDTO>, String> {
+    let scheduled_fixtures = preview_schedule(
+        season_id,
+        teams,
+        start_date,
+        weekdays,
+        fixture_repo,
+        season_repo,
+        tx_ctx,
+    )
+    .await?;
+
+    let mut fixtures = Vec::new();
+    for scheduled_fixture in scheduled_fixtures {
+        let fixture = create_fixture(
+            scheduled_fixture.date,
+            league_id,
+            season_id,
+            scheduled_fixture.venue_id,
+            scheduled_fixture.team_home_id,
+            scheduled_fixture.team_away_id,
+            first_referee_min_level,
+            second_referee_min_level,
+            fixture_repo,
+            league_repo,
+            season_repo,
+            venue_resolver,
+            team_resolver,
+            domain_event_repo,
+            tx_ctx,
+        )
+        .await?;
+
+        fixtures.push(fixture);
+    }
+
+    Ok(fixtures)
+}
+
+/// Pairs the teams round by round using the circle method, the second half of
+/// the season repeats the first half with home and away swapped
+fn double_round_robin(teams: &[ScheduleTeam]) -> Vec<Vec<(ScheduleTeam, ScheduleTeam)>> {
+    let mut slots: Vec<Option<ScheduleTeam>> = teams.iter().map(|t| Some(*t)).collect();
+    // NOTE: with an odd number of teams one team has a bye in every round
+    if slots.len() % 2 == 1 {
+        slots.push(None);
+    }
+    let slot_count = slots.len();
+
+    let mut first_half = Vec::new();
+    for round in 0..slot_count - 1 {
+        let mut pairings = Vec::new();
+        for i in 0..slot_count / 2 {
+            if let (Some(a), Some(b)) = (slots[i], slots[slot_count - 1 - i]) {
+                // alternate home rights so that no team plays at home in every round
+                if (round + i) % 2 == 0 {
+                    pairings.push((a, b));
+                } else {
+                    pairings.push((b, a));
+                }
+            }
+        }
+        first_half.push(pairings);
+
+        // the first slot stays fixed while all others rotate by one
+        slots[1..].rotate_right(1);
+    }
+
+    let second_half: Vec<Vec<(ScheduleTeam, ScheduleTeam)>> = first_half
+        .iter()
+        .map(|pairings| pairings.iter().map(|(h, a)| (*a, *h)).collect())
+        .collect();
+
+    first_half.into_iter().chain(second_half).collect()
+}
+
+/// The first day on or after the given date which is one of the allowed weekdays
+fn next_match_day(date: DateTime<Utc>, weekdays: &[Weekday]) -> DateTime<Utc> {
+    let mut day = date;
+    while !weekdays.contains(&day.weekday()) {
+        day += Duration::days(1);
+    }
+    day
+}
+
+async fn is_day_free<TxCtx>(
+    day: &DateTime<Utc>,
+    home: &ScheduleTeam,
+    away: &ScheduleTeam,
+    scheduled_fixtures: &[ScheduledFixture],
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<bool, String> {
+    let clashes_with_scheduled = scheduled_fixtures.iter().any(|f| {
+        f.date.date_naive() == day.date_naive()
+            && (f.venue_id == home.home_venue_id
+                || [f.team_home_id, f.team_away_id].contains(&home.team_id)
+                || [f.team_home_id, f.team_away_id].contains(&away.team_id))
+    });
+    if clashes_with_scheduled {
+        return Ok(false);
+    }
+
+    // the same constraints which create_fixture checks against the existing fixtures
+    if !fixture_repo
+        .find_by_day_and_venue(day, home.home_venue_id, tx_ctx)
+        .await?
+        .is_empty()
+    {
+        return Ok(false);
+    }
+    if !fixture_repo
+        .find_by_day_and_team(day, home.team_id, tx_ctx)
+        .await?
+        .is_empty()
+    {
+        return Ok(false);
+    }
+    if !fixture_repo
+        .find_by_day_and_team(day, away.team_id, tx_ctx)
+        .await?
+        .is_empty()
+    {
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc, Weekday};
+    use microservices_shared::{
+        domain_event_repo::MockDomainEventOutboxRepository,
+        domain_events::DomainEvent,
+        domain_ids::{TeamId, VenueId},
+        resolvers::traits::{MockTeamResolver, MockVenueResolver},
+    };
+    use restinterface::{TeamDTO, VenueDTO};
+    use uuid::Uuid;
+
+    use crate::{
+        application::schedule_services::{generate_schedule, ScheduleTeam},
+        domain::{
+            aggregates::{league::League, season::Season},
+            repositories::{
+                fixture_repo::MockFixtureRepository, league_repo::MockLeagueRepository,
+                season_repo::MockSeasonRepository,
+            },
+        },
+    };
+
+    #[tokio::test]
+    async fn test_given_three_teams_when_generate_schedule_then_events_stored() {
+        let mut fixture_repo = MockFixtureRepository::new();
+        let mut league_repo = MockLeagueRepository::new();
+        let mut season_repo = MockSeasonRepository::new();
+        let mut venue_resolver = MockVenueResolver::new();
+        let mut team_resolver = MockTeamResolver::new();
+        let mut domain_event_repo = MockDomainEventOutboxRepository::new();
+
+        let league = League::new("League A");
+        let season = Season::new("Season 2024/25");
+        let teams: Vec<ScheduleTeam> = (0..3)
+            .map(|_| ScheduleTeam {
+                team_id: TeamId::from(Uuid::new_v4()),
+                home_venue_id: VenueId::from(Uuid::new_v4()),
+            })
+            .collect();
+        // a saturday
+        let start_date = Utc.with_ymd_and_hms(2024, 9, 7, 18, 0, 0).unwrap();
+
+        league_repo
+            .expect_find_by_id()
+            .return_const(Ok(Some(league.clone())));
+        season_repo
+            .expect_find_by_id()
+            .return_const(Ok(Some(season.clone())));
+        venue_resolver.expect_resolve().returning(|venue_id| {
+            Ok(VenueDTO {
+                id: (*venue_id).into(),
+                name: "Venue".to_string(),
+                street: "Street".to_string(),
+                zip: "12345".to_string(),
+                city: "City".to_string(),
+                telephone: None,
+                email: None,
+                location: None,
+                archived: false,
+            })
+        });
+        team_resolver.expect_resolve().returning(|team_id| {
+            Ok(TeamDTO {
+                id: (*team_id).into(),
+                name: "Team".to_string(),
+                club: "Club".to_string(),
+                archived: false,
+            })
+        });
+        fixture_repo
+            .expect_find_by_day_and_venue()
+            .return_const(Ok(vec![]));
+        fixture_repo
+            .expect_find_by_day_and_team()
+            .return_const(Ok(vec![]));
+        fixture_repo.expect_save().times(6).return_const(Ok(()));
+        domain_event_repo
+            .expect_store()
+            .withf(|event, _| matches!(event, DomainEvent::FixtureCreated { .. }))
+            .times(6)
+            .return_const(Ok(()));
+
+        let fixtures = generate_schedule(
+            league.id(),
+            season.id(),
+            &teams,
+            start_date,
+            &[Weekday::Sat],
+            0,
+            0,
+            &fixture_repo,
+            &league_repo,
+            &season_repo,
+            &venue_resolver,
+            &team_resolver,
+            &domain_event_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        // with three teams one team has a bye, so every saturday exactly one fixture is played
+        assert_eq!(fixtures.len(), 6);
+        for (week, fixture) in fixtures.iter().enumerate() {
+            assert_eq!(
+                fixture.date,
+                start_date + chrono::Duration::weeks(week as i64)
+            );
+        }
+    }
+}
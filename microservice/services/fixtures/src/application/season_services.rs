@@ -0,0 +1,45 @@
+use microservices_shared::{
+    domain_event_repo::DomainEventOutboxRepository, domain_events::DomainEvent,
+    domain_ids::SeasonId,
+};
+use restinterface::SeasonDTO;
+
+use crate::domain::{aggregates::season::Season, repositories::season_repo::SeasonRepository};
+
+pub async fn create_season<TxCtx>(
+    name: &str,
+    season_repo: &impl SeasonRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<SeasonDTO, String> {
+    let season = Season::new(name);
+
+    season_repo.save(&season, tx_ctx).await?;
+
+    Ok(season.into())
+}
+
+pub async fn close_season<TxCtx>(
+    season_id: SeasonId,
+    season_repo: &impl SeasonRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<SeasonDTO, String> {
+    let mut season = season_repo
+        .find_by_id(season_id, tx_ctx)
+        .await?
+        .ok_or(format!("Season {:?} not found", season_id))?;
+
+    if season.is_closed() {
+        return Err("Season is already closed".to_string());
+    }
+
+    season.close();
+
+    season_repo.save(&season, tx_ctx).await?;
+
+    domain_event_repo
+        .store(DomainEvent::SeasonClosed { season_id }, tx_ctx)
+        .await?;
+
+    Ok(season.into())
+}
@@ -0,0 +1,232 @@
+use std::cmp::Ordering;
+
+use microservices_shared::{
+    domain_ids::{FixtureId, LeagueId, SeasonId, TeamId},
+    resolvers::traits::TeamResolver,
+};
+use restinterface::StandingDTO;
+
+use crate::domain::{
+    aggregates::standing::Standing,
+    repositories::{fixture_repo::FixtureRepository, standing_repo::StandingRepository},
+};
+
+/// Adds the recorded result of a fixture to the standings of both of its teams
+pub async fn update_standings_for_result<TxCtx>(
+    fixture_id: FixtureId,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    standing_repo: &impl StandingRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    let fixture = fixture_repo
+        .find_by_id(fixture_id, tx_ctx)
+        .await?
+        .ok_or(format!("Fixture {:?} not found", fixture_id))?;
+    let result = fixture
+        .result()
+        .ok_or(format!("Fixture {:?} has no result", fixture_id))?;
+
+    let sets_home = result.sets().iter().filter(|s| s.home > s.away).count() as i32;
+    let sets_away = result.sets().len() as i32 - sets_home;
+    let points_home: i32 = result.sets().iter().map(|s| s.home).sum();
+    let points_away: i32 = result.sets().iter().map(|s| s.away).sum();
+
+    update_standing(
+        *fixture.league_id(),
+        *fixture.season_id(),
+        *fixture.team_home_id(),
+        (sets_home, sets_away),
+        (points_home, points_away),
+        standing_repo,
+        tx_ctx,
+    )
+    .await?;
+
+    update_standing(
+        *fixture.league_id(),
+        *fixture.season_id(),
+        *fixture.team_away_id(),
+        (sets_away, sets_home),
+        (points_away, points_home),
+        standing_repo,
+        tx_ctx,
+    )
+    .await
+}
+
+/// Returns the standings ordered by table points, then wins, then set ratio and
+/// finally points ratio
+pub async fn get_standings<TxCtx>(
+    league_id: LeagueId,
+    season_id: SeasonId,
+    standing_repo: &impl StandingRepository<TxCtx = TxCtx, Error = String>,
+    team_resolver: &impl TeamResolver<Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<StandingDTO>, String> {
+    let mut standings = standing_repo
+        .find_by_league_and_season(league_id, season_id, tx_ctx)
+        .await?;
+
+    standings.sort_by(|a, b| {
+        b.table_points()
+            .cmp(&a.table_points())
+            .then(b.wins().cmp(&a.wins()))
+            .then(
+                b.set_ratio()
+                    .partial_cmp(&a.set_ratio())
+                    .unwrap_or(Ordering::Equal),
+            )
+            .then(
+                b.points_ratio()
+                    .partial_cmp(&a.points_ratio())
+                    .unwrap_or(Ordering::Equal),
+            )
+    });
+
+    let mut standing_dtos = Vec::new();
+    for standing in standings {
+        let team = team_resolver.resolve(&standing.team_id()).await?;
+
+        standing_dtos.push(StandingDTO {
+            team,
+            played: standing.played(),
+            wins: standing.wins(),
+            losses: standing.losses(),
+            sets_won: standing.sets_won(),
+            sets_lost: standing.sets_lost(),
+            set_ratio: standing.set_ratio(),
+            points_won: standing.points_won(),
+            points_lost: standing.points_lost(),
+            points_ratio: standing.points_ratio(),
+            table_points: standing.table_points(),
+        });
+    }
+
+    Ok(standing_dtos)
+}
+
+async fn update_standing<TxCtx>(
+    league_id: LeagueId,
+    season_id: SeasonId,
+    team_id: TeamId,
+    (sets_won, sets_lost): (i32, i32),
+    (points_won, points_lost): (i32, i32),
+    standing_repo: &impl StandingRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    let mut standing = standing_repo
+        .find_by_team(league_id, season_id, team_id, tx_ctx)
+        .await?
+        .unwrap_or(Standing::new(league_id, season_id, team_id));
+
+    standing.record_fixture(sets_won, sets_lost, points_won, points_lost);
+
+    standing_repo.save(&standing, tx_ctx).await
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use microservices_shared::domain_ids::{FixtureId, LeagueId, SeasonId, TeamId, VenueId};
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+
+    use crate::{
+        application::standing_services::update_standings_for_result,
+        domain::{
+            aggregates::{
+                fixture::{Fixture, FixtureResult, FixtureStatus, SetScore},
+                standing::Standing,
+            },
+            repositories::{
+                fixture_repo::MockFixtureRepository, standing_repo::MockStandingRepository,
+            },
+        },
+    };
+
+    #[tokio::test]
+    async fn test_given_five_set_result_when_update_standings_then_points_split_2_to_1() {
+        let mut fixture_repo = MockFixtureRepository::new();
+        let mut standing_repo = MockStandingRepository::new();
+
+        let fixture_id = FixtureId::from(Uuid::new_v4());
+        let league_id = LeagueId::from(Uuid::new_v4());
+        let season_id = SeasonId::from(Uuid::new_v4());
+        let team_home_id = TeamId::from(Uuid::new_v4());
+        let team_away_id = TeamId::from(Uuid::new_v4());
+
+        let sets = vec![
+            SetScore { home: 25, away: 20 },
+            SetScore { home: 22, away: 25 },
+            SetScore { home: 25, away: 23 },
+            SetScore { home: 19, away: 25 },
+            SetScore { home: 15, away: 12 },
+        ];
+        let result = FixtureResult::from_sets(sets, team_home_id, team_away_id).unwrap();
+
+        let fixture = Fixture::from_id(
+            fixture_id,
+            Utc::now(),
+            FixtureStatus::Played,
+            league_id,
+            season_id,
+            VenueId::from(Uuid::new_v4()),
+            team_home_id,
+            team_away_id,
+            None,
+            None,
+            0,
+            0,
+            Some(result),
+        );
+
+        // the away team already won a fixture in three sets earlier in the season
+        let mut away_standing = Standing::new(league_id, season_id, team_away_id);
+        away_standing.record_fixture(3, 0, 75, 60);
+
+        fixture_repo
+            .expect_find_by_id()
+            .with(eq(fixture_id), eq(&()))
+            .return_const(Ok(Some(fixture)));
+        standing_repo
+            .expect_find_by_team()
+            .with(eq(league_id), eq(season_id), eq(team_home_id), eq(&()))
+            .return_const(Ok(None));
+        standing_repo
+            .expect_find_by_team()
+            .with(eq(league_id), eq(season_id), eq(team_away_id), eq(&()))
+            .return_const(Ok(Some(away_standing)));
+
+        standing_repo
+            .expect_save()
+            .withf(move |standing, _| {
+                standing.team_id() == team_home_id
+                    && standing.played() == 1
+                    && standing.wins() == 1
+                    && standing.sets_won() == 3
+                    && standing.sets_lost() == 2
+                    && standing.points_won() == 106
+                    && standing.points_lost() == 105
+                    && standing.table_points() == 2
+            })
+            .times(1)
+            .return_const(Ok(()));
+        standing_repo
+            .expect_save()
+            .withf(move |standing, _| {
+                standing.team_id() == team_away_id
+                    && standing.played() == 2
+                    && standing.wins() == 1
+                    && standing.losses() == 1
+                    && standing.sets_won() == 5
+                    && standing.sets_lost() == 3
+                    && standing.table_points() == 4
+            })
+            .times(1)
+            .return_const(Ok(()));
+
+        update_standings_for_result(fixture_id, &fixture_repo, &standing_repo, &mut ())
+            .await
+            .unwrap();
+    }
+}
@@ -1 +1,4 @@
 pub mod fixture;
+pub mod league;
+pub mod season;
+pub mod standing;
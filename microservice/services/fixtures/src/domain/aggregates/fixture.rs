@@ -1,43 +1,146 @@
 use chrono::{DateTime, Utc};
-use microservices_shared::domain_ids::{FixtureId, RefereeId, TeamId, VenueId};
+use microservices_shared::domain_ids::{FixtureId, LeagueId, RefereeId, SeasonId, TeamId, VenueId};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FixtureStatus {
     Scheduled,
+    Postponed,
+    Played,
     Cancelled,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetScore {
+    pub home: i32,
+    pub away: i32,
+}
+
+// NOTE: fixtures are played best-of-five
+const SETS_TO_WIN: usize = 3;
+const MAX_SETS: usize = 5;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixtureResult {
+    sets: Vec<SetScore>,
+    winner: TeamId,
+}
+
+impl FixtureResult {
+    pub fn new(sets: Vec<SetScore>, winner: TeamId) -> Self {
+        Self { sets, winner }
+    }
+
+    /// Fixtures are played best-of-five: the winner is the first team to win three sets, after which no
+    /// further set is played. A set cannot end in a draw.
+    pub fn from_sets(
+        sets: Vec<SetScore>,
+        team_home_id: TeamId,
+        team_away_id: TeamId,
+    ) -> Result<Self, String> {
+        if sets.is_empty() {
+            return Err("A result needs at least one set".to_string());
+        }
+
+        if sets.len() > MAX_SETS {
+            return Err(format!("A result cannot have more than {} sets", MAX_SETS));
+        }
+
+        if sets.iter().any(|s| s.home < 0 || s.away < 0) {
+            return Err("Set scores cannot be negative".to_string());
+        }
+
+        if sets.iter().any(|s| s.home == s.away) {
+            return Err("A set cannot end in a draw".to_string());
+        }
+
+        let mut home_sets_won = 0;
+        let mut away_sets_won = 0;
+        for (idx, set) in sets.iter().enumerate() {
+            if home_sets_won == SETS_TO_WIN || away_sets_won == SETS_TO_WIN {
+                return Err(format!(
+                    "Set {} was played after the fixture was already decided",
+                    idx + 1
+                ));
+            }
+
+            if set.home > set.away {
+                home_sets_won += 1;
+            } else {
+                away_sets_won += 1;
+            }
+        }
+
+        if home_sets_won != SETS_TO_WIN && away_sets_won != SETS_TO_WIN {
+            return Err(format!(
+                "The fixture is not decided, the winner needs {} sets",
+                SETS_TO_WIN
+            ));
+        }
+
+        let winner = if home_sets_won > away_sets_won {
+            team_home_id
+        } else {
+            team_away_id
+        };
+
+        Ok(Self { sets, winner })
+    }
+
+    pub fn sets(&self) -> &Vec<SetScore> {
+        &self.sets
+    }
+
+    pub fn winner(&self) -> TeamId {
+        self.winner
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Fixture {
     id: FixtureId,
     date: DateTime<Utc>,
     status: FixtureStatus,
+    league_id: LeagueId,
+    season_id: SeasonId,
     venue_id: VenueId,
     team_home_id: TeamId,
     team_away_id: TeamId,
     first_referee_id: Option<RefereeId>,
     second_referee_id: Option<RefereeId>,
+    // NOTE: the minimum qualification level a referee needs to officiate the fixture in the respective role
+    first_referee_min_level: i32,
+    second_referee_min_level: i32,
+    result: Option<FixtureResult>,
 }
 
 impl Fixture {
     pub fn new(
         date: DateTime<Utc>,
+        league_id: LeagueId,
+        season_id: SeasonId,
         venue_id: VenueId,
         team_home_id: TeamId,
         team_away_id: TeamId,
         first_referee_id: Option<RefereeId>,
         second_referee_id: Option<RefereeId>,
+        first_referee_min_level: i32,
+        second_referee_min_level: i32,
     ) -> Self {
         Self {
             id: FixtureId(Uuid::new_v4()),
             date,
             status: FixtureStatus::Scheduled,
+            league_id,
+            season_id,
             venue_id,
             team_home_id,
             team_away_id,
             first_referee_id,
             second_referee_id,
+            first_referee_min_level,
+            second_referee_min_level,
+            result: None,
         }
     }
 
@@ -45,21 +148,31 @@ impl Fixture {
         id: FixtureId,
         date: DateTime<Utc>,
         status: FixtureStatus,
+        league_id: LeagueId,
+        season_id: SeasonId,
         venue_id: VenueId,
         team_home_id: TeamId,
         team_away_id: TeamId,
         first_referee_id: Option<RefereeId>,
         second_referee_id: Option<RefereeId>,
+        first_referee_min_level: i32,
+        second_referee_min_level: i32,
+        result: Option<FixtureResult>,
     ) -> Self {
         Self {
             id,
             date,
             status,
+            league_id,
+            season_id,
             venue_id,
             team_home_id,
             team_away_id,
             first_referee_id,
             second_referee_id,
+            first_referee_min_level,
+            second_referee_min_level,
+            result,
         }
     }
 
@@ -71,6 +184,14 @@ impl Fixture {
         &self.date
     }
 
+    pub fn league_id(&self) -> &LeagueId {
+        &self.league_id
+    }
+
+    pub fn season_id(&self) -> &SeasonId {
+        &self.season_id
+    }
+
     pub fn venue_id(&self) -> &VenueId {
         &self.venue_id
     }
@@ -95,21 +216,72 @@ impl Fixture {
         self.second_referee_id.as_ref()
     }
 
+    pub fn first_referee_min_level(&self) -> i32 {
+        self.first_referee_min_level
+    }
+
+    pub fn second_referee_min_level(&self) -> i32 {
+        self.second_referee_min_level
+    }
+
     pub fn is_scheduled(&self) -> bool {
         self.status == FixtureStatus::Scheduled
     }
 
+    pub fn is_postponed(&self) -> bool {
+        self.status == FixtureStatus::Postponed
+    }
+
+    pub fn is_played(&self) -> bool {
+        self.status == FixtureStatus::Played
+    }
+
+    pub fn result(&self) -> Option<&FixtureResult> {
+        self.result.as_ref()
+    }
+
     pub fn is_cancelled(&self) -> bool {
         self.status == FixtureStatus::Cancelled
     }
 
+    /// A fixture can be cancelled as long as it is scheduled or postponed
     pub fn cancel(&mut self) {
+        if self.status != FixtureStatus::Scheduled && self.status != FixtureStatus::Postponed {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("Fixture is neither scheduled nor postponed");
+        }
+
+        self.status = FixtureStatus::Cancelled;
+    }
+
+    pub fn postpone(&mut self) {
         if self.status != FixtureStatus::Scheduled {
             // NOTE: this is not how we would like to handle this in a real application
             panic!("Fixture is not scheduled");
         }
 
-        self.status = FixtureStatus::Cancelled;
+        self.status = FixtureStatus::Postponed;
+    }
+
+    /// Moves a postponed fixture to its new date and schedules it again
+    pub fn reschedule(&mut self, date: DateTime<Utc>) {
+        if self.status != FixtureStatus::Postponed {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("Fixture is not postponed");
+        }
+
+        self.date = date;
+        self.status = FixtureStatus::Scheduled;
+    }
+
+    pub fn record_result(&mut self, result: FixtureResult) {
+        if self.status != FixtureStatus::Scheduled {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("Fixture is not scheduled");
+        }
+
+        self.result = Some(result);
+        self.status = FixtureStatus::Played;
     }
 
     pub fn change_venue(&mut self, venue_id: VenueId) {
@@ -120,22 +292,46 @@ impl Fixture {
         self.date = date;
     }
 
-    pub fn assign_first_referee(&mut self, referee_id: RefereeId) {
-        if self.first_referee_id.is_some() {
-            // NOTE: this is not how we would like to handle this in a real application
-            panic!("First referee already assigned");
-        }
+    /// Assigning the same referee again is accepted, as the assignment might be redelivered
+    pub fn assign_first_referee(&mut self, referee_id: RefereeId) -> Result<(), String> {
+        self.ensure_referees_assignable()?;
 
-        self.first_referee_id = Some(referee_id);
+        match self.first_referee_id {
+            Some(assigned_referee_id) if assigned_referee_id != referee_id => Err(format!(
+                "First referee already assigned: {}",
+                assigned_referee_id.0
+            )),
+            _ => {
+                self.first_referee_id = Some(referee_id);
+                Ok(())
+            }
+        }
     }
 
-    pub fn assign_second_referee(&mut self, referee_id: RefereeId) {
-        if self.second_referee_id.is_some() {
-            // NOTE: this is not how we would like to handle this in a real application
-            panic!("Second referee already assigned");
+    /// Assigning the same referee again is accepted, as the assignment might be redelivered
+    pub fn assign_second_referee(&mut self, referee_id: RefereeId) -> Result<(), String> {
+        self.ensure_referees_assignable()?;
+
+        match self.second_referee_id {
+            Some(assigned_referee_id) if assigned_referee_id != referee_id => Err(format!(
+                "Second referee already assigned: {}",
+                assigned_referee_id.0
+            )),
+            _ => {
+                self.second_referee_id = Some(referee_id);
+                Ok(())
+            }
         }
+    }
 
-        self.second_referee_id = Some(referee_id);
+    fn ensure_referees_assignable(&self) -> Result<(), String> {
+        if self.status != FixtureStatus::Scheduled {
+            return Err(format!(
+                "Referees can only be assigned to scheduled fixtures, fixture is {:?}",
+                self.status
+            ));
+        }
+        Ok(())
     }
 
     pub fn unassign_first_referee(&mut self) {
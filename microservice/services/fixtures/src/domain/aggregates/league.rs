@@ -0,0 +1,29 @@
+use microservices_shared::domain_ids::LeagueId;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct League {
+    id: LeagueId,
+    name: String,
+}
+
+impl League {
+    pub fn new(name: &str) -> Self {
+        Self {
+            id: LeagueId(Uuid::new_v4()),
+            name: name.to_string(),
+        }
+    }
+
+    pub fn from_id(id: LeagueId, name: String) -> Self {
+        Self { id, name }
+    }
+
+    pub fn id(&self) -> LeagueId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
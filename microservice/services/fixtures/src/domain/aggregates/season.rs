@@ -0,0 +1,45 @@
+use microservices_shared::domain_ids::SeasonId;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct Season {
+    id: SeasonId,
+    name: String,
+    // NOTE: once a season is closed, its fixtures become read-only
+    closed: bool,
+}
+
+impl Season {
+    pub fn new(name: &str) -> Self {
+        Self {
+            id: SeasonId(Uuid::new_v4()),
+            name: name.to_string(),
+            closed: false,
+        }
+    }
+
+    pub fn from_id(id: SeasonId, name: String, closed: bool) -> Self {
+        Self { id, name, closed }
+    }
+
+    pub fn id(&self) -> SeasonId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn close(&mut self) {
+        if self.closed {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("Season already closed");
+        }
+
+        self.closed = true;
+    }
+}
@@ -0,0 +1,166 @@
+use microservices_shared::domain_ids::{LeagueId, SeasonId, TeamId};
+
+/// The standing of a team in a league and season. It is a read model which is
+/// built up from the recorded fixture results.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Standing {
+    league_id: LeagueId,
+    season_id: SeasonId,
+    team_id: TeamId,
+    played: i32,
+    wins: i32,
+    losses: i32,
+    sets_won: i32,
+    sets_lost: i32,
+    points_won: i32,
+    points_lost: i32,
+    table_points: i32,
+}
+
+impl Standing {
+    pub fn new(league_id: LeagueId, season_id: SeasonId, team_id: TeamId) -> Self {
+        Self {
+            league_id,
+            season_id,
+            team_id,
+            played: 0,
+            wins: 0,
+            losses: 0,
+            sets_won: 0,
+            sets_lost: 0,
+            points_won: 0,
+            points_lost: 0,
+            table_points: 0,
+        }
+    }
+
+    pub fn from_values(
+        league_id: LeagueId,
+        season_id: SeasonId,
+        team_id: TeamId,
+        played: i32,
+        wins: i32,
+        losses: i32,
+        sets_won: i32,
+        sets_lost: i32,
+        points_won: i32,
+        points_lost: i32,
+        table_points: i32,
+    ) -> Self {
+        Self {
+            league_id,
+            season_id,
+            team_id,
+            played,
+            wins,
+            losses,
+            sets_won,
+            sets_lost,
+            points_won,
+            points_lost,
+            table_points,
+        }
+    }
+
+    pub fn league_id(&self) -> LeagueId {
+        self.league_id
+    }
+
+    pub fn season_id(&self) -> SeasonId {
+        self.season_id
+    }
+
+    pub fn team_id(&self) -> TeamId {
+        self.team_id
+    }
+
+    pub fn played(&self) -> i32 {
+        self.played
+    }
+
+    pub fn wins(&self) -> i32 {
+        self.wins
+    }
+
+    pub fn losses(&self) -> i32 {
+        self.losses
+    }
+
+    pub fn sets_won(&self) -> i32 {
+        self.sets_won
+    }
+
+    pub fn sets_lost(&self) -> i32 {
+        self.sets_lost
+    }
+
+    pub fn points_won(&self) -> i32 {
+        self.points_won
+    }
+
+    pub fn points_lost(&self) -> i32 {
+        self.points_lost
+    }
+
+    pub fn table_points(&self) -> i32 {
+        self.table_points
+    }
+
+    pub fn set_ratio(&self) -> f64 {
+        ratio(self.sets_won, self.sets_lost)
+    }
+
+    pub fn points_ratio(&self) -> f64 {
+        ratio(self.points_won, self.points_lost)
+    }
+
+    /// Adds a played fixture from the perspective of this team, the table points
+    /// follow the volleyball 3-2-1-0 scheme: a win in three or four sets gives 3
+    /// points, a win in the deciding fifth set 2, a loss in the fifth set 1 and
+    /// any other loss 0.
+    pub fn record_fixture(
+        &mut self,
+        sets_won: i32,
+        sets_lost: i32,
+        points_won: i32,
+        points_lost: i32,
+    ) {
+        if sets_won == sets_lost {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("A fixture cannot end in a draw");
+        }
+
+        let won = sets_won > sets_lost;
+        let went_to_deciding_set = sets_won.min(sets_lost) == 2;
+
+        self.played += 1;
+        if won {
+            self.wins += 1;
+        } else {
+            self.losses += 1;
+        }
+        self.sets_won += sets_won;
+        self.sets_lost += sets_lost;
+        self.points_won += points_won;
+        self.points_lost += points_lost;
+        self.table_points += match (won, went_to_deciding_set) {
+            (true, false) => 3,
+            (true, true) => 2,
+            (false, true) => 1,
+            (false, false) => 0,
+        };
+    }
+}
+
+// NOTE: without anything lost the ratio is unbounded, volleyball tables show this as "MAX"
+fn ratio(won: i32, lost: i32) -> f64 {
+    if lost == 0 {
+        if won == 0 {
+            0.0
+        } else {
+            f64::MAX
+        }
+    } else {
+        won as f64 / lost as f64
+    }
+}
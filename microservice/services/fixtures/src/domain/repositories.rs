@@ -1 +1,4 @@
 pub mod fixture_repo;
+pub mod league_repo;
+pub mod season_repo;
+pub mod standing_repo;
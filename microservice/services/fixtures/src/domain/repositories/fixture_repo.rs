@@ -1,5 +1,5 @@
 use chrono::{DateTime, Utc};
-use microservices_shared::domain_ids::{FixtureId, TeamId, VenueId};
+use microservices_shared::domain_ids::{FixtureId, LeagueId, SeasonId, TeamId, VenueId};
 use mockall::automock;
 
 use crate::domain::aggregates::fixture::Fixture;
@@ -16,6 +16,12 @@ pub trait FixtureRepository {
         tx_ctx: &mut Self::TxCtx,
     ) -> Result<Option<Fixture>, Self::Error>;
     async fn get_all(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<Fixture>, Self::Error>;
+    async fn find_by_league_and_season(
+        &self,
+        league_id: Option<LeagueId>,
+        season_id: Option<SeasonId>,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<Fixture>, Self::Error>;
     async fn find_by_day_and_venue(
         &self,
         date: &DateTime<Utc>,
@@ -0,0 +1,19 @@
+use microservices_shared::domain_ids::LeagueId;
+use mockall::automock;
+
+use crate::domain::aggregates::league::League;
+
+#[allow(async_fn_in_trait)]
+#[automock(type Error = String; type TxCtx = ();)]
+pub trait LeagueRepository {
+    type Error;
+    type TxCtx;
+
+    async fn find_by_id(
+        &self,
+        league_id: LeagueId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<League>, Self::Error>;
+    async fn get_all(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<League>, Self::Error>;
+    async fn save(&self, league: &League, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error>;
+}
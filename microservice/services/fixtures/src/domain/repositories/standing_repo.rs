@@ -0,0 +1,26 @@
+use microservices_shared::domain_ids::{LeagueId, SeasonId, TeamId};
+use mockall::automock;
+
+use crate::domain::aggregates::standing::Standing;
+
+#[allow(async_fn_in_trait)]
+#[automock(type Error = String; type TxCtx = ();)]
+pub trait StandingRepository {
+    type Error;
+    type TxCtx;
+
+    async fn find_by_team(
+        &self,
+        league_id: LeagueId,
+        season_id: SeasonId,
+        team_id: TeamId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<Standing>, Self::Error>;
+    async fn find_by_league_and_season(
+        &self,
+        league_id: LeagueId,
+        season_id: SeasonId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<Standing>, Self::Error>;
+    async fn save(&self, standing: &Standing, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error>;
+}
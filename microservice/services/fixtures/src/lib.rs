@@ -1,8 +1,13 @@
 use std::sync::Arc;
 
-use domain::aggregates::fixture::FixtureStatus;
+use domain::aggregates::{
+    fixture::{FixtureResult, FixtureStatus, SetScore},
+    league::League,
+    season::Season,
+};
+use microservices_shared::token::AccessTokenVerifier;
 use opentelemetry::global::BoxedTracer;
-use restinterface::FixtureStatusDTO;
+use restinterface::{FixtureResultDTO, FixtureStatusDTO, LeagueDTO, SeasonDTO, SetScoreDTO};
 use sqlx::PgPool;
 
 pub mod adapters;
@@ -15,6 +20,7 @@ pub struct AppState {
     pub connection_pool: PgPool,
     pub redis_client: redis::Client,
     pub tracer: Arc<BoxedTracer>,
+    pub token_verifier: AccessTokenVerifier,
 }
 
 // NOTE: This is a temporary solution to convert the FixtureStatus enum to the FixtureStatusDTO enum.
@@ -23,7 +29,55 @@ impl From<FixtureStatus> for FixtureStatusDTO {
     fn from(status: FixtureStatus) -> Self {
         match status {
             FixtureStatus::Scheduled => FixtureStatusDTO::Scheduled,
+            FixtureStatus::Postponed => FixtureStatusDTO::Postponed,
+            FixtureStatus::Played => FixtureStatusDTO::Played,
             FixtureStatus::Cancelled => FixtureStatusDTO::Cancelled,
         }
     }
 }
+
+impl From<SetScoreDTO> for SetScore {
+    fn from(set_score: SetScoreDTO) -> Self {
+        SetScore {
+            home: set_score.home,
+            away: set_score.away,
+        }
+    }
+}
+
+impl From<SetScore> for SetScoreDTO {
+    fn from(set_score: SetScore) -> Self {
+        SetScoreDTO {
+            home: set_score.home,
+            away: set_score.away,
+        }
+    }
+}
+
+impl From<FixtureResult> for FixtureResultDTO {
+    fn from(result: FixtureResult) -> Self {
+        FixtureResultDTO {
+            sets: result.sets().iter().map(|s| (*s).into()).collect(),
+            winner: result.winner().into(),
+        }
+    }
+}
+
+impl From<League> for LeagueDTO {
+    fn from(league: League) -> Self {
+        LeagueDTO {
+            id: league.id().into(),
+            name: league.name().to_string(),
+        }
+    }
+}
+
+impl From<Season> for SeasonDTO {
+    fn from(season: Season) -> Self {
+        SeasonDTO {
+            id: season.id().into(),
+            name: season.name().to_string(),
+            closed: season.is_closed(),
+        }
+    }
+}
@@ -5,14 +5,24 @@ use axum::{
 };
 
 use fixtures::config::AppConfig;
-use fixtures::ports::kafka::domain_events_handler::DomainEventCallbacksImpl;
+use fixtures::ports::kafka::domain_events_handler::DomainEventHandlers;
 use fixtures::ports::rest::fixtures::{
-    cancel_fixture_handler, create_fixture_handler, get_all_fixtures_handler,
-    get_fixture_by_id_handler, update_fixture_date_handler, update_fixture_venue_handler,
+    cancel_fixture_handler, create_fixture_handler, generate_schedule_handler,
+    get_all_fixtures_handler, get_fixture_by_id_handler, get_fixtures_handler,
+    postpone_fixture_handler, preview_schedule_handler, record_fixture_result_handler,
+    reschedule_fixture_handler, update_fixture_date_handler, update_fixture_venue_handler,
 };
+use fixtures::ports::rest::leagues::{
+    create_league_handler, get_all_leagues_handler, get_league_by_id_handler,
+};
+use fixtures::ports::rest::seasons::{
+    close_season_handler, create_season_handler, get_all_seasons_handler, get_season_by_id_handler,
+};
+use fixtures::ports::rest::standings::get_standings_handler;
 use fixtures::AppState;
 
 use microservices_shared::domain_events::DomainEventConsumer;
+use microservices_shared::token::AccessTokenVerifier;
 use opentelemetry::{
     trace::{Span, Tracer},
     KeyValue,
@@ -35,22 +45,26 @@ async fn main() {
     let connection_pool = PgPool::connect(&config.db_url).await.unwrap();
     let redis_client = redis::Client::open(config.redis_url).unwrap();
 
-    let domain_event_callbacks = Box::new(DomainEventCallbacksImpl::new(
-        redis_client.get_connection().unwrap(),
-        tracer_arc.clone(),
-    ));
+    let domain_event_handlers =
+        DomainEventHandlers::new(redis_client.get_connection().unwrap(), tracer_arc.clone())
+            .into_registry();
     let mut domain_event_consumer = DomainEventConsumer::new(
         &config.kafka_consumer_group,
         &config.kafka_url,
         &config.kafka_domain_events_topics,
         connection_pool.clone(),
-        domain_event_callbacks,
+        domain_event_handlers,
     );
 
+    let token_verifier = AccessTokenVerifier::new(&config.idp_host, &config.idp_realm)
+        .await
+        .unwrap();
+
     let app_state = AppState {
         connection_pool: connection_pool.clone(),
         redis_client,
         tracer: tracer_arc,
+        token_verifier,
     };
     let state_arc = Arc::new(app_state);
 
@@ -66,12 +80,29 @@ async fn main() {
         .allow_origin(tower_http::cors::Any);
 
     let app = Router::new()
+        .route("/leagues", post(create_league_handler))
+        .route("/leagues/:id", get(get_league_by_id_handler))
+        .route("/leagues/all", get(get_all_leagues_handler))
+        .route(
+            "/leagues/:id/seasons/:season_id/standings",
+            get(get_standings_handler),
+        )
+        .route("/seasons", post(create_season_handler))
+        .route("/seasons/:id", get(get_season_by_id_handler))
+        .route("/seasons/all", get(get_all_seasons_handler))
+        .route("/seasons/:id/close", post(close_season_handler))
         .route("/fixtures", post(create_fixture_handler))
+        .route("/fixtures", get(get_fixtures_handler))
         .route("/fixtures/:id", get(get_fixture_by_id_handler))
         .route("/fixtures/all", get(get_all_fixtures_handler))
         .route("/fixtures/:id/date", post(update_fixture_date_handler))
         .route("/fixtures/:id/venue", post(update_fixture_venue_handler))
         .route("/fixtures/:id/cancel", post(cancel_fixture_handler))
+        .route("/fixtures/:id/postpone", post(postpone_fixture_handler))
+        .route("/fixtures/:id/reschedule", post(reschedule_fixture_handler))
+        .route("/fixtures/:id/result", post(record_fixture_result_handler))
+        .route("/fixtures/schedule", post(generate_schedule_handler))
+        .route("/fixtures/schedule/preview", post(preview_schedule_handler))
         .layer(cors)
         .with_state(state_arc);
 
@@ -1,17 +1,21 @@
 use std::sync::Arc;
 
 use crate::{
-    adapters::db::fixture_repo_pg::FixtureRepositoryPg,
-    application::fixture_services::{
-        assign_first_referee, assign_second_referee, unassign_first_referee,
-        unassign_second_referee,
+    adapters::db::{fixture_repo_pg::FixtureRepositoryPg, standing_repo_pg::StandingRepositoryPg},
+    application::{
+        fixture_services::{
+            assign_first_referee, assign_second_referee, unassign_first_referee,
+            unassign_second_referee,
+        },
+        standing_services::update_standings_for_result,
     },
+    domain::repositories::fixture_repo::FixtureRepository,
 };
-use axum::async_trait;
 use log::info;
 use microservices_shared::{
-    domain_events::{DomainEventCallbacks, DomainEventCallbacksLoggerImpl},
-    domain_ids::{FixtureId, RefereeId, TeamId, VenueId},
+    domain_event_repo::DomainEventRepositoryPg,
+    domain_events::DomainEventHandlerRegistry,
+    domain_ids::{CommitSagaId, FixtureId, RefereeId, SeasonId, TeamId, VenueId},
 };
 use opentelemetry::global::BoxedTracer;
 use opentelemetry::{
@@ -21,40 +25,88 @@ use opentelemetry::{
 use redis::Commands;
 use sqlx::types::chrono::{DateTime, Utc};
 
-pub struct DomainEventCallbacksImpl {
+pub struct DomainEventHandlers {
     redis_conn: redis::Connection,
     tracer: Arc<BoxedTracer>,
-    delegate: DomainEventCallbacksLoggerImpl,
 }
 
-impl DomainEventCallbacksImpl {
+impl DomainEventHandlers {
     pub fn new(redis_conn: redis::Connection, tracer: Arc<BoxedTracer>) -> Self {
-        Self {
-            redis_conn,
-            delegate: DomainEventCallbacksLoggerImpl::new(tracer.clone()),
-            tracer,
-        }
+        Self { redis_conn, tracer }
     }
-}
 
-#[async_trait]
-impl DomainEventCallbacks for DomainEventCallbacksImpl {
-    type TxCtx = sqlx::Transaction<'static, sqlx::Postgres>;
-    type Error = String;
-
-    async fn on_referee_created(
-        &mut self,
-        referee_id: RefereeId,
-        _tx_ctx: &mut Self::TxCtx,
-    ) -> Result<(), String> {
-        self.delegate.on_referee_created(referee_id, _tx_ctx).await
+    /// Registers the handlers for the domain events this service reacts to
+    pub fn into_registry(
+        self,
+    ) -> DomainEventHandlerRegistry<Self, sqlx::Transaction<'static, sqlx::Postgres>> {
+        let tracer = self.tracer.clone();
+        DomainEventHandlerRegistry::new(self, tracer)
+            .on_referee_club_changed(|handlers, referee_id, club_name, tx_ctx| {
+                Box::pin(handlers.on_referee_club_changed(referee_id, club_name, tx_ctx))
+            })
+            .on_referee_home_changed(|handlers, referee_id, tx_ctx| {
+                Box::pin(handlers.on_referee_home_changed(referee_id, tx_ctx))
+            })
+            .on_referee_archived(|handlers, referee_id, tx_ctx| {
+                Box::pin(handlers.on_referee_archived(referee_id, tx_ctx))
+            })
+            .on_team_updated(|handlers, team_id, tx_ctx| {
+                Box::pin(handlers.on_team_updated(team_id, tx_ctx))
+            })
+            .on_team_archived(|handlers, team_id, tx_ctx| {
+                Box::pin(handlers.on_team_archived(team_id, tx_ctx))
+            })
+            .on_venue_updated(|handlers, venue_id, tx_ctx| {
+                Box::pin(handlers.on_venue_updated(venue_id, tx_ctx))
+            })
+            .on_venue_archived(|handlers, venue_id, tx_ctx| {
+                Box::pin(handlers.on_venue_archived(venue_id, tx_ctx))
+            })
+            .on_fixture_date_changed(|handlers, fixture_id, date, tx_ctx| {
+                Box::pin(handlers.on_fixture_date_changed(fixture_id, date, tx_ctx))
+            })
+            .on_fixture_venue_changed(|handlers, fixture_id, venue_id, tx_ctx| {
+                Box::pin(handlers.on_fixture_venue_changed(fixture_id, venue_id, tx_ctx))
+            })
+            .on_fixture_cancelled(|handlers, fixture_id, tx_ctx| {
+                Box::pin(handlers.on_fixture_cancelled(fixture_id, tx_ctx))
+            })
+            .on_fixture_postponed(|handlers, fixture_id, tx_ctx| {
+                Box::pin(handlers.on_fixture_postponed(fixture_id, tx_ctx))
+            })
+            .on_fixture_result_recorded(|handlers, fixture_id, winner, tx_ctx| {
+                Box::pin(handlers.on_fixture_result_recorded(fixture_id, winner, tx_ctx))
+            })
+            .on_first_referee_assignment_removed(|handlers, fixture_id, referee_id, tx_ctx| {
+                Box::pin(
+                    handlers.on_first_referee_assignment_removed(fixture_id, referee_id, tx_ctx),
+                )
+            })
+            .on_second_referee_assignment_removed(|handlers, fixture_id, referee_id, tx_ctx| {
+                Box::pin(
+                    handlers.on_second_referee_assignment_removed(fixture_id, referee_id, tx_ctx),
+                )
+            })
+            .on_first_referee_assigned(|handlers, saga_id, fixture_id, referee_id, tx_ctx| {
+                Box::pin(
+                    handlers.on_first_referee_assigned(saga_id, fixture_id, referee_id, tx_ctx),
+                )
+            })
+            .on_second_referee_assigned(|handlers, saga_id, fixture_id, referee_id, tx_ctx| {
+                Box::pin(
+                    handlers.on_second_referee_assigned(saga_id, fixture_id, referee_id, tx_ctx),
+                )
+            })
+            .on_season_closed(|handlers, season_id, tx_ctx| {
+                Box::pin(handlers.on_season_closed(season_id, tx_ctx))
+            })
     }
 
     async fn on_referee_club_changed(
         &mut self,
         referee_id: RefereeId,
         club_name: String,
-        _tx_ctx: &mut Self::TxCtx,
+        _tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
     ) -> Result<(), String> {
         info!(
             "Received Domain Event: Referee club changed: {:?} -> {}",
@@ -74,39 +126,142 @@ impl DomainEventCallbacks for DomainEventCallbacksImpl {
         _result.map_err(|e| e.to_string())
     }
 
-    async fn on_team_created(
+    async fn on_referee_home_changed(
+        &mut self,
+        referee_id: RefereeId,
+        _tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event: Referee home changed: {:?}",
+            referee_id
+        );
+
+        let mut span = self.tracer.start("on_referee_home_changed");
+        span.set_attribute(KeyValue::new("referee_id", referee_id.to_string()));
+
+        info!("Invalidating cache entry for referee: {:?}", referee_id);
+
+        // NOTE: invalidate the cache entry for the referee
+        let key = format!("referee_{}", referee_id.0.to_string());
+        let _result: Result<(), redis::RedisError> = self.redis_conn.del(key);
+
+        _result.map_err(|e| e.to_string())
+    }
+
+    async fn on_referee_archived(
+        &mut self,
+        referee_id: RefereeId,
+        _tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!("Received Domain Event: Referee archived: {:?}", referee_id);
+
+        let mut span = self.tracer.start("on_referee_archived");
+        span.set_attribute(KeyValue::new("referee_id", referee_id.to_string()));
+
+        info!("Invalidating cache entry for referee: {:?}", referee_id);
+
+        // NOTE: invalidate the cache entry for the referee
+        let key = format!("referee_{}", referee_id.0.to_string());
+        let _result: Result<(), redis::RedisError> = self.redis_conn.del(key);
+
+        _result.map_err(|e| e.to_string())
+    }
+
+    async fn on_team_updated(
         &mut self,
         team_id: TeamId,
-        _tx_ctx: &mut Self::TxCtx,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
     ) -> Result<(), String> {
-        self.delegate.on_team_created(team_id, _tx_ctx).await
+        info!("Received Domain Event: Team updated: {:?}", team_id);
+
+        let mut span = self.tracer.start("on_team_updated");
+        span.set_attribute(KeyValue::new("team_id", team_id.to_string()));
+
+        info!("Invalidating cache entry for team: {:?}", team_id);
+
+        let key = format!("team_{}", team_id.0.to_string());
+        let _result: Result<(), redis::RedisError> = self.redis_conn.del(key);
+        _result.map_err(|e| e.to_string())?;
+
+        // NOTE: the cached fixtures contain the team, therefore they are stale too
+        let fixture_repo = FixtureRepositoryPg::new();
+        let fixtures = fixture_repo.get_all(tx_ctx).await?;
+
+        for fixture in fixtures
+            .iter()
+            .filter(|f| *f.team_home_id() == team_id || *f.team_away_id() == team_id)
+        {
+            invalidate_fixture_cache_entry(&mut self.redis_conn, fixture.id())?;
+        }
+
+        Ok(())
     }
 
-    async fn on_venue_created(
+    async fn on_team_archived(
+        &mut self,
+        team_id: TeamId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!("Received Domain Event: Team archived: {:?}", team_id);
+
+        // NOTE: the archived flag is part of the cached team and fixtures, therefore they are stale
+        // in the same way as if the team was updated
+        self.on_team_updated(team_id, tx_ctx).await
+    }
+
+    async fn on_venue_updated(
         &mut self,
         venue_id: VenueId,
-        _tx_ctx: &mut Self::TxCtx,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
     ) -> Result<(), String> {
-        self.delegate.on_venue_created(venue_id, _tx_ctx).await
+        info!("Received Domain Event: Venue updated: {:?}", venue_id);
+
+        let mut span = self.tracer.start("on_venue_updated");
+        span.set_attribute(KeyValue::new("venue_id", venue_id.to_string()));
+
+        info!("Invalidating cache entry for venue: {:?}", venue_id);
+
+        let key = format!("venue_{}", venue_id.0.to_string());
+        let _result: Result<(), redis::RedisError> = self.redis_conn.del(key);
+        _result.map_err(|e| e.to_string())?;
+
+        // NOTE: the cached fixtures contain the venue, therefore they are stale too
+        let fixture_repo = FixtureRepositoryPg::new();
+        let fixtures = fixture_repo.get_all(tx_ctx).await?;
+
+        for fixture in fixtures.iter().filter(|f| *f.venue_id() == venue_id) {
+            invalidate_fixture_cache_entry(&mut self.redis_conn, fixture.id())?;
+        }
+
+        Ok(())
     }
 
-    async fn on_fixture_created(
+    async fn on_venue_archived(
         &mut self,
-        fixture_id: FixtureId,
-        _tx_ctx: &mut Self::TxCtx,
+        venue_id: VenueId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
     ) -> Result<(), String> {
-        self.delegate.on_fixture_created(fixture_id, _tx_ctx).await
+        info!("Received Domain Event: Venue archived: {:?}", venue_id);
+
+        // NOTE: the archived flag is part of the cached venue and fixtures, therefore they are stale
+        // in the same way as if the venue was updated
+        self.on_venue_updated(venue_id, tx_ctx).await
     }
 
     async fn on_fixture_date_changed(
         &mut self,
         fixture_id: FixtureId,
         date: DateTime<Utc>,
-        _tx_ctx: &mut Self::TxCtx,
+        _tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
     ) -> Result<(), String> {
-        self.delegate
-            .on_fixture_date_changed(fixture_id, date, _tx_ctx)
-            .await?;
+        info!(
+            "Received Domain Event: Fixture date changed: {:?} -> {}",
+            fixture_id, date
+        );
+
+        let mut span = self.tracer.start("on_fixture_date_changed");
+        span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
+        span.set_attribute(KeyValue::new("date", date.to_string()));
 
         invalidate_fixture_cache_entry(&mut self.redis_conn, fixture_id)
     }
@@ -115,11 +270,16 @@ impl DomainEventCallbacks for DomainEventCallbacksImpl {
         &mut self,
         fixture_id: FixtureId,
         venue_id: VenueId,
-        _tx_ctx: &mut Self::TxCtx,
+        _tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
     ) -> Result<(), String> {
-        self.delegate
-            .on_fixture_venue_changed(fixture_id, venue_id, _tx_ctx)
-            .await?;
+        info!(
+            "Received Domain Event: Fixture venue changed: {:?} -> {:?}",
+            fixture_id, venue_id
+        );
+
+        let mut span = self.tracer.start("on_fixture_venue_changed");
+        span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
+        span.set_attribute(KeyValue::new("venue_id", venue_id.to_string()));
 
         invalidate_fixture_cache_entry(&mut self.redis_conn, fixture_id)
     }
@@ -127,42 +287,57 @@ impl DomainEventCallbacks for DomainEventCallbacksImpl {
     async fn on_fixture_cancelled(
         &mut self,
         fixture_id: FixtureId,
-        _tx_ctx: &mut Self::TxCtx,
+        _tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
     ) -> Result<(), String> {
-        self.delegate
-            .on_fixture_cancelled(fixture_id, _tx_ctx)
-            .await?;
+        info!("Received Domain Event: Fixture cancelled: {:?}", fixture_id);
+
+        let mut span = self.tracer.start("on_fixture_cancelled");
+        span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
 
         invalidate_fixture_cache_entry(&mut self.redis_conn, fixture_id)
     }
 
-    async fn on_availability_declared(
+    async fn on_fixture_postponed(
         &mut self,
         fixture_id: FixtureId,
-        referee_id: RefereeId,
-        _tx_ctx: &mut Self::TxCtx,
+        _tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
     ) -> Result<(), String> {
-        self.delegate
-            .on_availability_declared(fixture_id, referee_id, _tx_ctx)
-            .await
+        info!("Received Domain Event: Fixture postponed: {:?}", fixture_id);
+
+        let mut span = self.tracer.start("on_fixture_postponed");
+        span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
+
+        invalidate_fixture_cache_entry(&mut self.redis_conn, fixture_id)
     }
 
-    async fn on_availability_withdrawn(
+    async fn on_fixture_result_recorded(
         &mut self,
         fixture_id: FixtureId,
-        referee_id: RefereeId,
-        _tx_ctx: &mut Self::TxCtx,
+        winner: TeamId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
     ) -> Result<(), String> {
-        self.delegate
-            .on_availability_withdrawn(fixture_id, referee_id, _tx_ctx)
-            .await
+        info!(
+            "Received Domain Event in Fixtures: Fixture result recorded: {:?} -> {:?}",
+            fixture_id, winner
+        );
+
+        let mut span = self.tracer.start("on_fixture_result_recorded");
+        span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
+        span.set_attribute(KeyValue::new("winner", winner.to_string()));
+
+        let fixture_repo = FixtureRepositoryPg::new();
+        let standing_repo = StandingRepositoryPg::new();
+
+        update_standings_for_result(fixture_id, &fixture_repo, &standing_repo, tx_ctx).await?;
+
+        invalidate_fixture_cache_entry(&mut self.redis_conn, fixture_id)
     }
 
     async fn on_first_referee_assignment_removed(
         &mut self,
         fixture_id: FixtureId,
         referee_id: RefereeId,
-        tx_ctx: &mut Self::TxCtx,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
     ) -> Result<(), String> {
         info!(
             "Received Domain Event in Fixtures: First referee assignment removed: {:?} -> {:?}",
@@ -184,7 +359,7 @@ impl DomainEventCallbacks for DomainEventCallbacksImpl {
         &mut self,
         fixture_id: FixtureId,
         referee_id: RefereeId,
-        tx_ctx: &mut Self::TxCtx,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
     ) -> Result<(), String> {
         info!(
             "Received Domain Event in Fixtures: Second referee assignment removed: {:?} -> {:?}",
@@ -204,47 +379,92 @@ impl DomainEventCallbacks for DomainEventCallbacksImpl {
 
     async fn on_first_referee_assigned(
         &mut self,
+        saga_id: CommitSagaId,
         fixture_id: FixtureId,
         referee_id: RefereeId,
-        tx_ctx: &mut Self::TxCtx,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
     ) -> Result<(), String> {
         info!(
-            "Received Domain Event in Fixtures: First referee assigned: {:?} -> {:?}",
-            fixture_id, referee_id
+            "Received Domain Event in Fixtures: First referee assigned: {:?} -> {:?} (saga {:?})",
+            fixture_id, referee_id, saga_id
         );
 
         let mut span = self.tracer.start("on_first_referee_assigned");
+        span.set_attribute(KeyValue::new("saga_id", saga_id.to_string()));
         span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
         span.set_attribute(KeyValue::new("referee_id", referee_id.to_string()));
 
         let fixture_repo = FixtureRepositoryPg::new();
-
-        assign_first_referee(fixture_id, referee_id, &fixture_repo, tx_ctx).await?;
+        let domain_event_repo = DomainEventRepositoryPg::new();
+
+        assign_first_referee(
+            saga_id,
+            fixture_id,
+            referee_id,
+            &fixture_repo,
+            &domain_event_repo,
+            tx_ctx,
+        )
+        .await?;
 
         invalidate_fixture_cache_entry(&mut self.redis_conn, fixture_id)
     }
 
     async fn on_second_referee_assigned(
         &mut self,
+        saga_id: CommitSagaId,
         fixture_id: FixtureId,
         referee_id: RefereeId,
-        tx_ctx: &mut Self::TxCtx,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
     ) -> Result<(), String> {
         info!(
-            "Received Domain Event in Fixtures: Second referee assigned: {:?} -> {:?}",
-            fixture_id, referee_id
+            "Received Domain Event in Fixtures: Second referee assigned: {:?} -> {:?} (saga {:?})",
+            fixture_id, referee_id, saga_id
         );
 
         let mut span = self.tracer.start("on_second_referee_assigned");
+        span.set_attribute(KeyValue::new("saga_id", saga_id.to_string()));
         span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
         span.set_attribute(KeyValue::new("referee_id", referee_id.to_string()));
 
         let fixture_repo = FixtureRepositoryPg::new();
-
-        assign_second_referee(fixture_id, referee_id, &fixture_repo, tx_ctx).await?;
+        let domain_event_repo = DomainEventRepositoryPg::new();
+
+        assign_second_referee(
+            saga_id,
+            fixture_id,
+            referee_id,
+            &fixture_repo,
+            &domain_event_repo,
+            tx_ctx,
+        )
+        .await?;
 
         invalidate_fixture_cache_entry(&mut self.redis_conn, fixture_id)
     }
+
+    async fn on_season_closed(
+        &mut self,
+        season_id: SeasonId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!("Received Domain Event: Season closed: {:?}", season_id);
+
+        let mut span = self.tracer.start("on_season_closed");
+        span.set_attribute(KeyValue::new("season_id", season_id.to_string()));
+
+        // NOTE: the fixtures of a closed season are read-only, so other services must not see them as open anymore
+        let fixture_repo = FixtureRepositoryPg::new();
+        let fixtures = fixture_repo
+            .find_by_league_and_season(None, Some(season_id), tx_ctx)
+            .await?;
+
+        for fixture in fixtures {
+            invalidate_fixture_cache_entry(&mut self.redis_conn, fixture.id())?;
+        }
+
+        Ok(())
+    }
 }
 
 fn invalidate_fixture_cache_entry(
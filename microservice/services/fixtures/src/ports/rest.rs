@@ -1 +1,4 @@
 pub mod fixtures;
+pub mod leagues;
+pub mod seasons;
+pub mod standings;
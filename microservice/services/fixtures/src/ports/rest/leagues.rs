@@ -0,0 +1,78 @@
+use crate::adapters::db::league_repo_pg::LeagueRepositoryPg;
+use crate::domain::repositories::league_repo::LeagueRepository;
+use crate::{application, AppState};
+use axum::extract::{Path, State};
+use axum::Json;
+use log::debug;
+use restinterface::{LeagueCreationDTO, LeagueDTO, LeagueIdDTO};
+use shared::app_error::AppError;
+use std::sync::Arc;
+
+pub async fn create_league_handler(
+    State(state): State<Arc<AppState>>,
+    Json(league_creation): Json<LeagueCreationDTO>,
+) -> Result<Json<LeagueDTO>, AppError> {
+    debug!("Creating league: {:?}", league_creation);
+
+    let mut tx = state
+        .connection_pool
+        .begin()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    let league_repo = LeagueRepositoryPg::new();
+
+    let league =
+        application::league_services::create_league(&league_creation.name, &league_repo, &mut tx)
+            .await
+            .map_err(|e| AppError::from_error(&e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(league))
+}
+
+pub async fn get_league_by_id_handler(
+    State(state): State<Arc<AppState>>,
+    Path(league_id): Path<LeagueIdDTO>,
+) -> Result<Json<Option<LeagueDTO>>, AppError> {
+    debug!("Getting league by id: {}", league_id.0);
+
+    let mut tx = state
+        .connection_pool
+        .begin()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    let league_repo = LeagueRepositoryPg::new();
+
+    let league = league_repo
+        .find_by_id(league_id.into(), &mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e))?;
+
+    Ok(Json(league.map(|l| l.into())))
+}
+
+pub async fn get_all_leagues_handler(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<LeagueDTO>>, AppError> {
+    debug!("Getting all leagues");
+
+    let mut tx = state
+        .connection_pool
+        .begin()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    let league_repo = LeagueRepositoryPg::new();
+
+    let leagues = league_repo
+        .get_all(&mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e))?;
+
+    Ok(Json(leagues.into_iter().map(|l| l.into()).collect()))
+}
@@ -0,0 +1,110 @@
+use crate::adapters::db::season_repo_pg::SeasonRepositoryPg;
+use crate::domain::repositories::season_repo::SeasonRepository;
+use crate::{application, AppState};
+use axum::extract::{Path, State};
+use axum::Json;
+use log::debug;
+use microservices_shared::domain_event_repo::DomainEventRepositoryPg;
+use restinterface::{SeasonCreationDTO, SeasonDTO, SeasonIdDTO};
+use shared::app_error::AppError;
+use std::sync::Arc;
+
+pub async fn create_season_handler(
+    State(state): State<Arc<AppState>>,
+    Json(season_creation): Json<SeasonCreationDTO>,
+) -> Result<Json<SeasonDTO>, AppError> {
+    debug!("Creating season: {:?}", season_creation);
+
+    let mut tx = state
+        .connection_pool
+        .begin()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    let season_repo = SeasonRepositoryPg::new();
+
+    let season =
+        application::season_services::create_season(&season_creation.name, &season_repo, &mut tx)
+            .await
+            .map_err(|e| AppError::from_error(&e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(season))
+}
+
+pub async fn get_season_by_id_handler(
+    State(state): State<Arc<AppState>>,
+    Path(season_id): Path<SeasonIdDTO>,
+) -> Result<Json<Option<SeasonDTO>>, AppError> {
+    debug!("Getting season by id: {}", season_id.0);
+
+    let mut tx = state
+        .connection_pool
+        .begin()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    let season_repo = SeasonRepositoryPg::new();
+
+    let season = season_repo
+        .find_by_id(season_id.into(), &mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e))?;
+
+    Ok(Json(season.map(|s| s.into())))
+}
+
+pub async fn get_all_seasons_handler(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<SeasonDTO>>, AppError> {
+    debug!("Getting all seasons");
+
+    let mut tx = state
+        .connection_pool
+        .begin()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    let season_repo = SeasonRepositoryPg::new();
+
+    let seasons = season_repo
+        .get_all(&mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e))?;
+
+    Ok(Json(seasons.into_iter().map(|s| s.into()).collect()))
+}
+
+pub async fn close_season_handler(
+    State(state): State<Arc<AppState>>,
+    Path(season_id): Path<SeasonIdDTO>,
+) -> Result<Json<SeasonDTO>, AppError> {
+    debug!("Closing season: {}", season_id.0);
+
+    let mut tx = state
+        .connection_pool
+        .begin()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    let season_repo = SeasonRepositoryPg::new();
+    let domain_event_repo = DomainEventRepositoryPg::new();
+
+    let season = application::season_services::close_season(
+        season_id.into(),
+        &season_repo,
+        &domain_event_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(season))
+}
@@ -0,0 +1,47 @@
+use crate::adapters::db::standing_repo_pg::StandingRepositoryPg;
+use crate::{application, AppState};
+use axum::extract::{Path, State};
+use axum::Json;
+use log::debug;
+use microservices_shared::resolvers::impls::TeamResolverImpl;
+use restinterface::{LeagueIdDTO, SeasonIdDTO, StandingDTO};
+use shared::app_error::AppError;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+pub async fn get_standings_handler(
+    State(state): State<Arc<AppState>>,
+    Path((league_id, season_id)): Path<(LeagueIdDTO, SeasonIdDTO)>,
+) -> Result<Json<Vec<StandingDTO>>, AppError> {
+    debug!(
+        "Getting standings for league {} and season {}",
+        league_id.0, season_id.0
+    );
+
+    let mut tx = state
+        .connection_pool
+        .begin()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    let redis_conn = state
+        .redis_client
+        .get_connection()
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+    let redis_conn_arc_mutex = Arc::new(Mutex::new(redis_conn));
+
+    let standing_repo = StandingRepositoryPg::new();
+    let team_resolver = TeamResolverImpl::new(redis_conn_arc_mutex.clone());
+
+    let standings = application::standing_services::get_standings(
+        league_id.into(),
+        season_id.into(),
+        &standing_repo,
+        &team_resolver,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e))?;
+
+    Ok(Json(standings))
+}
@@ -0,0 +1,5 @@
+# mkdir -p ./.sqlx
+# cargo sqlx prepare --workspace
+# mv ../../.sqlx/* ./.sqlx
+# rm -r ../../.sqlx/
+cargo sqlx prepare
\ No newline at end of file
@@ -0,0 +1,4 @@
+. config/config.sh
+export SERVER_HOST='localhost:5006'
+
+cargo run --release
@@ -0,0 +1,2 @@
+pub mod notification_recipient_repo_pg;
+pub mod notification_repo_pg;
@@ -0,0 +1,74 @@
+use microservices_shared::domain_ids::RefereeId;
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::domain::{
+    aggregates::notification_recipient::NotificationRecipient,
+    repositories::notification_recipient_repo::NotificationRecipientRepository,
+};
+
+struct NotificationRecipientDb {
+    pub referee_id: Uuid,
+    pub email: String,
+}
+
+pub struct NotificationRecipientRepositoryPg();
+
+impl NotificationRecipientRepositoryPg {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl From<NotificationRecipientDb> for NotificationRecipient {
+    fn from(recipient: NotificationRecipientDb) -> Self {
+        NotificationRecipient::from_values(RefereeId::from(recipient.referee_id), recipient.email)
+    }
+}
+
+impl NotificationRecipientRepository for NotificationRecipientRepositoryPg {
+    type Error = String;
+    type TxCtx = Transaction<'static, Postgres>;
+
+    async fn find_by_referee(
+        &self,
+        referee_id: RefereeId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<NotificationRecipient>, Self::Error> {
+        let recipient: Option<NotificationRecipientDb> = sqlx::query_as!(
+            NotificationRecipientDb,
+            "SELECT referee_id, email FROM rustddd.notification_recipients WHERE referee_id = $1",
+            referee_id.0
+        )
+        .fetch_optional(&mut **tx_ctx)
+        .await
+        .map_err(|e| {
+            format!(
+                "NotificationRecipientRepositoryPg::find_by_referee failed with {}",
+                e
+            )
+        })?;
+
+        Ok(recipient.map(|r| r.into()))
+    }
+
+    async fn save(
+        &self,
+        recipient: &NotificationRecipient,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<(), Self::Error> {
+        sqlx::query!(
+            "INSERT INTO rustddd.notification_recipients (referee_id, email)
+            VALUES ($1, $2)
+            ON CONFLICT (referee_id)
+            DO UPDATE SET email = $2",
+            recipient.referee_id().0,
+            recipient.email()
+        )
+        .execute(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("NotificationRecipientRepositoryPg::save failed with {}", e))?;
+
+        Ok(())
+    }
+}
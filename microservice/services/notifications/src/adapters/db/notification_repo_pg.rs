@@ -0,0 +1,202 @@
+use chrono::{DateTime, Utc};
+use microservices_shared::domain_ids::{FixtureId, NotificationId, RefereeId};
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::domain::{
+    aggregates::notification::{DeliveryStatus, Notification, NotificationKind},
+    repositories::notification_repo::NotificationRepository,
+};
+
+#[derive(sqlx::Type, Debug)]
+#[sqlx(type_name = "rustddd.notification_kind", rename_all = "snake_case")]
+enum NotificationKindDb {
+    FirstRefereeAssigned,
+    SecondRefereeAssigned,
+    AssignmentRemoved,
+    FixtureDateChanged,
+    FixtureVenueChanged,
+    FixtureCancelled,
+}
+
+#[derive(sqlx::Type, Debug)]
+#[sqlx(type_name = "rustddd.delivery_status", rename_all = "lowercase")]
+enum DeliveryStatusDb {
+    Pending,
+    Sent,
+    Failed,
+}
+
+struct NotificationDb {
+    pub id: Uuid,
+    pub referee_id: Uuid,
+    pub fixture_id: Uuid,
+    pub kind: NotificationKindDb,
+    pub recipient: String,
+    pub subject: String,
+    pub body: String,
+    pub status: DeliveryStatusDb,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub next_attempt_at: DateTime<Utc>,
+    pub sent_at: Option<DateTime<Utc>>,
+}
+
+pub struct NotificationRepositoryPg();
+
+impl NotificationRepositoryPg {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl From<NotificationKindDb> for NotificationKind {
+    fn from(kind: NotificationKindDb) -> Self {
+        match kind {
+            NotificationKindDb::FirstRefereeAssigned => NotificationKind::FirstRefereeAssigned,
+            NotificationKindDb::SecondRefereeAssigned => NotificationKind::SecondRefereeAssigned,
+            NotificationKindDb::AssignmentRemoved => NotificationKind::AssignmentRemoved,
+            NotificationKindDb::FixtureDateChanged => NotificationKind::FixtureDateChanged,
+            NotificationKindDb::FixtureVenueChanged => NotificationKind::FixtureVenueChanged,
+            NotificationKindDb::FixtureCancelled => NotificationKind::FixtureCancelled,
+        }
+    }
+}
+
+impl From<NotificationKind> for NotificationKindDb {
+    fn from(kind: NotificationKind) -> Self {
+        match kind {
+            NotificationKind::FirstRefereeAssigned => NotificationKindDb::FirstRefereeAssigned,
+            NotificationKind::SecondRefereeAssigned => NotificationKindDb::SecondRefereeAssigned,
+            NotificationKind::AssignmentRemoved => NotificationKindDb::AssignmentRemoved,
+            NotificationKind::FixtureDateChanged => NotificationKindDb::FixtureDateChanged,
+            NotificationKind::FixtureVenueChanged => NotificationKindDb::FixtureVenueChanged,
+            NotificationKind::FixtureCancelled => NotificationKindDb::FixtureCancelled,
+        }
+    }
+}
+
+impl From<DeliveryStatusDb> for DeliveryStatus {
+    fn from(status: DeliveryStatusDb) -> Self {
+        match status {
+            DeliveryStatusDb::Pending => DeliveryStatus::Pending,
+            DeliveryStatusDb::Sent => DeliveryStatus::Sent,
+            DeliveryStatusDb::Failed => DeliveryStatus::Failed,
+        }
+    }
+}
+
+impl From<DeliveryStatus> for DeliveryStatusDb {
+    fn from(status: DeliveryStatus) -> Self {
+        match status {
+            DeliveryStatus::Pending => DeliveryStatusDb::Pending,
+            DeliveryStatus::Sent => DeliveryStatusDb::Sent,
+            DeliveryStatus::Failed => DeliveryStatusDb::Failed,
+        }
+    }
+}
+
+impl From<NotificationDb> for Notification {
+    fn from(notification: NotificationDb) -> Self {
+        Notification::from_values(
+            NotificationId::from(notification.id),
+            RefereeId::from(notification.referee_id),
+            FixtureId::from(notification.fixture_id),
+            notification.kind.into(),
+            notification.recipient,
+            notification.subject,
+            notification.body,
+            notification.status.into(),
+            notification.attempts,
+            notification.last_error,
+            notification.created_at,
+            notification.next_attempt_at,
+            notification.sent_at,
+        )
+    }
+}
+
+impl NotificationRepository for NotificationRepositoryPg {
+    type Error = String;
+    type TxCtx = Transaction<'static, Postgres>;
+
+    async fn find_by_referee(
+        &self,
+        referee_id: RefereeId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<Notification>, Self::Error> {
+        let notifications: Vec<NotificationDb> = sqlx::query_as!(
+            NotificationDb,
+            "SELECT id, referee_id, fixture_id, kind as \"kind: NotificationKindDb\", recipient, subject, body,
+                status as \"status: DeliveryStatusDb\", attempts, last_error, created_at, next_attempt_at, sent_at
+            FROM rustddd.notifications
+            WHERE referee_id = $1
+            ORDER BY created_at DESC",
+            referee_id.0
+        )
+        .fetch_all(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("NotificationRepositoryPg::find_by_referee failed with {}", e))?;
+
+        Ok(notifications.into_iter().map(|n| n.into()).collect())
+    }
+
+    async fn find_due(
+        &self,
+        now: DateTime<Utc>,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<Notification>, Self::Error> {
+        // NOTE: multiple instances deliver concurrently, locking the rows ensures each notification is sent by one of them only
+        let notifications: Vec<NotificationDb> = sqlx::query_as!(
+            NotificationDb,
+            "SELECT id, referee_id, fixture_id, kind as \"kind: NotificationKindDb\", recipient, subject, body,
+                status as \"status: DeliveryStatusDb\", attempts, last_error, created_at, next_attempt_at, sent_at
+            FROM rustddd.notifications
+            WHERE status = 'pending' AND next_attempt_at <= $1
+            ORDER BY created_at ASC
+            FOR UPDATE SKIP LOCKED",
+            now
+        )
+        .fetch_all(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("NotificationRepositoryPg::find_due failed with {}", e))?;
+
+        Ok(notifications.into_iter().map(|n| n.into()).collect())
+    }
+
+    async fn save(
+        &self,
+        notification: &Notification,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<(), Self::Error> {
+        let kind: NotificationKindDb = notification.kind().into();
+        let status: DeliveryStatusDb = notification.status().into();
+
+        sqlx::query!(
+            "INSERT INTO rustddd.notifications (id, referee_id, fixture_id, kind, recipient, subject, body,
+                status, attempts, last_error, created_at, next_attempt_at, sent_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            ON CONFLICT (id)
+            DO UPDATE SET status = $8, attempts = $9, last_error = $10, next_attempt_at = $12, sent_at = $13",
+            notification.id().0,
+            notification.referee_id().0,
+            notification.fixture_id().0,
+            kind as NotificationKindDb,
+            notification.recipient(),
+            notification.subject(),
+            notification.body(),
+            status as DeliveryStatusDb,
+            notification.attempts(),
+            notification.last_error(),
+            notification.created_at(),
+            notification.next_attempt_at(),
+            notification.sent_at()
+        )
+        .execute(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("NotificationRepositoryPg::save failed with {}", e))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1 @@
+pub mod smtp_mailer;
@@ -0,0 +1,52 @@
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+
+use crate::domain::mailer::Mailer;
+
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    sender: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn new(smtp_host: &str, smtp_port: u16, sender: &str) -> Self {
+        // NOTE: the SMTP server is expected to run next to the service (or to be a local sink during tests), therefore no TLS
+        let transport = AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(smtp_host)
+            .port(smtp_port)
+            .build();
+
+        Self {
+            transport,
+            sender: sender
+                .parse()
+                .unwrap_or_else(|e| panic!("Invalid sender address {}: {}", sender, e)),
+        }
+    }
+}
+
+impl Mailer for SmtpMailer {
+    type Error = String;
+
+    async fn send(&self, recipient: &str, subject: &str, body: &str) -> Result<(), Self::Error> {
+        let to: Mailbox = recipient
+            .parse()
+            .map_err(|e| format!("Invalid recipient address {}: {}", recipient, e))?;
+
+        let message = Message::builder()
+            .from(self.sender.clone())
+            .to(to)
+            .subject(subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(body.to_string())
+            .map_err(|e| format!("Building message failed with {}", e))?;
+
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| format!("Sending message failed with {}", e))?;
+
+        Ok(())
+    }
+}
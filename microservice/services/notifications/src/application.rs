@@ -0,0 +1,2 @@
+pub mod notification_recipient_services;
+pub mod notification_services;
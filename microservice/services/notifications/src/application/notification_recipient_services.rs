@@ -0,0 +1,28 @@
+use microservices_shared::{domain_ids::RefereeId, resolvers::traits::RefereeResolver};
+
+use crate::domain::{
+    aggregates::notification_recipient::NotificationRecipient,
+    repositories::notification_recipient_repo::NotificationRecipientRepository,
+};
+
+pub async fn set_notification_recipient<TxCtx>(
+    referee_id: RefereeId,
+    email: String,
+    referee_resolver: &impl RefereeResolver<Error = String>,
+    recipient_repo: &impl NotificationRecipientRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<NotificationRecipient, String> {
+    if !email.contains('@') {
+        return Err(format!(
+            "Invalid email address {} - cannot set notification recipient",
+            email
+        ));
+    }
+
+    let _referee = referee_resolver.resolve(&referee_id).await?;
+
+    let recipient = NotificationRecipient::new(referee_id, email);
+    recipient_repo.save(&recipient, tx_ctx).await?;
+
+    Ok(recipient)
+}
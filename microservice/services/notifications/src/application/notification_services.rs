@@ -0,0 +1,388 @@
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use microservices_shared::{
+    domain_ids::{FixtureId, RefereeId},
+    resolvers::traits::{FixtureResolver, RefereeResolver},
+};
+use restinterface::FixtureDTO;
+
+use crate::domain::{
+    aggregates::notification::{Notification, NotificationKind},
+    mailer::Mailer,
+    repositories::{
+        notification_recipient_repo::NotificationRecipientRepository,
+        notification_repo::NotificationRepository,
+    },
+    templates::{NotificationTemplate, TemplateContext},
+};
+
+/// Renders the notification for the referee and stores it for delivery.
+/// Referees without a notification address are skipped.
+pub async fn notify_referee<TxCtx>(
+    referee_id: RefereeId,
+    fixture: &FixtureDTO,
+    kind: NotificationKind,
+    referee_resolver: &impl RefereeResolver<Error = String>,
+    recipient_repo: &impl NotificationRecipientRepository<TxCtx = TxCtx, Error = String>,
+    notification_repo: &impl NotificationRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Option<Notification>, String> {
+    let recipient = match recipient_repo.find_by_referee(referee_id, tx_ctx).await? {
+        Some(recipient) => recipient,
+        None => {
+            info!(
+                "Referee {:?} has no notification address - not notifying about {:?}",
+                referee_id, kind
+            );
+            return Ok(None);
+        }
+    };
+
+    let referee = referee_resolver.resolve(&referee_id).await?;
+
+    let (subject, body) =
+        NotificationTemplate::for_kind(kind).render(&TemplateContext::new(&referee, fixture));
+
+    let notification = Notification::new(
+        referee_id,
+        FixtureId::from(fixture.id),
+        kind,
+        recipient.email().to_string(),
+        subject,
+        body,
+        Utc::now(),
+    );
+    notification_repo.save(&notification, tx_ctx).await?;
+
+    Ok(Some(notification))
+}
+
+/// Notifies the referee about an assignment to or its removal from the fixture
+pub async fn notify_assignment_change<TxCtx>(
+    fixture_id: FixtureId,
+    referee_id: RefereeId,
+    kind: NotificationKind,
+    fixture_resolver: &impl FixtureResolver<Error = String>,
+    referee_resolver: &impl RefereeResolver<Error = String>,
+    recipient_repo: &impl NotificationRecipientRepository<TxCtx = TxCtx, Error = String>,
+    notification_repo: &impl NotificationRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Option<Notification>, String> {
+    let fixture = fixture_resolver.resolve(&fixture_id).await?;
+
+    notify_referee(
+        referee_id,
+        &fixture,
+        kind,
+        referee_resolver,
+        recipient_repo,
+        notification_repo,
+        tx_ctx,
+    )
+    .await
+}
+
+/// Notifies the referees assigned to the fixture about a change of the fixture
+pub async fn notify_fixture_change<TxCtx>(
+    fixture_id: FixtureId,
+    kind: NotificationKind,
+    fixture_resolver: &impl FixtureResolver<Error = String>,
+    referee_resolver: &impl RefereeResolver<Error = String>,
+    recipient_repo: &impl NotificationRecipientRepository<TxCtx = TxCtx, Error = String>,
+    notification_repo: &impl NotificationRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<Notification>, String> {
+    let fixture = fixture_resolver.resolve(&fixture_id).await?;
+
+    let referee_ids: Vec<RefereeId> = [&fixture.first_referee, &fixture.second_referee]
+        .into_iter()
+        .flatten()
+        .map(|r| RefereeId::from(r.id))
+        .collect();
+
+    let mut notifications = Vec::new();
+    for referee_id in referee_ids {
+        if let Some(notification) = notify_referee(
+            referee_id,
+            &fixture,
+            kind,
+            referee_resolver,
+            recipient_repo,
+            notification_repo,
+            tx_ctx,
+        )
+        .await?
+        {
+            notifications.push(notification);
+        }
+    }
+
+    Ok(notifications)
+}
+
+/// Sends all notifications which are due, failed sends are retried later on
+/// until the notification runs out of attempts.
+pub async fn deliver_due_notifications<TxCtx>(
+    now: DateTime<Utc>,
+    mailer: &impl Mailer<Error = String>,
+    notification_repo: &impl NotificationRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<Notification>, String> {
+    let due_notifications = notification_repo.find_due(now, tx_ctx).await?;
+
+    let mut delivered = Vec::new();
+    for mut notification in due_notifications {
+        match mailer
+            .send(
+                notification.recipient(),
+                notification.subject(),
+                notification.body(),
+            )
+            .await
+        {
+            Ok(()) => notification.mark_sent(now),
+            Err(e) => {
+                warn!(
+                    "Delivering notification {:?} failed: {}",
+                    notification.id(),
+                    e
+                );
+                notification.mark_failed(e, now);
+            }
+        }
+
+        notification_repo.save(&notification, tx_ctx).await?;
+        delivered.push(notification);
+    }
+
+    Ok(delivered)
+}
+
+pub async fn get_notifications_for_referee<TxCtx>(
+    referee_id: RefereeId,
+    notification_repo: &impl NotificationRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<Notification>, String> {
+    notification_repo.find_by_referee(referee_id, tx_ctx).await
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone, Utc};
+    use microservices_shared::{
+        domain_ids::{FixtureId, RefereeId},
+        resolvers::traits::{MockFixtureResolver, MockRefereeResolver},
+    };
+    use mockall::predicate::eq;
+    use restinterface::{
+        FixtureDTO, FixtureIdDTO, FixtureStatusDTO, LeagueDTO, LeagueIdDTO, RefereeDTO,
+        RefereeIdDTO, SeasonDTO, SeasonIdDTO, TeamDTO, TeamIdDTO, VenueDTO, VenueIdDTO,
+    };
+    use uuid::Uuid;
+
+    use crate::{
+        application::notification_services::{deliver_due_notifications, notify_fixture_change},
+        domain::{
+            aggregates::{
+                notification::{DeliveryStatus, Notification, NotificationKind},
+                notification_recipient::NotificationRecipient,
+            },
+            mailer::MockMailer,
+            repositories::{
+                notification_recipient_repo::MockNotificationRecipientRepository,
+                notification_repo::MockNotificationRepository,
+            },
+        },
+    };
+
+    fn referee_dto(name: &str) -> RefereeDTO {
+        RefereeDTO {
+            id: RefereeIdDTO(Uuid::new_v4()),
+            name: name.to_string(),
+            club: "Club A".to_string(),
+            qualification_level: 1,
+            home: None,
+            archived: false,
+        }
+    }
+
+    fn fixture_dto(date: DateTime<Utc>, first_referee: Option<RefereeDTO>) -> FixtureDTO {
+        let team = TeamDTO {
+            id: TeamIdDTO(Uuid::new_v4()),
+            name: "Team A".to_string(),
+            club: "Club A".to_string(),
+            archived: false,
+        };
+        FixtureDTO {
+            id: FixtureIdDTO(Uuid::new_v4()),
+            league: LeagueDTO {
+                id: LeagueIdDTO(Uuid::new_v4()),
+                name: "League A".to_string(),
+            },
+            season: SeasonDTO {
+                id: SeasonIdDTO(Uuid::new_v4()),
+                name: "Season 2024/25".to_string(),
+                closed: false,
+            },
+            team_home: team.clone(),
+            team_away: team,
+            venue: VenueDTO {
+                id: VenueIdDTO(Uuid::new_v4()),
+                name: "Venue".to_string(),
+                street: "Street".to_string(),
+                zip: "12345".to_string(),
+                city: "City".to_string(),
+                telephone: None,
+                email: None,
+                location: None,
+                archived: false,
+            },
+            date,
+            status: FixtureStatusDTO::Scheduled,
+            result: None,
+            first_referee,
+            second_referee: None,
+            first_referee_min_level: 0,
+            second_referee_min_level: 0,
+        }
+    }
+
+    fn notification(recipient: &str, now: DateTime<Utc>) -> Notification {
+        Notification::new(
+            RefereeId(Uuid::new_v4()),
+            FixtureId(Uuid::new_v4()),
+            NotificationKind::FirstRefereeAssigned,
+            recipient.to_string(),
+            "Subject".to_string(),
+            "Body".to_string(),
+            now,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_given_assigned_referee_when_fixture_cancelled_then_referee_is_notified() {
+        let mut fixture_resolver = MockFixtureResolver::new();
+        let mut referee_resolver = MockRefereeResolver::new();
+        let mut recipient_repo = MockNotificationRecipientRepository::new();
+        let mut notification_repo = MockNotificationRepository::new();
+
+        let referee = referee_dto("Referee A");
+        let referee_id = RefereeId::from(referee.id);
+        let fixture = fixture_dto(
+            Utc.with_ymd_and_hms(2024, 12, 27, 18, 30, 0).unwrap(),
+            Some(referee.clone()),
+        );
+        let fixture_id = FixtureId::from(fixture.id);
+
+        fixture_resolver
+            .expect_resolve()
+            .with(eq(fixture_id))
+            .return_const(Ok(fixture));
+        referee_resolver
+            .expect_resolve()
+            .with(eq(referee_id))
+            .return_const(Ok(referee));
+        recipient_repo
+            .expect_find_by_referee()
+            .with(eq(referee_id), eq(()))
+            .return_const(Ok(Some(NotificationRecipient::new(
+                referee_id,
+                "referee@example.com".to_string(),
+            ))));
+        notification_repo
+            .expect_save()
+            .times(1)
+            .return_const(Ok(()));
+
+        let notifications = notify_fixture_change(
+            fixture_id,
+            NotificationKind::FixtureCancelled,
+            &fixture_resolver,
+            &referee_resolver,
+            &recipient_repo,
+            &notification_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].referee_id(), referee_id);
+        assert_eq!(notifications[0].recipient(), "referee@example.com");
+        assert_eq!(notifications[0].status(), DeliveryStatus::Pending);
+        assert!(notifications[0].body().contains("Referee A"));
+    }
+
+    #[tokio::test]
+    async fn test_given_referee_without_recipient_when_fixture_cancelled_then_nothing_is_stored() {
+        let mut fixture_resolver = MockFixtureResolver::new();
+        let referee_resolver = MockRefereeResolver::new();
+        let mut recipient_repo = MockNotificationRecipientRepository::new();
+        let mut notification_repo = MockNotificationRepository::new();
+
+        let fixture = fixture_dto(
+            Utc.with_ymd_and_hms(2024, 12, 27, 18, 30, 0).unwrap(),
+            Some(referee_dto("Referee A")),
+        );
+        let fixture_id = FixtureId::from(fixture.id);
+
+        fixture_resolver.expect_resolve().return_const(Ok(fixture));
+        recipient_repo
+            .expect_find_by_referee()
+            .return_const(Ok(None));
+        notification_repo.expect_save().times(0);
+
+        let notifications = notify_fixture_change(
+            fixture_id,
+            NotificationKind::FixtureCancelled,
+            &fixture_resolver,
+            &referee_resolver,
+            &recipient_repo,
+            &notification_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        assert!(notifications.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_given_due_notifications_when_delivering_then_delivery_status_is_recorded() {
+        let mut mailer = MockMailer::new();
+        let mut notification_repo = MockNotificationRepository::new();
+
+        let now = Utc::now();
+        let deliverable = notification("referee@example.com", now);
+        let undeliverable = notification("unknown@example.com", now);
+
+        notification_repo
+            .expect_find_due()
+            .return_const(Ok(vec![deliverable.clone(), undeliverable.clone()]));
+        mailer
+            .expect_send()
+            .withf(|recipient, _, _| recipient == "referee@example.com")
+            .return_const(Ok(()));
+        mailer
+            .expect_send()
+            .withf(|recipient, _, _| recipient == "unknown@example.com")
+            .return_const(Err("Mailbox unavailable".to_string()));
+        notification_repo
+            .expect_save()
+            .times(2)
+            .return_const(Ok(()));
+
+        let delivered = deliver_due_notifications(now, &mailer, &notification_repo, &mut ())
+            .await
+            .unwrap();
+
+        assert_eq!(delivered.len(), 2);
+        assert_eq!(delivered[0].id(), deliverable.id());
+        assert_eq!(delivered[0].status(), DeliveryStatus::Sent);
+        assert_eq!(delivered[0].sent_at(), Some(&now));
+        assert_eq!(delivered[1].id(), undeliverable.id());
+        assert_eq!(delivered[1].status(), DeliveryStatus::Pending);
+        assert_eq!(delivered[1].attempts(), 1);
+        assert_eq!(delivered[1].last_error(), Some("Mailbox unavailable"));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod aggregates;
+pub mod mailer;
+pub mod repositories;
+pub mod templates;
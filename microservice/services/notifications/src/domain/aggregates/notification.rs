@@ -0,0 +1,215 @@
+use chrono::{DateTime, Duration, Utc};
+use microservices_shared::domain_ids::{FixtureId, NotificationId, RefereeId};
+use uuid::Uuid;
+
+/// After this many failed attempts the delivery of a notification is given up
+pub const MAX_DELIVERY_ATTEMPTS: i32 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationKind {
+    FirstRefereeAssigned,
+    SecondRefereeAssigned,
+    AssignmentRemoved,
+    FixtureDateChanged,
+    FixtureVenueChanged,
+    FixtureCancelled,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryStatus {
+    Pending,
+    Sent,
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    id: NotificationId,
+    referee_id: RefereeId,
+    fixture_id: FixtureId,
+    kind: NotificationKind,
+    recipient: String,
+    subject: String,
+    body: String,
+    status: DeliveryStatus,
+    attempts: i32,
+    last_error: Option<String>,
+    created_at: DateTime<Utc>,
+    // NOTE: failed sends are retried with an increasing backoff, this is when the next try is due
+    next_attempt_at: DateTime<Utc>,
+    sent_at: Option<DateTime<Utc>>,
+}
+
+impl Notification {
+    pub fn new(
+        referee_id: RefereeId,
+        fixture_id: FixtureId,
+        kind: NotificationKind,
+        recipient: String,
+        subject: String,
+        body: String,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: NotificationId(Uuid::new_v4()),
+            referee_id,
+            fixture_id,
+            kind,
+            recipient,
+            subject,
+            body,
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            last_error: None,
+            created_at: now,
+            next_attempt_at: now,
+            sent_at: None,
+        }
+    }
+
+    pub fn from_values(
+        id: NotificationId,
+        referee_id: RefereeId,
+        fixture_id: FixtureId,
+        kind: NotificationKind,
+        recipient: String,
+        subject: String,
+        body: String,
+        status: DeliveryStatus,
+        attempts: i32,
+        last_error: Option<String>,
+        created_at: DateTime<Utc>,
+        next_attempt_at: DateTime<Utc>,
+        sent_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            referee_id,
+            fixture_id,
+            kind,
+            recipient,
+            subject,
+            body,
+            status,
+            attempts,
+            last_error,
+            created_at,
+            next_attempt_at,
+            sent_at,
+        }
+    }
+
+    pub fn id(&self) -> NotificationId {
+        self.id
+    }
+
+    pub fn referee_id(&self) -> RefereeId {
+        self.referee_id
+    }
+
+    pub fn fixture_id(&self) -> FixtureId {
+        self.fixture_id
+    }
+
+    pub fn kind(&self) -> NotificationKind {
+        self.kind
+    }
+
+    pub fn recipient(&self) -> &str {
+        &self.recipient
+    }
+
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    pub fn status(&self) -> DeliveryStatus {
+        self.status
+    }
+
+    pub fn attempts(&self) -> i32 {
+        self.attempts
+    }
+
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn next_attempt_at(&self) -> &DateTime<Utc> {
+        &self.next_attempt_at
+    }
+
+    pub fn sent_at(&self) -> Option<&DateTime<Utc>> {
+        self.sent_at.as_ref()
+    }
+
+    pub fn is_due(&self, now: &DateTime<Utc>) -> bool {
+        self.status == DeliveryStatus::Pending && self.next_attempt_at <= *now
+    }
+
+    pub fn mark_sent(&mut self, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.status = DeliveryStatus::Sent;
+        self.sent_at = Some(now);
+        self.last_error = None;
+    }
+
+    pub fn mark_failed(&mut self, error: String, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.last_error = Some(error);
+
+        if self.attempts >= MAX_DELIVERY_ATTEMPTS {
+            self.status = DeliveryStatus::Failed;
+        } else {
+            // NOTE: backing off exponentially gives a temporarily unavailable SMTP server time to recover
+            self.next_attempt_at = now + Duration::minutes(1 << (self.attempts - 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use microservices_shared::domain_ids::{FixtureId, RefereeId};
+    use uuid::Uuid;
+
+    use super::{DeliveryStatus, Notification, NotificationKind, MAX_DELIVERY_ATTEMPTS};
+
+    #[test]
+    fn test_given_failing_sends_when_mark_failed_then_backs_off_until_given_up() {
+        let now = Utc::now();
+        let mut notification = Notification::new(
+            RefereeId(Uuid::new_v4()),
+            FixtureId(Uuid::new_v4()),
+            NotificationKind::FixtureCancelled,
+            "referee@example.com".to_string(),
+            "Subject".to_string(),
+            "Body".to_string(),
+            now,
+        );
+        assert!(notification.is_due(&now));
+
+        notification.mark_failed("Connection refused".to_string(), now);
+        assert_eq!(notification.status(), DeliveryStatus::Pending);
+        assert!(!notification.is_due(&now));
+        assert_eq!(*notification.next_attempt_at(), now + Duration::minutes(1));
+
+        notification.mark_failed("Connection refused".to_string(), now);
+        assert_eq!(*notification.next_attempt_at(), now + Duration::minutes(2));
+
+        for _ in 2..MAX_DELIVERY_ATTEMPTS {
+            notification.mark_failed("Connection refused".to_string(), now);
+        }
+        assert_eq!(notification.status(), DeliveryStatus::Failed);
+        assert_eq!(notification.attempts(), MAX_DELIVERY_ATTEMPTS);
+        assert!(!notification.is_due(&(now + Duration::days(1))));
+    }
+}
@@ -0,0 +1,32 @@
+use microservices_shared::domain_ids::RefereeId;
+
+/// The address notifications for a referee are delivered to. Referees are not
+/// required to have one, in which case they are simply not notified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationRecipient {
+    referee_id: RefereeId,
+    email: String,
+}
+
+impl NotificationRecipient {
+    pub fn new(referee_id: RefereeId, email: String) -> Self {
+        if !email.contains('@') {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("Invalid email address {}", email);
+        }
+
+        Self { referee_id, email }
+    }
+
+    pub fn from_values(referee_id: RefereeId, email: String) -> Self {
+        Self { referee_id, email }
+    }
+
+    pub fn referee_id(&self) -> RefereeId {
+        self.referee_id
+    }
+
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+}
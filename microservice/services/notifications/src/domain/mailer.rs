@@ -0,0 +1,10 @@
+use mockall::automock;
+
+/// Delivers a rendered notification to its recipient
+#[allow(async_fn_in_trait)]
+#[automock(type Error = String;)]
+pub trait Mailer {
+    type Error;
+
+    async fn send(&self, recipient: &str, subject: &str, body: &str) -> Result<(), Self::Error>;
+}
@@ -0,0 +1,2 @@
+pub mod notification_recipient_repo;
+pub mod notification_repo;
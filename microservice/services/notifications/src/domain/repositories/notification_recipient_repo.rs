@@ -0,0 +1,22 @@
+use microservices_shared::domain_ids::RefereeId;
+use mockall::automock;
+
+use crate::domain::aggregates::notification_recipient::NotificationRecipient;
+
+#[allow(async_fn_in_trait)]
+#[automock(type Error = String; type TxCtx = ();)]
+pub trait NotificationRecipientRepository {
+    type Error;
+    type TxCtx;
+
+    async fn find_by_referee(
+        &self,
+        referee_id: RefereeId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<NotificationRecipient>, Self::Error>;
+    async fn save(
+        &self,
+        recipient: &NotificationRecipient,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<(), Self::Error>;
+}
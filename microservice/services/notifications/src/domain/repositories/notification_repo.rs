@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use microservices_shared::domain_ids::RefereeId;
+use mockall::automock;
+
+use crate::domain::aggregates::notification::Notification;
+
+#[allow(async_fn_in_trait)]
+#[automock(type Error = String; type TxCtx = ();)]
+pub trait NotificationRepository {
+    type Error;
+    type TxCtx;
+
+    async fn find_by_referee(
+        &self,
+        referee_id: RefereeId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<Notification>, Self::Error>;
+    async fn find_due(
+        &self,
+        now: DateTime<Utc>,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<Notification>, Self::Error>;
+    async fn save(
+        &self,
+        notification: &Notification,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<(), Self::Error>;
+}
@@ -0,0 +1,110 @@
+use chrono::{DateTime, Utc};
+use restinterface::{FixtureDTO, RefereeDTO};
+
+use super::aggregates::notification::NotificationKind;
+
+/// The values a template can refer to, each one is written as {name} in a template
+pub struct TemplateContext {
+    pub referee: String,
+    pub team_home: String,
+    pub team_away: String,
+    pub venue: String,
+    pub city: String,
+    pub date: DateTime<Utc>,
+}
+
+pub struct NotificationTemplate {
+    subject: &'static str,
+    body: &'static str,
+}
+
+impl TemplateContext {
+    pub fn new(referee: &RefereeDTO, fixture: &FixtureDTO) -> Self {
+        Self {
+            referee: referee.name.clone(),
+            team_home: fixture.team_home.name.clone(),
+            team_away: fixture.team_away.name.clone(),
+            venue: fixture.venue.name.clone(),
+            city: fixture.venue.city.clone(),
+            date: fixture.date,
+        }
+    }
+}
+
+impl NotificationTemplate {
+    pub fn for_kind(kind: NotificationKind) -> Self {
+        match kind {
+            NotificationKind::FirstRefereeAssigned => Self {
+                subject: "Assignment: {team_home} vs. {team_away} on {date}",
+                body: "Dear {referee},\n\nyou have been assigned as first referee to {team_home} vs. {team_away} on {date} at {venue}, {city}.\n",
+            },
+            NotificationKind::SecondRefereeAssigned => Self {
+                subject: "Assignment: {team_home} vs. {team_away} on {date}",
+                body: "Dear {referee},\n\nyou have been assigned as second referee to {team_home} vs. {team_away} on {date} at {venue}, {city}.\n",
+            },
+            NotificationKind::AssignmentRemoved => Self {
+                subject: "Assignment removed: {team_home} vs. {team_away} on {date}",
+                body: "Dear {referee},\n\nyour assignment to {team_home} vs. {team_away} on {date} at {venue}, {city} has been removed.\n",
+            },
+            NotificationKind::FixtureDateChanged => Self {
+                subject: "Date changed: {team_home} vs. {team_away}",
+                body: "Dear {referee},\n\n{team_home} vs. {team_away}, which you are assigned to, now takes place on {date} at {venue}, {city}.\n",
+            },
+            NotificationKind::FixtureVenueChanged => Self {
+                subject: "Venue changed: {team_home} vs. {team_away} on {date}",
+                body: "Dear {referee},\n\n{team_home} vs. {team_away} on {date}, which you are assigned to, now takes place at {venue}, {city}.\n",
+            },
+            NotificationKind::FixtureCancelled => Self {
+                subject: "Cancelled: {team_home} vs. {team_away} on {date}",
+                body: "Dear {referee},\n\n{team_home} vs. {team_away} on {date} at {venue}, {city}, which you are assigned to, has been cancelled.\n",
+            },
+        }
+    }
+
+    /// Renders subject and body of the template
+    pub fn render(&self, ctx: &TemplateContext) -> (String, String) {
+        (render_str(self.subject, ctx), render_str(self.body, ctx))
+    }
+}
+
+fn render_str(template: &str, ctx: &TemplateContext) -> String {
+    template
+        .replace("{referee}", &ctx.referee)
+        .replace("{team_home}", &ctx.team_home)
+        .replace("{team_away}", &ctx.team_away)
+        .replace("{venue}", &ctx.venue)
+        .replace("{city}", &ctx.city)
+        .replace("{date}", &ctx.date.format("%d.%m.%Y %H:%M UTC").to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::{NotificationTemplate, TemplateContext};
+    use crate::domain::aggregates::notification::NotificationKind;
+
+    #[test]
+    fn test_given_context_when_render_then_all_placeholders_replaced() {
+        let ctx = TemplateContext {
+            referee: "John Doe".to_string(),
+            team_home: "Team A".to_string(),
+            team_away: "Team B".to_string(),
+            venue: "Venue A".to_string(),
+            city: "City A".to_string(),
+            date: Utc.with_ymd_and_hms(2024, 10, 5, 18, 30, 0).unwrap(),
+        };
+
+        let (subject, body) =
+            NotificationTemplate::for_kind(NotificationKind::FirstRefereeAssigned).render(&ctx);
+
+        assert_eq!(
+            subject,
+            "Assignment: Team A vs. Team B on 05.10.2024 18:30 UTC"
+        );
+        assert_eq!(
+            body,
+            "Dear John Doe,\n\nyou have been assigned as first referee to Team A vs. Team B on 05.10.2024 18:30 UTC at Venue A, City A.\n"
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use axum::http::Method;
+use axum::{
+    routing::{get, put},
+    Router,
+};
+use notifications::adapters::smtp::smtp_mailer::SmtpMailer;
+use notifications::config::AppConfig;
+use notifications::ports::kafka::domain_events_handler::DomainEventHandlers;
+use notifications::ports::rest::notifications::{
+    fetch_notifications_for_referee_handler, set_notification_recipient_handler,
+};
+use notifications::ports::scheduler::run_notification_dispatcher;
+
+use microservices_shared::domain_events::DomainEventConsumer;
+use sqlx::PgPool;
+use std::sync::Arc;
+
+use notifications::AppState;
+use opentelemetry::{
+    trace::{Span, Tracer},
+    KeyValue,
+};
+
+#[tokio::main]
+async fn main() {
+    env_logger::init();
+
+    let config = AppConfig::new_from_env();
+
+    let tracer = microservices_shared::init_tracing(&config.otlp_endpoint, "notifications");
+    let mut span = tracer.start("application_start");
+    span.set_attribute(KeyValue::new("server_host", config.server_host.clone()));
+
+    let tracer_arc = Arc::new(tracer);
+
+    let connection_pool = PgPool::connect(&config.db_url).await.unwrap();
+    let redis_client = redis::Client::open(config.redis_url).unwrap();
+    let redis_conn = redis_client.get_connection().unwrap();
+
+    let domain_event_handlers =
+        DomainEventHandlers::new(redis_conn, tracer_arc.clone()).into_registry();
+    let mut domain_event_consumer = DomainEventConsumer::new(
+        &config.kafka_consumer_group,
+        &config.kafka_url,
+        &config.kafka_domain_events_topics,
+        connection_pool.clone(),
+        domain_event_handlers,
+    );
+
+    let mailer = SmtpMailer::new(&config.smtp_host, config.smtp_port, &config.smtp_sender);
+    let dispatcher_pool = connection_pool.clone();
+    let dispatcher_tracer = tracer_arc.clone();
+
+    let app_state = AppState {
+        tracer: tracer_arc,
+        connection_pool: connection_pool.clone(),
+        redis_client,
+    };
+    let state_arc = Arc::new(app_state);
+
+    let cors = tower_http::cors::CorsLayer::new()
+        .allow_methods([
+            Method::GET,
+            Method::POST,
+            Method::PUT,
+            Method::DELETE,
+            Method::OPTIONS,
+        ])
+        .allow_headers(tower_http::cors::Any)
+        .allow_origin(tower_http::cors::Any);
+
+    let app = Router::new()
+        .route(
+            "/notifications/referee/:referee_id",
+            get(fetch_notifications_for_referee_handler),
+        )
+        .route(
+            "/notifications/recipients/referee/:referee_id",
+            put(set_notification_recipient_handler),
+        )
+        .layer(cors)
+        .with_state(state_arc);
+
+    let listener = tokio::net::TcpListener::bind(&config.server_host)
+        .await
+        .unwrap();
+
+    tokio::spawn(async move {
+        domain_event_consumer.run().await;
+    });
+
+    tokio::spawn(async move {
+        run_notification_dispatcher(dispatcher_pool, mailer, dispatcher_tracer).await;
+    });
+
+    span.end();
+    axum::serve(listener, app).await.unwrap();
+}
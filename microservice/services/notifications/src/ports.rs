@@ -0,0 +1,3 @@
+pub mod kafka;
+pub mod rest;
+pub mod scheduler;
@@ -0,0 +1,280 @@
+use std::sync::Arc;
+
+use crate::{
+    adapters::db::{
+        notification_recipient_repo_pg::NotificationRecipientRepositoryPg,
+        notification_repo_pg::NotificationRepositoryPg,
+    },
+    application::notification_services::{notify_assignment_change, notify_fixture_change},
+    domain::aggregates::notification::NotificationKind,
+};
+use log::info;
+use microservices_shared::{
+    domain_events::DomainEventHandlerRegistry,
+    domain_ids::{CommitSagaId, FixtureId, RefereeId, VenueId},
+    resolvers::impls::{FixtureResolverImpl, RefereeResolverImpl},
+};
+use opentelemetry::global::BoxedTracer;
+use opentelemetry::{
+    trace::{Span, Tracer},
+    KeyValue,
+};
+use redis::Commands;
+use sqlx::types::chrono::{DateTime, Utc};
+use tokio::sync::Mutex;
+
+pub struct DomainEventHandlers {
+    redis_conn: Arc<Mutex<redis::Connection>>,
+    tracer: Arc<BoxedTracer>,
+}
+
+impl DomainEventHandlers {
+    pub fn new(redis_conn: redis::Connection, tracer: Arc<BoxedTracer>) -> Self {
+        Self {
+            // NOTE: the connection is shared with the resolvers, therefore it is wrapped in Arc<Mutex>
+            redis_conn: Arc::new(Mutex::new(redis_conn)),
+            tracer,
+        }
+    }
+
+    async fn notify_assignment_change(
+        &mut self,
+        fixture_id: FixtureId,
+        referee_id: RefereeId,
+        kind: NotificationKind,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        let mut span = self.tracer.start("notify_assignment_change");
+        span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
+        span.set_attribute(KeyValue::new("referee_id", referee_id.to_string()));
+
+        let fixture_resolver = FixtureResolverImpl::new(self.redis_conn.clone());
+        let referee_resolver = RefereeResolverImpl::new(self.redis_conn.clone());
+        let recipient_repo = NotificationRecipientRepositoryPg::new();
+        let notification_repo = NotificationRepositoryPg::new();
+
+        let notification = notify_assignment_change(
+            fixture_id,
+            referee_id,
+            kind,
+            &fixture_resolver,
+            &referee_resolver,
+            &recipient_repo,
+            &notification_repo,
+            tx_ctx,
+        )
+        .await?;
+
+        info!("Notification stored for delivery: {:?}", notification);
+
+        Ok(())
+    }
+
+    async fn notify_fixture_change(
+        &mut self,
+        fixture_id: FixtureId,
+        kind: NotificationKind,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        let mut span = self.tracer.start("notify_fixture_change");
+        span.set_attribute(KeyValue::new("fixture_id", fixture_id.to_string()));
+
+        invalidate_fixture_cache_entry(&self.redis_conn, fixture_id).await?;
+
+        let fixture_resolver = FixtureResolverImpl::new(self.redis_conn.clone());
+        let referee_resolver = RefereeResolverImpl::new(self.redis_conn.clone());
+        let recipient_repo = NotificationRecipientRepositoryPg::new();
+        let notification_repo = NotificationRepositoryPg::new();
+
+        let notifications = notify_fixture_change(
+            fixture_id,
+            kind,
+            &fixture_resolver,
+            &referee_resolver,
+            &recipient_repo,
+            &notification_repo,
+            tx_ctx,
+        )
+        .await?;
+
+        info!("Notifications stored for delivery: {:?}", notifications);
+
+        Ok(())
+    }
+
+    /// Registers the handlers for the domain events this service reacts to
+    pub fn into_registry(
+        self,
+    ) -> DomainEventHandlerRegistry<Self, sqlx::Transaction<'static, sqlx::Postgres>> {
+        let tracer = self.tracer.clone();
+        DomainEventHandlerRegistry::new(self, tracer)
+            .on_fixture_date_changed(|handlers, fixture_id, date, tx_ctx| {
+                Box::pin(handlers.on_fixture_date_changed(fixture_id, date, tx_ctx))
+            })
+            .on_fixture_venue_changed(|handlers, fixture_id, venue_id, tx_ctx| {
+                Box::pin(handlers.on_fixture_venue_changed(fixture_id, venue_id, tx_ctx))
+            })
+            .on_fixture_cancelled(|handlers, fixture_id, tx_ctx| {
+                Box::pin(handlers.on_fixture_cancelled(fixture_id, tx_ctx))
+            })
+            .on_first_referee_assignment_removed(|handlers, fixture_id, referee_id, tx_ctx| {
+                Box::pin(
+                    handlers.on_first_referee_assignment_removed(fixture_id, referee_id, tx_ctx),
+                )
+            })
+            .on_second_referee_assignment_removed(|handlers, fixture_id, referee_id, tx_ctx| {
+                Box::pin(
+                    handlers.on_second_referee_assignment_removed(fixture_id, referee_id, tx_ctx),
+                )
+            })
+            .on_first_referee_assigned(|handlers, _saga_id, fixture_id, referee_id, tx_ctx| {
+                Box::pin(
+                    handlers.on_first_referee_assigned(_saga_id, fixture_id, referee_id, tx_ctx),
+                )
+            })
+            .on_second_referee_assigned(|handlers, _saga_id, fixture_id, referee_id, tx_ctx| {
+                Box::pin(
+                    handlers.on_second_referee_assigned(_saga_id, fixture_id, referee_id, tx_ctx),
+                )
+            })
+    }
+
+    async fn on_fixture_date_changed(
+        &mut self,
+        fixture_id: FixtureId,
+        date: DateTime<Utc>,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Notifications: Fixture date changed: {:?} -> {}",
+            fixture_id, date
+        );
+
+        self.notify_fixture_change(fixture_id, NotificationKind::FixtureDateChanged, tx_ctx)
+            .await
+    }
+
+    async fn on_fixture_venue_changed(
+        &mut self,
+        fixture_id: FixtureId,
+        venue_id: VenueId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Notifications: Fixture venue changed: {:?} -> {:?}",
+            fixture_id, venue_id
+        );
+
+        self.notify_fixture_change(fixture_id, NotificationKind::FixtureVenueChanged, tx_ctx)
+            .await
+    }
+
+    async fn on_fixture_cancelled(
+        &mut self,
+        fixture_id: FixtureId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Notifications: Fixture cancelled: {:?}",
+            fixture_id
+        );
+
+        self.notify_fixture_change(fixture_id, NotificationKind::FixtureCancelled, tx_ctx)
+            .await
+    }
+
+    async fn on_first_referee_assignment_removed(
+        &mut self,
+        fixture_id: FixtureId,
+        referee_id: RefereeId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Notifications: First referee assignment removed: {:?} -> {:?}",
+            fixture_id, referee_id
+        );
+
+        self.notify_assignment_change(
+            fixture_id,
+            referee_id,
+            NotificationKind::AssignmentRemoved,
+            tx_ctx,
+        )
+        .await
+    }
+
+    async fn on_second_referee_assignment_removed(
+        &mut self,
+        fixture_id: FixtureId,
+        referee_id: RefereeId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Notifications: Second referee assignment removed: {:?} -> {:?}",
+            fixture_id, referee_id
+        );
+
+        self.notify_assignment_change(
+            fixture_id,
+            referee_id,
+            NotificationKind::AssignmentRemoved,
+            tx_ctx,
+        )
+        .await
+    }
+
+    async fn on_first_referee_assigned(
+        &mut self,
+        _saga_id: CommitSagaId,
+        fixture_id: FixtureId,
+        referee_id: RefereeId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Notifications: First referee assigned: {:?} -> {:?}",
+            fixture_id, referee_id
+        );
+
+        self.notify_assignment_change(
+            fixture_id,
+            referee_id,
+            NotificationKind::FirstRefereeAssigned,
+            tx_ctx,
+        )
+        .await
+    }
+
+    async fn on_second_referee_assigned(
+        &mut self,
+        _saga_id: CommitSagaId,
+        fixture_id: FixtureId,
+        referee_id: RefereeId,
+        tx_ctx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        info!(
+            "Received Domain Event in Notifications: Second referee assigned: {:?} -> {:?}",
+            fixture_id, referee_id
+        );
+
+        self.notify_assignment_change(
+            fixture_id,
+            referee_id,
+            NotificationKind::SecondRefereeAssigned,
+            tx_ctx,
+        )
+        .await
+    }
+}
+
+async fn invalidate_fixture_cache_entry(
+    redis_conn: &Arc<Mutex<redis::Connection>>,
+    fixture_id: FixtureId,
+) -> Result<(), String> {
+    info!("Invalidating cache entry for fixture: {:?}", fixture_id);
+
+    // NOTE: the event might arrive here before the fixtures service has invalidated the entry
+    let key = format!("fixture_{}", fixture_id.0);
+    let _result: Result<(), redis::RedisError> = redis_conn.lock().await.del(key);
+
+    _result.map_err(|e| e.to_string())
+}
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::Json;
+use log::{error, info};
+use microservices_shared::resolvers::impls::RefereeResolverImpl;
+use opentelemetry::{
+    trace::{Span, Tracer},
+    KeyValue,
+};
+use restinterface::{
+    NotificationDTO, NotificationKindDTO, NotificationRecipientDTO, NotificationStatusDTO,
+    RefereeIdDTO,
+};
+use shared::app_error::AppError;
+use tokio::sync::Mutex;
+
+use crate::adapters::db::notification_recipient_repo_pg::NotificationRecipientRepositoryPg;
+use crate::adapters::db::notification_repo_pg::NotificationRepositoryPg;
+use crate::application::notification_recipient_services::set_notification_recipient;
+use crate::application::notification_services::get_notifications_for_referee;
+use crate::domain::aggregates::notification::{DeliveryStatus, Notification, NotificationKind};
+use crate::domain::aggregates::notification_recipient::NotificationRecipient;
+use crate::AppState;
+
+impl From<NotificationKind> for NotificationKindDTO {
+    fn from(kind: NotificationKind) -> Self {
+        match kind {
+            NotificationKind::FirstRefereeAssigned => NotificationKindDTO::FirstRefereeAssigned,
+            NotificationKind::SecondRefereeAssigned => NotificationKindDTO::SecondRefereeAssigned,
+            NotificationKind::AssignmentRemoved => NotificationKindDTO::AssignmentRemoved,
+            NotificationKind::FixtureDateChanged => NotificationKindDTO::FixtureDateChanged,
+            NotificationKind::FixtureVenueChanged => NotificationKindDTO::FixtureVenueChanged,
+            NotificationKind::FixtureCancelled => NotificationKindDTO::FixtureCancelled,
+        }
+    }
+}
+
+impl From<DeliveryStatus> for NotificationStatusDTO {
+    fn from(status: DeliveryStatus) -> Self {
+        match status {
+            DeliveryStatus::Pending => NotificationStatusDTO::Pending,
+            DeliveryStatus::Sent => NotificationStatusDTO::Sent,
+            DeliveryStatus::Failed => NotificationStatusDTO::Failed,
+        }
+    }
+}
+
+impl From<Notification> for NotificationDTO {
+    fn from(notification: Notification) -> Self {
+        NotificationDTO {
+            id: notification.id().into(),
+            referee_id: notification.referee_id().into(),
+            fixture_id: notification.fixture_id().into(),
+            kind: notification.kind().into(),
+            recipient: notification.recipient().to_string(),
+            subject: notification.subject().to_string(),
+            body: notification.body().to_string(),
+            status: notification.status().into(),
+            attempts: notification.attempts(),
+            last_error: notification.last_error().map(|e| e.to_string()),
+            created_at: *notification.created_at(),
+            sent_at: notification.sent_at().copied(),
+        }
+    }
+}
+
+impl From<NotificationRecipient> for NotificationRecipientDTO {
+    fn from(recipient: NotificationRecipient) -> Self {
+        NotificationRecipientDTO {
+            referee_id: recipient.referee_id().into(),
+            email: recipient.email().to_string(),
+        }
+    }
+}
+
+pub async fn fetch_notifications_for_referee_handler(
+    State(state): State<Arc<AppState>>,
+    Path(referee_id): Path<RefereeIdDTO>,
+) -> Result<Json<Vec<NotificationDTO>>, AppError> {
+    info!("Fetching notifications for referee: {:?}", referee_id);
+    let mut span = state.tracer.start("fetch_notifications_for_referee");
+    span.set_attribute(KeyValue::new("referee_id", referee_id.to_string()));
+
+    let mut tx = state.connection_pool.begin().await.map_err(|e| {
+        error!("Error beginning transaction: {:?}", e);
+        AppError::from_error(&e.to_string())
+    })?;
+
+    let notification_repo = NotificationRepositoryPg::new();
+
+    let notifications =
+        get_notifications_for_referee(referee_id.into(), &notification_repo, &mut tx)
+            .await
+            .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(notifications.into_iter().map(|n| n.into()).collect()))
+}
+
+pub async fn set_notification_recipient_handler(
+    State(state): State<Arc<AppState>>,
+    Path(referee_id): Path<RefereeIdDTO>,
+    Json(email): Json<String>,
+) -> Result<Json<NotificationRecipientDTO>, AppError> {
+    info!(
+        "Setting notification recipient for referee: {:?} to {}",
+        referee_id, email
+    );
+    let mut span = state.tracer.start("set_notification_recipient");
+    span.set_attribute(KeyValue::new("referee_id", referee_id.to_string()));
+
+    let mut tx = state.connection_pool.begin().await.map_err(|e| {
+        error!("Error beginning transaction: {:?}", e);
+        AppError::from_error(&e.to_string())
+    })?;
+
+    let redis_conn = state
+        .redis_client
+        .get_connection()
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+    let redis_conn_arc_mutex = Arc::new(Mutex::new(redis_conn));
+
+    let referee_resolver = RefereeResolverImpl::new(redis_conn_arc_mutex.clone());
+    let recipient_repo = NotificationRecipientRepositoryPg::new();
+
+    let recipient = set_notification_recipient(
+        referee_id.into(),
+        email,
+        &referee_resolver,
+        &recipient_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(recipient.into()))
+}
@@ -0,0 +1,64 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::Utc;
+use log::{info, warn};
+use opentelemetry::{
+    global::BoxedTracer,
+    trace::{Span, Tracer},
+    KeyValue,
+};
+use sqlx::PgPool;
+
+use crate::{
+    adapters::{db::notification_repo_pg::NotificationRepositoryPg, smtp::smtp_mailer::SmtpMailer},
+    application::notification_services::deliver_due_notifications,
+};
+
+const NOTIFICATION_DELIVERY_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Periodically delivers the stored notifications which are due. Each run
+/// happens within a single transaction, which records the delivery status.
+pub async fn run_notification_dispatcher(
+    connection_pool: PgPool,
+    mailer: SmtpMailer,
+    tracer: Arc<BoxedTracer>,
+) {
+    let mut interval = tokio::time::interval(NOTIFICATION_DELIVERY_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        let mut span = tracer.start("deliver_due_notifications");
+
+        let mut tx = match connection_pool.begin().await {
+            Ok(tx) => tx,
+            Err(e) => {
+                warn!(
+                    "Error beginning transaction for delivering notifications: {}",
+                    e
+                );
+                continue;
+            }
+        };
+
+        let notification_repo = NotificationRepositoryPg::new();
+
+        let result =
+            deliver_due_notifications(Utc::now(), &mailer, &notification_repo, &mut tx).await;
+
+        match result {
+            Ok(delivered) => {
+                if let Err(e) = tx.commit().await {
+                    warn!("Error committing delivered notifications: {}", e);
+                    continue;
+                }
+
+                if !delivered.is_empty() {
+                    info!("Notifications delivered: {:?}", delivered);
+                }
+                span.set_attribute(KeyValue::new("delivered", delivered.len() as i64));
+            }
+            Err(e) => warn!("Error delivering notifications: {}", e),
+        }
+    }
+}
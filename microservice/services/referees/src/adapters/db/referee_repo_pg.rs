@@ -1,4 +1,4 @@
-use microservices_shared::domain_ids::RefereeId;
+use microservices_shared::{domain_ids::RefereeId, geo_location::GeoLocation};
 use sqlx::{FromRow, Postgres, Transaction};
 use uuid::Uuid;
 
@@ -11,11 +11,22 @@ struct RefereeDb {
     pub id: Uuid,
     pub name: String,
     pub club: String,
+    pub qualification_level: i32,
+    pub home_latitude: Option<f64>,
+    pub home_longitude: Option<f64>,
+    pub archived: bool,
 }
 
 impl From<RefereeDb> for Referee {
     fn from(referee: RefereeDb) -> Self {
-        Referee::from_id(referee.id, referee.name, referee.club)
+        Referee::from_id(
+            referee.id,
+            referee.name,
+            referee.club,
+            referee.qualification_level,
+            GeoLocation::from_coordinates(referee.home_latitude, referee.home_longitude),
+            referee.archived,
+        )
     }
 }
 
@@ -36,7 +47,7 @@ impl RefereeRepository for RefereeRepositoryPg {
     ) -> Result<Option<Referee>, Self::Error> {
         let referee: Option<RefereeDb> = sqlx::query_as!(
             RefereeDb,
-            "SELECT referee_id as id, name, club 
+            "SELECT referee_id as id, name, club, qualification_level, home_latitude, home_longitude, archived 
             FROM rustddd.referees 
             WHERE referee_id = $1",
             referee_id.0
@@ -45,13 +56,13 @@ impl RefereeRepository for RefereeRepositoryPg {
         .await
         .map_err(|e| e.to_string())?;
 
-        Ok(referee.map(|r| Referee::from_id(r.id, r.name, r.club)))
+        Ok(referee.map(Referee::from))
     }
 
     async fn get_all(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<Referee>, Self::Error> {
         let referees: Vec<RefereeDb> = sqlx::query_as!(
             RefereeDb,
-            "SELECT referee_id as id, name, club 
+            "SELECT referee_id as id, name, club, qualification_level, home_latitude, home_longitude, archived 
             FROM rustddd.referees
             ORDER BY name ASC"
         )
@@ -59,21 +70,22 @@ impl RefereeRepository for RefereeRepositoryPg {
         .await
         .map_err(|e| e.to_string())?;
 
-        Ok(referees
-            .into_iter()
-            .map(|r| Referee::from_id(r.id, r.name, r.club))
-            .collect())
+        Ok(referees.into_iter().map(Referee::from).collect())
     }
 
     async fn save(&self, referee: &Referee, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error> {
-        // NOTE: we do an upsert, which is only updating the club field, because only this one is allowed to change
+        // NOTE: we do an upsert, which is only updating the club, home and archived fields, because only these are allowed to change
         let _result = sqlx::query!(
-            "INSERT INTO rustddd.referees (referee_id, name, club) 
-            VALUES ($1, $2, $3)
-            ON CONFLICT (referee_id) DO UPDATE SET club = $3",
+            "INSERT INTO rustddd.referees (referee_id, name, club, qualification_level, home_latitude, home_longitude, archived) 
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (referee_id) DO UPDATE SET club = $3, home_latitude = $5, home_longitude = $6, archived = $7",
             referee.id().0,
             referee.name(),
             referee.club(),
+            referee.qualification_level(),
+            referee.home().map(|h| h.latitude()),
+            referee.home().map(|h| h.longitude()),
+            referee.is_archived(),
         )
         .execute(&mut **tx_ctx)
         .await
@@ -1,17 +1,27 @@
 use crate::domain::{aggregates::referee::Referee, repositories::referee_repo::RefereeRepository};
+use chrono::Utc;
 use microservices_shared::{
-    domain_event_repo::DomainEventOutboxRepository, domain_events::DomainEvent,
-    domain_ids::RefereeId,
+    domain_event_repo::DomainEventOutboxRepository,
+    domain_events::DomainEvent,
+    domain_ids::{FixtureId, RefereeId},
+    geo_location::GeoLocation,
+    resolvers::traits::{AssignmentResolver, FixtureResolver},
 };
+use restinterface::AssignmentStatusDTO;
 
 pub async fn create_referee<TxCtx>(
     name: &str,
     club: &str,
+    qualification_level: i32,
     repo: &impl RefereeRepository<TxCtx = TxCtx, Error = String>,
     domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
     tx_ctx: &mut TxCtx,
 ) -> Result<Referee, String> {
-    let referee = Referee::new(name, club);
+    if qualification_level < 0 {
+        return Err("Qualification level cannot be negative".to_string());
+    }
+
+    let referee = Referee::new(name, club, qualification_level);
 
     repo.save(&referee, tx_ctx)
         .await
@@ -62,15 +72,96 @@ pub async fn update_referee_club<TxCtx>(
     Ok(())
 }
 
+pub async fn update_referee_home<TxCtx>(
+    referee_id: RefereeId,
+    home: GeoLocation,
+    repo: &impl RefereeRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Referee, String> {
+    let referee = repo.find_by_id(referee_id, tx_ctx).await?;
+    let mut referee = referee.ok_or("Referee not found")?;
+    referee.change_home(home);
+
+    repo.save(&referee, tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    domain_event_repo
+        .store(
+            DomainEvent::RefereeHomeChanged {
+                referee_id: referee.id().clone(),
+            },
+            tx_ctx,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(referee)
+}
+
+/// Archives a referee, which is only possible as long as they are not committed to any upcoming fixture.
+/// Their staged assignments and availabilities are removed by the Assignments and Availabilities services
+/// upon the RefereeArchived domain event.
+pub async fn archive_referee<TxCtx>(
+    referee_id: RefereeId,
+    repo: &impl RefereeRepository<TxCtx = TxCtx, Error = String>,
+    assignment_resolver: &impl AssignmentResolver<Error = String>,
+    fixture_resolver: &impl FixtureResolver<Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Referee, String> {
+    let referee = repo.find_by_id(referee_id, tx_ctx).await?;
+    let mut referee = referee.ok_or("Referee not found")?;
+
+    if referee.is_archived() {
+        return Err("Referee is already archived".to_string());
+    }
+
+    let now = Utc::now();
+    let assignments = assignment_resolver.resolve(&referee_id).await?;
+    for assignment in assignments.iter().filter(|a| {
+        a.status == AssignmentStatusDTO::Committed || a.status == AssignmentStatusDTO::Confirmed
+    }) {
+        let fixture_id = FixtureId::from(assignment.fixture_id);
+        let fixture = fixture_resolver.resolve(&fixture_id).await?;
+        if fixture.is_upcoming(&now) {
+            return Err("Referee is still committed to upcoming fixtures".to_string());
+        }
+    }
+
+    referee.archive();
+
+    repo.save(&referee, tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    domain_event_repo
+        .store(DomainEvent::RefereeArchived { referee_id }, tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(referee)
+}
+
 #[cfg(test)]
 mod tests {
     use microservices_shared::{
-        domain_event_repo::DomainEventOutboxRepository, domain_events::DomainEvent,
-        domain_ids::RefereeId,
+        domain_event_repo::DomainEventOutboxRepository,
+        domain_events::DomainEvent,
+        domain_ids::{FixtureId, RefereeId},
+        geo_location::GeoLocation,
+        resolvers::traits::{AssignmentResolver, FixtureResolver},
     };
+    use restinterface::{
+        AssignmentDTO, AssignmentRefereeRoleDTO, AssignmentStatusDTO, FixtureDTO, FixtureIdDTO,
+    };
+    use uuid::Uuid;
 
     use crate::{
-        application::referee_services::{create_referee, update_referee_club},
+        application::referee_services::{
+            archive_referee, create_referee, update_referee_club, update_referee_home,
+        },
         domain::{aggregates::referee::Referee, repositories::referee_repo::RefereeRepository},
     };
     use std::cell::RefCell;
@@ -78,6 +169,13 @@ mod tests {
 
     struct TestDomainEventRepository {}
 
+    struct TestAssignmentResolver {
+        assignments: Vec<AssignmentDTO>,
+    }
+
+    // NOTE: only resolves fixtures of committed assignments, therefore fails if any is looked up
+    struct TestFixtureResolver {}
+
     struct TestRepo {
         // NOTE: we use a RefCell to allow for interior mutability because RefereeRepository trait does not pass by mutable reference
         data: RefCell<HashMap<RefereeId, Referee>>,
@@ -104,6 +202,27 @@ mod tests {
         }
     }
 
+    impl AssignmentResolver for TestAssignmentResolver {
+        type Error = String;
+
+        async fn resolve(&self, referee_id: &RefereeId) -> Result<Vec<AssignmentDTO>, Self::Error> {
+            Ok(self
+                .assignments
+                .iter()
+                .filter(|a| RefereeId::from(a.referee_id) == *referee_id)
+                .cloned()
+                .collect())
+        }
+    }
+
+    impl FixtureResolver for TestFixtureResolver {
+        type Error = String;
+
+        async fn resolve(&self, _fixture_id: &FixtureId) -> Result<FixtureDTO, Self::Error> {
+            Err("Fixture not found".to_string())
+        }
+    }
+
     impl RefereeRepository for TestRepo {
         type Error = String;
         type TxCtx = ();
@@ -136,11 +255,12 @@ mod tests {
         let repo = TestRepo::new();
         let domain_event_repo = TestDomainEventRepository {};
 
-        let referee = create_referee("John Doe", "Club A", &repo, &domain_event_repo, &mut ())
+        let referee = create_referee("John Doe", "Club A", 2, &repo, &domain_event_repo, &mut ())
             .await
             .unwrap();
         assert_eq!(referee.club(), "Club A");
         assert_eq!(referee.name(), "John Doe");
+        assert_eq!(referee.qualification_level(), 2);
 
         let all_referees = repo.get_all(&mut ()).await.unwrap();
         assert_eq!(all_referees.len(), 1);
@@ -153,7 +273,7 @@ mod tests {
         let repo = TestRepo::new();
         let domain_event_repo = TestDomainEventRepository {};
 
-        let referee = create_referee("John Doe", "Club A", &repo, &domain_event_repo, &mut ())
+        let referee = create_referee("John Doe", "Club A", 2, &repo, &domain_event_repo, &mut ())
             .await
             .unwrap();
         assert_eq!(referee.club(), "Club A");
@@ -174,4 +294,76 @@ mod tests {
         assert_eq!(all_referees[0].club(), "Club B");
         assert_eq!(all_referees[0].name(), "John Doe");
     }
+
+    #[tokio::test]
+    async fn test_update_referee_home() {
+        let repo = TestRepo::new();
+        let domain_event_repo = TestDomainEventRepository {};
+
+        let referee = create_referee("John Doe", "Club A", 2, &repo, &domain_event_repo, &mut ())
+            .await
+            .unwrap();
+        assert_eq!(referee.home(), None);
+
+        let home = GeoLocation::new(48.2082, 16.3738).unwrap();
+        let referee = update_referee_home(referee.id(), home, &repo, &domain_event_repo, &mut ())
+            .await
+            .unwrap();
+        assert_eq!(referee.home(), Some(home));
+
+        let all_referees = repo.get_all(&mut ()).await.unwrap();
+        assert_eq!(all_referees[0].home(), Some(home));
+    }
+
+    #[tokio::test]
+    async fn test_archive_referee() {
+        let repo = TestRepo::new();
+        let domain_event_repo = TestDomainEventRepository {};
+
+        let referee = create_referee("John Doe", "Club A", 2, &repo, &domain_event_repo, &mut ())
+            .await
+            .unwrap();
+        assert!(!referee.is_archived());
+
+        // NOTE: a staged assignment does not prevent archiving
+        let assignment_resolver = TestAssignmentResolver {
+            assignments: vec![AssignmentDTO {
+                status: AssignmentStatusDTO::Staged,
+                referee_role: AssignmentRefereeRoleDTO::First,
+                fixture_id: FixtureIdDTO(Uuid::new_v4()),
+                referee_id: referee.id().into(),
+                review_reason: None,
+            }],
+        };
+        let fixture_resolver = TestFixtureResolver {};
+
+        let referee = archive_referee(
+            referee.id(),
+            &repo,
+            &assignment_resolver,
+            &fixture_resolver,
+            &domain_event_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+        assert!(referee.is_archived());
+
+        let all_referees = repo.get_all(&mut ()).await.unwrap();
+        assert!(all_referees[0].is_archived());
+
+        let result = archive_referee(
+            referee.id(),
+            &repo,
+            &assignment_resolver,
+            &fixture_resolver,
+            &domain_event_repo,
+            &mut (),
+        )
+        .await;
+        assert_eq!(
+            result.err(),
+            Some("Referee is already archived".to_string())
+        );
+    }
 }
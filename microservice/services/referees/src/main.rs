@@ -10,10 +10,10 @@ use opentelemetry::{
     KeyValue,
 };
 use referees::config::AppConfig;
-use referees::ports::kafka::domain_events_handler::DomainEventCallbacksImpl;
+use referees::ports::kafka::domain_events_handler::DomainEventHandlers;
 use referees::ports::rest::referee::{
-    create_referee_handler, get_all_referees_handler, get_referee_by_id_handler,
-    update_referee_club_handler,
+    archive_referee_handler, create_referee_handler, get_all_referees_handler,
+    get_referee_by_id_handler, update_referee_club_handler, update_referee_home_handler,
 };
 use referees::AppState;
 use sqlx::PgPool;
@@ -35,16 +35,14 @@ async fn main() {
     let redis_client = redis::Client::open(config.redis_url).unwrap();
 
     let redis_conn = redis_client.get_connection().unwrap();
-    let domain_event_callbacks = Box::new(DomainEventCallbacksImpl::new(
-        redis_conn,
-        tracer_arc.clone(),
-    ));
+    let domain_event_handlers =
+        DomainEventHandlers::new(redis_conn, tracer_arc.clone()).into_registry();
     let mut domain_event_consumer = DomainEventConsumer::new(
         &config.kafka_consumer_group,
         &config.kafka_url,
         &config.kafka_domain_events_topics,
         connection_pool.clone(),
-        domain_event_callbacks,
+        domain_event_handlers,
     );
 
     let app_state = AppState {
@@ -70,6 +68,8 @@ async fn main() {
         .route("/referees/:id", get(get_referee_by_id_handler))
         .route("/referees/all", get(get_all_referees_handler))
         .route("/referees/:id/club", post(update_referee_club_handler))
+        .route("/referees/:id/home", post(update_referee_home_handler))
+        .route("/referees/:id/archive", post(archive_referee_handler))
         .layer(cors)
         .with_state(state_arc);
 
@@ -11,6 +11,15 @@ CREATE TABLE rustddd.domain_events_inbox (
     created_at TIMESTAMP WITH TIME ZONE NOT NULL
 );
 
+CREATE TABLE rustddd.domain_events_dead_letter (
+    id UUID NOT NULL PRIMARY KEY,
+    payload TEXT NOT NULL,
+    error VARCHAR NOT NULL,
+    attempts INTEGER NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    redrive_requested_at TIMESTAMP WITH TIME ZONE
+);
+
 CREATE TYPE rustddd.domain_event_type AS ENUM ('Inbox', 'Outbox');
 
 CREATE TABLE rustddd.domain_events (
@@ -0,0 +1,69 @@
+//! Inspects and re-drives the dead-lettered domain events of a service. Connects to the database
+//! given by DB_URL, therefore source the config.sh of the service first.
+//!
+//! Re-driving only requests it, the domain event consumer of the running service picks the
+//! dead letters up and processes them again.
+
+use microservices_shared::domain_event_repo::DomainEventRepositoryPg;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+const USAGE: &str = "Usage: dead_letters list | redrive <id> | redrive-all";
+
+#[tokio::main]
+async fn main() {
+    let db_url =
+        std::env::var("DB_URL").unwrap_or_else(|err| panic!("Cannot find DB_URL in env: {}", err));
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+
+    let connection_pool = PgPool::connect(&db_url).await.unwrap();
+    let mut tx = connection_pool.begin().await.unwrap();
+    let domain_event_repo = DomainEventRepositoryPg::new();
+
+    match args.as_slice() {
+        ["list"] => {
+            let dead_letters = domain_event_repo.get_dead_letters(&mut tx).await.unwrap();
+            for dead_letter in dead_letters.iter() {
+                println!(
+                    "{} | dead-lettered at {} | {} attempts | redrive requested at {}",
+                    dead_letter.id,
+                    dead_letter.created_at,
+                    dead_letter.attempts,
+                    dead_letter
+                        .redrive_requested_at
+                        .map_or("-".to_string(), |r| r.to_string())
+                );
+                println!("  error: {}", dead_letter.error);
+                println!("  payload: {}", dead_letter.payload);
+            }
+            println!("{} dead letters", dead_letters.len());
+        }
+        ["redrive", id] => {
+            let dead_letter_id =
+                Uuid::parse_str(id).unwrap_or_else(|e| panic!("Invalid dead letter id: {}", e));
+            let found = domain_event_repo
+                .request_dead_letter_redrive(dead_letter_id, &mut tx)
+                .await
+                .unwrap();
+            if found {
+                println!("Requested redrive of dead letter {}", dead_letter_id);
+            } else {
+                println!("Dead letter {} not found", dead_letter_id);
+            }
+        }
+        ["redrive-all"] => {
+            let count = domain_event_repo
+                .request_all_dead_letters_redrive(&mut tx)
+                .await
+                .unwrap();
+            println!("Requested redrive of {} dead letters", count);
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            std::process::exit(1);
+        }
+    }
+
+    tx.commit().await.unwrap();
+}
@@ -19,6 +19,17 @@ pub struct DomainEventInboxDb {
     pub created_at: DateTime<Utc>,
 }
 
+/// An incoming message which could not be processed, the payload is the raw Kafka message
+#[derive(Clone, Debug, sqlx::FromRow, PartialEq, Eq)]
+pub struct DomainEventDeadLetterDb {
+    pub id: Uuid,
+    pub payload: String,
+    pub error: String,
+    pub attempts: i32,
+    pub created_at: DateTime<Utc>,
+    pub redrive_requested_at: Option<DateTime<Utc>>,
+}
+
 #[allow(async_fn_in_trait)]
 #[automock(type Error = String; type TxCtx = ();)]
 pub trait DomainEventOutboxRepository {
@@ -97,6 +108,148 @@ impl DomainEventRepositoryPg {
 
         Ok(domain_event_db)
     }
+
+    pub async fn store_as_dead_letter(
+        &self,
+        payload: &str,
+        error: &str,
+        attempts: i32,
+        tx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<DomainEventDeadLetterDb, String> {
+        let dead_letter_db = DomainEventDeadLetterDb {
+            id: Uuid::new_v4(),
+            payload: payload.to_string(),
+            error: error.to_string(),
+            attempts,
+            created_at: Utc::now(),
+            redrive_requested_at: None,
+        };
+
+        sqlx::query!(
+            "INSERT INTO rustddd.domain_events_dead_letter (id, payload, error, attempts, created_at)
+            VALUES ($1, $2, $3, $4, $5)",
+            dead_letter_db.id,
+            dead_letter_db.payload,
+            dead_letter_db.error,
+            dead_letter_db.attempts,
+            dead_letter_db.created_at
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(dead_letter_db)
+    }
+
+    pub async fn get_dead_letters(
+        &self,
+        tx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<Vec<DomainEventDeadLetterDb>, String> {
+        sqlx::query_as!(
+            DomainEventDeadLetterDb,
+            "SELECT id, payload, error, attempts, created_at, redrive_requested_at
+            FROM rustddd.domain_events_dead_letter
+            ORDER BY created_at"
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    /// Claims the dead letters which were requested to be re-driven by resetting their request,
+    /// so that each of them is re-driven by a single consumer only
+    pub async fn claim_dead_letters_to_redrive(
+        &self,
+        tx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<Vec<DomainEventDeadLetterDb>, String> {
+        // NOTE: multiple instances re-drive concurrently, locking the rows ensures each dead letter is claimed by one of them only
+        sqlx::query_as!(
+            DomainEventDeadLetterDb,
+            "UPDATE rustddd.domain_events_dead_letter
+            SET redrive_requested_at = NULL
+            WHERE id IN (
+                SELECT id FROM rustddd.domain_events_dead_letter
+                WHERE redrive_requested_at IS NOT NULL
+                ORDER BY created_at
+                FOR UPDATE SKIP LOCKED)
+            RETURNING id, payload, error, attempts, created_at, redrive_requested_at"
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    /// Returns false if there is no dead letter with the given id
+    pub async fn request_dead_letter_redrive(
+        &self,
+        dead_letter_id: Uuid,
+        tx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<bool, String> {
+        let result = sqlx::query!(
+            "UPDATE rustddd.domain_events_dead_letter SET redrive_requested_at = $1 WHERE id = $2",
+            Utc::now(),
+            dead_letter_id
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Returns the number of dead letters which are re-driven
+    pub async fn request_all_dead_letters_redrive(
+        &self,
+        tx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<u64, String> {
+        let result = sqlx::query!(
+            "UPDATE rustddd.domain_events_dead_letter SET redrive_requested_at = $1",
+            Utc::now()
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn update_dead_letter_failure(
+        &self,
+        dead_letter_id: Uuid,
+        error: &str,
+        attempts: i32,
+        tx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        sqlx::query!(
+            "UPDATE rustddd.domain_events_dead_letter
+            SET error = $1, attempts = attempts + $2, redrive_requested_at = NULL
+            WHERE id = $3",
+            error,
+            attempts,
+            dead_letter_id
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    pub async fn remove_dead_letter(
+        &self,
+        dead_letter_id: Uuid,
+        tx: &mut sqlx::Transaction<'static, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        sqlx::query!(
+            "DELETE FROM rustddd.domain_events_dead_letter WHERE id = $1",
+            dead_letter_id
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
 }
 
 impl DomainEventOutboxRepository for DomainEventRepositoryPg {
@@ -1,6 +1,9 @@
 use std::str::FromStr;
 
-use restinterface::{FixtureIdDTO, RefereeIdDTO, TeamIdDTO, VenueIdDTO};
+use restinterface::{
+    AvailabilityLockIdDTO, BlackoutIdDTO, CommitSagaIdDTO, FixtureIdDTO, LeagueIdDTO,
+    NotificationIdDTO, RefereeIdDTO, SeasonIdDTO, TeamIdDTO, VenueIdDTO,
+};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -16,6 +19,24 @@ pub struct TeamId(pub Uuid);
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Serialize, Deserialize)]
 pub struct VenueId(pub Uuid);
 
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Serialize, Deserialize)]
+pub struct LeagueId(pub Uuid);
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Serialize, Deserialize)]
+pub struct SeasonId(pub Uuid);
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Serialize, Deserialize)]
+pub struct AvailabilityLockId(pub Uuid);
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Serialize, Deserialize)]
+pub struct BlackoutId(pub Uuid);
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Serialize, Deserialize)]
+pub struct NotificationId(pub Uuid);
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Serialize, Deserialize)]
+pub struct CommitSagaId(pub Uuid);
+
 impl ToString for FixtureId {
     fn to_string(&self) -> String {
         self.0.to_string()
@@ -40,6 +61,42 @@ impl ToString for VenueId {
     }
 }
 
+impl ToString for LeagueId {
+    fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl ToString for SeasonId {
+    fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl ToString for AvailabilityLockId {
+    fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl ToString for BlackoutId {
+    fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl ToString for NotificationId {
+    fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl ToString for CommitSagaId {
+    fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
 impl TryFrom<String> for FixtureId {
     type Error = String;
 
@@ -104,6 +161,102 @@ impl From<Uuid> for VenueId {
     }
 }
 
+impl TryFrom<String> for LeagueId {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Uuid::from_str(&value)
+            .map_err(|e| e.to_string())
+            .map(LeagueId)
+    }
+}
+
+impl From<Uuid> for LeagueId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl TryFrom<String> for SeasonId {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Uuid::from_str(&value)
+            .map_err(|e| e.to_string())
+            .map(SeasonId)
+    }
+}
+
+impl TryFrom<String> for AvailabilityLockId {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Uuid::from_str(&value)
+            .map_err(|e| e.to_string())
+            .map(AvailabilityLockId)
+    }
+}
+
+impl TryFrom<String> for BlackoutId {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Uuid::from_str(&value)
+            .map_err(|e| e.to_string())
+            .map(BlackoutId)
+    }
+}
+
+impl TryFrom<String> for NotificationId {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Uuid::from_str(&value)
+            .map_err(|e| e.to_string())
+            .map(NotificationId)
+    }
+}
+
+impl TryFrom<String> for CommitSagaId {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Uuid::from_str(&value)
+            .map_err(|e| e.to_string())
+            .map(CommitSagaId)
+    }
+}
+
+impl From<Uuid> for SeasonId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Uuid> for AvailabilityLockId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Uuid> for BlackoutId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Uuid> for NotificationId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Uuid> for CommitSagaId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
 impl From<VenueIdDTO> for VenueId {
     fn from(value: VenueIdDTO) -> Self {
         Self(value.0)
@@ -151,3 +304,75 @@ impl From<RefereeId> for RefereeIdDTO {
         RefereeIdDTO(id.0)
     }
 }
+
+impl From<LeagueIdDTO> for LeagueId {
+    fn from(value: LeagueIdDTO) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<LeagueId> for LeagueIdDTO {
+    fn from(id: LeagueId) -> Self {
+        LeagueIdDTO(id.0)
+    }
+}
+
+impl From<SeasonIdDTO> for SeasonId {
+    fn from(value: SeasonIdDTO) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<SeasonId> for SeasonIdDTO {
+    fn from(id: SeasonId) -> Self {
+        SeasonIdDTO(id.0)
+    }
+}
+
+impl From<AvailabilityLockIdDTO> for AvailabilityLockId {
+    fn from(value: AvailabilityLockIdDTO) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<BlackoutIdDTO> for BlackoutId {
+    fn from(value: BlackoutIdDTO) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<NotificationIdDTO> for NotificationId {
+    fn from(value: NotificationIdDTO) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<CommitSagaIdDTO> for CommitSagaId {
+    fn from(value: CommitSagaIdDTO) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<AvailabilityLockId> for AvailabilityLockIdDTO {
+    fn from(id: AvailabilityLockId) -> Self {
+        AvailabilityLockIdDTO(id.0)
+    }
+}
+
+impl From<BlackoutId> for BlackoutIdDTO {
+    fn from(id: BlackoutId) -> Self {
+        BlackoutIdDTO(id.0)
+    }
+}
+
+impl From<NotificationId> for NotificationIdDTO {
+    fn from(id: NotificationId) -> Self {
+        NotificationIdDTO(id.0)
+    }
+}
+
+impl From<CommitSagaId> for CommitSagaIdDTO {
+    fn from(id: CommitSagaId) -> Self {
+        CommitSagaIdDTO(id.0)
+    }
+}
@@ -0,0 +1,73 @@
+use restinterface::GeoLocationDTO;
+
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// A position on earth in decimal degrees, e.g. where a venue is located or where a referee lives
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoLocation {
+    latitude: f64,
+    longitude: f64,
+}
+
+impl GeoLocation {
+    pub fn new(latitude: f64, longitude: f64) -> Result<Self, String> {
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(format!("Latitude {} is out of range", latitude));
+        }
+
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(format!("Longitude {} is out of range", longitude));
+        }
+
+        Ok(Self {
+            latitude,
+            longitude,
+        })
+    }
+
+    /// Restores a location from optionally stored coordinates, which are only present if both are set
+    pub fn from_coordinates(latitude: Option<f64>, longitude: Option<f64>) -> Option<Self> {
+        latitude
+            .zip(longitude)
+            .and_then(|(latitude, longitude)| Self::new(latitude, longitude).ok())
+    }
+
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// The great-circle distance in kilometers, computed with the haversine formula
+    // NOTE: this is the distance as the crow flies, the actual distance on the road is longer
+    pub fn distance_km(&self, other: &GeoLocation) -> f64 {
+        let delta_latitude = (other.latitude - self.latitude).to_radians();
+        let delta_longitude = (other.longitude - self.longitude).to_radians();
+
+        let a = (delta_latitude / 2.0).sin().powi(2)
+            + self.latitude.to_radians().cos()
+                * other.latitude.to_radians().cos()
+                * (delta_longitude / 2.0).sin().powi(2);
+
+        2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+    }
+}
+
+impl From<GeoLocation> for GeoLocationDTO {
+    fn from(location: GeoLocation) -> Self {
+        GeoLocationDTO {
+            latitude: location.latitude(),
+            longitude: location.longitude(),
+        }
+    }
+}
+
+impl TryFrom<GeoLocationDTO> for GeoLocation {
+    type Error = String;
+
+    fn try_from(location: GeoLocationDTO) -> Result<Self, Self::Error> {
+        GeoLocation::new(location.latitude, location.longitude)
+    }
+}
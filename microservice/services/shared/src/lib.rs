@@ -14,6 +14,7 @@ use opentelemetry_sdk::{
 pub mod domain_event_repo;
 pub mod domain_events;
 pub mod domain_ids;
+pub mod geo_location;
 pub mod resolvers;
 pub mod token;
 
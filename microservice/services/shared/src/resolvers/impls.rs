@@ -3,7 +3,8 @@ use std::{future::Future, sync::Arc};
 use log::debug;
 use redis::Commands;
 use restinterface::{
-    fetch_fixture, fetch_referee, fetch_team, fetch_venue, FixtureDTO, FixtureIdDTO, RefereeDTO,
+    fetch_assignments, fetch_availabilities_for_referee, fetch_fixture, fetch_fixtures,
+    fetch_referee, fetch_team, fetch_venue, AssignmentDTO, FixtureDTO, FixtureIdDTO, RefereeDTO,
     RefereeIdDTO, TeamDTO, TeamIdDTO, VenueDTO, VenueIdDTO,
 };
 use serde::{de::DeserializeOwned, Serialize};
@@ -11,7 +12,10 @@ use tokio::sync::Mutex;
 
 use crate::domain_ids::{FixtureId, RefereeId, TeamId, VenueId};
 
-use super::traits::{FixtureResolver, RefereeResolver, TeamResolver, VenueResolver};
+use super::traits::{
+    AssignmentResolver, AvailabilityResolver, FixtureResolver, RefereeResolver,
+    TeamFixturesResolver, TeamResolver, VenueFixturesResolver, VenueResolver,
+};
 
 // NOTE: unfortunately we need to use a tokio Mutex with an Arc due to async code in REST handlers - otherwise axum would complain about "trait bound no longer satisfied"
 // see https://stackoverflow.com/questions/76307624/unexplained-trait-bound-no-longer-satisfied-when-modifying-axum-handler-body
@@ -30,6 +34,16 @@ pub struct FixtureResolverImpl {
     redis_conn: Arc<Mutex<redis::Connection>>,
 }
 
+// NOTE: availabilities are not cached in Redis because they change frequently and there is no
+// domain event handler in place that would invalidate the cached entries
+pub struct AvailabilityResolverImpl {}
+
+// NOTE: the assignments and fixtures referencing a referee, team or venue are not cached either, because
+// they are only needed to check whether it can be archived, which must not be decided on stale data
+pub struct AssignmentResolverImpl {}
+pub struct TeamFixturesResolverImpl {}
+pub struct VenueFixturesResolverImpl {}
+
 impl VenueResolverImpl {
     pub fn new(redis_conn: Arc<Mutex<redis::Connection>>) -> Self {
         Self { redis_conn }
@@ -110,6 +124,76 @@ impl FixtureResolver for FixtureResolverImpl {
     }
 }
 
+impl AvailabilityResolverImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl AvailabilityResolver for AvailabilityResolverImpl {
+    type Error = String;
+
+    async fn resolve(&self, referee_id: &RefereeId) -> Result<Vec<FixtureIdDTO>, Self::Error> {
+        fetch_availabilities_for_referee(RefereeIdDTO::from(*referee_id))
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+impl AssignmentResolverImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl AssignmentResolver for AssignmentResolverImpl {
+    type Error = String;
+
+    async fn resolve(&self, referee_id: &RefereeId) -> Result<Vec<AssignmentDTO>, Self::Error> {
+        let assignments = fetch_assignments().await;
+        Ok(assignments
+            .into_iter()
+            .filter(|a| a.referee_id.0 == referee_id.0)
+            .collect())
+    }
+}
+
+impl TeamFixturesResolverImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl TeamFixturesResolver for TeamFixturesResolverImpl {
+    type Error = String;
+
+    async fn resolve(&self, team_id: &TeamId) -> Result<Vec<FixtureDTO>, Self::Error> {
+        let fixtures = fetch_fixtures().await;
+        Ok(fixtures
+            .into_iter()
+            .filter(|f| f.team_home.id.0 == team_id.0 || f.team_away.id.0 == team_id.0)
+            .collect())
+    }
+}
+
+impl VenueFixturesResolverImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl VenueFixturesResolver for VenueFixturesResolverImpl {
+    type Error = String;
+
+    async fn resolve(&self, venue_id: &VenueId) -> Result<Vec<FixtureDTO>, Self::Error> {
+        let fixtures = fetch_fixtures().await;
+        Ok(fixtures
+            .into_iter()
+            .filter(|f| f.venue.id.0 == venue_id.0)
+            .collect())
+    }
+}
+
 async fn run_cached<Dto, F, Fut>(
     key: &str,
     redis_conn: &mut redis::Connection,
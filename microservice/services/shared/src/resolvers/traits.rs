@@ -1,5 +1,5 @@
 use mockall::automock;
-use restinterface::{FixtureDTO, RefereeDTO, TeamDTO, VenueDTO};
+use restinterface::{AssignmentDTO, FixtureDTO, FixtureIdDTO, RefereeDTO, TeamDTO, VenueDTO};
 
 use crate::domain_ids::{FixtureId, RefereeId, TeamId, VenueId};
 
@@ -30,3 +30,31 @@ pub trait FixtureResolver {
     type Error;
     async fn resolve(&self, fixture_id: &FixtureId) -> Result<FixtureDTO, Self::Error>;
 }
+
+#[allow(async_fn_in_trait)]
+#[automock(type Error = String;)]
+pub trait AvailabilityResolver {
+    type Error;
+    async fn resolve(&self, referee_id: &RefereeId) -> Result<Vec<FixtureIdDTO>, Self::Error>;
+}
+
+#[allow(async_fn_in_trait)]
+#[automock(type Error = String;)]
+pub trait AssignmentResolver {
+    type Error;
+    async fn resolve(&self, referee_id: &RefereeId) -> Result<Vec<AssignmentDTO>, Self::Error>;
+}
+
+#[allow(async_fn_in_trait)]
+#[automock(type Error = String;)]
+pub trait TeamFixturesResolver {
+    type Error;
+    async fn resolve(&self, team_id: &TeamId) -> Result<Vec<FixtureDTO>, Self::Error>;
+}
+
+#[allow(async_fn_in_trait)]
+#[automock(type Error = String;)]
+pub trait VenueFixturesResolver {
+    type Error;
+    async fn resolve(&self, venue_id: &VenueId) -> Result<Vec<FixtureDTO>, Self::Error>;
+}
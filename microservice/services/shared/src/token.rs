@@ -3,10 +3,16 @@ use std::{
     time::{SystemTime, UNIX_EPOCH},
 };
 
-use jsonwebtoken::DecodingKey;
+use jsonwebtoken::{jwk::JwkSet, Algorithm, DecodingKey, Validation};
 use log::info;
-use reqwest::Url;
+use reqwest::{
+    header::{HeaderMap, COOKIE},
+    Url,
+};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub use restinterface::ACCESS_TOKEN_COOKIE;
 
 pub struct TokenManager {
     idp_doc: IdpDiscoveryDocument,
@@ -273,6 +279,9 @@ pub struct AccessToken {
     pub given_name: String,
     pub family_name: String,
     pub email: String,
+    // NOTE: only accounts of referees carry this claim, it is mapped from a user attribute in the Idp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub referee_id: Option<Uuid>,
 }
 
 impl AccessToken {
@@ -363,6 +372,122 @@ impl TryFrom<String> for AccessToken {
     }
 }
 
+/// Looks up the encoded access token in the value of a cookie request header
+pub fn extract_access_token_from_cookie_header(cookie_header: &str) -> Option<String> {
+    let cookies: Vec<&str> = cookie_header.split("; ").collect();
+
+    for cookie in cookies.iter() {
+        let cookie_split: Vec<&str> = cookie.split("=").collect();
+        if cookie_split.len() == 2 && cookie_split[0] == ACCESS_TOKEN_COOKIE {
+            return Some(cookie_split[1].to_string());
+        }
+    }
+
+    None
+}
+
+/// Verifies the signature of access tokens against the public keys (JWKS) of the Idp
+pub struct AccessTokenVerifier {
+    jwks: JwkSet,
+}
+
+impl AccessTokenVerifier {
+    pub async fn new(idp_host: &str, realm: &str) -> Result<Self, reqwest::Error> {
+        let idp_doc = get_discovery_document(idp_host, realm).await?;
+        let jwks = get_jwks(&idp_doc).await?;
+
+        info!(
+            "AccessTokenVerifier successfully queried {} keys from Idp",
+            jwks.keys.len()
+        );
+
+        Ok(Self::from_jwks(jwks))
+    }
+
+    pub fn from_jwks(jwks: JwkSet) -> Self {
+        Self { jwks }
+    }
+
+    /// Decodes the access token only if it is signed by a key of the Idp and is not expired
+    pub fn verify(&self, access_token_encoded: &str) -> Result<AccessToken, String> {
+        let header = jsonwebtoken::decode_header(access_token_encoded)
+            .map_err(|e| format!("failed to decode access token header with error {}", e))?;
+
+        let kid = header
+            .kid
+            .ok_or("access token does not name its signing key".to_string())?;
+        let jwk = self
+            .jwks
+            .find(&kid)
+            .ok_or(format!("access token is signed with unknown key {}", kid))?;
+        let key = DecodingKey::from_jwk(jwk)
+            .map_err(|e| format!("failed to use signing key {} with error {}", kid, e))?;
+
+        // NOTE: the algorithm is fixed instead of taken from the token header, Keycloak signs access tokens with RS256
+        let mut validation = Validation::new(Algorithm::RS256);
+        validation.validate_aud = false;
+        validation.set_required_spec_claims(&["exp"]);
+
+        let access_token_result =
+            jsonwebtoken::decode::<AccessToken>(access_token_encoded, &key, &validation)
+                .map_err(|e| format!("failed to verify access token with error {}", e))?;
+
+        Ok(access_token_result.claims)
+    }
+}
+
+/// Verifies the access token carried in the cookie header of a request
+pub fn verify_access_token_from_headers(
+    headers: &HeaderMap,
+    verifier: &AccessTokenVerifier,
+) -> Result<AccessToken, String> {
+    let access_token_encoded = headers
+        .get(COOKIE)
+        .and_then(|cookie_header| cookie_header.to_str().ok())
+        .and_then(extract_access_token_from_cookie_header)
+        .ok_or("No access token found in cookie".to_string())?;
+
+    verifier.verify(&access_token_encoded)
+}
+
+/// Only requests carrying a verified access token with any of the allowed roles are authorised
+pub fn authorize_any_role(
+    headers: &HeaderMap,
+    verifier: &AccessTokenVerifier,
+    roles_allowed: &Vec<&str>,
+) -> Result<AccessToken, String> {
+    let access_token = verify_access_token_from_headers(headers, verifier)?;
+
+    if !access_token.satisfies_any_role(roles_allowed) {
+        return Err(format!(
+            "Access token has none of the roles {:?}",
+            roles_allowed
+        ));
+    }
+
+    Ok(access_token)
+}
+
+/// Only requests carrying a verified access token of the referee itself or of an admin may act on behalf of a referee
+pub fn authorize_referee_or_admin(
+    headers: &HeaderMap,
+    verifier: &AccessTokenVerifier,
+    referee_id: Uuid,
+) -> Result<AccessToken, String> {
+    let access_token = verify_access_token_from_headers(headers, verifier)?;
+
+    if access_token.referee_id != Some(referee_id)
+        && !access_token.satisfies_any_role(&vec!["admin"])
+    {
+        return Err(format!(
+            "Only referee {} or an admin may act on behalf of the referee",
+            referee_id
+        ));
+    }
+
+    Ok(access_token)
+}
+
 impl TryFrom<IdpTokens> for Tokens {
     type Error = String;
 
@@ -526,6 +651,13 @@ async fn get_discovery_document(
     response.json().await
 }
 
+async fn get_jwks(idp_doc: &IdpDiscoveryDocument) -> Result<JwkSet, reqwest::Error> {
+    let url = Url::parse(&idp_doc.jwks_uri);
+
+    let response = reqwest::Client::new().get(url.unwrap()).send().await?;
+    response.json().await
+}
+
 async fn request_idp_tokens_via_credentials(
     idp_doc: &IdpDiscoveryDocument,
     client_id: &str,
@@ -714,3 +846,171 @@ async fn introspect_access_token(
 
     response.json().await
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use jsonwebtoken::{jwk::JwkSet, Algorithm, EncodingKey, Header};
+    use reqwest::header::{HeaderMap, HeaderValue, COOKIE};
+    use uuid::Uuid;
+
+    use crate::token::{
+        authorize_any_role, authorize_referee_or_admin, AccessToken, AccessTokenVerifier,
+        ResourceAccess, ResourceAccessAccount, ACCESS_TOKEN_COOKIE,
+    };
+
+    const SIGNING_KEY_ID: &str = "test-key";
+    const SIGNING_KEY_PEM: &str = include_str!("../jwks_test_key.pem");
+    const SIGNING_KEY_MODULUS: &str = "xMhKzH3DN5hN_uVHUX_ZBzg9TMk6OMwPbVduKAAYQgkj9YDRMmbYao_T1pbp_vRDuGGqirTNVyRoF1VQRPWREznGriY1_LYxqRQZGCy86wcCjnFoMtydtLHGX01wcq2moiILa71WXE_STv2BkJrDy21Sdl6besoYGoYiUh4DLSWJXoCO9Xt3RisJOuHZLKCnUOpOnnLKisL0AmRT7lCsUp5xXvHYumIF4VAvHt3Pu7bIUIi2ESpvXhUypjqekk1DexiwP9pEdzkUDic0abtg0w8F51myJCJCAXFJ04a2R1XiCk8xxMQ0P5GcbzKdqTn34kWqGpxJwg6dC2qlDc-l6w";
+
+    fn verifier() -> AccessTokenVerifier {
+        let jwks: JwkSet = serde_json::from_value(serde_json::json!({
+            "keys": [{
+                "kid": SIGNING_KEY_ID,
+                "kty": "RSA",
+                "alg": "RS256",
+                "use": "sig",
+                "n": SIGNING_KEY_MODULUS,
+                "e": "AQAB"
+            }]
+        }))
+        .unwrap();
+
+        AccessTokenVerifier::from_jwks(jwks)
+    }
+
+    fn access_token(
+        roles: Vec<&str>,
+        referee_id: Option<Uuid>,
+        exp_offset_secs: i64,
+    ) -> AccessToken {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        AccessToken {
+            exp: (now as i64 + exp_offset_secs) as u64,
+            iat: now,
+            jti: Uuid::new_v4().to_string(),
+            iss: "http://localhost:8080/realms/rustddd".to_string(),
+            sub: Uuid::new_v4().to_string(),
+            typ: "Bearer".to_string(),
+            azp: "rustddd".to_string(),
+            sid: Uuid::new_v4().to_string(),
+            acr: "1".to_string(),
+            allowed_origins: vec!["http://localhost:1234".to_string()],
+            resource_access: ResourceAccess {
+                rustddd_auth: ResourceAccessAccount {
+                    roles: roles.into_iter().map(|r| r.to_string()).collect(),
+                },
+            },
+            scope: "openid profile email".to_string(),
+            email_verified: true,
+            name: "Alice Test".to_string(),
+            preferred_username: "alice".to_string(),
+            given_name: "Alice".to_string(),
+            family_name: "Test".to_string(),
+            email: "alice@test.com".to_string(),
+            referee_id,
+        }
+    }
+
+    fn sign(access_token: &AccessToken) -> String {
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some(SIGNING_KEY_ID.to_string());
+        let key = EncodingKey::from_rsa_pem(SIGNING_KEY_PEM.as_bytes()).unwrap();
+
+        jsonwebtoken::encode(&header, access_token, &key).unwrap()
+    }
+
+    fn cookie_headers(access_token_encoded: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            COOKIE,
+            HeaderValue::from_str(&format!("{}={}", ACCESS_TOKEN_COOKIE, access_token_encoded))
+                .unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn test_given_token_signed_by_idp_when_verify_then_claims_are_returned() {
+        let referee_id = Uuid::new_v4();
+        let access_token_encoded = sign(&access_token(vec!["referee"], Some(referee_id), 300));
+
+        let access_token = verifier().verify(&access_token_encoded).unwrap();
+
+        assert_eq!(access_token.referee_id, Some(referee_id));
+        assert!(access_token.satisfies_any_role(&vec!["referee"]));
+    }
+
+    #[test]
+    fn test_given_tampered_token_when_verify_then_error() {
+        let signed = sign(&access_token(vec!["referee"], None, 300));
+        let forged_claims = sign(&access_token(vec!["admin"], None, 300));
+
+        // NOTE: claims of one token with the signature of another
+        let signed_parts: Vec<&str> = signed.split('.').collect();
+        let forged_parts: Vec<&str> = forged_claims.split('.').collect();
+        let tampered = format!(
+            "{}.{}.{}",
+            signed_parts[0], forged_parts[1], signed_parts[2]
+        );
+
+        assert!(verifier().verify(&tampered).is_err());
+    }
+
+    #[test]
+    fn test_given_token_signed_with_secret_when_verify_then_error() {
+        let mut header = Header::new(Algorithm::HS256);
+        header.kid = Some(SIGNING_KEY_ID.to_string());
+        let forged = jsonwebtoken::encode(
+            &header,
+            &access_token(vec!["admin"], None, 300),
+            &EncodingKey::from_secret(SIGNING_KEY_MODULUS.as_bytes()),
+        )
+        .unwrap();
+
+        assert!(verifier().verify(&forged).is_err());
+    }
+
+    #[test]
+    fn test_given_expired_token_when_verify_then_error() {
+        let access_token_encoded = sign(&access_token(vec!["admin"], None, -600));
+
+        assert!(verifier().verify(&access_token_encoded).is_err());
+    }
+
+    #[test]
+    fn test_given_verified_token_without_role_when_authorize_then_error() {
+        let verifier = verifier();
+
+        let admin_headers = cookie_headers(&sign(&access_token(vec!["admin"], None, 300)));
+        let referee_headers = cookie_headers(&sign(&access_token(vec!["referee"], None, 300)));
+
+        assert!(authorize_any_role(&admin_headers, &verifier, &vec!["admin"]).is_ok());
+        assert!(authorize_any_role(&referee_headers, &verifier, &vec!["admin"]).is_err());
+        assert!(authorize_any_role(&HeaderMap::new(), &verifier, &vec!["admin"]).is_err());
+    }
+
+    #[test]
+    fn test_given_other_referee_when_authorize_referee_or_admin_then_error() {
+        let verifier = verifier();
+        let referee_id = Uuid::new_v4();
+
+        let referee_headers =
+            cookie_headers(&sign(&access_token(vec!["referee"], Some(referee_id), 300)));
+        let other_referee_headers = cookie_headers(&sign(&access_token(
+            vec!["referee"],
+            Some(Uuid::new_v4()),
+            300,
+        )));
+        let admin_headers = cookie_headers(&sign(&access_token(vec!["admin"], None, 300)));
+
+        assert!(authorize_referee_or_admin(&referee_headers, &verifier, referee_id).is_ok());
+        assert!(authorize_referee_or_admin(&admin_headers, &verifier, referee_id).is_ok());
+        assert!(authorize_referee_or_admin(&other_referee_headers, &verifier, referee_id).is_err());
+    }
+}
@@ -1,5 +1,7 @@
+use chrono::Utc;
 use microservices_shared::{
-    domain_event_repo::DomainEventOutboxRepository, domain_events::DomainEvent,
+    domain_event_repo::DomainEventOutboxRepository, domain_events::DomainEvent, domain_ids::TeamId,
+    resolvers::traits::TeamFixturesResolver,
 };
 
 use crate::domain::{aggregates::team::Team, repositories::team_repo::TeamRepository};
@@ -27,3 +29,83 @@ pub async fn create_team<TxCtx>(
 
     Ok(team)
 }
+
+pub async fn update_team_name<TxCtx>(
+    team_id: TeamId,
+    name: &str,
+    repo: &impl TeamRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Team, String> {
+    let team = repo.find_by_id(team_id, tx_ctx).await?;
+    let mut team = team.ok_or("Team not found")?;
+    team.change_name(name);
+
+    save_updated_team(&team, repo, domain_event_repo, tx_ctx).await?;
+
+    Ok(team)
+}
+
+pub async fn update_team_club<TxCtx>(
+    team_id: TeamId,
+    club: &str,
+    repo: &impl TeamRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Team, String> {
+    let team = repo.find_by_id(team_id, tx_ctx).await?;
+    let mut team = team.ok_or("Team not found")?;
+    team.change_club(club);
+
+    save_updated_team(&team, repo, domain_event_repo, tx_ctx).await?;
+
+    Ok(team)
+}
+
+/// Archives a team, which is only possible as long as it is not playing in any upcoming fixture
+pub async fn archive_team<TxCtx>(
+    team_id: TeamId,
+    repo: &impl TeamRepository<TxCtx = TxCtx, Error = String>,
+    team_fixtures_resolver: &impl TeamFixturesResolver<Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Team, String> {
+    let team = repo.find_by_id(team_id, tx_ctx).await?;
+    let mut team = team.ok_or("Team not found")?;
+
+    if team.is_archived() {
+        return Err("Team is already archived".to_string());
+    }
+
+    let now = Utc::now();
+    let fixtures = team_fixtures_resolver.resolve(&team_id).await?;
+    if fixtures.iter().any(|f| f.is_upcoming(&now)) {
+        return Err("Team is still playing in upcoming fixtures".to_string());
+    }
+
+    team.archive();
+
+    repo.save(&team, tx_ctx).await.map_err(|e| e.to_string())?;
+
+    domain_event_repo
+        .store(DomainEvent::TeamArchived { team_id }, tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(team)
+}
+
+async fn save_updated_team<TxCtx>(
+    team: &Team,
+    repo: &impl TeamRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    repo.save(team, tx_ctx).await.map_err(|e| e.to_string())?;
+
+    // NOTE: the cache entries are invalidated by the domain event listener of the Fixtures service
+    domain_event_repo
+        .store(DomainEvent::TeamUpdated { team_id: team.id() }, tx_ctx)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -4,7 +4,7 @@ use axum::{
     Router,
 };
 
-use microservices_shared::domain_events::{DomainEventCallbacksLoggerImpl, DomainEventConsumer};
+use microservices_shared::domain_events::{DomainEventConsumer, DomainEventHandlerRegistry};
 use opentelemetry::{
     trace::{Span, Tracer},
     KeyValue,
@@ -13,7 +13,8 @@ use sqlx::PgPool;
 use std::sync::Arc;
 use teams::config::AppConfig;
 use teams::ports::rest::teams::{
-    create_team_handler, get_all_teams_handler, get_team_by_id_handler,
+    archive_team_handler, create_team_handler, get_all_teams_handler, get_team_by_id_handler,
+    update_team_club_handler, update_team_name_handler,
 };
 use teams::AppState;
 
@@ -31,13 +32,14 @@ async fn main() {
 
     let connection_pool = PgPool::connect(&config.db_url).await.unwrap();
 
-    let domain_event_callbacks = Box::new(DomainEventCallbacksLoggerImpl::new(tracer_arc.clone()));
+    // NOTE: this service does not react to any domain events, they are only logged
+    let domain_event_handlers = DomainEventHandlerRegistry::new((), tracer_arc.clone());
     let mut domain_event_consumer = DomainEventConsumer::new(
         &config.kafka_consumer_group,
         &config.kafka_url,
         &config.kafka_domain_events_topics,
         connection_pool.clone(),
-        domain_event_callbacks,
+        domain_event_handlers,
     );
 
     let app_state = AppState {
@@ -61,6 +63,9 @@ async fn main() {
         .route("/teams", post(create_team_handler))
         .route("/teams/:id", get(get_team_by_id_handler))
         .route("/teams/all", get(get_all_teams_handler))
+        .route("/teams/:id/name", post(update_team_name_handler))
+        .route("/teams/:id/club", post(update_team_club_handler))
+        .route("/teams/:id/archive", post(archive_team_handler))
         .layer(cors)
         .with_state(state_arc);
 
@@ -1,5 +1,7 @@
+use chrono::Utc;
 use microservices_shared::{
     domain_event_repo::DomainEventOutboxRepository, domain_events::DomainEvent,
+    domain_ids::VenueId, geo_location::GeoLocation, resolvers::traits::VenueFixturesResolver,
 };
 
 use crate::domain::{aggregates::venue::Venue, repositories::venue_repo::VenueRepository};
@@ -11,11 +13,12 @@ pub async fn create_venue<TxCtx>(
     city: &str,
     telephone: Option<String>,
     email: Option<String>,
+    location: Option<GeoLocation>,
     repo: &impl VenueRepository<TxCtx = TxCtx, Error = String>,
     domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
     tx_ctx: &mut TxCtx,
 ) -> Result<Venue, String> {
-    let venue = Venue::new(&name, &street, &zip, &city, telephone, email);
+    let venue = Venue::new(&name, &street, &zip, &city, telephone, email, location);
 
     repo.save(&venue, tx_ctx).await.map_err(|e| e.to_string())?;
 
@@ -31,3 +34,92 @@ pub async fn create_venue<TxCtx>(
 
     Ok(venue)
 }
+
+pub async fn update_venue_address<TxCtx>(
+    venue_id: VenueId,
+    street: &str,
+    zip: &str,
+    city: &str,
+    location: Option<GeoLocation>,
+    repo: &impl VenueRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Venue, String> {
+    let venue = repo.find_by_id(venue_id, tx_ctx).await?;
+    let mut venue = venue.ok_or("Venue not found")?;
+    venue.change_address(street, zip, city, location);
+
+    save_updated_venue(&venue, repo, domain_event_repo, tx_ctx).await?;
+
+    Ok(venue)
+}
+
+pub async fn update_venue_contact<TxCtx>(
+    venue_id: VenueId,
+    telephone: Option<String>,
+    email: Option<String>,
+    repo: &impl VenueRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Venue, String> {
+    let venue = repo.find_by_id(venue_id, tx_ctx).await?;
+    let mut venue = venue.ok_or("Venue not found")?;
+    venue.change_contact(telephone, email);
+
+    save_updated_venue(&venue, repo, domain_event_repo, tx_ctx).await?;
+
+    Ok(venue)
+}
+
+/// Archives a venue, which is only possible as long as it is not used by any upcoming fixture
+pub async fn archive_venue<TxCtx>(
+    venue_id: VenueId,
+    repo: &impl VenueRepository<TxCtx = TxCtx, Error = String>,
+    venue_fixtures_resolver: &impl VenueFixturesResolver<Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Venue, String> {
+    let venue = repo.find_by_id(venue_id, tx_ctx).await?;
+    let mut venue = venue.ok_or("Venue not found")?;
+
+    if venue.is_archived() {
+        return Err("Venue is already archived".to_string());
+    }
+
+    let now = Utc::now();
+    let fixtures = venue_fixtures_resolver.resolve(&venue_id).await?;
+    if fixtures.iter().any(|f| f.is_upcoming(&now)) {
+        return Err("Venue is still used by upcoming fixtures".to_string());
+    }
+
+    venue.archive();
+
+    repo.save(&venue, tx_ctx).await.map_err(|e| e.to_string())?;
+
+    domain_event_repo
+        .store(DomainEvent::VenueArchived { venue_id }, tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(venue)
+}
+
+async fn save_updated_venue<TxCtx>(
+    venue: &Venue,
+    repo: &impl VenueRepository<TxCtx = TxCtx, Error = String>,
+    domain_event_repo: &impl DomainEventOutboxRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    repo.save(venue, tx_ctx).await.map_err(|e| e.to_string())?;
+
+    // NOTE: the cache entries are invalidated by the domain event listener of the Fixtures service
+    domain_event_repo
+        .store(
+            DomainEvent::VenueUpdated {
+                venue_id: venue.id(),
+            },
+            tx_ctx,
+        )
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -1,4 +1,4 @@
-use microservices_shared::domain_ids::VenueId;
+use microservices_shared::{domain_ids::VenueId, geo_location::GeoLocation};
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
@@ -10,6 +10,9 @@ pub struct Venue {
     city: String,
     telephone: Option<String>,
     email: Option<String>,
+    location: Option<GeoLocation>,
+    // NOTE: archived venues are kept for the history of fixtures, but hidden from the selection of venues
+    archived: bool,
 }
 
 impl Venue {
@@ -20,6 +23,7 @@ impl Venue {
         city: &str,
         telephone: Option<String>,
         email: Option<String>,
+        location: Option<GeoLocation>,
     ) -> Self {
         Self {
             id: VenueId(Uuid::new_v4()),
@@ -29,6 +33,8 @@ impl Venue {
             city: city.to_string(),
             telephone,
             email,
+            location,
+            archived: false,
         }
     }
 
@@ -40,6 +46,8 @@ impl Venue {
         city: String,
         telephone: Option<String>,
         email: Option<String>,
+        location: Option<GeoLocation>,
+        archived: bool,
     ) -> Self {
         Self {
             id,
@@ -49,6 +57,8 @@ impl Venue {
             city,
             telephone,
             email,
+            location,
+            archived,
         }
     }
 
@@ -79,4 +89,38 @@ impl Venue {
     pub fn email(&self) -> Option<String> {
         self.email.clone()
     }
+
+    pub fn location(&self) -> Option<GeoLocation> {
+        self.location
+    }
+
+    pub fn change_address(
+        &mut self,
+        street: &str,
+        zip: &str,
+        city: &str,
+        location: Option<GeoLocation>,
+    ) {
+        self.street = street.to_string();
+        self.zip = zip.to_string();
+        self.city = city.to_string();
+        self.location = location;
+    }
+
+    pub fn change_contact(&mut self, telephone: Option<String>, email: Option<String>) {
+        self.telephone = telephone;
+        self.email = email;
+    }
+
+    pub fn is_archived(&self) -> bool {
+        self.archived
+    }
+
+    pub fn archive(&mut self) {
+        if self.archived {
+            panic!("Venue already archived");
+        }
+
+        self.archived = true;
+    }
 }
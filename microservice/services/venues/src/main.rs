@@ -4,7 +4,7 @@ use axum::{
     Router,
 };
 
-use microservices_shared::domain_events::{DomainEventCallbacksLoggerImpl, DomainEventConsumer};
+use microservices_shared::domain_events::{DomainEventConsumer, DomainEventHandlerRegistry};
 use opentelemetry::{
     trace::{Span, Tracer},
     KeyValue,
@@ -13,7 +13,8 @@ use sqlx::PgPool;
 use std::sync::Arc;
 use venues::config::AppConfig;
 use venues::ports::rest::venues::{
-    create_venue_handler, get_all_venues_handler, get_venue_by_id_handler,
+    archive_venue_handler, create_venue_handler, get_all_venues_handler, get_venue_by_id_handler,
+    update_venue_address_handler, update_venue_contact_handler,
 };
 use venues::AppState;
 
@@ -31,13 +32,14 @@ async fn main() {
 
     let connection_pool = PgPool::connect(&config.db_url).await.unwrap();
 
-    let domain_event_callbacks = Box::new(DomainEventCallbacksLoggerImpl::new(tracer_arc.clone()));
+    // NOTE: this service does not react to any domain events, they are only logged
+    let domain_event_handlers = DomainEventHandlerRegistry::new((), tracer_arc.clone());
     let mut domain_event_consumer = DomainEventConsumer::new(
         &config.kafka_consumer_group,
         &config.kafka_url,
         &config.kafka_domain_events_topics,
         connection_pool.clone(),
-        domain_event_callbacks,
+        domain_event_handlers,
     );
 
     let app_state = AppState {
@@ -61,6 +63,9 @@ async fn main() {
         .route("/venues", post(create_venue_handler))
         .route("/venues/:id", get(get_venue_by_id_handler))
         .route("/venues/all", get(get_all_venues_handler))
+        .route("/venues/:id/address", post(update_venue_address_handler))
+        .route("/venues/:id/contact", post(update_venue_contact_handler))
+        .route("/venues/:id/archive", post(archive_venue_handler))
         .layer(cors)
         .with_state(state_arc);
 
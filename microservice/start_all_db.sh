@@ -7,6 +7,9 @@ sh ./start_db.sh &
 cd ../fixtures
 sh ./start_db.sh &
 
+cd ../notifications
+sh ./start_db.sh &
+
 cd ../referees
 sh ./start_db.sh &
 
@@ -0,0 +1 @@
+docker compose -f infra/smtp/docker-compose.yaml up -d
\ No newline at end of file
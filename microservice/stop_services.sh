@@ -7,6 +7,9 @@ docker stop rustddd-availabilities-instance-2
 docker stop rustddd-fixtures-instance-1
 docker stop rustddd-fixtures-instance-2
 
+docker stop rustddd-notifications-instance-1
+docker stop rustddd-notifications-instance-2
+
 docker stop rustddd-referees-instance-1
 docker stop rustddd-referees-instance-2
 
@@ -27,6 +30,9 @@ docker rm rustddd-availabilities-instance-2
 docker rm rustddd-fixtures-instance-1
 docker rm rustddd-fixtures-instance-2
 
+docker rm rustddd-notifications-instance-1
+docker rm rustddd-notifications-instance-2
+
 docker rm rustddd-referees-instance-1
 docker rm rustddd-referees-instance-2
 
@@ -4,7 +4,11 @@ CREATE SCHEMA IF NOT EXISTS rustddd;
 CREATE TABLE IF NOT EXISTS rustddd.referees (
     referee_id UUID NOT NULL PRIMARY KEY,
     name VARCHAR NOT NULL,
-    club VARCHAR NOT NULL
+    club VARCHAR NOT NULL,
+    qualification_level INTEGER NOT NULL DEFAULT 0,
+    home_latitude DOUBLE PRECISION,
+    home_longitude DOUBLE PRECISION,
+    archived BOOLEAN NOT NULL DEFAULT FALSE
 );
 
 CREATE TABLE IF NOT EXISTS rustddd.venues (
@@ -14,19 +18,36 @@ CREATE TABLE IF NOT EXISTS rustddd.venues (
     zip VARCHAR NOT NULL,
     city VARCHAR NOT NULL,
     telephone VARCHAR,
-    email VARCHAR
+    email VARCHAR,
+    latitude DOUBLE PRECISION,
+    longitude DOUBLE PRECISION,
+    archived BOOLEAN NOT NULL DEFAULT FALSE
 );
 
 CREATE TABLE IF NOT EXISTS rustddd.teams (
     team_id UUID NOT NULL PRIMARY KEY,
     name VARCHAR NOT NULL,
-    club VARCHAR NOT NULL
+    club VARCHAR NOT NULL,
+    archived BOOLEAN NOT NULL DEFAULT FALSE
 );
 
-CREATE TYPE rustddd.fixture_status AS ENUM ('scheduled', 'cancelled');
+CREATE TABLE IF NOT EXISTS rustddd.leagues (
+    league_id UUID NOT NULL PRIMARY KEY,
+    name VARCHAR NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS rustddd.seasons (
+    season_id UUID NOT NULL PRIMARY KEY,
+    name VARCHAR NOT NULL,
+    closed BOOLEAN NOT NULL DEFAULT false
+);
+
+CREATE TYPE rustddd.fixture_status AS ENUM ('scheduled', 'postponed', 'played', 'cancelled');
 
 CREATE TABLE IF NOT EXISTS rustddd.fixtures (
     fixture_id UUID NOT NULL PRIMARY KEY,
+    league_id UUID NOT NULL,
+    season_id UUID NOT NULL,
     team_home_id UUID NOT NULL,
     team_away_id UUID NOT NULL,
     venue_id UUID NOT NULL,
@@ -34,6 +55,13 @@ CREATE TABLE IF NOT EXISTS rustddd.fixtures (
     status rustddd.fixture_status NOT NULL,
     first_referee_id UUID,
     second_referee_id UUID,
+    first_referee_min_level INTEGER NOT NULL DEFAULT 0,
+    second_referee_min_level INTEGER NOT NULL DEFAULT 0,
+    result_home_points INTEGER[],
+    result_away_points INTEGER[],
+    result_winner_id UUID,
+    CONSTRAINT fk_league FOREIGN KEY (league_id) REFERENCES rustddd.leagues(league_id),
+    CONSTRAINT fk_season FOREIGN KEY (season_id) REFERENCES rustddd.seasons(season_id),
     CONSTRAINT fk_team_home FOREIGN KEY (team_home_id) REFERENCES rustddd.teams(team_id),
     CONSTRAINT fk_team_away FOREIGN KEY (team_away_id) REFERENCES rustddd.teams(team_id),
     CONSTRAINT fk_venue FOREIGN KEY (venue_id) REFERENCES rustddd.venues(venue_id),
@@ -49,7 +77,29 @@ CREATE TABLE IF NOT EXISTS rustddd.availabilities (
     UNIQUE (fixture_id, referee_id)
 );
 
-CREATE TYPE rustddd.assignment_status AS ENUM ('committed', 'staged');
+CREATE TABLE IF NOT EXISTS rustddd.availability_locks (
+    id UUID NOT NULL PRIMARY KEY,
+    fixture_id UUID,
+    from_date TIMESTAMP WITH TIME ZONE,
+    to_date TIMESTAMP WITH TIME ZONE,
+    CONSTRAINT fk_fixture FOREIGN KEY (fixture_id) REFERENCES rustddd.fixtures(fixture_id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS rustddd.declaration_deadlines (
+    fixture_id UUID NOT NULL PRIMARY KEY,
+    deadline TIMESTAMP WITH TIME ZONE NOT NULL,
+    CONSTRAINT fk_fixture FOREIGN KEY (fixture_id) REFERENCES rustddd.fixtures(fixture_id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS rustddd.blackouts (
+    id UUID NOT NULL PRIMARY KEY,
+    referee_id UUID NOT NULL,
+    from_date TIMESTAMP WITH TIME ZONE NOT NULL,
+    to_date TIMESTAMP WITH TIME ZONE NOT NULL,
+    CONSTRAINT fk_referee FOREIGN KEY (referee_id) REFERENCES rustddd.referees(referee_id) ON DELETE CASCADE
+);
+
+CREATE TYPE rustddd.assignment_status AS ENUM ('committed', 'staged', 'confirmed', 'declined');
 CREATE TYPE rustddd.assignment_referee_role AS ENUM ('first', 'second');
 
 CREATE TABLE IF NOT EXISTS rustddd.assignments (
@@ -57,6 +107,7 @@ CREATE TABLE IF NOT EXISTS rustddd.assignments (
     fixture_id UUID NOT NULL,
     referee_id UUID NOT NULL,
     referee_role rustddd.assignment_referee_role NOT NULL,
+    review_reason VARCHAR,
     CONSTRAINT fk_fixture FOREIGN KEY (fixture_id) REFERENCES rustddd.fixtures(fixture_id),
     CONSTRAINT fk_referee FOREIGN KEY (referee_id) REFERENCES rustddd.referees(referee_id),
     UNIQUE (fixture_id, referee_id)
@@ -77,10 +128,16 @@ INSERT INTO rustddd.teams (team_id, name, club) VALUES
 ('9b93e265-deb3-4139-a9b8-e261d7985a05'::UUID, 'Team B', 'Club B'),
 ('bca10019-1a77-48c6-a605-77c9289255b1'::UUID, 'Team C', 'Club C');
 
-INSERT INTO rustddd.fixtures (fixture_id, team_home_id, team_away_id, venue_id, date, status, first_referee_id, second_referee_id) VALUES
-('ba045e60-1ae2-4902-8293-02b04747a888'::UUID, 'def7f2ca-58a1-44ed-8f2b-78386c9746cf'::UUID, '9b93e265-deb3-4139-a9b8-e261d7985a05'::UUID, '6ee926bc-3728-4cdb-8efb-98d350a07854'::UUID, '2024-01-01 10:00:00', 'scheduled', '2ef28cf5-6471-4051-ae11-0f419aef3234', 'e1214a09-42e1-4194-9acc-d310172d001a'),
-('0aacbbba-1646-4478-8594-2401f19ad08d'::UUID, 'bca10019-1a77-48c6-a605-77c9289255b1'::UUID, 'def7f2ca-58a1-44ed-8f2b-78386c9746cf'::UUID, 'cf49df42-cf40-48fa-b2e7-d31b4c796ce1'::UUID, '2024-01-02 11:00:00', 'scheduled', '3bda5555-d604-432e-829a-78c782cccc18', 'e1214a09-42e1-4194-9acc-d310172d001a'),
-('45c7140e-3361-40e6-b54c-d0af3f9c0749'::UUID, '9b93e265-deb3-4139-a9b8-e261d7985a05'::UUID, 'bca10019-1a77-48c6-a605-77c9289255b1'::UUID, '54e9b343-be07-4e08-a0b7-c82778aa1604'::UUID, '2024-01-03 12:00:00', 'cancelled', NULL, NULL);
+INSERT INTO rustddd.leagues (league_id, name) VALUES
+('5b1c3a0e-7f2d-4a8e-9c61-2d4f8e0b7a15'::UUID, 'League A');
+
+INSERT INTO rustddd.seasons (season_id, name, closed) VALUES
+('a3e8d2c4-61f0-4b7a-8d25-9e4c1f7b3a60'::UUID, 'Season 2023/24', false);
+
+INSERT INTO rustddd.fixtures (fixture_id, league_id, season_id, team_home_id, team_away_id, venue_id, date, status, first_referee_id, second_referee_id) VALUES
+('ba045e60-1ae2-4902-8293-02b04747a888'::UUID, '5b1c3a0e-7f2d-4a8e-9c61-2d4f8e0b7a15'::UUID, 'a3e8d2c4-61f0-4b7a-8d25-9e4c1f7b3a60'::UUID, 'def7f2ca-58a1-44ed-8f2b-78386c9746cf'::UUID, '9b93e265-deb3-4139-a9b8-e261d7985a05'::UUID, '6ee926bc-3728-4cdb-8efb-98d350a07854'::UUID, '2024-01-01 10:00:00', 'scheduled', '2ef28cf5-6471-4051-ae11-0f419aef3234', 'e1214a09-42e1-4194-9acc-d310172d001a'),
+('0aacbbba-1646-4478-8594-2401f19ad08d'::UUID, '5b1c3a0e-7f2d-4a8e-9c61-2d4f8e0b7a15'::UUID, 'a3e8d2c4-61f0-4b7a-8d25-9e4c1f7b3a60'::UUID, 'bca10019-1a77-48c6-a605-77c9289255b1'::UUID, 'def7f2ca-58a1-44ed-8f2b-78386c9746cf'::UUID, 'cf49df42-cf40-48fa-b2e7-d31b4c796ce1'::UUID, '2024-01-02 11:00:00', 'scheduled', '3bda5555-d604-432e-829a-78c782cccc18', 'e1214a09-42e1-4194-9acc-d310172d001a'),
+('45c7140e-3361-40e6-b54c-d0af3f9c0749'::UUID, '5b1c3a0e-7f2d-4a8e-9c61-2d4f8e0b7a15'::UUID, 'a3e8d2c4-61f0-4b7a-8d25-9e4c1f7b3a60'::UUID, '9b93e265-deb3-4139-a9b8-e261d7985a05'::UUID, 'bca10019-1a77-48c6-a605-77c9289255b1'::UUID, '54e9b343-be07-4e08-a0b7-c82778aa1604'::UUID, '2024-01-03 12:00:00', 'cancelled', NULL, NULL);
 
 INSERT INTO rustddd.availabilities (fixture_id, referee_id) VALUES
 ('ba045e60-1ae2-4902-8293-02b04747a888'::UUID, '2ef28cf5-6471-4051-ae11-0f419aef3234'::UUID),
@@ -3,4 +3,9 @@ pub mod referee_repo_pg;
 pub mod team_repo_pg;
 pub mod venue_repo_pg;
 pub mod availability_repo_pg;
-pub mod assignment_repo_pg;
\ No newline at end of file
+pub mod assignment_repo_pg;
+pub mod league_repo_pg;
+pub mod season_repo_pg;
+pub mod availability_lock_repo_pg;
+pub mod declaration_deadline_repo_pg;
+pub mod blackout_repo_pg;
\ No newline at end of file
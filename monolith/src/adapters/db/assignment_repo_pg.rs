@@ -15,6 +15,8 @@ enum AssignmentRefereeRoleDb {
 enum AssignmentStatusDb {
     Committed,
     Staged,
+    Confirmed,
+    Declined,
 }
 
 #[derive(sqlx::FromRow, Debug)]
@@ -23,6 +25,7 @@ struct AssignmentDb {
     pub fixture_id: Uuid,
     pub referee_id: Uuid,
     pub referee_role: AssignmentRefereeRoleDb,
+    pub review_reason: Option<String>,
 }
 
 pub struct AssignmentRepositoryPg ();
@@ -39,6 +42,8 @@ impl From<AssignmentStatusDb> for AssignmentStatus {
         match status {
             AssignmentStatusDb::Committed => AssignmentStatus::Committed,
             AssignmentStatusDb::Staged => AssignmentStatus::Staged,
+            AssignmentStatusDb::Confirmed => AssignmentStatus::Confirmed,
+            AssignmentStatusDb::Declined => AssignmentStatus::Declined,
         }
     }
 }
@@ -48,6 +53,8 @@ impl From<AssignmentStatus> for AssignmentStatusDb {
         match status {
             AssignmentStatus::Committed => AssignmentStatusDb::Committed,
             AssignmentStatus::Staged => AssignmentStatusDb::Staged,
+            AssignmentStatus::Confirmed => AssignmentStatusDb::Confirmed,
+            AssignmentStatus::Declined => AssignmentStatusDb::Declined,
         }
     }
 }
@@ -76,7 +83,8 @@ impl From<AssignmentDb> for Assignment {
             assignment.fixture_id.into(),
             assignment.referee_id.into(),
             assignment.referee_role.into(),
-            assignment.status.into()
+            assignment.status.into(),
+            assignment.review_reason
         )
     }
 }
@@ -88,7 +96,7 @@ impl AssignmentRepository for AssignmentRepositoryPg {
     async fn get_all(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<Assignment>, Self::Error> {
         let assignments = sqlx::query_as!(
             AssignmentDb,
-            "SELECT status as \"status: AssignmentStatusDb\", fixture_id, referee_id, referee_role as \"referee_role: AssignmentRefereeRoleDb\" 
+            "SELECT status as \"status: AssignmentStatusDb\", fixture_id, referee_id, referee_role as \"referee_role: AssignmentRefereeRoleDb\", review_reason 
             FROM rustddd.assignments"
         )
         .fetch_all(&mut **tx_ctx)
@@ -101,7 +109,7 @@ impl AssignmentRepository for AssignmentRepositoryPg {
     async fn find_all_staged(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<Assignment>, Self::Error> {
         let assignments = sqlx::query_as!(
             AssignmentDb,
-            "SELECT status as \"status: AssignmentStatusDb\", fixture_id, referee_id, referee_role as \"referee_role: AssignmentRefereeRoleDb\" 
+            "SELECT status as \"status: AssignmentStatusDb\", fixture_id, referee_id, referee_role as \"referee_role: AssignmentRefereeRoleDb\", review_reason 
             FROM rustddd.assignments WHERE status = 'staged'"
         )
         .fetch_all(&mut **tx_ctx)
@@ -111,10 +119,24 @@ impl AssignmentRepository for AssignmentRepositoryPg {
         Ok(assignments.into_iter().map(|a| a.into()).collect())
     }
 
+    async fn find_all_committed_by_referee(&self, referee_id: RefereeId, tx_ctx: &mut Self::TxCtx) -> Result<Vec<Assignment>, Self::Error> {
+        let assignments = sqlx::query_as!(
+            AssignmentDb,
+            "SELECT status as \"status: AssignmentStatusDb\", fixture_id, referee_id, referee_role as \"referee_role: AssignmentRefereeRoleDb\", review_reason 
+            FROM rustddd.assignments WHERE status IN ('committed', 'confirmed') AND referee_id = $1",
+            referee_id.0
+        )
+        .fetch_all(&mut **tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(assignments.into_iter().map(|a| a.into()).collect())
+    }
+
     async fn find_by_fixture_and_referee(&self, fixture_id: FixtureId, referee_id: RefereeId, tx_ctx: &mut Self::TxCtx) -> Result<Option<Assignment>, Self::Error> {
         let assignment = sqlx::query_as!(
             AssignmentDb,
-            "SELECT status as \"status: AssignmentStatusDb\", fixture_id, referee_id, referee_role as \"referee_role: AssignmentRefereeRoleDb\" 
+            "SELECT status as \"status: AssignmentStatusDb\", fixture_id, referee_id, referee_role as \"referee_role: AssignmentRefereeRoleDb\", review_reason 
             FROM rustddd.assignments WHERE fixture_id = $1 AND referee_id = $2",
             fixture_id.0,
             referee_id.0
@@ -143,14 +165,15 @@ impl AssignmentRepository for AssignmentRepositoryPg {
         let referee_role: AssignmentRefereeRoleDb = assignment.referee_role().into();
         let status: AssignmentStatusDb = assignment.status().into();
         sqlx::query!(
-            "INSERT INTO rustddd.assignments (status, fixture_id, referee_id, referee_role) 
-            VALUES ($1, $2, $3, $4)
+            "INSERT INTO rustddd.assignments (status, fixture_id, referee_id, referee_role, review_reason) 
+            VALUES ($1, $2, $3, $4, $5)
             ON CONFLICT (fixture_id, referee_id) 
-            DO UPDATE SET referee_role = $4, status = $1",
+            DO UPDATE SET referee_role = $4, status = $1, review_reason = $5",
             status as AssignmentStatusDb,
             assignment.fixture_id().0,
             assignment.referee_id().0,
-            referee_role as AssignmentRefereeRoleDb
+            referee_role as AssignmentRefereeRoleDb,
+            assignment.review_reason()
             )
         .execute(&mut **tx_ctx)
         .await
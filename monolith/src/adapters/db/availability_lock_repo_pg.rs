@@ -0,0 +1,124 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::domain::{
+    aggregates::{
+        availability_lock::{AvailabilityLock, AvailabilityLockId, AvailabilityLockScope},
+        fixture::FixtureId,
+    },
+    repositories::availability_lock_repo::AvailabilityLockRepository,
+};
+
+pub struct AvailabilityLockRepositoryPg();
+
+// NOTE: a fixture lock only has the fixture_id set, a date range lock only from_date and to_date
+struct AvailabilityLockDb {
+    pub id: Uuid,
+    pub fixture_id: Option<Uuid>,
+    pub from_date: Option<DateTime<Utc>>,
+    pub to_date: Option<DateTime<Utc>>,
+}
+
+impl From<AvailabilityLockDb> for AvailabilityLock {
+    fn from(lock: AvailabilityLockDb) -> Self {
+        let scope = match lock.fixture_id {
+            Some(fixture_id) => AvailabilityLockScope::Fixture(FixtureId::from(fixture_id)),
+            None => AvailabilityLockScope::DateRange {
+                from: lock.from_date.expect("Date range lock without from date"),
+                to: lock.to_date.expect("Date range lock without to date"),
+            },
+        };
+
+        AvailabilityLock::from_id(AvailabilityLockId::from(lock.id), scope)
+    }
+}
+
+impl AvailabilityLockRepositoryPg {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl AvailabilityLockRepository for AvailabilityLockRepositoryPg {
+    type Error = String;
+    type TxCtx = Transaction<'static, Postgres>;
+
+    async fn find_by_id(
+        &self,
+        lock_id: AvailabilityLockId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<AvailabilityLock>, Self::Error> {
+        let lock: Option<AvailabilityLockDb> = sqlx::query_as!(
+            AvailabilityLockDb,
+            "SELECT id, fixture_id, from_date, to_date
+            FROM rustddd.availability_locks
+            WHERE id = $1",
+            lock_id.0
+        )
+        .fetch_optional(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("AvailabilityLockRepositoryPg::find_by_id failed with {}", e))?;
+
+        Ok(lock.map(|l| l.into()))
+    }
+
+    async fn get_all(
+        &self,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<AvailabilityLock>, Self::Error> {
+        let locks: Vec<AvailabilityLockDb> = sqlx::query_as!(
+            AvailabilityLockDb,
+            "SELECT id, fixture_id, from_date, to_date
+            FROM rustddd.availability_locks"
+        )
+        .fetch_all(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("AvailabilityLockRepositoryPg::get_all failed with {}", e))?;
+
+        Ok(locks.into_iter().map(|l| l.into()).collect())
+    }
+
+    async fn save(
+        &self,
+        lock: &AvailabilityLock,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<(), Self::Error> {
+        let (fixture_id, from_date, to_date) = match lock.scope() {
+            AvailabilityLockScope::Fixture(fixture_id) => (Some(fixture_id.0), None, None),
+            AvailabilityLockScope::DateRange { from, to } => (None, Some(from), Some(to)),
+        };
+
+        // NOTE: a lock never changes, it is only created and deleted
+        sqlx::query!(
+            "INSERT INTO rustddd.availability_locks (id, fixture_id, from_date, to_date)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (id) DO NOTHING",
+            lock.id().0,
+            fixture_id,
+            from_date,
+            to_date
+        )
+        .execute(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("AvailabilityLockRepositoryPg::save failed with {}", e))?;
+
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        lock_id: AvailabilityLockId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<(), Self::Error> {
+        sqlx::query!(
+            "DELETE FROM rustddd.availability_locks WHERE id = $1",
+            lock_id.0
+        )
+        .execute(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("AvailabilityLockRepositoryPg::delete failed with {}", e))?;
+
+        Ok(())
+    }
+}
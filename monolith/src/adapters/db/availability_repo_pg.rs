@@ -45,6 +45,38 @@ impl AvailabilityRepository for AvailabilityRepositoryPg {
         Ok(())
     }
 
+    async fn declare_availabilities(&self, fixtures: &[FixtureId], referee: &Referee, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error> {
+        let fixture_ids: Vec<Uuid> = fixtures.iter().map(|f| f.0).collect();
+
+        sqlx::query!(
+            "INSERT INTO rustddd.availabilities (fixture_id, referee_id)
+            SELECT fixture_id, $2 FROM UNNEST($1::UUID[]) AS fixture_id
+            ON CONFLICT (fixture_id, referee_id) DO NOTHING",
+            &fixture_ids,
+            referee.id().0
+        )
+        .execute(&mut **tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    async fn withdraw_availabilities(&self, fixtures: &[FixtureId], referee: &Referee, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error> {
+        let fixture_ids: Vec<Uuid> = fixtures.iter().map(|f| f.0).collect();
+
+        sqlx::query!(
+            "DELETE FROM rustddd.availabilities WHERE fixture_id = ANY($1) AND referee_id = $2",
+            &fixture_ids,
+            referee.id().0
+        )
+        .execute(&mut **tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
     async fn get_availabilities_for_referee(&self, referee: &Referee, tx_ctx: &mut Self::TxCtx) -> Result<Vec<FixtureId>, Self::Error> {
         let availabilities = sqlx::query_as!(
             AvailabilityDb,
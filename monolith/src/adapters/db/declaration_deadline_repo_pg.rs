@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::domain::{
+    aggregates::{declaration_deadline::DeclarationDeadline, fixture::FixtureId},
+    repositories::declaration_deadline_repo::DeclarationDeadlineRepository,
+};
+
+pub struct DeclarationDeadlineRepositoryPg();
+
+struct DeclarationDeadlineDb {
+    pub fixture_id: Uuid,
+    pub deadline: DateTime<Utc>,
+}
+
+impl From<DeclarationDeadlineDb> for DeclarationDeadline {
+    fn from(deadline: DeclarationDeadlineDb) -> Self {
+        DeclarationDeadline::new(FixtureId::from(deadline.fixture_id), deadline.deadline)
+    }
+}
+
+impl DeclarationDeadlineRepositoryPg {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl DeclarationDeadlineRepository for DeclarationDeadlineRepositoryPg {
+    type Error = String;
+    type TxCtx = Transaction<'static, Postgres>;
+
+    async fn find_by_fixture(
+        &self,
+        fixture_id: FixtureId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<DeclarationDeadline>, Self::Error> {
+        let deadline: Option<DeclarationDeadlineDb> = sqlx::query_as!(
+            DeclarationDeadlineDb,
+            "SELECT fixture_id, deadline
+            FROM rustddd.declaration_deadlines
+            WHERE fixture_id = $1",
+            fixture_id.0
+        )
+        .fetch_optional(&mut **tx_ctx)
+        .await
+        .map_err(|e| {
+            format!(
+                "DeclarationDeadlineRepositoryPg::find_by_fixture failed with {}",
+                e
+            )
+        })?;
+
+        Ok(deadline.map(|d| d.into()))
+    }
+
+    async fn save(
+        &self,
+        deadline: &DeclarationDeadline,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<(), Self::Error> {
+        sqlx::query!(
+            "INSERT INTO rustddd.declaration_deadlines (fixture_id, deadline) VALUES ($1, $2)
+            ON CONFLICT (fixture_id)
+            DO UPDATE SET deadline = $2",
+            deadline.fixture_id().0,
+            deadline.deadline()
+        )
+        .execute(&mut **tx_ctx)
+        .await
+        .map_err(|e| format!("DeclarationDeadlineRepositoryPg::save failed with {}", e))?;
+
+        Ok(())
+    }
+}
@@ -5,11 +5,14 @@ use uuid::Uuid;
 
 use crate::domain::{
     aggregates::{
-        fixture::{Fixture, FixtureId, FixtureStatus},
+        fixture::{Fixture, FixtureId, FixtureResult, FixtureStatus, SetScore},
+        league::{League, LeagueId},
         referee::Referee,
+        season::{Season, SeasonId},
         team::{Team, TeamId},
         venue::{Venue, VenueId},
     },
+    geo_location::GeoLocation,
     repositories::fixture_repo::FixtureRepository,
 };
 
@@ -19,6 +22,8 @@ pub struct FixtureRepositoryPg();
 #[sqlx(type_name = "rustddd.fixture_status", rename_all = "lowercase")]
 enum FixtureStatusDb {
     Scheduled,
+    Postponed,
+    Played,
     Cancelled,
 }
 
@@ -27,6 +32,16 @@ struct FixtureDb {
     pub id: Uuid,
     pub date: DateTime<Utc>,
     pub status: FixtureStatusDb,
+    pub first_referee_min_level: i32,
+    pub second_referee_min_level: i32,
+    pub result_home_points: Option<Vec<i32>>,
+    pub result_away_points: Option<Vec<i32>>,
+    pub result_winner_id: Option<Uuid>,
+    pub league_id: Uuid,
+    pub league_name: String,
+    pub season_id: Uuid,
+    pub season_name: String,
+    pub season_closed: bool,
     pub venue_id: Uuid,
     pub venue_name: String,
     pub venue_street: String,
@@ -34,18 +49,31 @@ struct FixtureDb {
     pub venue_city: String,
     pub venue_telephone: Option<String>,
     pub venue_email: Option<String>,
+    pub venue_latitude: Option<f64>,
+    pub venue_longitude: Option<f64>,
+    pub venue_archived: bool,
     pub team_home_id: Uuid,
     pub team_home_name: String,
     pub team_home_club: String,
+    pub team_home_archived: bool,
     pub team_away_id: Uuid,
     pub team_away_name: String,
     pub team_away_club: String,
+    pub team_away_archived: bool,
     pub first_referee_id: Option<Uuid>,
     pub first_referee_name: Option<String>,
     pub first_referee_club: Option<String>,
+    pub first_referee_qualification_level: Option<i32>,
+    pub first_referee_home_latitude: Option<f64>,
+    pub first_referee_home_longitude: Option<f64>,
+    pub first_referee_archived: Option<bool>,
     pub second_referee_id: Option<Uuid>,
     pub second_referee_name: Option<String>,
     pub second_referee_club: Option<String>,
+    pub second_referee_qualification_level: Option<i32>,
+    pub second_referee_home_latitude: Option<f64>,
+    pub second_referee_home_longitude: Option<f64>,
+    pub second_referee_archived: Option<bool>,
 }
 
 impl FixtureRepositoryPg {
@@ -58,6 +86,8 @@ impl From<FixtureStatusDb> for FixtureStatus {
     fn from(status: FixtureStatusDb) -> Self {
         match status {
             FixtureStatusDb::Scheduled => FixtureStatus::Scheduled,
+            FixtureStatusDb::Postponed => FixtureStatus::Postponed,
+            FixtureStatusDb::Played => FixtureStatus::Played,
             FixtureStatusDb::Cancelled => FixtureStatus::Cancelled,
         }
     }
@@ -67,6 +97,8 @@ impl From<FixtureStatus> for FixtureStatusDb {
     fn from(status: FixtureStatus) -> Self {
         match status {
             FixtureStatus::Scheduled => FixtureStatusDb::Scheduled,
+            FixtureStatus::Postponed => FixtureStatusDb::Postponed,
+            FixtureStatus::Played => FixtureStatusDb::Played,
             FixtureStatus::Cancelled => FixtureStatusDb::Cancelled,
         }
     }
@@ -74,10 +106,34 @@ impl From<FixtureStatus> for FixtureStatusDb {
 
 impl From<FixtureDb> for Fixture {
     fn from(fixture: FixtureDb) -> Self {
+        // NOTE: the set scores of a result are stored as two arrays of the same length, one per team
+        let result = fixture.result_winner_id.map(|winner_id| {
+            let home_points = fixture
+                .result_home_points
+                .expect("result_home_points is required");
+            let away_points = fixture
+                .result_away_points
+                .expect("result_away_points is required");
+            FixtureResult::new(
+                home_points
+                    .into_iter()
+                    .zip(away_points)
+                    .map(|(home, away)| SetScore { home, away })
+                    .collect(),
+                TeamId::from(winner_id),
+            )
+        });
+
         Fixture::from_id(
             FixtureId::from(fixture.id),
             fixture.date,
             fixture.status.into(),
+            League::from_id(LeagueId::from(fixture.league_id), fixture.league_name),
+            Season::from_id(
+                SeasonId::from(fixture.season_id),
+                fixture.season_name,
+                fixture.season_closed,
+            ),
             Venue::from_id(
                 VenueId::from(fixture.venue_id),
                 fixture.venue_name,
@@ -86,16 +142,20 @@ impl From<FixtureDb> for Fixture {
                 fixture.venue_city,
                 fixture.venue_telephone,
                 fixture.venue_email,
+                GeoLocation::from_coordinates(fixture.venue_latitude, fixture.venue_longitude),
+                fixture.venue_archived,
             ),
             Team::from_id(
                 TeamId::from(fixture.team_home_id),
                 fixture.team_home_name,
                 fixture.team_home_club,
+                fixture.team_home_archived,
             ),
             Team::from_id(
                 TeamId::from(fixture.team_away_id),
                 fixture.team_away_name,
                 fixture.team_away_club,
+                fixture.team_away_archived,
             ),
             fixture.first_referee_id.map(|id| {
                 Referee::from_id(
@@ -106,6 +166,16 @@ impl From<FixtureDb> for Fixture {
                     fixture
                         .first_referee_club
                         .expect("first_referee_club is required"),
+                    fixture
+                        .first_referee_qualification_level
+                        .expect("first_referee_qualification_level is required"),
+                    GeoLocation::from_coordinates(
+                        fixture.first_referee_home_latitude,
+                        fixture.first_referee_home_longitude,
+                    ),
+                    fixture
+                        .first_referee_archived
+                        .expect("first_referee_archived is required"),
                 )
             }),
             fixture.second_referee_id.map(|id| {
@@ -117,8 +187,21 @@ impl From<FixtureDb> for Fixture {
                     fixture
                         .second_referee_club
                         .expect("second_referee_club is required"),
+                    fixture
+                        .second_referee_qualification_level
+                        .expect("second_referee_qualification_level is required"),
+                    GeoLocation::from_coordinates(
+                        fixture.second_referee_home_latitude,
+                        fixture.second_referee_home_longitude,
+                    ),
+                    fixture
+                        .second_referee_archived
+                        .expect("second_referee_archived is required"),
                 )
             }),
+            fixture.first_referee_min_level,
+            fixture.second_referee_min_level,
+            result,
         )
     }
 }
@@ -135,13 +218,17 @@ impl FixtureRepository for FixtureRepositoryPg {
         // NOTE: need to force nullable for referees, see https://docs.rs/sqlx/0.4.2/sqlx/macro.query.html#force-nullable
         let fixture: Option<FixtureDb> = sqlx::query_as!(
             FixtureDb,
-            "SELECT f.fixture_id as id, f.date, f.status as \"status: FixtureStatusDb\",
-                v.venue_id as venue_id, v.name as venue_name, v.street as venue_street, v.zip as venue_zip, v.city as venue_city, v.telephone as venue_telephone, v.email as venue_email,
-                th.team_id as team_home_id, th.name as team_home_name, th.club as team_home_club,
-                ta.team_id as team_away_id, ta.name as team_away_name, ta.club as team_away_club,
-                r1.referee_id as \"first_referee_id?\", r1.name as \"first_referee_name?\", r1.club as \"first_referee_club?\",
-                r2.referee_id as \"second_referee_id?\", r2.name as \"second_referee_name?\", r2.club as \"second_referee_club?\"
+            "SELECT f.fixture_id as id, f.date, f.status as \"status: FixtureStatusDb\", f.first_referee_min_level, f.second_referee_min_level,
+                f.result_home_points, f.result_away_points, f.result_winner_id,
+                l.league_id as league_id, l.name as league_name, s.season_id as season_id, s.name as season_name, s.closed as season_closed,
+                v.venue_id as venue_id, v.name as venue_name, v.street as venue_street, v.zip as venue_zip, v.city as venue_city, v.telephone as venue_telephone, v.email as venue_email, v.latitude as venue_latitude, v.longitude as venue_longitude, v.archived as venue_archived,
+                th.team_id as team_home_id, th.name as team_home_name, th.club as team_home_club, th.archived as team_home_archived,
+                ta.team_id as team_away_id, ta.name as team_away_name, ta.club as team_away_club, ta.archived as team_away_archived,
+                r1.referee_id as \"first_referee_id?\", r1.name as \"first_referee_name?\", r1.club as \"first_referee_club?\", r1.qualification_level as \"first_referee_qualification_level?\", r1.home_latitude as \"first_referee_home_latitude?\", r1.home_longitude as \"first_referee_home_longitude?\", r1.archived as \"first_referee_archived?\",
+                r2.referee_id as \"second_referee_id?\", r2.name as \"second_referee_name?\", r2.club as \"second_referee_club?\", r2.qualification_level as \"second_referee_qualification_level?\", r2.home_latitude as \"second_referee_home_latitude?\", r2.home_longitude as \"second_referee_home_longitude?\", r2.archived as \"second_referee_archived?\"
             FROM rustddd.fixtures f
+            JOIN rustddd.leagues l ON l.league_id = f.league_id
+            JOIN rustddd.seasons s ON s.season_id = f.season_id
             JOIN rustddd.venues v ON v.venue_id = f.venue_id
             JOIN rustddd.teams th ON th.team_id = f.team_home_id
             JOIN rustddd.teams ta ON ta.team_id = f.team_away_id
@@ -161,19 +248,59 @@ impl FixtureRepository for FixtureRepositoryPg {
     async fn get_all(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<Fixture>, Self::Error> {
         let fixtures: Vec<FixtureDb> = sqlx::query_as!(
             FixtureDb,
-            "SELECT f.fixture_id as id, f.date, f.status as \"status: FixtureStatusDb\",
-                v.venue_id as venue_id, v.name as venue_name, v.street as venue_street, v.zip as venue_zip, v.city as venue_city, v.telephone as venue_telephone, v.email as venue_email,
-                th.team_id as team_home_id, th.name as team_home_name, th.club as team_home_club,
-                ta.team_id as team_away_id, ta.name as team_away_name, ta.club as team_away_club,
-                r1.referee_id as \"first_referee_id?\", r1.name as \"first_referee_name?\", r1.club as \"first_referee_club?\",
-                r2.referee_id as \"second_referee_id?\", r2.name as \"second_referee_name?\", r2.club as \"second_referee_club?\"
+            "SELECT f.fixture_id as id, f.date, f.status as \"status: FixtureStatusDb\", f.first_referee_min_level, f.second_referee_min_level,
+                f.result_home_points, f.result_away_points, f.result_winner_id,
+                l.league_id as league_id, l.name as league_name, s.season_id as season_id, s.name as season_name, s.closed as season_closed,
+                v.venue_id as venue_id, v.name as venue_name, v.street as venue_street, v.zip as venue_zip, v.city as venue_city, v.telephone as venue_telephone, v.email as venue_email, v.latitude as venue_latitude, v.longitude as venue_longitude, v.archived as venue_archived,
+                th.team_id as team_home_id, th.name as team_home_name, th.club as team_home_club, th.archived as team_home_archived,
+                ta.team_id as team_away_id, ta.name as team_away_name, ta.club as team_away_club, ta.archived as team_away_archived,
+                r1.referee_id as \"first_referee_id?\", r1.name as \"first_referee_name?\", r1.club as \"first_referee_club?\", r1.qualification_level as \"first_referee_qualification_level?\", r1.home_latitude as \"first_referee_home_latitude?\", r1.home_longitude as \"first_referee_home_longitude?\", r1.archived as \"first_referee_archived?\",
+                r2.referee_id as \"second_referee_id?\", r2.name as \"second_referee_name?\", r2.club as \"second_referee_club?\", r2.qualification_level as \"second_referee_qualification_level?\", r2.home_latitude as \"second_referee_home_latitude?\", r2.home_longitude as \"second_referee_home_longitude?\", r2.archived as \"second_referee_archived?\"
+            FROM rustddd.fixtures f
+            JOIN rustddd.leagues l ON l.league_id = f.league_id
+            JOIN rustddd.seasons s ON s.season_id = f.season_id
+            JOIN rustddd.venues v ON v.venue_id = f.venue_id
+            JOIN rustddd.teams th ON th.team_id = f.team_home_id
+            JOIN rustddd.teams ta ON ta.team_id = f.team_away_id
+            LEFT JOIN rustddd.referees r1 ON r1.referee_id = f.first_referee_id
+            LEFT JOIN rustddd.referees r2 ON r2.referee_id = f.second_referee_id
+            ORDER BY f.date ASC",
+        )
+        .fetch_all(&mut **tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(fixtures.into_iter().map(Fixture::from).collect())
+    }
+
+    async fn find_by_league_and_season(
+        &self,
+        league_id: Option<LeagueId>,
+        season_id: Option<SeasonId>,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<Fixture>, Self::Error> {
+        let fixtures: Vec<FixtureDb> = sqlx::query_as!(
+            FixtureDb,
+            "SELECT f.fixture_id as id, f.date, f.status as \"status: FixtureStatusDb\", f.first_referee_min_level, f.second_referee_min_level,
+                f.result_home_points, f.result_away_points, f.result_winner_id,
+                l.league_id as league_id, l.name as league_name, s.season_id as season_id, s.name as season_name, s.closed as season_closed,
+                v.venue_id as venue_id, v.name as venue_name, v.street as venue_street, v.zip as venue_zip, v.city as venue_city, v.telephone as venue_telephone, v.email as venue_email, v.latitude as venue_latitude, v.longitude as venue_longitude, v.archived as venue_archived,
+                th.team_id as team_home_id, th.name as team_home_name, th.club as team_home_club, th.archived as team_home_archived,
+                ta.team_id as team_away_id, ta.name as team_away_name, ta.club as team_away_club, ta.archived as team_away_archived,
+                r1.referee_id as \"first_referee_id?\", r1.name as \"first_referee_name?\", r1.club as \"first_referee_club?\", r1.qualification_level as \"first_referee_qualification_level?\", r1.home_latitude as \"first_referee_home_latitude?\", r1.home_longitude as \"first_referee_home_longitude?\", r1.archived as \"first_referee_archived?\",
+                r2.referee_id as \"second_referee_id?\", r2.name as \"second_referee_name?\", r2.club as \"second_referee_club?\", r2.qualification_level as \"second_referee_qualification_level?\", r2.home_latitude as \"second_referee_home_latitude?\", r2.home_longitude as \"second_referee_home_longitude?\", r2.archived as \"second_referee_archived?\"
             FROM rustddd.fixtures f
+            JOIN rustddd.leagues l ON l.league_id = f.league_id
+            JOIN rustddd.seasons s ON s.season_id = f.season_id
             JOIN rustddd.venues v ON v.venue_id = f.venue_id
             JOIN rustddd.teams th ON th.team_id = f.team_home_id
             JOIN rustddd.teams ta ON ta.team_id = f.team_away_id
             LEFT JOIN rustddd.referees r1 ON r1.referee_id = f.first_referee_id
             LEFT JOIN rustddd.referees r2 ON r2.referee_id = f.second_referee_id
+            WHERE ($1::uuid IS NULL OR f.league_id = $1) AND ($2::uuid IS NULL OR f.season_id = $2)
             ORDER BY f.date ASC",
+            league_id.map(|l| l.0),
+            season_id.map(|s| s.0),
         )
         .fetch_all(&mut **tx_ctx)
         .await
@@ -200,13 +327,17 @@ impl FixtureRepository for FixtureRepositoryPg {
 
         let fixtures: Vec<FixtureDb> = sqlx::query_as!(
             FixtureDb,
-            "SELECT f.fixture_id as id, f.date, f.status as \"status: FixtureStatusDb\",
-                v.venue_id as venue_id, v.name as venue_name, v.street as venue_street, v.zip as venue_zip, v.city as venue_city, v.telephone as venue_telephone, v.email as venue_email,
-                th.team_id as team_home_id, th.name as team_home_name, th.club as team_home_club,
-                ta.team_id as team_away_id, ta.name as team_away_name, ta.club as team_away_club,
-                r1.referee_id as first_referee_id, r1.name as first_referee_name, r1.club as first_referee_club,
-                r2.referee_id as second_referee_id, r2.name as second_referee_name, r2.club as second_referee_club
+            "SELECT f.fixture_id as id, f.date, f.status as \"status: FixtureStatusDb\", f.first_referee_min_level, f.second_referee_min_level,
+                f.result_home_points, f.result_away_points, f.result_winner_id,
+                l.league_id as league_id, l.name as league_name, s.season_id as season_id, s.name as season_name, s.closed as season_closed,
+                v.venue_id as venue_id, v.name as venue_name, v.street as venue_street, v.zip as venue_zip, v.city as venue_city, v.telephone as venue_telephone, v.email as venue_email, v.latitude as venue_latitude, v.longitude as venue_longitude, v.archived as venue_archived,
+                th.team_id as team_home_id, th.name as team_home_name, th.club as team_home_club, th.archived as team_home_archived,
+                ta.team_id as team_away_id, ta.name as team_away_name, ta.club as team_away_club, ta.archived as team_away_archived,
+                r1.referee_id as first_referee_id, r1.name as first_referee_name, r1.club as first_referee_club, r1.qualification_level as first_referee_qualification_level, r1.home_latitude as first_referee_home_latitude, r1.home_longitude as first_referee_home_longitude, r1.archived as first_referee_archived,
+                r2.referee_id as second_referee_id, r2.name as second_referee_name, r2.club as second_referee_club, r2.qualification_level as second_referee_qualification_level, r2.home_latitude as second_referee_home_latitude, r2.home_longitude as second_referee_home_longitude, r2.archived as second_referee_archived
             FROM rustddd.fixtures f
+            JOIN rustddd.leagues l ON l.league_id = f.league_id
+            JOIN rustddd.seasons s ON s.season_id = f.season_id
             JOIN rustddd.venues v ON v.venue_id = f.venue_id
             JOIN rustddd.teams th ON th.team_id = f.team_home_id
             JOIN rustddd.teams ta ON ta.team_id = f.team_away_id
@@ -243,13 +374,17 @@ impl FixtureRepository for FixtureRepositoryPg {
 
         let fixtures: Vec<FixtureDb> = sqlx::query_as!(
             FixtureDb,
-            "SELECT f.fixture_id as id, f.date, f.status as \"status: FixtureStatusDb\",
-                v.venue_id as venue_id, v.name as venue_name, v.street as venue_street, v.zip as venue_zip, v.city as venue_city, v.telephone as venue_telephone, v.email as venue_email,
-                th.team_id as team_home_id, th.name as team_home_name, th.club as team_home_club,
-                ta.team_id as team_away_id, ta.name as team_away_name, ta.club as team_away_club,
-                r1.referee_id as first_referee_id, r1.name as first_referee_name, r1.club as first_referee_club,
-                r2.referee_id as second_referee_id, r2.name as second_referee_name, r2.club as second_referee_club
+            "SELECT f.fixture_id as id, f.date, f.status as \"status: FixtureStatusDb\", f.first_referee_min_level, f.second_referee_min_level,
+                f.result_home_points, f.result_away_points, f.result_winner_id,
+                l.league_id as league_id, l.name as league_name, s.season_id as season_id, s.name as season_name, s.closed as season_closed,
+                v.venue_id as venue_id, v.name as venue_name, v.street as venue_street, v.zip as venue_zip, v.city as venue_city, v.telephone as venue_telephone, v.email as venue_email, v.latitude as venue_latitude, v.longitude as venue_longitude, v.archived as venue_archived,
+                th.team_id as team_home_id, th.name as team_home_name, th.club as team_home_club, th.archived as team_home_archived,
+                ta.team_id as team_away_id, ta.name as team_away_name, ta.club as team_away_club, ta.archived as team_away_archived,
+                r1.referee_id as first_referee_id, r1.name as first_referee_name, r1.club as first_referee_club, r1.qualification_level as first_referee_qualification_level, r1.home_latitude as first_referee_home_latitude, r1.home_longitude as first_referee_home_longitude, r1.archived as first_referee_archived,
+                r2.referee_id as second_referee_id, r2.name as second_referee_name, r2.club as second_referee_club, r2.qualification_level as second_referee_qualification_level, r2.home_latitude as second_referee_home_latitude, r2.home_longitude as second_referee_home_longitude, r2.archived as second_referee_archived
             FROM rustddd.fixtures f
+            JOIN rustddd.leagues l ON l.league_id = f.league_id
+            JOIN rustddd.seasons s ON s.season_id = f.season_id
             JOIN rustddd.venues v ON v.venue_id = f.venue_id
             JOIN rustddd.teams th ON th.team_id = f.team_home_id
             JOIN rustddd.teams ta ON ta.team_id = f.team_away_id
@@ -272,12 +407,19 @@ impl FixtureRepository for FixtureRepositoryPg {
         let status: FixtureStatusDb = FixtureStatusDb::from(fixture.status().clone());
         let first_referee_id = fixture.first_referee().map(|r| r.id().0);
         let second_referee_id = fixture.second_referee().map(|r| r.id().0);
-        // NOTE: we do an upsert that only updates the stuff that is allowed to change: cancelled, date, venue_id, first_referee_id, second_referee_id
+        let result_home_points: Option<Vec<i32>> = fixture
+            .result()
+            .map(|r| r.sets().iter().map(|s| s.home).collect());
+        let result_away_points: Option<Vec<i32>> = fixture
+            .result()
+            .map(|r| r.sets().iter().map(|s| s.away).collect());
+        let result_winner_id = fixture.result().map(|r| r.winner().0);
+        // NOTE: we do an upsert that only updates the stuff that is allowed to change: cancelled, date, venue_id, first_referee_id, second_referee_id, result
         sqlx::query!(
-            "INSERT INTO rustddd.fixtures (fixture_id, date, venue_id, team_home_id, team_away_id, status, first_referee_id, second_referee_id) 
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "INSERT INTO rustddd.fixtures (fixture_id, date, venue_id, team_home_id, team_away_id, status, first_referee_id, second_referee_id, first_referee_min_level, second_referee_min_level, league_id, season_id, result_home_points, result_away_points, result_winner_id) 
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
             ON CONFLICT (fixture_id) 
-            DO UPDATE SET date = $2, venue_id = $3, status = $6, first_referee_id = $7, second_referee_id = $8",
+            DO UPDATE SET date = $2, venue_id = $3, status = $6, first_referee_id = $7, second_referee_id = $8, result_home_points = $13, result_away_points = $14, result_winner_id = $15",
             fixture.id().0,
             fixture.date(),
             fixture.venue().id().0,
@@ -285,7 +427,14 @@ impl FixtureRepository for FixtureRepositoryPg {
             fixture.team_away().id().0,
             status as FixtureStatusDb,
             first_referee_id,
-            second_referee_id
+            second_referee_id,
+            fixture.first_referee_min_level(),
+            fixture.second_referee_min_level(),
+            fixture.league().id().0,
+            fixture.season().id().0,
+            result_home_points.as_deref(),
+            result_away_points.as_deref(),
+            result_winner_id
         )
         .execute(&mut **tx_ctx)
         .await
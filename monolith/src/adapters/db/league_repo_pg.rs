@@ -0,0 +1,78 @@
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::domain::{
+    aggregates::league::{League, LeagueId},
+    repositories::league_repo::LeagueRepository,
+};
+
+pub struct LeagueRepositoryPg();
+
+struct LeagueDb {
+    pub id: Uuid,
+    pub name: String,
+}
+
+impl From<LeagueDb> for League {
+    fn from(league: LeagueDb) -> Self {
+        League::from_id(LeagueId::from(league.id), league.name)
+    }
+}
+
+impl LeagueRepositoryPg {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl LeagueRepository for LeagueRepositoryPg {
+    type Error = String;
+    type TxCtx = Transaction<'static, Postgres>;
+
+    async fn find_by_id(
+        &self,
+        league_id: LeagueId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<League>, Self::Error> {
+        let league: Option<LeagueDb> = sqlx::query_as!(
+            LeagueDb,
+            "SELECT league_id as id, name 
+            FROM rustddd.leagues 
+            WHERE league_id = $1",
+            league_id.0
+        )
+        .fetch_optional(&mut **tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(league.map(|l| l.into()))
+    }
+
+    async fn get_all(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<League>, Self::Error> {
+        let leagues: Vec<LeagueDb> = sqlx::query_as!(
+            LeagueDb,
+            "SELECT league_id as id, name 
+            FROM rustddd.leagues
+            ORDER BY name ASC"
+        )
+        .fetch_all(&mut **tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(leagues.into_iter().map(|l| l.into()).collect())
+    }
+
+    async fn save(&self, league: &League, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error> {
+        // NOTE: no upsert, because League is not allowed to change after creation
+        sqlx::query!(
+            "INSERT INTO rustddd.leagues (league_id, name) VALUES ($1, $2)",
+            league.id().0,
+            league.name().to_string()
+        )
+        .execute(&mut **tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+}
@@ -12,11 +12,12 @@ struct TeamDb {
     pub id: Uuid,
     pub name: String,
     pub club: String,
+    pub archived: bool,
 }
 
 impl From<TeamDb> for Team {
     fn from(team: TeamDb) -> Self {
-        Team::from_id(TeamId::from(team.id), team.name, team.club)
+        Team::from_id(TeamId::from(team.id), team.name, team.club, team.archived)
     }
 }
 
@@ -37,7 +38,7 @@ impl TeamRepository for TeamRepositoryPg {
     ) -> Result<Option<Team>, Self::Error> {
         let team: Option<TeamDb> = sqlx::query_as!(
             TeamDb,
-            "SELECT team_id as id, name, club 
+            "SELECT team_id as id, name, club, archived 
             FROM rustddd.teams 
             WHERE team_id = $1",
             team_id.0
@@ -52,7 +53,7 @@ impl TeamRepository for TeamRepositoryPg {
     async fn get_all(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<Team>, Self::Error> {
         let teams: Vec<TeamDb> = sqlx::query_as!(
             TeamDb,
-            "SELECT team_id as id, name, club 
+            "SELECT team_id as id, name, club, archived 
             FROM rustddd.teams
             ORDER BY name ASC"
         )
@@ -64,12 +65,13 @@ impl TeamRepository for TeamRepositoryPg {
     }
 
     async fn save(&self, team: &Team, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error> {
-        // NOTE: no upsert, because Team is not allowed to change after creation
         sqlx::query!(
-            "INSERT INTO rustddd.teams (team_id, name, club) VALUES ($1, $2, $3)",
+            "INSERT INTO rustddd.teams (team_id, name, club, archived) VALUES ($1, $2, $3, $4)
+            ON CONFLICT (team_id) DO UPDATE SET name = $2, club = $3, archived = $4",
             team.id().0,
             team.name().to_string(),
-            team.club().to_string()
+            team.club().to_string(),
+            team.is_archived()
         )
         .execute(&mut **tx_ctx)
         .await
@@ -3,6 +3,7 @@ use uuid::Uuid;
 
 use crate::domain::{
     aggregates::venue::{Venue, VenueId},
+    geo_location::GeoLocation,
     repositories::venue_repo::VenueRepository,
 };
 
@@ -14,6 +15,9 @@ struct VenueDb {
     city: String,
     telephone: Option<String>,
     email: Option<String>,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+    archived: bool,
 }
 
 pub struct VenueRepositoryPg {}
@@ -34,6 +38,8 @@ impl From<VenueDb> for Venue {
             venue.city,
             venue.telephone,
             venue.email,
+            GeoLocation::from_coordinates(venue.latitude, venue.longitude),
+            venue.archived,
         )
     }
 }
@@ -49,7 +55,7 @@ impl VenueRepository for VenueRepositoryPg {
     ) -> Result<Option<Venue>, Self::Error> {
         let venue: Option<VenueDb> = sqlx::query_as!(
                 VenueDb,
-            "SELECT venue_id as id, name, street, zip, city, telephone, email
+            "SELECT venue_id as id, name, street, zip, city, telephone, email, latitude, longitude, archived
             FROM rustddd.venues 
             WHERE venue_id = $1",
             venue_id.0
@@ -64,7 +70,7 @@ impl VenueRepository for VenueRepositoryPg {
     async fn get_all(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<Venue>, Self::Error> {
         let venues: Vec<VenueDb> = sqlx::query_as!(
             VenueDb,
-            "SELECT venue_id as id, name, street, zip, city, telephone, email 
+            "SELECT venue_id as id, name, street, zip, city, telephone, email, latitude, longitude, archived 
             FROM rustddd.venues
             ORDER BY name ASC"
         )
@@ -76,16 +82,21 @@ impl VenueRepository for VenueRepositoryPg {
     }
 
     async fn save(&self, venue: &Venue, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error> {
-        // NOTE: no upsert, because Venue is not allowed to change after creation
+        // NOTE: we do an upsert, which is not updating the name, because the name identifies the venue for the users
         let _result = sqlx::query!(
-            "INSERT INTO rustddd.venues (venue_id, name, street, zip, city, telephone, email) VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING *",
+            "INSERT INTO rustddd.venues (venue_id, name, street, zip, city, telephone, email, latitude, longitude, archived) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (venue_id) DO UPDATE SET street = $3, zip = $4, city = $5, telephone = $6, email = $7, latitude = $8, longitude = $9, archived = $10
+            RETURNING *",
             venue.id().0,
             venue.name(),
             venue.street(),
             venue.zip(),
             venue.city(),
             venue.telephone(),
-            venue.email()
+            venue.email(),
+            venue.location().map(|l| l.latitude()),
+            venue.location().map(|l| l.longitude()),
+            venue.is_archived()
         )
         .fetch_one(&mut **tx_ctx)
         .await
@@ -4,4 +4,13 @@ pub mod referee_services;
 pub mod team_services;
 pub mod utils;
 pub mod venue_services;
-pub mod assignment_services;
\ No newline at end of file
+pub mod assignment_services;
+pub mod league_services;
+pub mod season_services;
+pub mod standing_services;
+pub mod schedule_services;
+pub mod fixture_import_services;
+pub mod calendar_services;
+pub mod availability_lock_services;
+pub mod blackout_services;
+pub mod expense_services;
\ No newline at end of file
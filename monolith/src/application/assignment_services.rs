@@ -1,17 +1,28 @@
-use restinterface::{AssignmentDTO, AssignmentStagingDTO};
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use restinterface::{
+    AssignmentDTO, AssignmentStagingDTO, AssignmentValidationReportDTO,
+    AssignmentValidationRuleDTO, AssignmentValidationSeverityDTO, AssignmentValidationViolationDTO,
+    UnconfirmedFixtureDTO,
+};
+use shared::booking::{booking_violations, FixtureSlot, RefereeBooking};
 
 use crate::domain::{
     aggregates::{
         assignment::{Assignment, AssignmentRefereeRole},
-        fixture::FixtureId,
-        referee::RefereeId,
+        fixture::{Fixture, FixtureId},
+        referee::{Referee, RefereeId},
     },
     repositories::{
-        assignment_repo::AssignmentRepository, fixture_repo::FixtureRepository,
-        referee_repo::RefereeRepository,
+        assignment_repo::AssignmentRepository, availability_repo::AvailabilityRepository,
+        fixture_repo::FixtureRepository, referee_repo::RefereeRepository,
     },
 };
 
+/// Committed assignments of fixtures within this many hours from now are close to game day and should be confirmed
+pub const UNCONFIRMED_WINDOW_DEFAULT_HOURS: i64 = 72;
+
 pub async fn remove_staged_assignment<TxCtx>(
     fixture_id: FixtureId,
     referee_id: RefereeId,
@@ -68,53 +79,113 @@ pub async fn remove_committed_assignment<TxCtx>(
         ));
     }
 
-    let mut fixture = fixture_repo
-        .find_by_id(assignment.fixture_id(), tx_ctx)
+    unassign_referee(&assignment, fixture_repo, tx_ctx).await?;
+    assignment_repo.delete(&assignment, tx_ctx).await
+}
+
+pub async fn confirm_assignment<TxCtx>(
+    fixture_id: FixtureId,
+    referee_id: RefereeId,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<AssignmentDTO, String> {
+    let mut assignment = assignment_repo
+        .find_by_fixture_and_referee(fixture_id, referee_id, tx_ctx)
         .await?
-        .expect(&format!(
-            "Fixture {} not found when removing committed assignment",
-            assignment.fixture_id().0
+        .ok_or(format!(
+            "Assignment with fixture_id {} and referee_id {} not found",
+            fixture_id.0, referee_id.0
+        ))?;
+
+    if !assignment.is_unconfirmed() {
+        return Err(format!(
+            "Assignment with fixture_id {} and referee_id {} not committed or already confirmed",
+            fixture_id.0, referee_id.0
         ));
+    }
 
-    match assignment.referee_role() {
-        AssignmentRefereeRole::First => {
-            if fixture.first_referee().is_none() {
-                return Err(format!(
-                    "First referee not assigned for fixture {}",
-                    fixture.id().0
-                ));
-            }
+    assignment.confirm();
+    assignment_repo.save(&assignment, tx_ctx).await?;
 
-            if fixture.first_referee().unwrap().id() != assignment.referee_id() {
-                return Err(format!(
-                    "First referee not assigned for fixture {}",
-                    fixture.id().0
-                ));
-            }
+    Ok(assignment.into())
+}
 
-            fixture.unassign_first_referee();
-        }
-        AssignmentRefereeRole::Second => {
-            if fixture.second_referee().is_none() {
-                return Err(format!(
-                    "Second referee not assigned for fixture {}",
-                    fixture.id().0
-                ));
-            }
+pub async fn decline_assignment<TxCtx>(
+    fixture_id: FixtureId,
+    referee_id: RefereeId,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<AssignmentDTO, String> {
+    let mut assignment = assignment_repo
+        .find_by_fixture_and_referee(fixture_id, referee_id, tx_ctx)
+        .await?
+        .ok_or(format!(
+            "Assignment with fixture_id {} and referee_id {} not found",
+            fixture_id.0, referee_id.0
+        ))?;
 
-            if fixture.second_referee().unwrap().id() != assignment.referee_id() {
-                return Err(format!(
-                    "Second referee not assigned for fixture {}",
-                    fixture.id().0
-                ));
-            }
+    if !assignment.is_committed() {
+        return Err(format!(
+            "Assignment with fixture_id {} and referee_id {} not committed",
+            fixture_id.0, referee_id.0
+        ));
+    }
 
-            fixture.unassign_second_referee()
+    // NOTE: declining frees the fixture just like removing the committed assignment, but the declined
+    // assignment is kept, so that the admin can see who turned the fixture down
+    unassign_referee(&assignment, fixture_repo, tx_ctx).await?;
+
+    assignment.decline();
+    assignment_repo.save(&assignment, tx_ctx).await?;
+
+    Ok(assignment.into())
+}
+
+/// Returns the scheduled fixtures taking place within the given window from now on, which
+/// have committed assignments that are not confirmed by their referees yet, ordered by date.
+pub async fn get_unconfirmed_assignments<TxCtx>(
+    now: DateTime<Utc>,
+    within: Duration,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<UnconfirmedFixtureDTO>, String> {
+    let assignments = assignment_repo.get_all(tx_ctx).await?;
+
+    let mut unconfirmed: HashMap<FixtureId, Vec<Assignment>> = HashMap::new();
+    for assignment in assignments.into_iter().filter(|a| a.is_unconfirmed()) {
+        unconfirmed
+            .entry(assignment.fixture_id())
+            .or_default()
+            .push(assignment);
+    }
+
+    let mut unconfirmed_fixtures = Vec::new();
+    for (fixture_id, assignments) in unconfirmed {
+        let fixture = fixture_repo
+            .find_by_id(fixture_id, tx_ctx)
+            .await?
+            .expect(&format!(
+                "Fixture {} not found when fetching unconfirmed assignments",
+                fixture_id.0
+            ));
+
+        if !fixture.is_scheduled() || *fixture.date() < now || *fixture.date() > now + within {
+            continue;
         }
+
+        unconfirmed_fixtures.push((fixture, assignments));
     }
+    unconfirmed_fixtures.sort_by_key(|(f, _)| *f.date());
 
-    fixture_repo.save(&fixture, tx_ctx).await?;
-    assignment_repo.delete(&assignment, tx_ctx).await
+    Ok(unconfirmed_fixtures
+        .into_iter()
+        .map(|(fixture, assignments)| UnconfirmedFixtureDTO {
+            fixture: fixture.into(),
+            unconfirmed_assignments: assignments.into_iter().map(|a| a.into()).collect(),
+        })
+        .collect())
 }
 
 pub async fn stage_assignment<TxCtx>(
@@ -131,6 +202,13 @@ pub async fn stage_assignment<TxCtx>(
             "Fixture {} not found when staging assignment",
             assignment_staging.fixture_id.0
         ));
+
+    if fixture.is_read_only() {
+        return Err(format!(
+            "Fixture {} is read-only because its season is closed",
+            fixture.id().0
+        ));
+    }
     let referee = referee_repo
         .find_by_id(assignment_staging.referee_id.0.into(), tx_ctx)
         .await?
@@ -139,6 +217,26 @@ pub async fn stage_assignment<TxCtx>(
             assignment_staging.referee_id.0
         ));
 
+    let referee_role: AssignmentRefereeRole = assignment_staging.referee_role.into();
+    if !is_qualified(&fixture, &referee, referee_role) {
+        return Err(format!(
+            "Referee {} with qualification level {} is under-qualified for the {:?} referee role in fixture {}",
+            referee.id().0,
+            referee.qualification_level(),
+            referee_role,
+            fixture.id().0
+        ));
+    }
+
+    if fixture.is_conflict_of_interest(&referee) {
+        return Err(format!(
+            "Referee {} cannot be assigned to fixture {} due to a conflict of interest: their club {} is playing",
+            referee.id().0,
+            fixture.id().0,
+            referee.club()
+        ));
+    }
+
     let assignment_lookup = assignment_repo
         .find_by_fixture_and_referee(fixture.id().0.into(), referee.id().0.into(), tx_ctx)
         .await?;
@@ -165,10 +263,31 @@ pub async fn commit_assignments<TxCtx>(
     assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
     fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
     referee_repo: &impl RefereeRepository<TxCtx = TxCtx, Error = String>,
+    availability_repo: &impl AvailabilityRepository<TxCtx = TxCtx, Error = String>,
     tx_ctx: &mut TxCtx,
 ) -> Result<String, String> {
-    // NOTE: committing assignments also validates them and rejects if any invalid
-    validate_assignments(assignment_repo, tx_ctx).await?;
+    // NOTE: committing assignments also validates them and rejects if the report contains any errors
+    let report = validate_assignments(
+        assignment_repo,
+        fixture_repo,
+        referee_repo,
+        availability_repo,
+        tx_ctx,
+    )
+    .await?;
+
+    if report.has_errors() {
+        return Err(format!(
+            "Assignments invalid: {}",
+            report
+                .violations
+                .iter()
+                .filter(|v| v.severity == AssignmentValidationSeverityDTO::Error)
+                .map(|v| v.message.clone())
+                .collect::<Vec<String>>()
+                .join("; ")
+        ));
+    }
 
     // NOTE: committing assignments fetches all staged assignments and assigns the referees to the fixtures according to their roles
     // and changes the assignment status to committed
@@ -207,17 +326,1003 @@ pub async fn commit_assignments<TxCtx>(
 }
 
 pub async fn validate_assignments<TxCtx>(
-    _assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
-    _tx_ctx: &mut TxCtx,
-) -> Result<String, String> {
-    // NOTE: this is not fully implemented, just a placeholder because its
-    // complex domain logic, requiring additional domain functions in the
-    // aggregates and the domain service layer.
-    // An assignment is valid if:
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    referee_repo: &impl RefereeRepository<TxCtx = TxCtx, Error = String>,
+    availability_repo: &impl AvailabilityRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<AssignmentValidationReportDTO, String> {
+    // NOTE: a staged assignment is valid if:
     //  - the fixture is in a playable state (i.e. not postponed, cancelled, etc.)
+    //  - the fixture is not read-only, i.e. its season is not closed
     //  - the referee is available for the fixture
     //  - the referee is not already assigned to another fixture at the same time
     //  - the referee is not assigned to a fixture that is "close" to the fixture (i.e. same field, same game-hour), except when its at the same venue
+    //  - the referee has no conflict of interest, i.e. their club is not playing in the fixture
+    // The staged assignments are checked against all other assignments, both staged and committed,
+    // declined assignments are ignored as they do not book the referee anymore.
+    // Violations are not returned as an error but collected into a report, so that the caller can
+    // see which assignment violates which rule.
+
+    let assignments = assignment_repo.get_all(tx_ctx).await?;
+
+    // NOTE: the same fixture is referenced by multiple assignments, therefore we cache them
+    let mut fixtures: HashMap<FixtureId, Fixture> = HashMap::new();
+    for assignment in assignments.iter() {
+        if fixtures.contains_key(&assignment.fixture_id()) {
+            continue;
+        }
+
+        let fixture = fixture_repo
+            .find_by_id(assignment.fixture_id(), tx_ctx)
+            .await?
+            .expect(&format!(
+                "Fixture {} not found when validating assignments",
+                assignment.fixture_id().0
+            ));
+        fixtures.insert(fixture.id(), fixture);
+    }
+
+    let mut violations: Vec<AssignmentValidationViolationDTO> = Vec::new();
+
+    for assignment in assignments.iter() {
+        if !assignment.is_staged() {
+            continue;
+        }
+
+        let fixture = fixtures.get(&assignment.fixture_id()).unwrap();
+        let referee = referee_repo
+            .find_by_id(assignment.referee_id(), tx_ctx)
+            .await?
+            .expect(&format!(
+                "Referee {} not found when validating assignments",
+                assignment.referee_id().0
+            ));
+
+        if !fixture.is_scheduled() {
+            violations.push(violation(
+                assignment,
+                AssignmentValidationRuleDTO::FixtureNotScheduled,
+                AssignmentValidationSeverityDTO::Error,
+                format!(
+                    "Fixture {} is not scheduled for referee {}",
+                    fixture.id().0,
+                    referee.id().0
+                ),
+            ));
+        }
+
+        if fixture.is_read_only() {
+            violations.push(violation(
+                assignment,
+                AssignmentValidationRuleDTO::FixtureReadOnly,
+                AssignmentValidationSeverityDTO::Error,
+                format!(
+                    "Fixture {} is read-only because its season is closed",
+                    fixture.id().0
+                ),
+            ));
+        }
+
+        if fixture.is_conflict_of_interest(&referee) {
+            violations.push(violation(
+                assignment,
+                AssignmentValidationRuleDTO::RefereeConflictOfInterest,
+                AssignmentValidationSeverityDTO::Error,
+                format!(
+                    "Referee {} has a conflict of interest for fixture {}: their club {} is playing",
+                    referee.id().0,
+                    fixture.id().0,
+                    referee.club()
+                ),
+            ));
+        }
+
+        if !availability_repo
+            .is_available(fixture, &referee, tx_ctx)
+            .await?
+        {
+            violations.push(violation(
+                assignment,
+                AssignmentValidationRuleDTO::RefereeNotAvailable,
+                AssignmentValidationSeverityDTO::Error,
+                format!(
+                    "Referee {} is not available for fixture {}",
+                    referee.id().0,
+                    fixture.id().0
+                ),
+            ));
+        }
+    }
+
+    let bookings: Vec<RefereeBooking> = assignments
+        .iter()
+        .filter(|a| !a.is_declined())
+        .map(|a| {
+            let fixture = fixtures.get(&a.fixture_id()).unwrap();
+            RefereeBooking {
+                fixture_id: fixture.id().into(),
+                referee_id: a.referee_id().into(),
+                slot: FixtureSlot {
+                    date: *fixture.date(),
+                    venue_id: fixture.venue().id().into(),
+                },
+                staged: a.is_staged(),
+            }
+        })
+        .collect();
+    violations.extend(booking_violations(&bookings));
+
+    Ok(AssignmentValidationReportDTO { violations })
+}
+
+/// Frees the role of the referee of a committed assignment in its fixture
+async fn unassign_referee<TxCtx>(
+    assignment: &Assignment,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    let mut fixture = fixture_repo
+        .find_by_id(assignment.fixture_id(), tx_ctx)
+        .await?
+        .expect(&format!(
+            "Fixture {} not found when unassigning referee",
+            assignment.fixture_id().0
+        ));
+
+    if fixture.is_read_only() {
+        return Err(format!(
+            "Fixture {} is read-only because its season is closed",
+            fixture.id().0
+        ));
+    }
+
+    match assignment.referee_role() {
+        AssignmentRefereeRole::First => {
+            if fixture.first_referee().is_none() {
+                return Err(format!(
+                    "First referee not assigned for fixture {}",
+                    fixture.id().0
+                ));
+            }
 
-    Ok("Assignments validated".to_string())
+            if fixture.first_referee().unwrap().id() != assignment.referee_id() {
+                return Err(format!(
+                    "First referee not assigned for fixture {}",
+                    fixture.id().0
+                ));
+            }
+
+            fixture.unassign_first_referee();
+        }
+        AssignmentRefereeRole::Second => {
+            if fixture.second_referee().is_none() {
+                return Err(format!(
+                    "Second referee not assigned for fixture {}",
+                    fixture.id().0
+                ));
+            }
+
+            if fixture.second_referee().unwrap().id() != assignment.referee_id() {
+                return Err(format!(
+                    "Second referee not assigned for fixture {}",
+                    fixture.id().0
+                ));
+            }
+
+            fixture.unassign_second_referee()
+        }
+    }
+
+    fixture_repo.save(&fixture, tx_ctx).await
+}
+
+/// A referee is qualified for a role in a fixture if their level is at least the level required for it
+fn is_qualified(fixture: &Fixture, referee: &Referee, role: AssignmentRefereeRole) -> bool {
+    let min_level = match role {
+        AssignmentRefereeRole::First => fixture.first_referee_min_level(),
+        AssignmentRefereeRole::Second => fixture.second_referee_min_level(),
+    };
+    referee.qualification_level() >= min_level
+}
+
+fn violation(
+    assignment: &Assignment,
+    rule: AssignmentValidationRuleDTO,
+    severity: AssignmentValidationSeverityDTO,
+    message: String,
+) -> AssignmentValidationViolationDTO {
+    AssignmentValidationViolationDTO {
+        fixture_id: assignment.fixture_id().into(),
+        referee_id: assignment.referee_id().into(),
+        rule,
+        severity,
+        message,
+    }
+}
+
+pub async fn propose_assignments<TxCtx>(
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    referee_repo: &impl RefereeRepository<TxCtx = TxCtx, Error = String>,
+    availability_repo: &impl AvailabilityRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<AssignmentStagingDTO>, String> {
+    // NOTE: this is a simple greedy solver and not an optimal one: it goes through the scheduled fixtures
+    // in the date range ordered by date and fills the open referee roles with the available referee who
+    // has the fewest assignments so far, skipping referees that would end up in a same-time or travel
+    // conflict, that are under-qualified for the role, or that have a conflict of interest. Existing
+    // staged and committed assignments are left untouched and are taken into account.
+
+    let fixtures: HashMap<FixtureId, Fixture> = fixture_repo
+        .get_all(tx_ctx)
+        .await?
+        .into_iter()
+        .map(|f| (f.id(), f))
+        .collect();
+    let assignments = assignment_repo.get_all(tx_ctx).await?;
+    let referees = referee_repo.get_all(tx_ctx).await?;
+
+    let mut availabilities: HashMap<RefereeId, Vec<FixtureId>> = HashMap::new();
+    for referee in referees.iter() {
+        let referee_availabilities = availability_repo
+            .get_availabilities_for_referee(referee, tx_ctx)
+            .await?;
+        availabilities.insert(referee.id(), referee_availabilities);
+    }
+
+    // NOTE: the fixtures each referee is booked for, both by existing and by proposed assignments
+    let mut bookings: HashMap<RefereeId, Vec<FixtureId>> = HashMap::new();
+    for assignment in assignments.iter().filter(|a| !a.is_declined()) {
+        bookings
+            .entry(assignment.referee_id())
+            .or_default()
+            .push(assignment.fixture_id());
+    }
+
+    let mut fixtures_in_range: Vec<&Fixture> = fixtures
+        .values()
+        .filter(|f| f.is_scheduled() && !f.is_read_only() && *f.date() >= from && *f.date() <= to)
+        .collect();
+    fixtures_in_range.sort_by_key(|f| *f.date());
+
+    let mut proposal: Vec<AssignmentStagingDTO> = Vec::new();
+
+    for fixture in fixtures_in_range {
+        for role in [AssignmentRefereeRole::First, AssignmentRefereeRole::Second] {
+            let role_filled = match role {
+                AssignmentRefereeRole::First => fixture.first_referee().is_some(),
+                AssignmentRefereeRole::Second => fixture.second_referee().is_some(),
+            } || assignments.iter().any(|a| {
+                a.fixture_id() == fixture.id() && a.referee_role() == role && !a.is_declined()
+            });
+            if role_filled {
+                continue;
+            }
+
+            let candidate = referees
+                .iter()
+                .filter(|r| availabilities.get(&r.id()).unwrap().contains(&fixture.id()))
+                .filter(|r| is_qualified(fixture, r, role))
+                .filter(|r| !fixture.is_conflict_of_interest(r))
+                // NOTE: a referee who declined the fixture is not proposed for it again
+                .filter(|r| {
+                    !assignments.iter().any(|a| {
+                        a.is_declined()
+                            && a.fixture_id() == fixture.id()
+                            && a.referee_id() == r.id()
+                    })
+                })
+                .filter(|r| {
+                    !bookings.get(&r.id()).is_some_and(|booked| {
+                        booked.iter().any(|booked_fixture_id| {
+                            *booked_fixture_id == fixture.id()
+                                || fixtures
+                                    .get(booked_fixture_id)
+                                    .is_some_and(|f| fixture.is_in_conflict_with(f))
+                        })
+                    })
+                })
+                .min_by_key(|r| bookings.get(&r.id()).map_or(0, |booked| booked.len()));
+
+            if let Some(referee) = candidate {
+                proposal.push(AssignmentStagingDTO {
+                    fixture_id: fixture.id().into(),
+                    referee_id: referee.id().into(),
+                    referee_role: role.into(),
+                });
+                bookings.entry(referee.id()).or_default().push(fixture.id());
+            }
+        }
+    }
+
+    Ok(proposal)
+}
+
+pub async fn accept_assignment_proposal<TxCtx>(
+    proposal: &[AssignmentStagingDTO],
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    referee_repo: &impl RefereeRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<AssignmentDTO>, String> {
+    // NOTE: the proposal is accepted wholesale, that is all assignments are staged within the same TX
+    let mut assignments = Vec::new();
+    for assignment_staging in proposal {
+        let assignment = stage_assignment(
+            assignment_staging,
+            assignment_repo,
+            fixture_repo,
+            referee_repo,
+            tx_ctx,
+        )
+        .await?;
+        assignments.push(assignment);
+    }
+
+    Ok(assignments)
+}
+
+pub async fn flag_conflicts_of_interest<TxCtx>(
+    referee_id: RefereeId,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    referee_repo: &impl RefereeRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<AssignmentDTO>, String> {
+    // NOTE: when a referee changes their club, committed assignments might now be in a conflict of interest.
+    // We do not remove these assignments automatically but flag them, so that the admin can review them.
+    let referee = referee_repo
+        .find_by_id(referee_id, tx_ctx)
+        .await?
+        .expect(&format!(
+            "Referee {} not found when flagging conflicts of interest",
+            referee_id.0
+        ));
+
+    let assignments = assignment_repo.get_all(tx_ctx).await?;
+
+    let mut flagged_assignments = Vec::new();
+    for mut assignment in assignments {
+        if assignment.referee_id() != referee_id || !assignment.is_committed() {
+            continue;
+        }
+
+        let fixture = fixture_repo
+            .find_by_id(assignment.fixture_id(), tx_ctx)
+            .await?
+            .expect(&format!(
+                "Fixture {} not found when flagging conflicts of interest",
+                assignment.fixture_id().0
+            ));
+
+        if fixture.is_conflict_of_interest(&referee) {
+            assignment.flag_for_review(&format!(
+                "Conflict of interest: club {} of the referee is playing",
+                referee.club()
+            ));
+            assignment_repo.save(&assignment, tx_ctx).await?;
+            flagged_assignments.push(assignment.into());
+        }
+    }
+
+    Ok(flagged_assignments)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::{DateTime, Duration, Utc};
+    use restinterface::{
+        AssignmentRefereeRoleDTO, AssignmentStagingDTO, AssignmentStatusDTO,
+        AssignmentValidationReportDTO, AssignmentValidationRuleDTO,
+        AssignmentValidationSeverityDTO,
+    };
+    use uuid::Uuid;
+
+    use crate::domain::{
+        aggregates::{
+            assignment::{Assignment, AssignmentRefereeRole, AssignmentStatus},
+            fixture::{Fixture, FixtureId, FixtureStatus},
+            league::League,
+            referee::{Referee, RefereeId},
+            season::Season,
+            team::{Team, TeamId},
+            venue::{Venue, VenueId},
+        },
+        repositories::{
+            assignment_repo::MockAssignmentRepository,
+            availability_repo::MockAvailabilityRepository, fixture_repo::MockFixtureRepository,
+            referee_repo::MockRefereeRepository,
+        },
+    };
+
+    use super::{
+        decline_assignment, get_unconfirmed_assignments, propose_assignments, stage_assignment,
+        validate_assignments,
+    };
+
+    fn test_venue(name: &str) -> Venue {
+        Venue::from_id(
+            VenueId::from(Uuid::new_v4()),
+            name.to_string(),
+            "Street".to_string(),
+            "12345".to_string(),
+            "City".to_string(),
+            None,
+            None,
+            None,
+            false,
+        )
+    }
+
+    fn test_fixture(date: DateTime<Utc>, status: FixtureStatus, venue: &Venue) -> Fixture {
+        test_fixture_in_season(date, status, venue, Season::new("Season 2024/25"))
+    }
+
+    fn test_fixture_in_season(
+        date: DateTime<Utc>,
+        status: FixtureStatus,
+        venue: &Venue,
+        season: Season,
+    ) -> Fixture {
+        Fixture::from_id(
+            FixtureId::from(Uuid::new_v4()),
+            date,
+            status,
+            League::new("League A"),
+            season,
+            venue.clone(),
+            Team::from_id(
+                TeamId::from(Uuid::new_v4()),
+                "Team A".to_string(),
+                "Club A".to_string(),
+                false,
+            ),
+            Team::from_id(
+                TeamId::from(Uuid::new_v4()),
+                "Team B".to_string(),
+                "Club B".to_string(),
+                false,
+            ),
+            None,
+            None,
+            0,
+            0,
+            None,
+        )
+    }
+
+    async fn run_validation(
+        fixtures: Vec<Fixture>,
+        referees: Vec<Referee>,
+        assignments: Vec<Assignment>,
+        unavailable: Vec<(FixtureId, RefereeId)>,
+    ) -> AssignmentValidationReportDTO {
+        let mut assignment_repo = MockAssignmentRepository::new();
+        let mut fixture_repo = MockFixtureRepository::new();
+        let mut referee_repo = MockRefereeRepository::new();
+        let mut availability_repo = MockAvailabilityRepository::new();
+
+        let fixtures: HashMap<FixtureId, Fixture> =
+            fixtures.into_iter().map(|f| (f.id(), f)).collect();
+        let referees: HashMap<RefereeId, Referee> =
+            referees.into_iter().map(|r| (r.id(), r)).collect();
+
+        assignment_repo
+            .expect_get_all()
+            .return_const(Ok(assignments));
+        fixture_repo
+            .expect_find_by_id()
+            .returning(move |id, _| Ok(fixtures.get(&id).cloned()));
+        referee_repo
+            .expect_find_by_id()
+            .returning(move |id, _| Ok(referees.get(&id).cloned()));
+        availability_repo
+            .expect_is_available()
+            .returning(move |fixture, referee, _| {
+                Ok(!unavailable.contains(&(fixture.id(), referee.id())))
+            });
+
+        validate_assignments(
+            &assignment_repo,
+            &fixture_repo,
+            &referee_repo,
+            &availability_repo,
+            &mut (),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_validate_assignments_valid() {
+        let venue = test_venue("Venue A");
+        let fixture = test_fixture(Utc::now(), FixtureStatus::Scheduled, &venue);
+        let referee = Referee::new("John Doe", "Club C", 0);
+        let assignment =
+            Assignment::staged(fixture.id(), referee.id(), AssignmentRefereeRole::First);
+
+        let report = run_validation(vec![fixture], vec![referee], vec![assignment], vec![]).await;
+
+        assert!(report.violations.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_validate_assignments_cancelled_fixture() {
+        let venue = test_venue("Venue A");
+        let fixture = test_fixture(Utc::now(), FixtureStatus::Cancelled, &venue);
+        let referee = Referee::new("John Doe", "Club C", 0);
+        let assignment =
+            Assignment::staged(fixture.id(), referee.id(), AssignmentRefereeRole::First);
+
+        let report = run_validation(vec![fixture], vec![referee], vec![assignment], vec![]).await;
+
+        assert!(report.has_errors());
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(
+            report.violations[0].rule,
+            AssignmentValidationRuleDTO::FixtureNotScheduled
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_assignments_referee_not_available() {
+        let venue = test_venue("Venue A");
+        let fixture = test_fixture(Utc::now(), FixtureStatus::Scheduled, &venue);
+        let referee = Referee::new("John Doe", "Club C", 0);
+        let assignment =
+            Assignment::staged(fixture.id(), referee.id(), AssignmentRefereeRole::First);
+        let unavailable = vec![(fixture.id(), referee.id())];
+
+        let report =
+            run_validation(vec![fixture], vec![referee], vec![assignment], unavailable).await;
+
+        assert!(report.has_errors());
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(
+            report.violations[0].rule,
+            AssignmentValidationRuleDTO::RefereeNotAvailable
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_assignments_same_time() {
+        let now = Utc::now();
+        let venue = test_venue("Venue A");
+        let fixture = test_fixture(now, FixtureStatus::Scheduled, &venue);
+        let other_fixture = test_fixture(now, FixtureStatus::Scheduled, &venue);
+        let referee = Referee::new("John Doe", "Club C", 0);
+        let assignment =
+            Assignment::staged(fixture.id(), referee.id(), AssignmentRefereeRole::First);
+        let other_assignment = Assignment::new(
+            other_fixture.id(),
+            referee.id(),
+            AssignmentRefereeRole::Second,
+            AssignmentStatus::Committed,
+            None,
+        );
+
+        let report = run_validation(
+            vec![fixture, other_fixture],
+            vec![referee],
+            vec![assignment, other_assignment],
+            vec![],
+        )
+        .await;
+
+        assert!(report.has_errors());
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(
+            report.violations[0].rule,
+            AssignmentValidationRuleDTO::RefereeAssignedAtSameTime
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_assignments_close_fixtures() {
+        let now = Utc::now();
+        let venue_a = test_venue("Venue A");
+        let venue_b = test_venue("Venue B");
+        let fixture = test_fixture(now, FixtureStatus::Scheduled, &venue_a);
+        let same_venue_fixture = test_fixture(
+            now + Duration::minutes(30),
+            FixtureStatus::Scheduled,
+            &venue_a,
+        );
+        let other_venue_fixture = test_fixture(
+            now + Duration::minutes(30),
+            FixtureStatus::Scheduled,
+            &venue_b,
+        );
+        let referee = Referee::new("John Doe", "Club C", 0);
+
+        // a close fixture at the same venue is fine, but reported as a warning
+        let report = run_validation(
+            vec![fixture.clone(), same_venue_fixture.clone()],
+            vec![referee.clone()],
+            vec![
+                Assignment::staged(fixture.id(), referee.id(), AssignmentRefereeRole::First),
+                Assignment::staged(
+                    same_venue_fixture.id(),
+                    referee.id(),
+                    AssignmentRefereeRole::First,
+                ),
+            ],
+            vec![],
+        )
+        .await;
+        assert!(!report.has_errors());
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(
+            report.violations[0].severity,
+            AssignmentValidationSeverityDTO::Warning
+        );
+
+        // a close fixture at a different venue is not, and is reported only once
+        let report = run_validation(
+            vec![fixture.clone(), other_venue_fixture.clone()],
+            vec![referee.clone()],
+            vec![
+                Assignment::staged(fixture.id(), referee.id(), AssignmentRefereeRole::First),
+                Assignment::staged(
+                    other_venue_fixture.id(),
+                    referee.id(),
+                    AssignmentRefereeRole::First,
+                ),
+            ],
+            vec![],
+        )
+        .await;
+        assert!(report.has_errors());
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(
+            report.violations[0].rule,
+            AssignmentValidationRuleDTO::RefereeAssignedToCloseFixture
+        );
+    }
+
+    #[tokio::test]
+    async fn test_propose_assignments_respects_conflicts() {
+        let now = Utc::now();
+        let fixture = test_fixture(now, FixtureStatus::Scheduled, &test_venue("Venue A"));
+        let other_fixture = test_fixture(now, FixtureStatus::Scheduled, &test_venue("Venue B"));
+        let cancelled_fixture = test_fixture(
+            now + Duration::days(1),
+            FixtureStatus::Cancelled,
+            &test_venue("Venue C"),
+        );
+        let referee = Referee::new("John Doe", "Club C", 0);
+        let other_referee = Referee::new("Jane Smith", "Club D", 0);
+        let fixture_ids = vec![fixture.id(), other_fixture.id(), cancelled_fixture.id()];
+
+        let mut assignment_repo = MockAssignmentRepository::new();
+        let mut fixture_repo = MockFixtureRepository::new();
+        let mut referee_repo = MockRefereeRepository::new();
+        let mut availability_repo = MockAvailabilityRepository::new();
+
+        assignment_repo.expect_get_all().return_const(Ok(vec![]));
+        fixture_repo.expect_get_all().return_const(Ok(vec![
+            fixture.clone(),
+            other_fixture.clone(),
+            cancelled_fixture.clone(),
+        ]));
+        referee_repo
+            .expect_get_all()
+            .return_const(Ok(vec![referee.clone(), other_referee.clone()]));
+        availability_repo
+            .expect_get_availabilities_for_referee()
+            .return_const(Ok(fixture_ids));
+
+        let proposal = propose_assignments(
+            now - Duration::days(1),
+            now + Duration::days(2),
+            &assignment_repo,
+            &fixture_repo,
+            &referee_repo,
+            &availability_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        // NOTE: both referees are available for all fixtures, but the two scheduled fixtures are at the same time,
+        // therefore only one of them can be fully staffed, and the cancelled one is skipped
+        assert_eq!(proposal.len(), 2);
+        assert_eq!(proposal[0].fixture_id, proposal[1].fixture_id);
+        assert_ne!(proposal[0].referee_id, proposal[1].referee_id);
+        assert_ne!(proposal[0].referee_role, proposal[1].referee_role);
+        assert_ne!(proposal[0].fixture_id, cancelled_fixture.id().into());
+    }
+
+    #[tokio::test]
+    async fn test_validate_assignments_conflict_of_interest() {
+        let venue = test_venue("Venue A");
+        let fixture = test_fixture(Utc::now(), FixtureStatus::Scheduled, &venue);
+        let referee = Referee::new("John Doe", "Club A", 0);
+        let assignment =
+            Assignment::staged(fixture.id(), referee.id(), AssignmentRefereeRole::First);
+
+        let report = run_validation(vec![fixture], vec![referee], vec![assignment], vec![]).await;
+
+        assert!(report.has_errors());
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(
+            report.violations[0].rule,
+            AssignmentValidationRuleDTO::RefereeConflictOfInterest
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_assignments_fixture_in_closed_season() {
+        let venue = test_venue("Venue A");
+        let mut season = Season::new("Season 2023/24");
+        season.close();
+        let fixture = test_fixture_in_season(Utc::now(), FixtureStatus::Scheduled, &venue, season);
+        let referee = Referee::new("John Doe", "Club C", 0);
+        let assignment =
+            Assignment::staged(fixture.id(), referee.id(), AssignmentRefereeRole::First);
+
+        let report = run_validation(vec![fixture], vec![referee], vec![assignment], vec![]).await;
+
+        assert!(report.has_errors());
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(
+            report.violations[0].rule,
+            AssignmentValidationRuleDTO::FixtureReadOnly
+        );
+    }
+
+    #[tokio::test]
+    async fn test_flag_conflicts_of_interest() {
+        let now = Utc::now();
+        let fixture = test_fixture(now, FixtureStatus::Scheduled, &test_venue("Venue A"));
+        let other_fixture = test_fixture(
+            now + Duration::days(1),
+            FixtureStatus::Scheduled,
+            &test_venue("Venue B"),
+        );
+        let mut referee = Referee::new("John Doe", "Club C", 0);
+        referee.change_club("Club B");
+
+        let conflicting_assignment = Assignment::new(
+            fixture.id(),
+            referee.id(),
+            AssignmentRefereeRole::First,
+            AssignmentStatus::Committed,
+            None,
+        );
+        let staged_assignment = Assignment::staged(
+            other_fixture.id(),
+            referee.id(),
+            AssignmentRefereeRole::First,
+        );
+
+        let mut assignment_repo = MockAssignmentRepository::new();
+        let mut fixture_repo = MockFixtureRepository::new();
+        let mut referee_repo = MockRefereeRepository::new();
+
+        let fixtures: HashMap<FixtureId, Fixture> = vec![fixture.clone(), other_fixture]
+            .into_iter()
+            .map(|f| (f.id(), f))
+            .collect();
+
+        assignment_repo
+            .expect_get_all()
+            .return_const(Ok(vec![conflicting_assignment, staged_assignment]));
+        assignment_repo
+            .expect_save()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        fixture_repo
+            .expect_find_by_id()
+            .returning(move |id, _| Ok(fixtures.get(&id).cloned()));
+        referee_repo
+            .expect_find_by_id()
+            .return_const(Ok(Some(referee.clone())));
+
+        let flagged = super::flag_conflicts_of_interest(
+            referee.id(),
+            &assignment_repo,
+            &fixture_repo,
+            &referee_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        // NOTE: only the committed assignment is flagged, staged ones are caught by the validation
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].fixture_id, fixture.id().into());
+        assert!(flagged[0].review_reason.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_stage_assignment_under_qualified() {
+        let venue = test_venue("Venue A");
+        let fixture = Fixture::new(
+            Utc::now(),
+            League::new("League A"),
+            Season::new("Season 2024/25"),
+            venue,
+            Team::new("Team A", "Club A"),
+            Team::new("Team B", "Club B"),
+            None,
+            None,
+            2,
+            1,
+        );
+        let referee = Referee::new("John Doe", "Club C", 1);
+
+        let mut assignment_repo = MockAssignmentRepository::new();
+        let mut fixture_repo = MockFixtureRepository::new();
+        let mut referee_repo = MockRefereeRepository::new();
+
+        assignment_repo
+            .expect_find_by_fixture_and_referee()
+            .return_const(Ok(None));
+        assignment_repo.expect_save().returning(|_, _| Ok(()));
+        fixture_repo
+            .expect_find_by_id()
+            .return_const(Ok(Some(fixture.clone())));
+        referee_repo
+            .expect_find_by_id()
+            .return_const(Ok(Some(referee.clone())));
+
+        // the referee is under-qualified for the first referee role
+        let result = stage_assignment(
+            &AssignmentStagingDTO {
+                fixture_id: fixture.id().into(),
+                referee_id: referee.id().into(),
+                referee_role: AssignmentRefereeRoleDTO::First,
+            },
+            &assignment_repo,
+            &fixture_repo,
+            &referee_repo,
+            &mut (),
+        )
+        .await;
+        assert!(result.is_err());
+
+        // but qualified enough for the second referee role
+        let result = stage_assignment(
+            &AssignmentStagingDTO {
+                fixture_id: fixture.id().into(),
+                referee_id: referee.id().into(),
+                referee_role: AssignmentRefereeRoleDTO::Second,
+            },
+            &assignment_repo,
+            &fixture_repo,
+            &referee_repo,
+            &mut (),
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_decline_assignment_frees_fixture() {
+        let referee = Referee::new("John Doe", "Club C", 0);
+        let mut fixture =
+            test_fixture(Utc::now(), FixtureStatus::Scheduled, &test_venue("Venue A"));
+        fixture.assign_first_referee(referee.clone());
+        let assignment = Assignment::new(
+            fixture.id(),
+            referee.id(),
+            AssignmentRefereeRole::First,
+            AssignmentStatus::Confirmed,
+            None,
+        );
+
+        let mut assignment_repo = MockAssignmentRepository::new();
+        let mut fixture_repo = MockFixtureRepository::new();
+
+        assignment_repo
+            .expect_find_by_fixture_and_referee()
+            .return_const(Ok(Some(assignment)));
+        assignment_repo
+            .expect_save()
+            .withf(|a, _| a.is_declined())
+            .times(1)
+            .returning(|_, _| Ok(()));
+        fixture_repo
+            .expect_find_by_id()
+            .return_const(Ok(Some(fixture.clone())));
+        fixture_repo
+            .expect_save()
+            .withf(|f, _| f.first_referee().is_none())
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let declined = decline_assignment(
+            fixture.id(),
+            referee.id(),
+            &assignment_repo,
+            &fixture_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(declined.status, AssignmentStatusDTO::Declined);
+    }
+
+    #[tokio::test]
+    async fn test_get_unconfirmed_assignments_close_to_game_day() {
+        let now = Utc::now();
+        let venue = test_venue("Venue A");
+        let close_fixture = test_fixture(now + Duration::days(1), FixtureStatus::Scheduled, &venue);
+        let confirmed_fixture =
+            test_fixture(now + Duration::days(2), FixtureStatus::Scheduled, &venue);
+        let far_fixture = test_fixture(now + Duration::days(10), FixtureStatus::Scheduled, &venue);
+        let first_referee = Referee::new("John Doe", "Club C", 0);
+        let second_referee = Referee::new("Jane Doe", "Club D", 0);
+
+        let assignment = |fixture: &Fixture, referee: &Referee, role, status| {
+            Assignment::new(fixture.id(), referee.id(), role, status, None)
+        };
+        let assignments = vec![
+            assignment(
+                &close_fixture,
+                &first_referee,
+                AssignmentRefereeRole::First,
+                AssignmentStatus::Committed,
+            ),
+            assignment(
+                &close_fixture,
+                &second_referee,
+                AssignmentRefereeRole::Second,
+                AssignmentStatus::Confirmed,
+            ),
+            assignment(
+                &confirmed_fixture,
+                &first_referee,
+                AssignmentRefereeRole::First,
+                AssignmentStatus::Confirmed,
+            ),
+            assignment(
+                &far_fixture,
+                &first_referee,
+                AssignmentRefereeRole::First,
+                AssignmentStatus::Committed,
+            ),
+        ];
+
+        let mut assignment_repo = MockAssignmentRepository::new();
+        let mut fixture_repo = MockFixtureRepository::new();
+
+        let fixtures: HashMap<FixtureId, Fixture> =
+            vec![close_fixture.clone(), confirmed_fixture, far_fixture]
+                .into_iter()
+                .map(|f| (f.id(), f))
+                .collect();
+
+        assignment_repo
+            .expect_get_all()
+            .return_const(Ok(assignments));
+        fixture_repo
+            .expect_find_by_id()
+            .returning(move |id, _| Ok(fixtures.get(&id).cloned()));
+
+        let unconfirmed = get_unconfirmed_assignments(
+            now,
+            Duration::days(3),
+            &assignment_repo,
+            &fixture_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(unconfirmed.len(), 1);
+        assert_eq!(unconfirmed[0].fixture.id, close_fixture.id().into());
+        assert_eq!(unconfirmed[0].unconfirmed_assignments.len(), 1);
+        assert_eq!(
+            unconfirmed[0].unconfirmed_assignments[0].referee_id,
+            first_referee.id().into()
+        );
+    }
 }
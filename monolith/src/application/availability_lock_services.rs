@@ -0,0 +1,238 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    aggregates::{
+        availability_lock::{AvailabilityLock, AvailabilityLockId, AvailabilityLockScope},
+        declaration_deadline::DeclarationDeadline,
+        fixture::{Fixture, FixtureId},
+    },
+    repositories::{
+        availability_lock_repo::AvailabilityLockRepository,
+        declaration_deadline_repo::DeclarationDeadlineRepository, fixture_repo::FixtureRepository,
+    },
+};
+
+pub async fn lock_availabilities<TxCtx>(
+    scope: AvailabilityLockScope,
+    availability_lock_repo: &impl AvailabilityLockRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<AvailabilityLock, String> {
+    if let AvailabilityLockScope::DateRange { from, to } = scope {
+        if from > to {
+            return Err(
+                "Lock date range ends before it starts - cannot lock availabilities".to_string(),
+            );
+        }
+    }
+
+    let lock = AvailabilityLock::new(scope);
+    availability_lock_repo.save(&lock, tx_ctx).await?;
+
+    Ok(lock)
+}
+
+pub async fn unlock_availabilities<TxCtx>(
+    lock_id: AvailabilityLockId,
+    availability_lock_repo: &impl AvailabilityLockRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    let lock = availability_lock_repo
+        .find_by_id(lock_id, tx_ctx)
+        .await?
+        .ok_or(format!("Availability lock {:?} not found", lock_id))?;
+
+    availability_lock_repo.delete(lock.id(), tx_ctx).await
+}
+
+pub async fn get_availability_locks<TxCtx>(
+    availability_lock_repo: &impl AvailabilityLockRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<AvailabilityLock>, String> {
+    availability_lock_repo.get_all(tx_ctx).await
+}
+
+pub async fn set_declaration_deadline<TxCtx>(
+    fixture_id: FixtureId,
+    deadline: DateTime<Utc>,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    declaration_deadline_repo: &impl DeclarationDeadlineRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<DeclarationDeadline, String> {
+    let fixture = fixture_repo
+        .find_by_id(fixture_id, tx_ctx)
+        .await?
+        .ok_or(format!("Fixture {:?} not found", fixture_id))?;
+
+    if deadline > *fixture.date() {
+        return Err(
+            "Declaration deadline is after the fixture date - cannot set deadline".to_string(),
+        );
+    }
+
+    let declaration_deadline = DeclarationDeadline::new(fixture.id(), deadline);
+    declaration_deadline_repo
+        .save(&declaration_deadline, tx_ctx)
+        .await?;
+
+    Ok(declaration_deadline)
+}
+
+/// Availabilities of a fixture cannot be changed while a lock covers it or
+/// once its declaration deadline has passed.
+pub async fn ensure_availabilities_changeable<TxCtx>(
+    fixture: &Fixture,
+    now: DateTime<Utc>,
+    availability_lock_repo: &impl AvailabilityLockRepository<TxCtx = TxCtx, Error = String>,
+    declaration_deadline_repo: &impl DeclarationDeadlineRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    let locks = availability_lock_repo.get_all(tx_ctx).await?;
+    if let Some(lock) = locks
+        .iter()
+        .find(|l| l.covers(fixture.id(), fixture.date()))
+    {
+        return Err(format!(
+            "Availabilities for this fixture are locked by {:?} - cannot change availability",
+            lock.id()
+        ));
+    }
+
+    if let Some(deadline) = declaration_deadline_repo
+        .find_by_fixture(fixture.id(), tx_ctx)
+        .await?
+    {
+        if deadline.is_closed(&now) {
+            return Err(
+                "Declaration deadline for this fixture has passed - cannot change availability"
+                    .to_string(),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+
+    use crate::{
+        application::availability_lock_services::ensure_availabilities_changeable,
+        domain::{
+            aggregates::{
+                availability_lock::{AvailabilityLock, AvailabilityLockScope},
+                declaration_deadline::DeclarationDeadline,
+                fixture::{Fixture, FixtureId, FixtureStatus},
+                league::League,
+                season::Season,
+                team::Team,
+                venue::{Venue, VenueId},
+            },
+            repositories::{
+                availability_lock_repo::MockAvailabilityLockRepository,
+                declaration_deadline_repo::MockDeclarationDeadlineRepository,
+            },
+        },
+    };
+
+    fn fixture_at(date: chrono::DateTime<Utc>) -> Fixture {
+        let venue = Venue::from_id(
+            VenueId::from(Uuid::new_v4()),
+            "Venue A".to_string(),
+            "Street A".to_string(),
+            "12345".to_string(),
+            "City A".to_string(),
+            None,
+            None,
+            None,
+            false,
+        );
+
+        Fixture::from_id(
+            FixtureId::from(Uuid::new_v4()),
+            date,
+            FixtureStatus::Scheduled,
+            League::new("League A"),
+            Season::new("Season 2024/25"),
+            venue,
+            Team::new("Team A", "Club A"),
+            Team::new("Team B", "Club B"),
+            None,
+            None,
+            0,
+            0,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_given_date_range_lock_covering_fixture_when_ensure_changeable_then_error() {
+        let mut availability_lock_repo = MockAvailabilityLockRepository::new();
+        let declaration_deadline_repo = MockDeclarationDeadlineRepository::new();
+
+        let fixture = fixture_at(Utc.with_ymd_and_hms(2024, 10, 5, 18, 30, 0).unwrap());
+        let lock = AvailabilityLock::new(AvailabilityLockScope::DateRange {
+            from: *fixture.date() - Duration::days(1),
+            to: *fixture.date() + Duration::days(1),
+        });
+
+        availability_lock_repo
+            .expect_get_all()
+            .return_const(Ok(vec![lock]));
+
+        let result = ensure_availabilities_changeable(
+            &fixture,
+            *fixture.date() - Duration::days(7),
+            &availability_lock_repo,
+            &declaration_deadline_repo,
+            &mut (),
+        )
+        .await;
+
+        assert!(result
+            .unwrap_err()
+            .starts_with("Availabilities for this fixture are locked"));
+    }
+
+    #[tokio::test]
+    async fn test_given_passed_deadline_when_ensure_changeable_then_error() {
+        let mut availability_lock_repo = MockAvailabilityLockRepository::new();
+        let mut declaration_deadline_repo = MockDeclarationDeadlineRepository::new();
+
+        let fixture = fixture_at(Utc.with_ymd_and_hms(2024, 10, 5, 18, 30, 0).unwrap());
+        let deadline = *fixture.date() - Duration::days(2);
+
+        availability_lock_repo
+            .expect_get_all()
+            .return_const(Ok(vec![]));
+        declaration_deadline_repo
+            .expect_find_by_fixture()
+            .with(eq(fixture.id()), eq(&()))
+            .return_const(Ok(Some(DeclarationDeadline::new(fixture.id(), deadline))));
+
+        let before_deadline = ensure_availabilities_changeable(
+            &fixture,
+            deadline - Duration::hours(1),
+            &availability_lock_repo,
+            &declaration_deadline_repo,
+            &mut (),
+        )
+        .await;
+        let after_deadline = ensure_availabilities_changeable(
+            &fixture,
+            deadline + Duration::hours(1),
+            &availability_lock_repo,
+            &declaration_deadline_repo,
+            &mut (),
+        )
+        .await;
+
+        assert!(before_deadline.is_ok());
+        assert_eq!(
+            after_deadline.unwrap_err(),
+            "Declaration deadline for this fixture has passed - cannot change availability"
+        );
+    }
+}
@@ -1,4 +1,26 @@
-use crate::domain::{aggregates::{fixture::FixtureId, referee::RefereeId}, repositories::{availability_repo::AvailabilityRepository, fixture_repo::FixtureRepository, referee_repo::RefereeRepository}};
+use std::collections::HashSet;
+
+use chrono::{DateTime, Datelike, Utc, Weekday};
+
+use crate::{application::availability_lock_services::ensure_availabilities_changeable, domain::{aggregates::{fixture::{Fixture, FixtureId, FixtureStatus}, referee::RefereeId, venue::VenueId}, repositories::{availability_lock_repo::AvailabilityLockRepository, availability_repo::AvailabilityRepository, blackout_repo::BlackoutRepository, declaration_deadline_repo::DeclarationDeadlineRepository, fixture_repo::FixtureRepository, referee_repo::RefereeRepository}}};
+
+/// Selects the fixtures of a bulk declaration, criteria which are not given match every fixture
+#[derive(Debug, Clone, Default)]
+pub struct AvailabilityBulkDeclaration {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub venue_ids: Vec<VenueId>,
+    pub weekdays: Vec<Weekday>,
+}
+
+impl AvailabilityBulkDeclaration {
+    pub fn matches(&self, fixture: &Fixture) -> bool {
+        self.from.is_none_or(|from| from <= *fixture.date())
+            && self.to.is_none_or(|to| *fixture.date() <= to)
+            && (self.venue_ids.is_empty() || self.venue_ids.contains(&fixture.venue().id()))
+            && (self.weekdays.is_empty() || self.weekdays.contains(&fixture.date().weekday()))
+    }
+}
 
 pub async fn declare_availability<TxCtx>(
     fixture_id: FixtureId,
@@ -6,32 +28,89 @@ pub async fn declare_availability<TxCtx>(
     fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
     referee_repo: &impl RefereeRepository<TxCtx = TxCtx, Error = String>,
     availability_repo: &impl AvailabilityRepository<TxCtx = TxCtx, Error = String>,
+    availability_lock_repo: &impl AvailabilityLockRepository<TxCtx = TxCtx, Error = String>,
+    declaration_deadline_repo: &impl DeclarationDeadlineRepository<TxCtx = TxCtx, Error = String>,
+    blackout_repo: &impl BlackoutRepository<TxCtx = TxCtx, Error = String>,
     tx_ctx: &mut TxCtx,
 ) -> Result<(), String> {
 
     let fixture = fixture_repo.find_by_id(fixture_id, tx_ctx).await?.expect("Fixture not found when declaring availability");
     let referee = referee_repo.find_by_id(referee_id, tx_ctx).await?.expect("Referee not found when declaring availability");
 
+    ensure_availabilities_changeable(&fixture, Utc::now(), availability_lock_repo, declaration_deadline_repo, tx_ctx).await?;
+
     if availability_repo.is_available(&fixture, &referee, tx_ctx).await? {
         return Err("Referee is already available for this fixture - cannot declare availability".to_string());
     }
 
+    let blackouts = blackout_repo.find_by_referee(referee_id, tx_ctx).await?;
+    if blackouts.iter().any(|b| b.covers(fixture.date())) {
+        return Err("Referee has a blackout on the date of this fixture - cannot declare availability".to_string());
+    }
+
     availability_repo.declare_availability(&fixture, &referee, tx_ctx).await?;
 
     Ok(())
 }
 
+/// Declares the availability of the referee for all scheduled fixtures matching
+/// the bulk declaration and returns the fixtures which got declared. Fixtures
+/// the referee is available for already, which fall into a blackout of the
+/// referee or whose availabilities cannot be changed anymore are skipped.
+pub async fn declare_availabilities_bulk<TxCtx>(
+    referee_id: RefereeId,
+    bulk_declaration: &AvailabilityBulkDeclaration,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    referee_repo: &impl RefereeRepository<TxCtx = TxCtx, Error = String>,
+    availability_repo: &impl AvailabilityRepository<TxCtx = TxCtx, Error = String>,
+    availability_lock_repo: &impl AvailabilityLockRepository<TxCtx = TxCtx, Error = String>,
+    declaration_deadline_repo: &impl DeclarationDeadlineRepository<TxCtx = TxCtx, Error = String>,
+    blackout_repo: &impl BlackoutRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<FixtureId>, String> {
+    let referee = referee_repo.find_by_id(referee_id, tx_ctx).await?.expect("Referee not found when declaring availabilities");
+
+    let declared: HashSet<FixtureId> = availability_repo.get_availabilities_for_referee(&referee, tx_ctx).await?.into_iter().collect();
+    let blackouts = blackout_repo.find_by_referee(referee_id, tx_ctx).await?;
+    let fixtures = fixture_repo.get_all(tx_ctx).await?;
+
+    let now = Utc::now();
+    let mut fixture_ids = Vec::new();
+    for fixture in fixtures.iter().filter(|f| *f.status() == FixtureStatus::Scheduled && bulk_declaration.matches(f)) {
+        if declared.contains(&fixture.id()) || blackouts.iter().any(|b| b.covers(fixture.date())) {
+            continue;
+        }
+
+        // NOTE: locked fixtures are skipped instead of failing the whole declaration
+        if ensure_availabilities_changeable(fixture, now, availability_lock_repo, declaration_deadline_repo, tx_ctx).await.is_err() {
+            continue;
+        }
+
+        fixture_ids.push(fixture.id());
+    }
+
+    if !fixture_ids.is_empty() {
+        availability_repo.declare_availabilities(&fixture_ids, &referee, tx_ctx).await?;
+    }
+
+    Ok(fixture_ids)
+}
+
 pub async fn withdraw_availability<TxCtx>(
     fixture_id: FixtureId,
     referee_id: RefereeId,
     fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
     referee_repo: &impl RefereeRepository<TxCtx = TxCtx, Error = String>,
     availability_repo: &impl AvailabilityRepository<TxCtx = TxCtx, Error = String>,
+    availability_lock_repo: &impl AvailabilityLockRepository<TxCtx = TxCtx, Error = String>,
+    declaration_deadline_repo: &impl DeclarationDeadlineRepository<TxCtx = TxCtx, Error = String>,
     tx_ctx: &mut TxCtx,
 ) -> Result<(), String> {
     let fixture = fixture_repo.find_by_id(fixture_id, tx_ctx).await?.expect("Fixture not found when withdrawing availability");
     let referee = referee_repo.find_by_id(referee_id, tx_ctx).await?.expect("Referee not found when withdrawing availability");
 
+    ensure_availabilities_changeable(&fixture, Utc::now(), availability_lock_repo, declaration_deadline_repo, tx_ctx).await?;
+
     if ! availability_repo.is_available(&fixture, &referee, tx_ctx).await? {
         return Err("Referee is not available for this fixture - cannot withdraw availability".to_string());
     }
@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    application::availability_lock_services::ensure_availabilities_changeable,
+    domain::{
+        aggregates::{
+            blackout::{Blackout, BlackoutId},
+            fixture::FixtureId,
+            referee::RefereeId,
+        },
+        repositories::{
+            availability_lock_repo::AvailabilityLockRepository,
+            availability_repo::AvailabilityRepository, blackout_repo::BlackoutRepository,
+            declaration_deadline_repo::DeclarationDeadlineRepository,
+            fixture_repo::FixtureRepository, referee_repo::RefereeRepository,
+        },
+    },
+};
+
+/// Declares a blackout for the referee and withdraws the availabilities of the
+/// referee for all fixtures within it. Availabilities of fixtures which cannot
+/// be changed anymore are kept, as they have been finalised already.
+pub async fn declare_blackout<TxCtx>(
+    referee_id: RefereeId,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    referee_repo: &impl RefereeRepository<TxCtx = TxCtx, Error = String>,
+    availability_repo: &impl AvailabilityRepository<TxCtx = TxCtx, Error = String>,
+    availability_lock_repo: &impl AvailabilityLockRepository<TxCtx = TxCtx, Error = String>,
+    declaration_deadline_repo: &impl DeclarationDeadlineRepository<TxCtx = TxCtx, Error = String>,
+    blackout_repo: &impl BlackoutRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Blackout, String> {
+    if from > to {
+        return Err("Blackout ends before it starts - cannot declare blackout".to_string());
+    }
+
+    let referee = referee_repo
+        .find_by_id(referee_id, tx_ctx)
+        .await?
+        .expect("Referee not found when declaring blackout");
+
+    let blackout = Blackout::new(referee_id, from, to);
+    blackout_repo.save(&blackout, tx_ctx).await?;
+
+    let declared: HashSet<FixtureId> = availability_repo
+        .get_availabilities_for_referee(&referee, tx_ctx)
+        .await?
+        .into_iter()
+        .collect();
+    let fixtures = fixture_repo.get_all(tx_ctx).await?;
+
+    let now = Utc::now();
+    let mut fixture_ids = Vec::new();
+    for fixture in fixtures
+        .iter()
+        .filter(|f| declared.contains(&f.id()) && blackout.covers(f.date()))
+    {
+        if ensure_availabilities_changeable(
+            fixture,
+            now,
+            availability_lock_repo,
+            declaration_deadline_repo,
+            tx_ctx,
+        )
+        .await
+        .is_err()
+        {
+            continue;
+        }
+
+        fixture_ids.push(fixture.id());
+    }
+
+    if !fixture_ids.is_empty() {
+        availability_repo
+            .withdraw_availabilities(&fixture_ids, &referee, tx_ctx)
+            .await?;
+    }
+
+    Ok(blackout)
+}
+
+/// Removes the blackout, withdrawn availabilities are not declared again
+pub async fn remove_blackout<TxCtx>(
+    blackout_id: BlackoutId,
+    blackout_repo: &impl BlackoutRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<(), String> {
+    let blackout = blackout_repo
+        .find_by_id(blackout_id, tx_ctx)
+        .await?
+        .ok_or(format!("Blackout {:?} not found", blackout_id))?;
+
+    blackout_repo.delete(blackout.id(), tx_ctx).await
+}
+
+pub async fn get_blackouts_for_referee<TxCtx>(
+    referee_id: RefereeId,
+    blackout_repo: &impl BlackoutRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<Blackout>, String> {
+    blackout_repo.find_by_referee(referee_id, tx_ctx).await
+}
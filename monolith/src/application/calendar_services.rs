@@ -0,0 +1,276 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::{
+    aggregates::{
+        assignment::{Assignment, AssignmentRefereeRole},
+        fixture::Fixture,
+        referee::RefereeId,
+    },
+    repositories::{
+        assignment_repo::AssignmentRepository, fixture_repo::FixtureRepository,
+        referee_repo::RefereeRepository,
+    },
+};
+
+// NOTE: fixtures only have a start, we assume a volleyball match takes two hours
+const FIXTURE_DURATION_HOURS: i64 = 2;
+
+/// Renders all committed assignments of the referee as an RFC 5545 calendar.
+/// The UID of an event is derived from the fixture and the referee only, so a
+/// calendar client updates the existing event when the date of a fixture changes.
+pub async fn get_referee_calendar<TxCtx>(
+    referee_id: RefereeId,
+    referee_repo: &impl RefereeRepository<TxCtx = TxCtx, Error = String>,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<String, String> {
+    let referee = referee_repo
+        .find_by_id(referee_id, tx_ctx)
+        .await?
+        .ok_or(format!("Referee {:?} not found", referee_id))?;
+
+    let assignments = assignment_repo
+        .find_all_committed_by_referee(referee_id, tx_ctx)
+        .await?;
+
+    let now = Utc::now();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//rust-ddd//referee assignments//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!(
+            "X-WR-CALNAME:{}",
+            escape_text(&format!("Assignments {}", referee.name()))
+        ),
+    ];
+
+    for assignment in assignments.iter() {
+        let fixture = fixture_repo
+            .find_by_id(assignment.fixture_id(), tx_ctx)
+            .await?
+            .ok_or(format!("Fixture {:?} not found", assignment.fixture_id()))?;
+
+        lines.extend(render_event(assignment, &fixture, now));
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    Ok(lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<String>>()
+        .join(""))
+}
+
+fn render_event(assignment: &Assignment, fixture: &Fixture, now: DateTime<Utc>) -> Vec<String> {
+    let role = match assignment.referee_role() {
+        AssignmentRefereeRole::First => "First referee",
+        AssignmentRefereeRole::Second => "Second referee",
+    };
+    let venue = fixture.venue();
+    let status = if fixture.is_cancelled() {
+        "CANCELLED"
+    } else {
+        "CONFIRMED"
+    };
+
+    vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}", event_uid(assignment)),
+        format!("DTSTAMP:{}", format_date_time(&now)),
+        format!("DTSTART:{}", format_date_time(fixture.date())),
+        format!(
+            "DTEND:{}",
+            format_date_time(&(*fixture.date() + Duration::hours(FIXTURE_DURATION_HOURS)))
+        ),
+        format!(
+            "SUMMARY:{}",
+            escape_text(&format!(
+                "{} vs {} ({})",
+                fixture.team_home().name(),
+                fixture.team_away().name(),
+                role
+            ))
+        ),
+        format!(
+            "LOCATION:{}",
+            escape_text(&format!(
+                "{}, {}, {} {}",
+                venue.name(),
+                venue.street(),
+                venue.zip(),
+                venue.city()
+            ))
+        ),
+        format!(
+            "DESCRIPTION:{}",
+            escape_text(&format!(
+                "{} {}\n{}",
+                fixture.league().name(),
+                fixture.season().name(),
+                role
+            ))
+        ),
+        format!("STATUS:{}", status),
+        "END:VEVENT".to_string(),
+    ]
+}
+
+fn event_uid(assignment: &Assignment) -> String {
+    format!(
+        "{}-{}@rust-ddd",
+        assignment.fixture_id().0,
+        assignment.referee_id().0
+    )
+}
+
+fn format_date_time(date: &DateTime<Utc>) -> String {
+    date.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escapes the characters which have a special meaning in TEXT values
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Content lines must not be longer than 75 octets, longer lines continue on
+/// the next line starting with a space. Every line ends with CRLF.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut line_length = 0;
+
+    for c in line.chars() {
+        if line_length + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            line_length = 1;
+        }
+        folded.push(c);
+        line_length += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+
+    use crate::{
+        application::calendar_services::{fold_line, get_referee_calendar},
+        domain::{
+            aggregates::{
+                assignment::{Assignment, AssignmentRefereeRole, AssignmentStatus},
+                fixture::{Fixture, FixtureId, FixtureStatus},
+                league::League,
+                referee::Referee,
+                season::Season,
+                team::Team,
+                venue::{Venue, VenueId},
+            },
+            repositories::{
+                assignment_repo::MockAssignmentRepository, fixture_repo::MockFixtureRepository,
+                referee_repo::MockRefereeRepository,
+            },
+        },
+    };
+
+    #[test]
+    fn test_given_long_line_when_fold_then_continuation_lines_start_with_space() {
+        let line = format!("DESCRIPTION:{}", "a".repeat(100));
+
+        let folded = fold_line(&line);
+
+        let folded_lines: Vec<&str> = folded.trim_end_matches("\r\n").split("\r\n").collect();
+        assert_eq!(folded_lines.len(), 2);
+        assert_eq!(folded_lines[0].len(), 75);
+        assert!(folded_lines[1].starts_with(' '));
+        assert_eq!(folded.replace("\r\n ", ""), format!("{}\r\n", line));
+    }
+
+    #[tokio::test]
+    async fn test_given_committed_assignment_when_get_referee_calendar_then_event_rendered() {
+        let mut referee_repo = MockRefereeRepository::new();
+        let mut assignment_repo = MockAssignmentRepository::new();
+        let mut fixture_repo = MockFixtureRepository::new();
+
+        let referee = Referee::new("Referee A", "Club A", 1);
+        let venue = Venue::from_id(
+            VenueId::from(Uuid::new_v4()),
+            "Sporthalle, Nord".to_string(),
+            "Street A".to_string(),
+            "12345".to_string(),
+            "City A".to_string(),
+            None,
+            None,
+            None,
+            false,
+        );
+        let fixture = Fixture::from_id(
+            FixtureId::from(Uuid::new_v4()),
+            Utc.with_ymd_and_hms(2024, 10, 5, 18, 30, 0).unwrap(),
+            FixtureStatus::Scheduled,
+            League::new("League A"),
+            Season::new("Season 2024/25"),
+            venue,
+            Team::new("Team A", "Club A"),
+            Team::new("Team B", "Club B"),
+            None,
+            None,
+            0,
+            0,
+            None,
+        );
+        let assignment = Assignment::new(
+            fixture.id(),
+            referee.id(),
+            AssignmentRefereeRole::Second,
+            AssignmentStatus::Committed,
+            None,
+        );
+
+        referee_repo
+            .expect_find_by_id()
+            .with(eq(referee.id()), eq(&()))
+            .return_const(Ok(Some(referee.clone())));
+        assignment_repo
+            .expect_find_all_committed_by_referee()
+            .with(eq(referee.id()), eq(&()))
+            .return_const(Ok(vec![assignment]));
+        fixture_repo
+            .expect_find_by_id()
+            .with(eq(fixture.id()), eq(&()))
+            .return_const(Ok(Some(fixture.clone())));
+
+        let calendar = get_referee_calendar(
+            referee.id(),
+            &referee_repo,
+            &assignment_repo,
+            &fixture_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        // the UID line is longer than 75 octets and therefore folded
+        let calendar = calendar.replace("\r\n ", "");
+        let lines: Vec<&str> = calendar.trim_end_matches("\r\n").split("\r\n").collect();
+        assert_eq!(lines.first(), Some(&"BEGIN:VCALENDAR"));
+        assert_eq!(lines.last(), Some(&"END:VCALENDAR"));
+        assert!(
+            lines.contains(&format!("UID:{}-{}@rust-ddd", fixture.id().0, referee.id().0).as_str())
+        );
+        assert!(lines.contains(&"DTSTART:20241005T183000Z"));
+        assert!(lines.contains(&"DTEND:20241005T203000Z"));
+        assert!(lines.contains(&"SUMMARY:Team A vs Team B (Second referee)"));
+        assert!(lines.contains(&"LOCATION:Sporthalle\\, Nord\\, Street A\\, 12345 City A"));
+        assert!(lines.contains(&"STATUS:CONFIRMED"));
+    }
+}
@@ -0,0 +1,389 @@
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use restinterface::{ExpenseItemDTO, ExpenseStatementDTO};
+
+use crate::domain::{
+    aggregates::{
+        assignment::{Assignment, AssignmentRefereeRole},
+        fixture::Fixture,
+        referee::RefereeId,
+    },
+    geo_location::GeoLocation,
+    repositories::{
+        assignment_repo::AssignmentRepository, fixture_repo::FixtureRepository,
+        referee_repo::RefereeRepository,
+    },
+};
+
+/// The rates with which referees are reimbursed, all amounts are in cents
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpenseRates {
+    pub per_km_cents: i64,
+    pub first_referee_fee_cents: i64,
+    pub second_referee_fee_cents: i64,
+}
+
+/// Computes the travel expenses of a referee for all their committed assignments of fixtures
+/// taking place in the given month (UTC). The travel is reimbursed per km for the way from the
+/// home of the referee to the venue and back, plus a flat fee depending on the referee role.
+pub async fn get_expense_statement<TxCtx>(
+    referee_id: RefereeId,
+    year: i32,
+    month: u32,
+    rates: &ExpenseRates,
+    referee_repo: &impl RefereeRepository<TxCtx = TxCtx, Error = String>,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<ExpenseStatementDTO, String> {
+    let (from, to) = month_range(year, month)?;
+
+    let referee = referee_repo
+        .find_by_id(referee_id, tx_ctx)
+        .await?
+        .ok_or(format!("Referee {:?} not found", referee_id))?;
+
+    let assignments = assignment_repo
+        .find_all_committed_by_referee(referee_id, tx_ctx)
+        .await?;
+
+    let mut items = Vec::new();
+    for assignment in assignments.iter() {
+        let fixture = fixture_repo
+            .find_by_id(assignment.fixture_id(), tx_ctx)
+            .await?
+            .ok_or(format!("Fixture {:?} not found", assignment.fixture_id()))?;
+
+        // NOTE: referees only travel to fixtures which take place, therefore cancelled and postponed ones are not reimbursed
+        if *fixture.date() < from
+            || *fixture.date() >= to
+            || fixture.is_cancelled()
+            || fixture.is_postponed()
+        {
+            continue;
+        }
+
+        items.push(expense_item(assignment, &fixture, referee.home(), rates));
+    }
+    items.sort_by_key(|i| i.date);
+
+    Ok(ExpenseStatementDTO {
+        total_cents: items.iter().map(|i| i.total_cents).sum(),
+        referee: referee.into(),
+        year,
+        month,
+        items,
+    })
+}
+
+/// Renders the expense statement as CSV with one row per assignment and a final row with the total,
+/// amounts are given in the currency unit with two decimals
+pub fn render_expense_statement_csv(statement: &ExpenseStatementDTO) -> String {
+    let mut lines = vec!["date,home,away,venue,role,distance_km,travel,fee,total".to_string()];
+
+    for item in statement.items.iter() {
+        let columns = [
+            item.date.format("%Y-%m-%d %H:%M").to_string(),
+            item.team_home_name.clone(),
+            item.team_away_name.clone(),
+            item.venue_name.clone(),
+            format!("{:?}", item.referee_role),
+            item.distance_km
+                .map_or(String::new(), |d| format!("{:.1}", d)),
+            format_cents(item.travel_cents),
+            format_cents(item.fee_cents),
+            format_cents(item.total_cents),
+        ];
+
+        lines.push(
+            columns
+                .iter()
+                .map(|c| escape_csv_field(c))
+                .collect::<Vec<String>>()
+                .join(","),
+        );
+    }
+
+    lines.push(format!(
+        ",,,,,,,Total,{}",
+        format_cents(statement.total_cents)
+    ));
+
+    lines.iter().map(|line| format!("{}\r\n", line)).collect()
+}
+
+fn expense_item(
+    assignment: &Assignment,
+    fixture: &Fixture,
+    home: Option<GeoLocation>,
+    rates: &ExpenseRates,
+) -> ExpenseItemDTO {
+    // NOTE: the distance is rounded to 100m, so that the amounts can be retraced from the statement
+    let distance_km = home
+        .zip(fixture.venue().location())
+        .map(|(home, venue)| (home.distance_km(&venue) * 10.0).round() / 10.0);
+    let travel_cents =
+        distance_km.map_or(0, |d| (2.0 * d * rates.per_km_cents as f64).round() as i64);
+    let fee_cents = match assignment.referee_role() {
+        AssignmentRefereeRole::First => rates.first_referee_fee_cents,
+        AssignmentRefereeRole::Second => rates.second_referee_fee_cents,
+    };
+
+    ExpenseItemDTO {
+        fixture_id: fixture.id().into(),
+        date: *fixture.date(),
+        team_home_name: fixture.team_home().name().to_string(),
+        team_away_name: fixture.team_away().name().to_string(),
+        venue_name: fixture.venue().name().to_string(),
+        referee_role: assignment.referee_role().into(),
+        distance_km,
+        travel_cents,
+        fee_cents,
+        total_cents: travel_cents + fee_cents,
+    }
+}
+
+fn month_range(year: i32, month: u32) -> Result<(DateTime<Utc>, DateTime<Utc>), String> {
+    let first_day = NaiveDate::from_ymd_opt(year, month, 1)
+        .ok_or(format!("Invalid month {}-{}", year, month))?;
+    let next_first_day = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    }
+    .ok_or(format!("Invalid month {}-{}", year, month))?;
+
+    Ok((
+        Utc.from_utc_datetime(&first_day.and_hms_opt(0, 0, 0).unwrap()),
+        Utc.from_utc_datetime(&next_first_day.and_hms_opt(0, 0, 0).unwrap()),
+    ))
+}
+
+fn format_cents(cents: i64) -> String {
+    format!("{}.{:02}", cents / 100, cents % 100)
+}
+
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+
+    use crate::{
+        application::expense_services::{
+            get_expense_statement, render_expense_statement_csv, ExpenseRates,
+        },
+        domain::{
+            aggregates::{
+                assignment::{Assignment, AssignmentRefereeRole, AssignmentStatus},
+                fixture::{Fixture, FixtureId, FixtureStatus},
+                league::League,
+                referee::Referee,
+                season::Season,
+                team::Team,
+                venue::{Venue, VenueId},
+            },
+            geo_location::GeoLocation,
+            repositories::{
+                assignment_repo::MockAssignmentRepository, fixture_repo::MockFixtureRepository,
+                referee_repo::MockRefereeRepository,
+            },
+        },
+    };
+
+    const RATES: ExpenseRates = ExpenseRates {
+        per_km_cents: 30,
+        first_referee_fee_cents: 2500,
+        second_referee_fee_cents: 2000,
+    };
+
+    fn test_fixture(date_day: u32, status: FixtureStatus, venue: &Venue) -> Fixture {
+        Fixture::from_id(
+            FixtureId::from(Uuid::new_v4()),
+            Utc.with_ymd_and_hms(2024, 10, date_day, 18, 30, 0).unwrap(),
+            status,
+            League::new("League A"),
+            Season::new("Season 2024/25"),
+            venue.clone(),
+            Team::new("Team A", "Club A"),
+            Team::new("Team B", "Club B"),
+            None,
+            None,
+            0,
+            0,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_given_two_locations_when_distance_km_then_great_circle_distance() {
+        let vienna = GeoLocation::new(48.2082, 16.3738).unwrap();
+        let graz = GeoLocation::new(47.0707, 15.4395).unwrap();
+
+        let distance_km = vienna.distance_km(&graz);
+
+        assert!((distance_km - 145.0).abs() < 1.0, "was {}", distance_km);
+        assert_eq!(vienna.distance_km(&vienna), 0.0);
+        assert!(GeoLocation::new(90.5, 0.0).is_err());
+        assert!(GeoLocation::new(0.0, -180.5).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_given_committed_assignments_when_get_expense_statement_then_travel_and_fees_reimbursed(
+    ) {
+        let mut referee = Referee::new("Referee A", "Club C", 1);
+        referee.change_home(GeoLocation::new(48.2082, 16.3738).unwrap());
+
+        let venue = Venue::from_id(
+            VenueId::from(Uuid::new_v4()),
+            "Sporthalle, Graz".to_string(),
+            "Street A".to_string(),
+            "8010".to_string(),
+            "Graz".to_string(),
+            None,
+            None,
+            Some(GeoLocation::new(47.0707, 15.4395).unwrap()),
+            false,
+        );
+        let venue_without_location = Venue::from_id(
+            VenueId::from(Uuid::new_v4()),
+            "Venue B".to_string(),
+            "Street B".to_string(),
+            "1010".to_string(),
+            "Vienna".to_string(),
+            None,
+            None,
+            None,
+            false,
+        );
+
+        let later_fixture = test_fixture(20, FixtureStatus::Played, &venue);
+        let earlier_fixture = test_fixture(5, FixtureStatus::Scheduled, &venue_without_location);
+        let cancelled_fixture = test_fixture(12, FixtureStatus::Cancelled, &venue);
+        let fixtures = [
+            later_fixture.clone(),
+            earlier_fixture.clone(),
+            cancelled_fixture.clone(),
+        ];
+
+        let assignments = vec![
+            Assignment::new(
+                later_fixture.id(),
+                referee.id(),
+                AssignmentRefereeRole::First,
+                AssignmentStatus::Confirmed,
+                None,
+            ),
+            Assignment::new(
+                earlier_fixture.id(),
+                referee.id(),
+                AssignmentRefereeRole::Second,
+                AssignmentStatus::Committed,
+                None,
+            ),
+            Assignment::new(
+                cancelled_fixture.id(),
+                referee.id(),
+                AssignmentRefereeRole::First,
+                AssignmentStatus::Committed,
+                None,
+            ),
+        ];
+
+        let mut referee_repo = MockRefereeRepository::new();
+        let mut assignment_repo = MockAssignmentRepository::new();
+        let mut fixture_repo = MockFixtureRepository::new();
+
+        referee_repo
+            .expect_find_by_id()
+            .with(eq(referee.id()), eq(&()))
+            .return_const(Ok(Some(referee.clone())));
+        assignment_repo
+            .expect_find_all_committed_by_referee()
+            .with(eq(referee.id()), eq(&()))
+            .return_const(Ok(assignments));
+        fixture_repo
+            .expect_find_by_id()
+            .returning(move |fixture_id, _| {
+                Ok(fixtures.iter().find(|f| f.id() == fixture_id).cloned())
+            });
+
+        let statement = get_expense_statement(
+            referee.id(),
+            2024,
+            10,
+            &RATES,
+            &referee_repo,
+            &assignment_repo,
+            &fixture_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(statement.items.len(), 2);
+
+        // no location for the venue, therefore only the fee is reimbursed
+        assert_eq!(statement.items[0].fixture_id, earlier_fixture.id().into());
+        assert_eq!(statement.items[0].distance_km, None);
+        assert_eq!(statement.items[0].travel_cents, 0);
+        assert_eq!(statement.items[0].total_cents, 2000);
+
+        let distance_km = statement.items[1].distance_km.unwrap();
+        assert!((distance_km - 145.0).abs() < 1.0);
+        assert_eq!(
+            statement.items[1].travel_cents,
+            (2.0 * distance_km * 30.0).round() as i64
+        );
+        assert_eq!(
+            statement.items[1].total_cents,
+            statement.items[1].travel_cents + 2500
+        );
+        assert_eq!(
+            statement.total_cents,
+            statement.items[0].total_cents + statement.items[1].total_cents
+        );
+
+        let csv = render_expense_statement_csv(&statement);
+        let lines: Vec<&str> = csv.trim_end_matches("\r\n").split("\r\n").collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[0],
+            "date,home,away,venue,role,distance_km,travel,fee,total"
+        );
+        assert_eq!(
+            lines[1],
+            "2024-10-05 18:30,Team A,Team B,Venue B,Second,,0.00,20.00,20.00"
+        );
+        assert!(lines[2].starts_with("2024-10-20 18:30,Team A,Team B,\"Sporthalle, Graz\",First,"));
+        assert!(lines[3].starts_with(",,,,,,,Total,"));
+    }
+
+    #[tokio::test]
+    async fn test_given_invalid_month_when_get_expense_statement_then_error() {
+        let referee_repo = MockRefereeRepository::new();
+        let assignment_repo = MockAssignmentRepository::new();
+        let fixture_repo = MockFixtureRepository::new();
+
+        let result = get_expense_statement(
+            Referee::new("Referee A", "Club C", 1).id(),
+            2024,
+            13,
+            &RATES,
+            &referee_repo,
+            &assignment_repo,
+            &fixture_repo,
+            &mut (),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+}
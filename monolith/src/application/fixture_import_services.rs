@@ -0,0 +1,291 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+
+use crate::{
+    application::fixture_services::create_fixture,
+    domain::{
+        aggregates::{
+            fixture::Fixture, league::LeagueId, season::SeasonId, team::Team, venue::Venue,
+        },
+        repositories::{
+            fixture_repo::FixtureRepository, league_repo::LeagueRepository,
+            season_repo::SeasonRepository, team_repo::TeamRepository, venue_repo::VenueRepository,
+        },
+    },
+};
+
+/// The outcome of importing a single row of the CSV, the line is 1-based
+#[derive(Debug)]
+pub struct FixtureImportRow {
+    pub line: usize,
+    pub outcome: Result<Fixture, String>,
+}
+
+/// Imports the fixtures of a CSV with the columns date, time, home team, away
+/// team and venue. Each row runs through create_fixture, a failing row is
+/// reported and does not stop the import of the remaining rows. Whether the
+/// created fixtures are kept is up to the caller committing the transaction.
+pub async fn import_fixtures<TxCtx>(
+    csv: &str,
+    league_id: LeagueId,
+    season_id: SeasonId,
+    first_referee_min_level: i32,
+    second_referee_min_level: i32,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    venue_repo: &impl VenueRepository<TxCtx = TxCtx, Error = String>,
+    team_repo: &impl TeamRepository<TxCtx = TxCtx, Error = String>,
+    league_repo: &impl LeagueRepository<TxCtx = TxCtx, Error = String>,
+    season_repo: &impl SeasonRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<FixtureImportRow>, String> {
+    let teams = team_repo.get_all(tx_ctx).await?;
+    let venues = venue_repo.get_all(tx_ctx).await?;
+
+    let mut rows = Vec::new();
+    for (index, line) in csv.lines().enumerate() {
+        if line.trim().is_empty() || (index == 0 && is_header(line)) {
+            continue;
+        }
+
+        let outcome = match parse_row(line, &teams, &venues) {
+            Ok(row) => {
+                create_fixture(
+                    row.date,
+                    league_id,
+                    season_id,
+                    row.venue.id(),
+                    row.team_home.id(),
+                    row.team_away.id(),
+                    first_referee_min_level,
+                    second_referee_min_level,
+                    fixture_repo,
+                    venue_repo,
+                    team_repo,
+                    league_repo,
+                    season_repo,
+                    tx_ctx,
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+
+        rows.push(FixtureImportRow {
+            line: index + 1,
+            outcome,
+        });
+    }
+
+    Ok(rows)
+}
+
+struct ParsedRow<'a> {
+    date: DateTime<Utc>,
+    team_home: &'a Team,
+    team_away: &'a Team,
+    venue: &'a Venue,
+}
+
+fn is_header(line: &str) -> bool {
+    split_csv_line(line)
+        .first()
+        .is_some_and(|column| column.eq_ignore_ascii_case("date"))
+}
+
+fn parse_row<'a>(
+    line: &str,
+    teams: &'a [Team],
+    venues: &'a [Venue],
+) -> Result<ParsedRow<'a>, String> {
+    let columns = split_csv_line(line);
+    if columns.len() != 5 {
+        return Err(format!("Expected 5 columns but found {}", columns.len()));
+    }
+
+    let date = NaiveDate::parse_from_str(&columns[0], "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date '{}', expected YYYY-MM-DD", columns[0]))?;
+    let time = NaiveTime::parse_from_str(&columns[1], "%H:%M")
+        .map_err(|_| format!("Invalid time '{}', expected HH:MM", columns[1]))?;
+
+    Ok(ParsedRow {
+        // NOTE: the federation lists local times, we keep it simple and treat them as UTC
+        date: date.and_time(time).and_utc(),
+        team_home: find_by_name(teams, &columns[2], |t| t.name(), "Team")?,
+        team_away: find_by_name(teams, &columns[3], |t| t.name(), "Team")?,
+        venue: find_by_name(venues, &columns[4], |v| v.name(), "Venue")?,
+    })
+}
+
+fn find_by_name<'a, T>(
+    items: &'a [T],
+    name: &str,
+    name_of: impl Fn(&T) -> &str,
+    kind: &str,
+) -> Result<&'a T, String> {
+    let mut matches = items
+        .iter()
+        .filter(|i| name_of(i).eq_ignore_ascii_case(name));
+    match (matches.next(), matches.next()) {
+        (Some(item), None) => Ok(item),
+        (None, _) => Err(format!("{} '{}' not found", kind, name)),
+        (Some(_), Some(_)) => Err(format!("{} name '{}' is ambiguous", kind, name)),
+    }
+}
+
+/// Splits a CSV line at commas, a field in double quotes may contain commas and
+/// escaped quotes ("")
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut columns = Vec::new();
+    let mut column = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                column.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => columns.push(std::mem::take(&mut column)),
+            _ => column.push(c),
+        }
+    }
+    columns.push(column);
+
+    columns.into_iter().map(|c| c.trim().to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+
+    use crate::{
+        application::fixture_import_services::{import_fixtures, split_csv_line},
+        domain::{
+            aggregates::{
+                league::League,
+                season::Season,
+                team::Team,
+                venue::{Venue, VenueId},
+            },
+            repositories::{
+                fixture_repo::MockFixtureRepository, league_repo::MockLeagueRepository,
+                season_repo::MockSeasonRepository, team_repo::MockTeamRepository,
+                venue_repo::MockVenueRepository,
+            },
+        },
+    };
+
+    #[test]
+    fn test_given_quoted_field_when_split_csv_line_then_comma_kept() {
+        let columns = split_csv_line(r#"2024-10-05, 18:30,Team A,"Team ""B""",  "Hall, North""#);
+
+        assert_eq!(
+            columns,
+            vec!["2024-10-05", "18:30", "Team A", "Team \"B\"", "Hall, North"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_given_csv_with_invalid_rows_when_import_then_errors_reported_per_row() {
+        let mut fixture_repo = MockFixtureRepository::new();
+        let mut venue_repo = MockVenueRepository::new();
+        let mut team_repo = MockTeamRepository::new();
+        let mut league_repo = MockLeagueRepository::new();
+        let mut season_repo = MockSeasonRepository::new();
+
+        let league = League::new("League A");
+        let season = Season::new("Season 2024/25");
+        let team_a = Team::new("Team A", "Club A");
+        let team_b = Team::new("Team B", "Club B");
+        let venue = Venue::from_id(
+            VenueId::from(Uuid::new_v4()),
+            "Venue A".to_string(),
+            "Street A".to_string(),
+            "12345".to_string(),
+            "City A".to_string(),
+            None,
+            None,
+            None,
+            false,
+        );
+
+        team_repo
+            .expect_get_all()
+            .return_const(Ok(vec![team_a.clone(), team_b.clone()]));
+        venue_repo
+            .expect_get_all()
+            .return_const(Ok(vec![venue.clone()]));
+        team_repo.expect_find_by_id().returning(move |id, _| {
+            Ok([team_a.clone(), team_b.clone()]
+                .into_iter()
+                .find(|t| t.id() == id))
+        });
+        venue_repo
+            .expect_find_by_id()
+            .return_const(Ok(Some(venue.clone())));
+        league_repo
+            .expect_find_by_id()
+            .return_const(Ok(Some(league.clone())));
+        season_repo
+            .expect_find_by_id()
+            .return_const(Ok(Some(season.clone())));
+        fixture_repo
+            .expect_find_by_day_and_venue()
+            .return_const(Ok(vec![]));
+        fixture_repo
+            .expect_find_by_day_and_team()
+            .return_const(Ok(vec![]));
+        fixture_repo.expect_save().times(1).return_const(Ok(()));
+
+        let csv = "date,time,home,away,venue\n\
+            2024-10-05,18:30,Team A,Team B,Venue A\n\
+            \n\
+            2024-10-12,18:30,Team B,Team C,Venue A\n\
+            12.10.2024,18:30,Team B,Team A,Venue A\n\
+            2024-10-19,18:30,Team B,Team A\n";
+
+        let rows = import_fixtures(
+            csv,
+            league.id(),
+            season.id(),
+            0,
+            0,
+            &fixture_repo,
+            &venue_repo,
+            &team_repo,
+            &league_repo,
+            &season_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(rows.len(), 4);
+
+        assert_eq!(rows[0].line, 2);
+        let fixture = rows[0].outcome.as_ref().unwrap();
+        assert_eq!(
+            fixture.date(),
+            &Utc.with_ymd_and_hms(2024, 10, 5, 18, 30, 0).unwrap()
+        );
+
+        assert_eq!(rows[1].line, 4);
+        assert_eq!(
+            rows[1].outcome.as_ref().unwrap_err(),
+            "Team 'Team C' not found"
+        );
+        assert_eq!(rows[2].line, 5);
+        assert!(rows[2]
+            .outcome
+            .as_ref()
+            .unwrap_err()
+            .starts_with("Invalid date"));
+        assert_eq!(rows[3].line, 6);
+        assert_eq!(
+            rows[3].outcome.as_ref().unwrap_err(),
+            "Expected 5 columns but found 4"
+        );
+    }
+}
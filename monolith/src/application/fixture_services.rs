@@ -2,25 +2,43 @@ use chrono::{DateTime, Utc};
 
 use crate::domain::{
     aggregates::{
-        fixture::{Fixture, FixtureId},
+        fixture::{Fixture, FixtureId, FixtureResult, SetScore},
+        league::LeagueId,
+        referee::RefereeId,
+        season::SeasonId,
         team::TeamId,
         venue::VenueId,
     },
     repositories::{
-        fixture_repo::FixtureRepository, team_repo::TeamRepository, venue_repo::VenueRepository,
+        fixture_repo::FixtureRepository, league_repo::LeagueRepository,
+        season_repo::SeasonRepository, team_repo::TeamRepository, venue_repo::VenueRepository,
     },
 };
 
 pub async fn create_fixture<TxCtx>(
     date: DateTime<Utc>,
+    league_id: LeagueId,
+    season_id: SeasonId,
     venue_id: VenueId,
     team_home_id: TeamId,
     team_away_id: TeamId,
+    first_referee_min_level: i32,
+    second_referee_min_level: i32,
     fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
     venue_repo: &impl VenueRepository<TxCtx = TxCtx, Error = String>,
     team_repo: &impl TeamRepository<TxCtx = TxCtx, Error = String>,
+    league_repo: &impl LeagueRepository<TxCtx = TxCtx, Error = String>,
+    season_repo: &impl SeasonRepository<TxCtx = TxCtx, Error = String>,
     tx_ctx: &mut TxCtx,
 ) -> Result<Fixture, String> {
+    let league = league_repo
+        .find_by_id(league_id, tx_ctx)
+        .await?
+        .expect("League not found");
+    let season = season_repo
+        .find_by_id(season_id, tx_ctx)
+        .await?
+        .expect("Season not found");
     let venue = venue_repo
         .find_by_id(venue_id, tx_ctx)
         .await?
@@ -34,10 +52,18 @@ pub async fn create_fixture<TxCtx>(
         .await?
         .expect("Team away not found");
 
+    if season.is_closed() {
+        return Err("Cannot create a fixture in a closed season".to_string());
+    }
+
     if team_home.id() == team_away.id() {
         return Err("Team home and team away cannot be the same".to_string());
     }
 
+    if first_referee_min_level < 0 || second_referee_min_level < 0 {
+        return Err("Minimum referee levels cannot be negative".to_string());
+    }
+
     // we simplify the constraint to no other fixture at the same venue on the same day
     let fixtures = fixture_repo
         .find_by_day_and_venue(&date, venue_id, tx_ctx)
@@ -60,7 +86,18 @@ pub async fn create_fixture<TxCtx>(
         return Err("There is already a fixture at the same day for the away team".to_string());
     }
 
-    let fixture = Fixture::new(date, venue, team_home, team_away, None, None);
+    let fixture = Fixture::new(
+        date,
+        league,
+        season,
+        venue,
+        team_home,
+        team_away,
+        None,
+        None,
+        first_referee_min_level,
+        second_referee_min_level,
+    );
 
     fixture_repo
         .save(&fixture, tx_ctx)
@@ -80,6 +117,10 @@ pub async fn update_fixture_date<TxCtx>(
         .await?
         .expect("Fixture not found");
 
+    if fixture.is_read_only() {
+        return Err("Fixture is read-only because its season is closed".to_string());
+    }
+
     fixture.change_date(date);
 
     fixture_repo
@@ -102,6 +143,10 @@ pub async fn update_fixture_venue<TxCtx>(
         .await?
         .expect("Fixture not found");
 
+    if fixture.is_read_only() {
+        return Err("Fixture is read-only because its season is closed".to_string());
+    }
+
     let venue = venue_repo
         .find_by_id(venue_id, tx_ctx)
         .await?
@@ -127,10 +172,18 @@ pub async fn cancel_fixture<TxCtx>(
         .await?
         .expect("Fixture not found");
 
+    if fixture.is_read_only() {
+        return Err("Fixture is read-only because its season is closed".to_string());
+    }
+
     if fixture.is_cancelled() {
         return Err("Fixture is already cancelled".to_string());
     }
 
+    if fixture.is_played() {
+        return Err("Fixture has already been played".to_string());
+    }
+
     fixture.cancel();
 
     fixture_repo
@@ -141,6 +194,115 @@ pub async fn cancel_fixture<TxCtx>(
     Ok(fixture)
 }
 
+pub async fn postpone_fixture<TxCtx>(
+    fixture_id: FixtureId,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Fixture, String> {
+    let mut fixture = fixture_repo
+        .find_by_id(fixture_id, tx_ctx)
+        .await?
+        .expect("Fixture not found");
+
+    if fixture.is_read_only() {
+        return Err("Fixture is read-only because its season is closed".to_string());
+    }
+
+    if !fixture.is_scheduled() {
+        return Err("Only a scheduled fixture can be postponed".to_string());
+    }
+
+    fixture.postpone();
+
+    fixture_repo
+        .save(&fixture, tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(fixture)
+}
+
+pub async fn reschedule_fixture<TxCtx>(
+    fixture_id: FixtureId,
+    date: DateTime<Utc>,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Fixture, String> {
+    let mut fixture = fixture_repo
+        .find_by_id(fixture_id, tx_ctx)
+        .await?
+        .expect("Fixture not found");
+
+    if fixture.is_read_only() {
+        return Err("Fixture is read-only because its season is closed".to_string());
+    }
+
+    if !fixture.is_postponed() {
+        return Err("Only a postponed fixture can be rescheduled".to_string());
+    }
+
+    fixture.reschedule(date);
+
+    fixture_repo
+        .save(&fixture, tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(fixture)
+}
+
+/// Who submits the result of a fixture: either the referee assigned as first
+/// referee or an admin.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResultSubmitter {
+    FirstReferee(RefereeId),
+    Admin,
+}
+
+pub async fn record_fixture_result<TxCtx>(
+    fixture_id: FixtureId,
+    sets: Vec<SetScore>,
+    submitted_by: ResultSubmitter,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Fixture, String> {
+    let mut fixture = fixture_repo
+        .find_by_id(fixture_id, tx_ctx)
+        .await?
+        .expect("Fixture not found");
+
+    if fixture.is_read_only() {
+        return Err("Fixture is read-only because its season is closed".to_string());
+    }
+
+    if let ResultSubmitter::FirstReferee(referee_id) = submitted_by {
+        if fixture.first_referee().map(|r| r.id()) != Some(referee_id) {
+            return Err(
+                "Only the assigned first referee or an admin may record the result".to_string(),
+            );
+        }
+    }
+
+    if !fixture.is_scheduled() {
+        return Err("Only a scheduled fixture can have its result recorded".to_string());
+    }
+
+    if *fixture.date() > Utc::now() {
+        return Err("Fixture has not been played yet".to_string());
+    }
+
+    let result =
+        FixtureResult::from_sets(sets, fixture.team_home().id(), fixture.team_away().id())?;
+    fixture.record_result(result);
+
+    fixture_repo
+        .save(&fixture, tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(fixture)
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::Utc;
@@ -148,21 +310,31 @@ mod tests {
     use uuid::Uuid;
 
     use crate::{
-        application::{fixture_services::cancel_fixture, utils::assert_fixtures_values_eq},
+        application::{
+            fixture_services::{
+                cancel_fixture, postpone_fixture, record_fixture_result, reschedule_fixture,
+                ResultSubmitter,
+            },
+            utils::assert_fixtures_values_eq,
+        },
         domain::{
             aggregates::{
-                fixture::{Fixture, FixtureId, FixtureStatus},
+                fixture::{Fixture, FixtureId, FixtureStatus, SetScore},
+                league::{League, LeagueId},
+                referee::Referee,
+                season::{Season, SeasonId},
                 team::{Team, TeamId},
                 venue::{Venue, VenueId},
             },
             repositories::{
-                fixture_repo::MockFixtureRepository, team_repo::MockTeamRepository,
+                fixture_repo::MockFixtureRepository, league_repo::MockLeagueRepository,
+                season_repo::MockSeasonRepository, team_repo::MockTeamRepository,
                 venue_repo::MockVenueRepository,
             },
         },
     };
 
-    use super::create_fixture;
+    use super::{create_fixture, update_fixture_date};
 
     #[tokio::test]
     async fn test_create_fixture() {
@@ -170,7 +342,15 @@ mod tests {
         let mut fixture_repo = MockFixtureRepository::new();
         let mut venue_repo = MockVenueRepository::new();
         let mut team_repo = MockTeamRepository::new();
-
+        let mut league_repo = MockLeagueRepository::new();
+        let mut season_repo = MockSeasonRepository::new();
+
+        let league = League::from_id(LeagueId::from(Uuid::new_v4()), "League A".to_string());
+        let season = Season::from_id(
+            SeasonId::from(Uuid::new_v4()),
+            "Season 2024/25".to_string(),
+            false,
+        );
         let venue_id = VenueId::from(Uuid::new_v4());
         let team_home_id = TeamId::from(Uuid::new_v4());
         let team_away_id = TeamId::from(Uuid::new_v4());
@@ -182,9 +362,31 @@ mod tests {
             "City A".to_string(),
             None,
             None,
+            None,
+            false,
+        );
+        let team_home = Team::from_id(
+            team_home_id,
+            "Team A".to_string(),
+            "Club A".to_string(),
+            false,
         );
-        let team_home = Team::from_id(team_home_id, "Team A".to_string(), "Club A".to_string());
-        let team_away = Team::from_id(team_away_id, "Team B".to_string(), "Club B".to_string());
+        let team_away = Team::from_id(
+            team_away_id,
+            "Team B".to_string(),
+            "Club B".to_string(),
+            false,
+        );
+
+        league_repo
+            .expect_find_by_id()
+            .with(eq(league.id()), eq(&()))
+            .return_const(Ok(Some(league.clone())));
+
+        season_repo
+            .expect_find_by_id()
+            .with(eq(season.id()), eq(&()))
+            .return_const(Ok(Some(season.clone())));
 
         venue_repo
             .expect_find_by_id()
@@ -213,12 +415,18 @@ mod tests {
 
         let fixture_created = create_fixture(
             now,
+            league.id(),
+            season.id(),
             venue.id(),
             team_home.id(),
             team_away.id(),
+            1,
+            0,
             &fixture_repo,
             &venue_repo,
             &team_repo,
+            &league_repo,
+            &season_repo,
             &mut (),
         )
         .await
@@ -228,11 +436,16 @@ mod tests {
             fixture_created.id(),
             now,
             FixtureStatus::Scheduled,
+            league,
+            season,
             venue,
             team_home,
             team_away,
             None,
             None,
+            1,
+            0,
+            None,
         );
 
         assert_fixtures_values_eq(&fixture_created, &fixture_expected);
@@ -255,19 +468,36 @@ mod tests {
             "City A".to_string(),
             None,
             None,
+            None,
+            false,
+        );
+        let team_home = Team::from_id(
+            team_home_id,
+            "Team A".to_string(),
+            "Club A".to_string(),
+            false,
+        );
+        let team_away = Team::from_id(
+            team_away_id,
+            "Team B".to_string(),
+            "Club B".to_string(),
+            false,
         );
-        let team_home = Team::from_id(team_home_id, "Team A".to_string(), "Club A".to_string());
-        let team_away = Team::from_id(team_away_id, "Team B".to_string(), "Club B".to_string());
 
         let fixture = Fixture::from_id(
             fixture_id,
             now,
             FixtureStatus::Scheduled,
+            League::new("League A"),
+            Season::new("Season 2024/25"),
             venue,
             team_home,
             team_away,
             None,
             None,
+            0,
+            0,
+            None,
         );
 
         fixture_repo
@@ -301,19 +531,36 @@ mod tests {
             "City A".to_string(),
             None,
             None,
+            None,
+            false,
+        );
+        let team_home = Team::from_id(
+            team_home_id,
+            "Team A".to_string(),
+            "Club A".to_string(),
+            false,
+        );
+        let team_away = Team::from_id(
+            team_away_id,
+            "Team B".to_string(),
+            "Club B".to_string(),
+            false,
         );
-        let team_home = Team::from_id(team_home_id, "Team A".to_string(), "Club A".to_string());
-        let team_away = Team::from_id(team_away_id, "Team B".to_string(), "Club B".to_string());
 
         let fixture = Fixture::from_id(
             fixture_id,
             now,
             FixtureStatus::Cancelled,
+            League::new("League A"),
+            Season::new("Season 2024/25"),
             venue,
             team_home,
             team_away,
             None,
             None,
+            0,
+            0,
+            None,
         );
 
         fixture_repo
@@ -326,4 +573,259 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_given_scheduled_fixture_when_postpone_and_reschedule_then_scheduled_at_new_date()
+    {
+        let now = Utc::now();
+        let rescheduled_date = now + chrono::Duration::days(7);
+        let mut fixture_repo = MockFixtureRepository::new();
+
+        let fixture_id = FixtureId::from(Uuid::new_v4());
+        let venue = Venue::from_id(
+            VenueId::from(Uuid::new_v4()),
+            "Venue A".to_string(),
+            "Location A".to_string(),
+            "12345".to_string(),
+            "City A".to_string(),
+            None,
+            None,
+            None,
+            false,
+        );
+        let team_home = Team::from_id(
+            TeamId::from(Uuid::new_v4()),
+            "Team A".to_string(),
+            "Club A".to_string(),
+            false,
+        );
+        let team_away = Team::from_id(
+            TeamId::from(Uuid::new_v4()),
+            "Team B".to_string(),
+            "Club B".to_string(),
+            false,
+        );
+
+        let fixture = Fixture::from_id(
+            fixture_id,
+            now,
+            FixtureStatus::Scheduled,
+            League::new("League A"),
+            Season::new("Season 2024/25"),
+            venue,
+            team_home,
+            team_away,
+            None,
+            None,
+            0,
+            0,
+            None,
+        );
+
+        let mut postponed_fixture = fixture.clone();
+        postponed_fixture.postpone();
+
+        let mut seq = mockall::Sequence::new();
+        fixture_repo
+            .expect_find_by_id()
+            .with(eq(fixture_id), eq(&()))
+            .times(1)
+            .in_sequence(&mut seq)
+            .return_const(Ok(Some(fixture.clone())));
+        fixture_repo
+            .expect_find_by_id()
+            .with(eq(fixture_id), eq(&()))
+            .times(1)
+            .in_sequence(&mut seq)
+            .return_const(Ok(Some(postponed_fixture)));
+        fixture_repo.expect_save().return_const(Ok(()));
+
+        let fixture_postponed = postpone_fixture(fixture_id, &fixture_repo, &mut ())
+            .await
+            .unwrap();
+        assert!(fixture_postponed.is_postponed());
+
+        let fixture_rescheduled =
+            reschedule_fixture(fixture_id, rescheduled_date, &fixture_repo, &mut ())
+                .await
+                .unwrap();
+        assert!(fixture_rescheduled.is_scheduled());
+        assert_eq!(*fixture_rescheduled.date(), rescheduled_date);
+    }
+
+    #[tokio::test]
+    async fn test_given_fixture_in_closed_season_when_change_date_then_error() {
+        let now = Utc::now();
+        let mut fixture_repo = MockFixtureRepository::new();
+
+        let fixture_id = FixtureId::from(Uuid::new_v4());
+        let venue = Venue::from_id(
+            VenueId::from(Uuid::new_v4()),
+            "Venue A".to_string(),
+            "Location A".to_string(),
+            "12345".to_string(),
+            "City A".to_string(),
+            None,
+            None,
+            None,
+            false,
+        );
+        let team_home = Team::new("Team A", "Club A");
+        let team_away = Team::new("Team B", "Club B");
+        let mut season = Season::new("Season 2023/24");
+        season.close();
+
+        let fixture = Fixture::from_id(
+            fixture_id,
+            now,
+            FixtureStatus::Scheduled,
+            League::new("League A"),
+            season,
+            venue,
+            team_home,
+            team_away,
+            None,
+            None,
+            0,
+            0,
+            None,
+        );
+
+        fixture_repo
+            .expect_find_by_id()
+            .with(eq(fixture_id), eq(&()))
+            .return_const(Ok(Some(fixture.clone())));
+        fixture_repo.expect_save().never();
+
+        let result = update_fixture_date(fixture_id, now, &fixture_repo, &mut ()).await;
+
+        assert!(result.is_err());
+    }
+
+    fn past_fixture_with_first_referee(first_referee: Referee) -> Fixture {
+        let venue = Venue::from_id(
+            VenueId::from(Uuid::new_v4()),
+            "Venue A".to_string(),
+            "Location A".to_string(),
+            "12345".to_string(),
+            "City A".to_string(),
+            None,
+            None,
+            None,
+            false,
+        );
+
+        Fixture::from_id(
+            FixtureId::from(Uuid::new_v4()),
+            Utc::now() - chrono::Duration::hours(2),
+            FixtureStatus::Scheduled,
+            League::new("League A"),
+            Season::new("Season 2024/25"),
+            venue,
+            Team::new("Team A", "Club A"),
+            Team::new("Team B", "Club B"),
+            Some(first_referee),
+            None,
+            0,
+            0,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_given_first_referee_when_record_result_then_fixture_played() {
+        let mut fixture_repo = MockFixtureRepository::new();
+        let referee = Referee::new("John Doe", "Club C", 0);
+        let fixture = past_fixture_with_first_referee(referee.clone());
+        let fixture_id = fixture.id();
+        let team_away_id = fixture.team_away().id();
+
+        fixture_repo
+            .expect_find_by_id()
+            .with(eq(fixture_id), eq(&()))
+            .return_const(Ok(Some(fixture)));
+        fixture_repo.expect_save().times(1).return_const(Ok(()));
+
+        let sets = vec![
+            SetScore { home: 25, away: 20 },
+            SetScore { home: 18, away: 25 },
+            SetScore { home: 22, away: 25 },
+            SetScore { home: 20, away: 25 },
+        ];
+
+        let fixture_played = record_fixture_result(
+            fixture_id,
+            sets,
+            ResultSubmitter::FirstReferee(referee.id()),
+            &fixture_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+
+        assert!(fixture_played.is_played());
+        let result = fixture_played.result().unwrap();
+        assert_eq!(result.sets().len(), 4);
+        assert_eq!(result.winner(), team_away_id);
+    }
+
+    #[tokio::test]
+    async fn test_given_other_referee_when_record_result_then_error() {
+        let mut fixture_repo = MockFixtureRepository::new();
+        let fixture = past_fixture_with_first_referee(Referee::new("John Doe", "Club C", 0));
+        let fixture_id = fixture.id();
+        let other_referee = Referee::new("Jane Doe", "Club D", 0);
+
+        fixture_repo
+            .expect_find_by_id()
+            .with(eq(fixture_id), eq(&()))
+            .return_const(Ok(Some(fixture)));
+        fixture_repo.expect_save().never();
+
+        let result = record_fixture_result(
+            fixture_id,
+            vec![
+                SetScore { home: 25, away: 20 },
+                SetScore { home: 25, away: 23 },
+                SetScore { home: 25, away: 18 },
+            ],
+            ResultSubmitter::FirstReferee(other_referee.id()),
+            &fixture_repo,
+            &mut (),
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_given_result_which_is_not_best_of_five_when_record_result_then_error() {
+        let fixture = past_fixture_with_first_referee(Referee::new("John Doe", "Club C", 0));
+        let won = SetScore { home: 25, away: 20 };
+        let lost = SetScore { home: 20, away: 25 };
+
+        for sets in [
+            vec![won],
+            vec![won, won, lost, lost],
+            vec![won, won, won, lost],
+            vec![won, lost, won, lost, lost, won],
+        ] {
+            let mut fixture_repo = MockFixtureRepository::new();
+            fixture_repo
+                .expect_find_by_id()
+                .return_const(Ok(Some(fixture.clone())));
+            fixture_repo.expect_save().never();
+
+            let result = record_fixture_result(
+                fixture.id(),
+                sets.clone(),
+                ResultSubmitter::Admin,
+                &fixture_repo,
+                &mut (),
+            )
+            .await;
+
+            assert!(result.is_err(), "Result {:?} should be rejected", sets);
+        }
+    }
 }
@@ -0,0 +1,19 @@
+use log::debug;
+
+use crate::domain::{aggregates::league::League, repositories::league_repo::LeagueRepository};
+
+pub async fn create_league<TxCtx>(
+    name: &str,
+    repo: &impl LeagueRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<League, String> {
+    let league = League::new(name);
+
+    repo.save(&league, tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    debug!("League created: {:?}", league);
+
+    Ok(league)
+}
@@ -1,17 +1,27 @@
+use chrono::Utc;
 use log::debug;
 
 use crate::domain::{
     aggregates::referee::{Referee, RefereeId},
-    repositories::referee_repo::RefereeRepository,
+    geo_location::GeoLocation,
+    repositories::{
+        assignment_repo::AssignmentRepository, availability_repo::AvailabilityRepository,
+        fixture_repo::FixtureRepository, referee_repo::RefereeRepository,
+    },
 };
 
 pub async fn create_referee<TxCtx>(
     name: &str,
     club: &str,
+    qualification_level: i32,
     repo: &impl RefereeRepository<TxCtx = TxCtx, Error = String>,
     tx_ctx: &mut TxCtx,
 ) -> Result<Referee, String> {
-    let referee = Referee::new(name, club);
+    if qualification_level < 0 {
+        return Err("Qualification level cannot be negative".to_string());
+    }
+
+    let referee = Referee::new(name, club, qualification_level);
 
     repo.save(&referee, tx_ctx)
         .await
@@ -41,16 +51,108 @@ pub async fn update_referee_club<TxCtx>(
     Ok(())
 }
 
+pub async fn update_referee_home<TxCtx>(
+    referee_id: RefereeId,
+    home: GeoLocation,
+    repo: &impl RefereeRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Referee, String> {
+    let referee = repo.find_by_id(referee_id, tx_ctx).await?;
+    let mut referee = referee.ok_or("Referee not found")?;
+    referee.change_home(home);
+
+    repo.save(&referee, tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    debug!("Referee updated: {:?}", referee);
+
+    Ok(referee)
+}
+
+/// Archives a retired referee, which is only possible as long as they are not committed to any upcoming fixture.
+/// Their availabilities and staged assignments are removed, because they cannot be fulfilled anymore.
+pub async fn archive_referee<TxCtx>(
+    referee_id: RefereeId,
+    referee_repo: &impl RefereeRepository<TxCtx = TxCtx, Error = String>,
+    assignment_repo: &impl AssignmentRepository<TxCtx = TxCtx, Error = String>,
+    availability_repo: &impl AvailabilityRepository<TxCtx = TxCtx, Error = String>,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Referee, String> {
+    let mut referee = referee_repo
+        .find_by_id(referee_id, tx_ctx)
+        .await?
+        .ok_or("Referee not found")?;
+
+    if referee.is_archived() {
+        return Err("Referee is already archived".to_string());
+    }
+
+    let now = Utc::now();
+    let committed_assignments = assignment_repo
+        .find_all_committed_by_referee(referee_id, tx_ctx)
+        .await?;
+    for assignment in committed_assignments.iter() {
+        let fixture = fixture_repo
+            .find_by_id(assignment.fixture_id(), tx_ctx)
+            .await?
+            .ok_or("Fixture not found")?;
+        if fixture.is_upcoming(&now) {
+            return Err("Referee is still committed to upcoming fixtures".to_string());
+        }
+    }
+
+    let staged_assignments = assignment_repo.find_all_staged(tx_ctx).await?;
+    for assignment in staged_assignments
+        .iter()
+        .filter(|a| a.referee_id() == referee_id)
+    {
+        assignment_repo.delete(assignment, tx_ctx).await?;
+    }
+
+    let availabilities = availability_repo
+        .get_availabilities_for_referee(&referee, tx_ctx)
+        .await?;
+    availability_repo
+        .withdraw_availabilities(&availabilities, &referee, tx_ctx)
+        .await?;
+
+    referee.archive();
+
+    referee_repo
+        .save(&referee, tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    debug!("Referee archived: {:?}", referee);
+
+    Ok(referee)
+}
+
 #[cfg(test)]
 mod tests {
     use std::cell::RefCell;
     use std::collections::HashMap;
 
+    use uuid::Uuid;
+
     use crate::{
-        application::referee_services::{create_referee, update_referee_club},
+        application::referee_services::{
+            archive_referee, create_referee, update_referee_club, update_referee_home,
+        },
         domain::{
-            aggregates::referee::{Referee, RefereeId},
-            repositories::referee_repo::RefereeRepository,
+            aggregates::{
+                assignment::{Assignment, AssignmentRefereeRole},
+                fixture::FixtureId,
+                referee::{Referee, RefereeId},
+            },
+            geo_location::GeoLocation,
+            repositories::{
+                assignment_repo::MockAssignmentRepository,
+                availability_repo::MockAvailabilityRepository, fixture_repo::MockFixtureRepository,
+                referee_repo::RefereeRepository,
+            },
         },
     };
 
@@ -98,11 +200,12 @@ mod tests {
     async fn test_create_referee() {
         let repo = TestRepo::new();
 
-        let referee = create_referee("John Doe", "Club A", &repo, &mut ())
+        let referee = create_referee("John Doe", "Club A", 2, &repo, &mut ())
             .await
             .unwrap();
         assert_eq!(referee.club(), "Club A");
         assert_eq!(referee.name(), "John Doe");
+        assert_eq!(referee.qualification_level(), 2);
 
         let all_referees = repo.get_all(&mut ()).await.unwrap();
         assert_eq!(all_referees.len(), 1);
@@ -114,7 +217,7 @@ mod tests {
     async fn test_update_referee_club() {
         let repo = TestRepo::new();
 
-        let referee = create_referee("John Doe", "Club A", &repo, &mut ())
+        let referee = create_referee("John Doe", "Club A", 2, &repo, &mut ())
             .await
             .unwrap();
         assert_eq!(referee.club(), "Club A");
@@ -129,4 +232,94 @@ mod tests {
         assert_eq!(all_referees[0].club(), "Club B");
         assert_eq!(all_referees[0].name(), "John Doe");
     }
+
+    #[tokio::test]
+    async fn test_update_referee_home() {
+        let repo = TestRepo::new();
+
+        let referee = create_referee("John Doe", "Club A", 2, &repo, &mut ())
+            .await
+            .unwrap();
+        assert_eq!(referee.home(), None);
+
+        let home = GeoLocation::new(48.2082, 16.3738).unwrap();
+        let referee = update_referee_home(referee.id(), home, &repo, &mut ())
+            .await
+            .unwrap();
+        assert_eq!(referee.home(), Some(home));
+
+        let all_referees = repo.get_all(&mut ()).await.unwrap();
+        assert_eq!(all_referees[0].home(), Some(home));
+    }
+
+    #[tokio::test]
+    async fn test_archive_referee() {
+        let repo = TestRepo::new();
+
+        let referee = create_referee("John Doe", "Club A", 2, &repo, &mut ())
+            .await
+            .unwrap();
+        assert!(!referee.is_archived());
+
+        let fixture_id = FixtureId::from(Uuid::new_v4());
+        let staged_assignment =
+            Assignment::staged(fixture_id, referee.id(), AssignmentRefereeRole::First);
+        let other_staged_assignment = Assignment::staged(
+            fixture_id,
+            RefereeId::from(Uuid::new_v4()),
+            AssignmentRefereeRole::Second,
+        );
+
+        let mut assignment_repo = MockAssignmentRepository::new();
+        assignment_repo
+            .expect_find_all_committed_by_referee()
+            .return_const(Ok(vec![]));
+        assignment_repo
+            .expect_find_all_staged()
+            .return_const(Ok(vec![staged_assignment.clone(), other_staged_assignment]));
+        // NOTE: only the staged assignment of the archived referee is removed
+        assignment_repo
+            .expect_delete()
+            .withf(move |assignment, _| *assignment == staged_assignment)
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let mut availability_repo = MockAvailabilityRepository::new();
+        availability_repo
+            .expect_get_availabilities_for_referee()
+            .return_const(Ok(vec![fixture_id]));
+        availability_repo
+            .expect_withdraw_availabilities()
+            .withf(move |fixture_ids, _, _| fixture_ids == [fixture_id])
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let fixture_repo = MockFixtureRepository::new();
+
+        let archived_referee = archive_referee(
+            referee.id(),
+            &repo,
+            &assignment_repo,
+            &availability_repo,
+            &fixture_repo,
+            &mut (),
+        )
+        .await
+        .unwrap();
+        assert!(archived_referee.is_archived());
+
+        let all_referees = repo.get_all(&mut ()).await.unwrap();
+        assert!(all_referees[0].is_archived());
+
+        let result = archive_referee(
+            referee.id(),
+            &repo,
+            &assignment_repo,
+            &availability_repo,
+            &fixture_repo,
+            &mut (),
+        )
+        .await;
+        assert_eq!(result.unwrap_err(), "Referee is already archived");
+    }
 }
@@ -0,0 +1,47 @@
+use log::debug;
+
+use crate::domain::{
+    aggregates::season::{Season, SeasonId},
+    repositories::season_repo::SeasonRepository,
+};
+
+pub async fn create_season<TxCtx>(
+    name: &str,
+    repo: &impl SeasonRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Season, String> {
+    let season = Season::new(name);
+
+    repo.save(&season, tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    debug!("Season created: {:?}", season);
+
+    Ok(season)
+}
+
+pub async fn close_season<TxCtx>(
+    season_id: SeasonId,
+    repo: &impl SeasonRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Season, String> {
+    let mut season = repo
+        .find_by_id(season_id, tx_ctx)
+        .await?
+        .ok_or("Season not found")?;
+
+    if season.is_closed() {
+        return Err("Season is already closed".to_string());
+    }
+
+    season.close();
+
+    repo.save(&season, tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    debug!("Season closed: {:?}", season);
+
+    Ok(season)
+}
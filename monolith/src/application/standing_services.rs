@@ -0,0 +1,182 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use crate::domain::{
+    aggregates::{league::LeagueId, season::SeasonId, standing::Standing},
+    repositories::fixture_repo::FixtureRepository,
+};
+
+/// Computes the standings of a league and season from its played fixtures,
+/// ordered by table points, then wins, then set ratio and finally points ratio
+pub async fn get_standings<TxCtx>(
+    league_id: LeagueId,
+    season_id: SeasonId,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Vec<Standing>, String> {
+    let fixtures = fixture_repo
+        .find_by_league_and_season(Some(league_id), Some(season_id), tx_ctx)
+        .await?;
+
+    let mut standings = HashMap::new();
+    for fixture in fixtures.iter().filter(|f| f.is_played()) {
+        let result = match fixture.result() {
+            Some(result) => result,
+            None => continue,
+        };
+
+        let sets_home = result.sets().iter().filter(|s| s.home > s.away).count() as i32;
+        let sets_away = result.sets().len() as i32 - sets_home;
+        let points_home: i32 = result.sets().iter().map(|s| s.home).sum();
+        let points_away: i32 = result.sets().iter().map(|s| s.away).sum();
+
+        standings
+            .entry(fixture.team_home().id())
+            .or_insert_with(|| Standing::new(fixture.team_home().clone()))
+            .record_fixture(sets_home, sets_away, points_home, points_away);
+        standings
+            .entry(fixture.team_away().id())
+            .or_insert_with(|| Standing::new(fixture.team_away().clone()))
+            .record_fixture(sets_away, sets_home, points_away, points_home);
+    }
+
+    let mut standings: Vec<Standing> = standings.into_values().collect();
+    standings.sort_by(|a, b| {
+        b.table_points()
+            .cmp(&a.table_points())
+            .then(b.wins().cmp(&a.wins()))
+            .then(
+                b.set_ratio()
+                    .partial_cmp(&a.set_ratio())
+                    .unwrap_or(Ordering::Equal),
+            )
+            .then(
+                b.points_ratio()
+                    .partial_cmp(&a.points_ratio())
+                    .unwrap_or(Ordering::Equal),
+            )
+    });
+
+    Ok(standings)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+
+    use crate::{
+        application::standing_services::get_standings,
+        domain::{
+            aggregates::{
+                fixture::{Fixture, FixtureId, FixtureResult, FixtureStatus, SetScore},
+                league::League,
+                season::Season,
+                team::Team,
+                venue::{Venue, VenueId},
+            },
+            repositories::fixture_repo::MockFixtureRepository,
+        },
+    };
+
+    fn played_fixture(
+        league: &League,
+        season: &Season,
+        team_home: &Team,
+        team_away: &Team,
+        sets: Vec<SetScore>,
+    ) -> Fixture {
+        let venue = Venue::from_id(
+            VenueId::from(Uuid::new_v4()),
+            "Venue A".to_string(),
+            "Location A".to_string(),
+            "12345".to_string(),
+            "City A".to_string(),
+            None,
+            None,
+            None,
+            false,
+        );
+        let result = FixtureResult::from_sets(sets, team_home.id(), team_away.id()).unwrap();
+
+        Fixture::from_id(
+            FixtureId::from(Uuid::new_v4()),
+            Utc::now(),
+            FixtureStatus::Played,
+            league.clone(),
+            season.clone(),
+            venue,
+            team_home.clone(),
+            team_away.clone(),
+            None,
+            None,
+            0,
+            0,
+            Some(result),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_given_played_fixtures_when_get_standings_then_ordered_by_table_points() {
+        let mut fixture_repo = MockFixtureRepository::new();
+
+        let league = League::new("League A");
+        let season = Season::new("Season 2024/25");
+        let team_a = Team::new("Team A", "Club A");
+        let team_b = Team::new("Team B", "Club B");
+        let team_c = Team::new("Team C", "Club C");
+
+        // team b wins in five sets against team a, team c wins in three sets against team b
+        let fixtures = vec![
+            played_fixture(
+                &league,
+                &season,
+                &team_a,
+                &team_b,
+                vec![
+                    SetScore { home: 25, away: 20 },
+                    SetScore { home: 22, away: 25 },
+                    SetScore { home: 25, away: 23 },
+                    SetScore { home: 19, away: 25 },
+                    SetScore { home: 12, away: 15 },
+                ],
+            ),
+            played_fixture(
+                &league,
+                &season,
+                &team_b,
+                &team_c,
+                vec![
+                    SetScore { home: 20, away: 25 },
+                    SetScore { home: 23, away: 25 },
+                    SetScore { home: 18, away: 25 },
+                ],
+            ),
+        ];
+
+        fixture_repo
+            .expect_find_by_league_and_season()
+            .with(eq(Some(league.id())), eq(Some(season.id())), eq(&()))
+            .return_const(Ok(fixtures));
+
+        let standings = get_standings(league.id(), season.id(), &fixture_repo, &mut ())
+            .await
+            .unwrap();
+
+        assert_eq!(standings.len(), 3);
+
+        assert_eq!(standings[0].team().id(), team_c.id());
+        assert_eq!(standings[0].table_points(), 3);
+
+        assert_eq!(standings[1].team().id(), team_b.id());
+        assert_eq!(standings[1].played(), 2);
+        assert_eq!(standings[1].wins(), 1);
+        assert_eq!(standings[1].losses(), 1);
+        assert_eq!(standings[1].sets_won(), 3);
+        assert_eq!(standings[1].sets_lost(), 5);
+        assert_eq!(standings[1].table_points(), 2);
+
+        assert_eq!(standings[2].team().id(), team_a.id());
+        assert_eq!(standings[2].table_points(), 1);
+    }
+}
@@ -1,6 +1,10 @@
+use chrono::Utc;
 use log::debug;
 
-use crate::domain::{aggregates::team::Team, repositories::team_repo::TeamRepository};
+use crate::domain::{
+    aggregates::team::{Team, TeamId},
+    repositories::{fixture_repo::FixtureRepository, team_repo::TeamRepository},
+};
 
 pub async fn create_team<TxCtx>(
     name: &str,
@@ -16,3 +20,73 @@ pub async fn create_team<TxCtx>(
 
     Ok(team)
 }
+
+pub async fn update_team_name<TxCtx>(
+    team_id: TeamId,
+    name: &str,
+    repo: &impl TeamRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Team, String> {
+    let team = repo.find_by_id(team_id, tx_ctx).await?;
+    let mut team = team.ok_or("Team not found")?;
+    team.change_name(name);
+
+    repo.save(&team, tx_ctx).await.map_err(|e| e.to_string())?;
+
+    debug!("Team name changed: {:?}", team);
+
+    Ok(team)
+}
+
+pub async fn update_team_club<TxCtx>(
+    team_id: TeamId,
+    club: &str,
+    repo: &impl TeamRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Team, String> {
+    let team = repo.find_by_id(team_id, tx_ctx).await?;
+    let mut team = team.ok_or("Team not found")?;
+    team.change_club(club);
+
+    repo.save(&team, tx_ctx).await.map_err(|e| e.to_string())?;
+
+    debug!("Team club changed: {:?}", team);
+
+    Ok(team)
+}
+
+/// Archives a team, which is only possible as long as it is not playing in any upcoming fixture
+pub async fn archive_team<TxCtx>(
+    team_id: TeamId,
+    team_repo: &impl TeamRepository<TxCtx = TxCtx, Error = String>,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Team, String> {
+    let mut team = team_repo
+        .find_by_id(team_id, tx_ctx)
+        .await?
+        .ok_or("Team not found")?;
+
+    if team.is_archived() {
+        return Err("Team is already archived".to_string());
+    }
+
+    let now = Utc::now();
+    let fixtures = fixture_repo.get_all(tx_ctx).await?;
+    if fixtures.iter().any(|f| {
+        f.is_upcoming(&now) && (f.team_home().id() == team_id || f.team_away().id() == team_id)
+    }) {
+        return Err("Team is still playing in upcoming fixtures".to_string());
+    }
+
+    team.archive();
+
+    team_repo
+        .save(&team, tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    debug!("Team archived: {:?}", team);
+
+    Ok(team)
+}
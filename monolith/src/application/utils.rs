@@ -1,5 +1,7 @@
 use crate::domain::aggregates::fixture::Fixture;
+use crate::domain::aggregates::league::League;
 use crate::domain::aggregates::referee::Referee;
+use crate::domain::aggregates::season::Season;
 use crate::domain::aggregates::team::Team;
 use crate::domain::aggregates::venue::Venue;
 
@@ -28,11 +30,28 @@ pub fn assert_teams_values_eq(team1: &Team, team2: &Team) {
     assert_eq!(team1.club(), team2.club());
 }
 
+#[allow(dead_code)]
+pub fn assert_leagues_values_eq(league1: &League, league2: &League) {
+    assert_eq!(league1.id(), league2.id());
+    assert_eq!(league1.name(), league2.name());
+}
+
+#[allow(dead_code)]
+pub fn assert_seasons_values_eq(season1: &Season, season2: &Season) {
+    assert_eq!(season1.id(), season2.id());
+    assert_eq!(season1.name(), season2.name());
+    assert_eq!(season1.is_closed(), season2.is_closed());
+}
+
 #[allow(dead_code)]
 pub fn assert_referees_values_eq(referee1: &Referee, referee2: &Referee) {
     assert_eq!(referee1.id(), referee2.id());
     assert_eq!(referee1.name(), referee2.name());
     assert_eq!(referee1.club(), referee2.club());
+    assert_eq!(
+        referee1.qualification_level(),
+        referee2.qualification_level()
+    );
 }
 
 #[allow(dead_code)]
@@ -40,10 +59,20 @@ pub fn assert_fixtures_values_eq(fixture1: &Fixture, fixture2: &Fixture) {
     assert_eq!(fixture1.id(), fixture2.id());
     assert_eq!(fixture1.date(), fixture2.date());
     assert_eq!(fixture1.status(), fixture2.status());
+    assert_eq!(
+        fixture1.first_referee_min_level(),
+        fixture2.first_referee_min_level()
+    );
+    assert_eq!(
+        fixture1.second_referee_min_level(),
+        fixture2.second_referee_min_level()
+    );
 
-    assert_venues_values_eq(&fixture1.venue(), &fixture2.venue());
-    assert_teams_values_eq(&fixture1.team_home(), &fixture2.team_home());
-    assert_teams_values_eq(&fixture1.team_away(), &fixture2.team_away());
+    assert_leagues_values_eq(fixture1.league(), fixture2.league());
+    assert_seasons_values_eq(fixture1.season(), fixture2.season());
+    assert_venues_values_eq(fixture1.venue(), fixture2.venue());
+    assert_teams_values_eq(fixture1.team_home(), fixture2.team_home());
+    assert_teams_values_eq(fixture1.team_away(), fixture2.team_away());
 
     assert_options_eq_with_comparator(
         &fixture1.first_referee(),
@@ -1,6 +1,11 @@
+use chrono::Utc;
 use log::debug;
 
-use crate::domain::{aggregates::venue::Venue, repositories::venue_repo::VenueRepository};
+use crate::domain::{
+    aggregates::venue::{Venue, VenueId},
+    geo_location::GeoLocation,
+    repositories::{fixture_repo::FixtureRepository, venue_repo::VenueRepository},
+};
 
 pub async fn create_venue<TxCtx>(
     name: &str,
@@ -9,13 +14,87 @@ pub async fn create_venue<TxCtx>(
     city: &str,
     telephone: Option<String>,
     email: Option<String>,
+    location: Option<GeoLocation>,
     repo: &impl VenueRepository<TxCtx = TxCtx, Error = String>,
     tx_ctx: &mut TxCtx,
 ) -> Result<Venue, String> {
-    let venue = Venue::new(&name, &street, &zip, &city, telephone, email);
+    let venue = Venue::new(&name, &street, &zip, &city, telephone, email, location);
 
     repo.save(&venue, tx_ctx).await.map_err(|e| e.to_string())?;
 
     debug!("Venue created: {:?}", venue);
     Ok(venue)
 }
+
+pub async fn update_venue_address<TxCtx>(
+    venue_id: VenueId,
+    street: &str,
+    zip: &str,
+    city: &str,
+    location: Option<GeoLocation>,
+    repo: &impl VenueRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Venue, String> {
+    let venue = repo.find_by_id(venue_id, tx_ctx).await?;
+    let mut venue = venue.ok_or("Venue not found")?;
+    venue.change_address(street, zip, city, location);
+
+    repo.save(&venue, tx_ctx).await.map_err(|e| e.to_string())?;
+
+    debug!("Venue address changed: {:?}", venue);
+    Ok(venue)
+}
+
+pub async fn update_venue_contact<TxCtx>(
+    venue_id: VenueId,
+    telephone: Option<String>,
+    email: Option<String>,
+    repo: &impl VenueRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Venue, String> {
+    let venue = repo.find_by_id(venue_id, tx_ctx).await?;
+    let mut venue = venue.ok_or("Venue not found")?;
+    venue.change_contact(telephone, email);
+
+    repo.save(&venue, tx_ctx).await.map_err(|e| e.to_string())?;
+
+    debug!("Venue contact changed: {:?}", venue);
+    Ok(venue)
+}
+
+/// Archives a venue, which is only possible as long as no upcoming fixture takes place there
+pub async fn archive_venue<TxCtx>(
+    venue_id: VenueId,
+    venue_repo: &impl VenueRepository<TxCtx = TxCtx, Error = String>,
+    fixture_repo: &impl FixtureRepository<TxCtx = TxCtx, Error = String>,
+    tx_ctx: &mut TxCtx,
+) -> Result<Venue, String> {
+    let mut venue = venue_repo
+        .find_by_id(venue_id, tx_ctx)
+        .await?
+        .ok_or("Venue not found")?;
+
+    if venue.is_archived() {
+        return Err("Venue is already archived".to_string());
+    }
+
+    let now = Utc::now();
+    let fixtures = fixture_repo.get_all(tx_ctx).await?;
+    if fixtures
+        .iter()
+        .any(|f| f.is_upcoming(&now) && f.venue().id() == venue_id)
+    {
+        return Err("Venue is still used by upcoming fixtures".to_string());
+    }
+
+    venue.archive();
+
+    venue_repo
+        .save(&venue, tx_ctx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    debug!("Venue archived: {:?}", venue);
+
+    Ok(venue)
+}
@@ -0,0 +1,86 @@
+use std::process::ExitCode;
+
+use restinterface::{import_fixtures, FixtureImportDTO, LeagueIdDTO, SeasonIdDTO};
+use uuid::Uuid;
+
+const USAGE: &str = "Usage: import_fixtures <league-id> <season-id> <csv-file> [--dry-run]";
+
+/// Imports the fixtures of a federation CSV through the running monolith, with
+/// --dry-run all rows are checked but no fixture is created
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let dry_run = args.iter().any(|a| a == "--dry-run");
+    let args: Vec<&String> = args.iter().filter(|a| *a != "--dry-run").collect();
+
+    if args.len() != 3 {
+        eprintln!("{}", USAGE);
+        return ExitCode::FAILURE;
+    }
+
+    let (league_id, season_id) = match (Uuid::parse_str(args[0]), Uuid::parse_str(args[1])) {
+        (Ok(league_id), Ok(season_id)) => (league_id, season_id),
+        _ => {
+            eprintln!("League and season id must be UUIDs\n{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let csv = match std::fs::read_to_string(args[2]) {
+        Ok(csv) => csv,
+        Err(e) => {
+            eprintln!("Cannot read {}: {}", args[2], e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let fixture_import = FixtureImportDTO {
+        league_id: LeagueIdDTO(league_id),
+        season_id: SeasonIdDTO(season_id),
+        csv,
+        first_referee_min_level: 0,
+        second_referee_min_level: 0,
+        dry_run,
+    };
+
+    let rows = match import_fixtures(&fixture_import).await {
+        Ok(rows) => rows,
+        Err(e) => {
+            eprintln!("Import failed: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for row in rows.iter() {
+        match (&row.fixture, &row.error) {
+            (Some(fixture), _) => println!(
+                "line {}: ok {} - {} at {} on {}",
+                row.line,
+                fixture.team_home.name,
+                fixture.team_away.name,
+                fixture.venue.name,
+                fixture.date
+            ),
+            (None, Some(error)) => println!("line {}: error {}", row.line, error),
+            (None, None) => println!("line {}: no outcome", row.line),
+        }
+    }
+
+    let failed = rows.iter().filter(|r| r.error.is_some()).count();
+    println!(
+        "{} of {} rows {}",
+        rows.len() - failed,
+        rows.len(),
+        if dry_run {
+            "would be imported (dry run)"
+        } else {
+            "imported"
+        }
+    );
+
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -2,6 +2,10 @@
 pub struct AppConfig {
     pub db_url: String,
     pub server_host: String,
+    pub expense_rate_per_km_cents: i64,
+    pub expense_fee_first_referee_cents: i64,
+    pub expense_fee_second_referee_cents: i64,
+    pub access_token_secret: String,
 }
 
 impl AppConfig {
@@ -9,6 +13,14 @@ impl AppConfig {
         AppConfig {
             db_url: get_from_env_or_panic("DB_URL"),
             server_host: get_from_env_or_panic("HOST"),
+            expense_rate_per_km_cents: parse_from_env_or_panic("EXPENSE_RATE_PER_KM_CENTS"),
+            expense_fee_first_referee_cents: parse_from_env_or_panic(
+                "EXPENSE_FEE_FIRST_REFEREE_CENTS",
+            ),
+            expense_fee_second_referee_cents: parse_from_env_or_panic(
+                "EXPENSE_FEE_SECOND_REFEREE_CENTS",
+            ),
+            access_token_secret: get_from_env_or_panic("ACCESS_TOKEN_SECRET"),
         }
     }
 }
@@ -16,3 +28,10 @@ impl AppConfig {
 pub fn get_from_env_or_panic(key: &str) -> String {
     std::env::var(key).unwrap_or_else(|err| panic!("Cannot find {} in env: {}", key, err))
 }
+
+fn parse_from_env_or_panic(key: &str) -> i64 {
+    let value = get_from_env_or_panic(key);
+    value
+        .parse()
+        .unwrap_or_else(|err| panic!("Invalid {} {}: {}", key, value, err))
+}
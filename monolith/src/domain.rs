@@ -1,2 +1,3 @@
 pub mod aggregates;
+pub mod geo_location;
 pub mod repositories;
\ No newline at end of file
@@ -2,4 +2,10 @@ pub mod fixture;
 pub mod referee;
 pub mod team;
 pub mod venue;
-pub mod assignment;
\ No newline at end of file
+pub mod assignment;
+pub mod league;
+pub mod season;
+pub mod standing;
+pub mod availability_lock;
+pub mod declaration_deadline;
+pub mod blackout;
\ No newline at end of file
@@ -0,0 +1,80 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::fixture::FixtureId;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub struct AvailabilityLockId(pub Uuid);
+
+/// The fixtures covered by a lock, either a single fixture or all fixtures
+/// within a date range (both ends inclusive)
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum AvailabilityLockScope {
+    Fixture(FixtureId),
+    DateRange {
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct AvailabilityLock {
+    id: AvailabilityLockId,
+    // NOTE: as long as the lock exists, availabilities of the covered fixtures cannot be changed
+    scope: AvailabilityLockScope,
+}
+
+impl TryFrom<String> for AvailabilityLockId {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Uuid::from_str(&value)
+            .map_err(|e| e.to_string())
+            .map(AvailabilityLockId)
+    }
+}
+
+impl From<Uuid> for AvailabilityLockId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl AvailabilityLock {
+    pub fn new(scope: AvailabilityLockScope) -> Self {
+        if let AvailabilityLockScope::DateRange { from, to } = scope {
+            if from > to {
+                // NOTE: this is not how we would like to handle this in a real application
+                panic!("Lock date range ends before it starts");
+            }
+        }
+
+        Self {
+            id: AvailabilityLockId(Uuid::new_v4()),
+            scope,
+        }
+    }
+
+    pub fn from_id(id: AvailabilityLockId, scope: AvailabilityLockScope) -> Self {
+        Self { id, scope }
+    }
+
+    pub fn id(&self) -> AvailabilityLockId {
+        self.id
+    }
+
+    pub fn scope(&self) -> AvailabilityLockScope {
+        self.scope
+    }
+
+    pub fn covers(&self, fixture_id: FixtureId, fixture_date: &DateTime<Utc>) -> bool {
+        match self.scope {
+            AvailabilityLockScope::Fixture(locked_fixture_id) => locked_fixture_id == fixture_id,
+            AvailabilityLockScope::DateRange { from, to } => {
+                from <= *fixture_date && *fixture_date <= to
+            }
+        }
+    }
+}
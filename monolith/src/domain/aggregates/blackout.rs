@@ -0,0 +1,84 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::referee::RefereeId;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub struct BlackoutId(pub Uuid);
+
+/// A period in which a referee is not available at all, e.g. holidays
+#[derive(Debug, Clone)]
+pub struct Blackout {
+    id: BlackoutId,
+    referee_id: RefereeId,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+}
+
+impl TryFrom<String> for BlackoutId {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Uuid::from_str(&value)
+            .map_err(|e| e.to_string())
+            .map(BlackoutId)
+    }
+}
+
+impl From<Uuid> for BlackoutId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl Blackout {
+    pub fn new(referee_id: RefereeId, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        if from > to {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("Blackout ends before it starts");
+        }
+
+        Self {
+            id: BlackoutId(Uuid::new_v4()),
+            referee_id,
+            from,
+            to,
+        }
+    }
+
+    pub fn from_id(
+        id: BlackoutId,
+        referee_id: RefereeId,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            referee_id,
+            from,
+            to,
+        }
+    }
+
+    pub fn id(&self) -> BlackoutId {
+        self.id
+    }
+
+    pub fn referee_id(&self) -> RefereeId {
+        self.referee_id
+    }
+
+    pub fn from(&self) -> &DateTime<Utc> {
+        &self.from
+    }
+
+    pub fn to(&self) -> &DateTime<Utc> {
+        &self.to
+    }
+
+    pub fn covers(&self, date: &DateTime<Utc>) -> bool {
+        self.from <= *date && *date <= self.to
+    }
+}
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+
+use super::fixture::FixtureId;
+
+#[derive(Debug, Clone)]
+pub struct DeclarationDeadline {
+    fixture_id: FixtureId,
+    // NOTE: declarations close automatically once the deadline has passed, no job needs to run for that
+    deadline: DateTime<Utc>,
+}
+
+impl DeclarationDeadline {
+    pub fn new(fixture_id: FixtureId, deadline: DateTime<Utc>) -> Self {
+        Self {
+            fixture_id,
+            deadline,
+        }
+    }
+
+    pub fn fixture_id(&self) -> FixtureId {
+        self.fixture_id
+    }
+
+    pub fn deadline(&self) -> &DateTime<Utc> {
+        &self.deadline
+    }
+
+    pub fn is_closed(&self, now: &DateTime<Utc>) -> bool {
+        self.deadline <= *now
+    }
+}
@@ -1,9 +1,16 @@
 use std::str::FromStr;
 
 use chrono::{DateTime, Utc};
+use shared::booking::GAME_HOUR_MINUTES;
 use uuid::Uuid;
 
-use super::{referee::Referee, team::Team, venue::Venue};
+use super::{
+    league::League,
+    referee::Referee,
+    season::Season,
+    team::{Team, TeamId},
+    venue::Venue,
+};
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
 pub struct FixtureId(pub Uuid);
@@ -27,39 +34,142 @@ impl From<Uuid> for FixtureId {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FixtureStatus {
     Scheduled,
+    Postponed,
+    Played,
     Cancelled,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetScore {
+    pub home: i32,
+    pub away: i32,
+}
+
+// NOTE: fixtures are played best-of-five
+const SETS_TO_WIN: usize = 3;
+const MAX_SETS: usize = 5;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixtureResult {
+    sets: Vec<SetScore>,
+    winner: TeamId,
+}
+
+impl FixtureResult {
+    pub fn new(sets: Vec<SetScore>, winner: TeamId) -> Self {
+        Self { sets, winner }
+    }
+
+    /// Fixtures are played best-of-five: the winner is the first team to win three sets, after which no
+    /// further set is played. A set cannot end in a draw.
+    pub fn from_sets(
+        sets: Vec<SetScore>,
+        team_home_id: TeamId,
+        team_away_id: TeamId,
+    ) -> Result<Self, String> {
+        if sets.is_empty() {
+            return Err("A result needs at least one set".to_string());
+        }
+
+        if sets.len() > MAX_SETS {
+            return Err(format!("A result cannot have more than {} sets", MAX_SETS));
+        }
+
+        if sets.iter().any(|s| s.home < 0 || s.away < 0) {
+            return Err("Set scores cannot be negative".to_string());
+        }
+
+        if sets.iter().any(|s| s.home == s.away) {
+            return Err("A set cannot end in a draw".to_string());
+        }
+
+        let mut home_sets_won = 0;
+        let mut away_sets_won = 0;
+        for (idx, set) in sets.iter().enumerate() {
+            if home_sets_won == SETS_TO_WIN || away_sets_won == SETS_TO_WIN {
+                return Err(format!(
+                    "Set {} was played after the fixture was already decided",
+                    idx + 1
+                ));
+            }
+
+            if set.home > set.away {
+                home_sets_won += 1;
+            } else {
+                away_sets_won += 1;
+            }
+        }
+
+        if home_sets_won != SETS_TO_WIN && away_sets_won != SETS_TO_WIN {
+            return Err(format!(
+                "The fixture is not decided, the winner needs {} sets",
+                SETS_TO_WIN
+            ));
+        }
+
+        let winner = if home_sets_won > away_sets_won {
+            team_home_id
+        } else {
+            team_away_id
+        };
+
+        Ok(Self { sets, winner })
+    }
+
+    pub fn sets(&self) -> &Vec<SetScore> {
+        &self.sets
+    }
+
+    pub fn winner(&self) -> TeamId {
+        self.winner
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Fixture {
     id: FixtureId,
     date: DateTime<Utc>,
+    league: League,
+    season: Season,
     venue: Venue,
     team_home: Team,
     team_away: Team,
     status: FixtureStatus,
     first_referee: Option<Referee>,
     second_referee: Option<Referee>,
+    // NOTE: the minimum qualification level a referee needs to officiate the fixture in the respective role
+    first_referee_min_level: i32,
+    second_referee_min_level: i32,
+    result: Option<FixtureResult>,
 }
 
 impl Fixture {
     pub fn new(
         date: DateTime<Utc>,
+        league: League,
+        season: Season,
         venue: Venue,
         team_home: Team,
         team_away: Team,
         first_referee: Option<Referee>,
         second_referee: Option<Referee>,
+        first_referee_min_level: i32,
+        second_referee_min_level: i32,
     ) -> Self {
         Self {
             id: FixtureId(Uuid::new_v4()),
             date,
+            league,
+            season,
             venue,
             team_home,
             team_away,
             status: FixtureStatus::Scheduled,
             first_referee,
             second_referee,
+            first_referee_min_level,
+            second_referee_min_level,
+            result: None,
         }
     }
 
@@ -67,21 +177,31 @@ impl Fixture {
         id: FixtureId,
         date: DateTime<Utc>,
         status: FixtureStatus,
+        league: League,
+        season: Season,
         venue: Venue,
         team_home: Team,
         team_away: Team,
         first_referee: Option<Referee>,
         second_referee: Option<Referee>,
+        first_referee_min_level: i32,
+        second_referee_min_level: i32,
+        result: Option<FixtureResult>,
     ) -> Self {
         Self {
             id,
             date,
+            league,
+            season,
             venue,
             team_home,
             team_away,
             status,
             first_referee,
             second_referee,
+            first_referee_min_level,
+            second_referee_min_level,
+            result,
         }
     }
 
@@ -93,6 +213,14 @@ impl Fixture {
         &self.date
     }
 
+    pub fn league(&self) -> &League {
+        &self.league
+    }
+
+    pub fn season(&self) -> &Season {
+        &self.season
+    }
+
     pub fn venue(&self) -> &Venue {
         &self.venue
     }
@@ -117,21 +245,103 @@ impl Fixture {
         self.second_referee.as_ref()
     }
 
+    pub fn first_referee_min_level(&self) -> i32 {
+        self.first_referee_min_level
+    }
+
+    pub fn second_referee_min_level(&self) -> i32 {
+        self.second_referee_min_level
+    }
+
     pub fn is_scheduled(&self) -> bool {
         self.status == FixtureStatus::Scheduled
     }
 
+    pub fn is_postponed(&self) -> bool {
+        self.status == FixtureStatus::Postponed
+    }
+
+    pub fn is_played(&self) -> bool {
+        self.status == FixtureStatus::Played
+    }
+
+    pub fn result(&self) -> Option<&FixtureResult> {
+        self.result.as_ref()
+    }
+
     pub fn is_cancelled(&self) -> bool {
         self.status == FixtureStatus::Cancelled
     }
 
+    /// A fixture becomes read-only once its season is closed
+    pub fn is_read_only(&self) -> bool {
+        self.season.is_closed()
+    }
+
+    /// A fixture is upcoming if it is scheduled in the future, or postponed and thus still waiting for its new date
+    pub fn is_upcoming(&self, now: &DateTime<Utc>) -> bool {
+        self.is_postponed() || (self.is_scheduled() && self.date > *now)
+    }
+
+    /// Two fixtures are at the same time if they start at the exact same date and time
+    pub fn is_at_same_time(&self, other: &Fixture) -> bool {
+        self.date == other.date
+    }
+
+    /// Two fixtures are "close" if they start within the same game-hour
+    pub fn is_close_to(&self, other: &Fixture) -> bool {
+        (self.date - other.date).num_minutes().abs() < GAME_HOUR_MINUTES
+    }
+
+    /// A referee cannot officiate two fixtures which are at the same time, or close to each other at different venues
+    pub fn is_in_conflict_with(&self, other: &Fixture) -> bool {
+        self.is_at_same_time(other)
+            || (self.is_close_to(other) && self.venue.id() != other.venue.id())
+    }
+
+    /// A referee has a conflict of interest if their club is playing in the fixture
+    pub fn is_conflict_of_interest(&self, referee: &Referee) -> bool {
+        referee.club() == self.team_home.club() || referee.club() == self.team_away.club()
+    }
+
+    /// A fixture can be cancelled as long as it is scheduled or postponed
     pub fn cancel(&mut self) {
+        if self.status != FixtureStatus::Scheduled && self.status != FixtureStatus::Postponed {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("Fixture is neither scheduled nor postponed");
+        }
+
+        self.status = FixtureStatus::Cancelled;
+    }
+
+    pub fn postpone(&mut self) {
         if self.status != FixtureStatus::Scheduled {
             // NOTE: this is not how we would like to handle this in a real application
             panic!("Fixture is not scheduled");
         }
 
-        self.status = FixtureStatus::Cancelled;
+        self.status = FixtureStatus::Postponed;
+    }
+
+    /// Moves a postponed fixture to its new date and schedules it again
+    pub fn reschedule(&mut self, date: DateTime<Utc>) {
+        if self.status != FixtureStatus::Postponed {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("Fixture is not postponed");
+        }
+
+        self.date = date;
+        self.status = FixtureStatus::Scheduled;
+    }
+
+    pub fn record_result(&mut self, result: FixtureResult) {
+        if self.status != FixtureStatus::Scheduled {
+            // NOTE: this is not how we would like to handle this in a real application
+            panic!("Fixture is not scheduled");
+        }
+
+        self.result = Some(result);
+        self.status = FixtureStatus::Played;
     }
 
     pub fn change_venue(&mut self, venue: Venue) {
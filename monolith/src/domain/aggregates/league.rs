@@ -0,0 +1,49 @@
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub struct LeagueId(pub Uuid);
+
+#[derive(Debug, Clone)]
+pub struct League {
+    id: LeagueId,
+    name: String,
+}
+
+impl TryFrom<String> for LeagueId {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Uuid::from_str(&value)
+            .map_err(|e| e.to_string())
+            .map(LeagueId)
+    }
+}
+
+impl From<Uuid> for LeagueId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl League {
+    pub fn new(name: &str) -> Self {
+        Self {
+            id: LeagueId(Uuid::new_v4()),
+            name: name.to_string(),
+        }
+    }
+
+    pub fn from_id(id: LeagueId, name: String) -> Self {
+        Self { id, name }
+    }
+
+    pub fn id(&self) -> LeagueId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
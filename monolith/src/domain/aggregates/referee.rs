@@ -2,6 +2,8 @@ use std::str::FromStr;
 
 use uuid::Uuid;
 
+use crate::domain::geo_location::GeoLocation;
+
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
 pub struct RefereeId(pub Uuid);
 
@@ -10,6 +12,12 @@ pub struct Referee {
     id: RefereeId,
     name: String,
     club: String,
+    // NOTE: the licence grade of the referee, the higher the level the more demanding fixtures they can officiate
+    qualification_level: i32,
+    // NOTE: where the referee travels from to the venues, needed to reimburse their travel expenses
+    home: Option<GeoLocation>,
+    // NOTE: retired referees are archived instead of deleted, so that past assignments still refer to them
+    archived: bool,
 }
 
 impl TryFrom<String> for RefereeId {
@@ -29,19 +37,32 @@ impl From<Uuid> for RefereeId {
 }
 
 impl Referee {
-    pub fn new(name: &str, club: &str) -> Self {
+    pub fn new(name: &str, club: &str, qualification_level: i32) -> Self {
         Self {
             id: RefereeId(Uuid::new_v4()),
             name: name.to_string(),
             club: club.to_string(),
+            qualification_level,
+            home: None,
+            archived: false,
         }
     }
 
-    pub fn from_id(id: Uuid, name: String, club: String) -> Self {
+    pub fn from_id(
+        id: Uuid,
+        name: String,
+        club: String,
+        qualification_level: i32,
+        home: Option<GeoLocation>,
+        archived: bool,
+    ) -> Self {
         Self {
             id: RefereeId(id),
             name,
             club,
+            qualification_level,
+            home,
+            archived,
         }
     }
 
@@ -57,7 +78,31 @@ impl Referee {
         &self.club
     }
 
+    pub fn qualification_level(&self) -> i32 {
+        self.qualification_level
+    }
+
+    pub fn home(&self) -> Option<GeoLocation> {
+        self.home
+    }
+
     pub fn change_club(&mut self, new_club: &str) {
         self.club = new_club.to_string();
     }
+
+    pub fn change_home(&mut self, home: GeoLocation) {
+        self.home = Some(home);
+    }
+
+    pub fn is_archived(&self) -> bool {
+        self.archived
+    }
+
+    pub fn archive(&mut self) {
+        if self.archived {
+            panic!("Referee already archived");
+        }
+
+        self.archived = true;
+    }
 }
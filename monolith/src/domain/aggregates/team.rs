@@ -10,6 +10,8 @@ pub struct Team {
     id: TeamId,
     name: String,
     club: String,
+    // NOTE: archived teams are kept for the history of fixtures, but cannot be scheduled anymore
+    archived: bool,
 }
 
 impl TryFrom<String> for TeamId {
@@ -34,11 +36,17 @@ impl Team {
             id: TeamId(Uuid::new_v4()),
             name: name.to_string(),
             club: club.to_string(),
+            archived: false,
         }
     }
 
-    pub fn from_id(id: TeamId, name: String, club: String) -> Self {
-        Self { id, name, club }
+    pub fn from_id(id: TeamId, name: String, club: String, archived: bool) -> Self {
+        Self {
+            id,
+            name,
+            club,
+            archived,
+        }
     }
 
     pub fn id(&self) -> TeamId {
@@ -52,4 +60,24 @@ impl Team {
     pub fn club(&self) -> &str {
         &self.club
     }
+
+    pub fn change_name(&mut self, new_name: &str) {
+        self.name = new_name.to_string();
+    }
+
+    pub fn change_club(&mut self, new_club: &str) {
+        self.club = new_club.to_string();
+    }
+
+    pub fn is_archived(&self) -> bool {
+        self.archived
+    }
+
+    pub fn archive(&mut self) {
+        if self.archived {
+            panic!("Team already archived");
+        }
+
+        self.archived = true;
+    }
 }
@@ -2,6 +2,8 @@ use std::str::FromStr;
 
 use uuid::Uuid;
 
+use crate::domain::geo_location::GeoLocation;
+
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
 pub struct VenueId(pub Uuid);
 
@@ -14,6 +16,8 @@ pub struct Venue {
     city: String,
     telephone: Option<String>,
     email: Option<String>,
+    location: Option<GeoLocation>,
+    archived: bool,
 }
 
 impl TryFrom<String> for VenueId {
@@ -40,6 +44,7 @@ impl Venue {
         city: &str,
         telephone: Option<String>,
         email: Option<String>,
+        location: Option<GeoLocation>,
     ) -> Self {
         Self {
             id: VenueId(Uuid::new_v4()),
@@ -49,6 +54,8 @@ impl Venue {
             city: city.to_string(),
             telephone,
             email,
+            location,
+            archived: false,
         }
     }
 
@@ -60,6 +67,8 @@ impl Venue {
         city: String,
         telephone: Option<String>,
         email: Option<String>,
+        location: Option<GeoLocation>,
+        archived: bool,
     ) -> Self {
         Self {
             id,
@@ -69,6 +78,8 @@ impl Venue {
             city,
             telephone,
             email,
+            location,
+            archived,
         }
     }
 
@@ -99,4 +110,38 @@ impl Venue {
     pub fn email(&self) -> Option<String> {
         self.email.clone()
     }
+
+    pub fn location(&self) -> Option<GeoLocation> {
+        self.location
+    }
+
+    pub fn change_address(
+        &mut self,
+        street: &str,
+        zip: &str,
+        city: &str,
+        location: Option<GeoLocation>,
+    ) {
+        self.street = street.to_string();
+        self.zip = zip.to_string();
+        self.city = city.to_string();
+        self.location = location;
+    }
+
+    pub fn change_contact(&mut self, telephone: Option<String>, email: Option<String>) {
+        self.telephone = telephone;
+        self.email = email;
+    }
+
+    pub fn is_archived(&self) -> bool {
+        self.archived
+    }
+
+    pub fn archive(&mut self) {
+        if self.archived {
+            panic!("Venue already archived");
+        }
+
+        self.archived = true;
+    }
 }
@@ -3,4 +3,9 @@ pub mod referee_repo;
 pub mod team_repo;
 pub mod venue_repo;
 pub mod availability_repo;
-pub mod assignment_repo;
\ No newline at end of file
+pub mod assignment_repo;
+pub mod league_repo;
+pub mod season_repo;
+pub mod availability_lock_repo;
+pub mod declaration_deadline_repo;
+pub mod blackout_repo;
\ No newline at end of file
@@ -1,5 +1,8 @@
+use mockall::automock;
+
 use crate::domain::aggregates::{assignment::Assignment, fixture::FixtureId, referee::RefereeId};
 
+#[automock(type Error = String; type TxCtx = ();)]
 pub trait AssignmentRepository {
     type Error;
     type TxCtx;
@@ -11,4 +14,5 @@ pub trait AssignmentRepository {
     async fn save(&self, assignment: &Assignment, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error>;
 
     async fn find_all_staged(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<Assignment>, Self::Error>;
+    async fn find_all_committed_by_referee(&self, referee_id: RefereeId, tx_ctx: &mut Self::TxCtx) -> Result<Vec<Assignment>, Self::Error>;
 }
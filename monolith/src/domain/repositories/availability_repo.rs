@@ -9,6 +9,8 @@ pub trait AvailabilityRepository {
 
     async fn declare_availability(&self, fixture: &Fixture, referee: &Referee, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error>;
     async fn withdraw_availability(&self, fixture: &Fixture, referee: &Referee, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error>;
+    async fn declare_availabilities(&self, fixtures: &[FixtureId], referee: &Referee, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error>;
+    async fn withdraw_availabilities(&self, fixtures: &[FixtureId], referee: &Referee, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error>;
     async fn get_availabilities_for_referee(&self, referee: &Referee, tx_ctx: &mut Self::TxCtx) -> Result<Vec<FixtureId>, Self::Error>;
     async fn is_available(&self, fixture: &Fixture, referee: &Referee, tx_ctx: &mut Self::TxCtx) -> Result<bool, Self::Error>;
 }
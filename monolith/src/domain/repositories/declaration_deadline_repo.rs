@@ -0,0 +1,21 @@
+use mockall::automock;
+
+use crate::domain::aggregates::{declaration_deadline::DeclarationDeadline, fixture::FixtureId};
+
+#[automock(type Error = String; type TxCtx = ();)]
+pub trait DeclarationDeadlineRepository {
+    type Error;
+    type TxCtx;
+
+    async fn find_by_fixture(
+        &self,
+        fixture_id: FixtureId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<DeclarationDeadline>, Self::Error>;
+
+    async fn save(
+        &self,
+        deadline: &DeclarationDeadline,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<(), Self::Error>;
+}
@@ -3,6 +3,8 @@ use mockall::automock;
 
 use crate::domain::aggregates::{
     fixture::{Fixture, FixtureId},
+    league::LeagueId,
+    season::SeasonId,
     team::TeamId,
     venue::VenueId,
 };
@@ -19,6 +21,12 @@ pub trait FixtureRepository {
         tx_ctx: &mut Self::TxCtx,
     ) -> Result<Option<Fixture>, Self::Error>;
     async fn get_all(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<Fixture>, Self::Error>;
+    async fn find_by_league_and_season(
+        &self,
+        league_id: Option<LeagueId>,
+        season_id: Option<SeasonId>,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Vec<Fixture>, Self::Error>;
     async fn find_by_day_and_venue(
         &self,
         date: &DateTime<Utc>,
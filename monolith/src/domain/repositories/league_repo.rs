@@ -0,0 +1,18 @@
+use mockall::automock;
+
+use crate::domain::aggregates::league::{League, LeagueId};
+
+#[automock(type Error = String; type TxCtx = ();)]
+pub trait LeagueRepository {
+    type Error;
+    type TxCtx;
+
+    async fn find_by_id(
+        &self,
+        league_id: LeagueId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<League>, Self::Error>;
+    async fn get_all(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<League>, Self::Error>;
+
+    async fn save(&self, league: &League, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error>;
+}
@@ -1,5 +1,8 @@
+use mockall::automock;
+
 use crate::domain::aggregates::referee::{Referee, RefereeId};
 
+#[automock(type Error = String; type TxCtx = ();)]
 pub trait RefereeRepository {
     type Error;
     type TxCtx;
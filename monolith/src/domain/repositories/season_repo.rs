@@ -0,0 +1,18 @@
+use mockall::automock;
+
+use crate::domain::aggregates::season::{Season, SeasonId};
+
+#[automock(type Error = String; type TxCtx = ();)]
+pub trait SeasonRepository {
+    type Error;
+    type TxCtx;
+
+    async fn find_by_id(
+        &self,
+        season_id: SeasonId,
+        tx_ctx: &mut Self::TxCtx,
+    ) -> Result<Option<Season>, Self::Error>;
+    async fn get_all(&self, tx_ctx: &mut Self::TxCtx) -> Result<Vec<Season>, Self::Error>;
+
+    async fn save(&self, season: &Season, tx_ctx: &mut Self::TxCtx) -> Result<(), Self::Error>;
+}
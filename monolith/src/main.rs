@@ -1,3 +1,4 @@
+use crate::application::expense_services::ExpenseRates;
 use crate::config::AppConfig;
 use axum::http::Method;
 use axum::routing::{delete, put};
@@ -7,20 +8,43 @@ use axum::{
 };
 
 use ports::rest::assignments::{
-    commit_assignments_handler, fetch_assignments_handler, remove_committed_assignment_handler,
+    accept_assignment_proposal_handler, commit_assignments_handler, confirm_assignment_handler,
+    decline_assignment_handler, fetch_assignments_handler, fetch_unconfirmed_assignments_handler,
+    propose_assignments_handler, remove_committed_assignment_handler,
     remove_staged_assignment_handler, stage_assignment_handler, validate_assignments_handler,
 };
 use ports::rest::availabilities::{
-    declare_availability_handler, fetch_availabilities_for_referee_handler,
-    withdraw_availability_handler,
+    declare_availabilities_bulk_handler, declare_availability_handler,
+    fetch_availabilities_for_referee_handler, withdraw_availability_handler,
 };
+use ports::rest::availability_locks::{
+    fetch_availability_locks_handler, lock_availabilities_handler,
+    set_declaration_deadline_handler, unlock_availabilities_handler,
+};
+use ports::rest::blackouts::{
+    declare_blackout_handler, fetch_blackouts_for_referee_handler, remove_blackout_handler,
+};
+use ports::rest::expenses::{get_expense_statement_csv_handler, get_expense_statement_handler};
 use ports::rest::fixture::{
-    cancel_fixture_handler, create_fixture_handler, get_all_fixtures_handler,
-    get_fixture_by_id_handler, update_fixture_date_handler, update_fixture_venue_handler,
+    cancel_fixture_handler, create_fixture_handler, generate_schedule_handler,
+    get_all_fixtures_handler, get_fixture_by_id_handler, get_fixtures_handler,
+    import_fixtures_handler, postpone_fixture_handler, preview_schedule_handler,
+    record_fixture_result_handler, reschedule_fixture_handler, update_fixture_date_handler,
+    update_fixture_venue_handler,
+};
+use ports::rest::league::{
+    create_league_handler, get_all_leagues_handler, get_league_by_id_handler,
 };
 use ports::rest::referee::*;
+use ports::rest::season::{
+    close_season_handler, create_season_handler, get_all_seasons_handler, get_season_by_id_handler,
+};
+use ports::rest::standings::get_standings_handler;
 use ports::rest::state::AppState;
-use ports::rest::team::{create_team_handler, get_all_teams_handler, get_team_by_id_handler};
+use ports::rest::team::{
+    archive_team_handler, create_team_handler, get_all_teams_handler, get_team_by_id_handler,
+    update_team_club_handler, update_team_name_handler,
+};
 use ports::rest::venues::*;
 use sqlx::PgPool;
 use std::sync::Arc;
@@ -37,7 +61,15 @@ async fn main() {
     let app_cfg = AppConfig::new_from_env();
     let connection_pool = PgPool::connect(&app_cfg.db_url).await.unwrap();
 
-    let app_state = AppState { connection_pool };
+    let app_state = AppState {
+        connection_pool,
+        expense_rates: ExpenseRates {
+            per_km_cents: app_cfg.expense_rate_per_km_cents,
+            first_referee_fee_cents: app_cfg.expense_fee_first_referee_cents,
+            second_referee_fee_cents: app_cfg.expense_fee_second_referee_cents,
+        },
+        access_token_secret: app_cfg.access_token_secret,
+    };
     let state_arc = Arc::new(app_state);
 
     let cors = tower_http::cors::CorsLayer::new()
@@ -56,22 +88,56 @@ async fn main() {
         .route("/referees/:id", get(get_referee_by_id_handler))
         .route("/referees/all", get(get_all_referees_handler))
         .route("/referees/:id/club", post(update_referee_club_handler))
+        .route("/referees/:id/home", post(update_referee_home_handler))
+        .route("/referees/:id/archive", post(archive_referee_handler))
+        .route(
+            "/referees/:id/assignments.ics",
+            get(get_referee_calendar_handler),
+        )
         .route("/venues", post(create_venue_handler))
         .route("/venues/:id", get(get_venue_by_id_handler))
         .route("/venues/all", get(get_all_venues_handler))
+        .route("/venues/:id/address", post(update_venue_address_handler))
+        .route("/venues/:id/contact", post(update_venue_contact_handler))
+        .route("/venues/:id/archive", post(archive_venue_handler))
         .route("/teams", post(create_team_handler))
         .route("/teams/:id", get(get_team_by_id_handler))
         .route("/teams/all", get(get_all_teams_handler))
+        .route("/teams/:id/name", post(update_team_name_handler))
+        .route("/teams/:id/club", post(update_team_club_handler))
+        .route("/teams/:id/archive", post(archive_team_handler))
+        .route("/leagues", post(create_league_handler))
+        .route("/leagues/:id", get(get_league_by_id_handler))
+        .route("/leagues/all", get(get_all_leagues_handler))
+        .route(
+            "/leagues/:id/seasons/:season_id/standings",
+            get(get_standings_handler),
+        )
+        .route("/seasons", post(create_season_handler))
+        .route("/seasons/:id", get(get_season_by_id_handler))
+        .route("/seasons/all", get(get_all_seasons_handler))
+        .route("/seasons/:id/close", post(close_season_handler))
         .route("/fixtures", post(create_fixture_handler))
+        .route("/fixtures", get(get_fixtures_handler))
         .route("/fixtures/:id", get(get_fixture_by_id_handler))
         .route("/fixtures/all", get(get_all_fixtures_handler))
         .route("/fixtures/:id/date", post(update_fixture_date_handler))
         .route("/fixtures/:id/venue", post(update_fixture_venue_handler))
         .route("/fixtures/:id/cancel", post(cancel_fixture_handler))
+        .route("/fixtures/:id/postpone", post(postpone_fixture_handler))
+        .route("/fixtures/:id/reschedule", post(reschedule_fixture_handler))
+        .route("/fixtures/:id/result", post(record_fixture_result_handler))
+        .route("/fixtures/schedule", post(generate_schedule_handler))
+        .route("/fixtures/schedule/preview", post(preview_schedule_handler))
+        .route("/fixtures/import", post(import_fixtures_handler))
         .route(
             "/availabilities/declare/fixture/:fixture_id/referee/:referee_id",
             post(declare_availability_handler),
         )
+        .route(
+            "/availabilities/declare/referee/:referee_id",
+            post(declare_availabilities_bulk_handler),
+        )
         .route(
             "/availabilities/withdraw/fixture/:fixture_id/referee/:referee_id",
             post(withdraw_availability_handler),
@@ -80,6 +146,26 @@ async fn main() {
             "/availabilities/referee/:referee_id",
             get(fetch_availabilities_for_referee_handler),
         )
+        .route(
+            "/availabilities/locks",
+            get(fetch_availability_locks_handler).post(lock_availabilities_handler),
+        )
+        .route(
+            "/availabilities/locks/:lock_id",
+            delete(unlock_availabilities_handler),
+        )
+        .route(
+            "/availabilities/deadlines/fixture/:fixture_id",
+            put(set_declaration_deadline_handler),
+        )
+        .route(
+            "/availabilities/blackouts/referee/:referee_id",
+            get(fetch_blackouts_for_referee_handler).post(declare_blackout_handler),
+        )
+        .route(
+            "/availabilities/blackouts/:blackout_id",
+            delete(remove_blackout_handler),
+        )
         .route("/assignments", get(fetch_assignments_handler))
         .route("/assignments", put(stage_assignment_handler))
         .route(
@@ -90,8 +176,33 @@ async fn main() {
             "/assignments/committed/:fixture_id/:referee_id",
             delete(remove_committed_assignment_handler),
         )
+        .route(
+            "/assignments/committed/:fixture_id/:referee_id/confirm",
+            post(confirm_assignment_handler),
+        )
+        .route(
+            "/assignments/committed/:fixture_id/:referee_id/decline",
+            post(decline_assignment_handler),
+        )
+        .route(
+            "/assignments/unconfirmed",
+            get(fetch_unconfirmed_assignments_handler),
+        )
         .route("/assignments/validate", post(validate_assignments_handler))
         .route("/assignments/commit", post(commit_assignments_handler))
+        .route("/assignments/proposal", post(propose_assignments_handler))
+        .route(
+            "/assignments/proposal/accept",
+            post(accept_assignment_proposal_handler),
+        )
+        .route(
+            "/expenses/referee/:referee_id/:year/:month",
+            get(get_expense_statement_handler),
+        )
+        .route(
+            "/expenses/referee/:referee_id/:year/:month/statement.csv",
+            get(get_expense_statement_csv_handler),
+        )
         .layer(cors)
         .with_state(state_arc);
 
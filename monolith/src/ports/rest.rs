@@ -1,8 +1,15 @@
 pub mod assignments;
+pub mod auth;
 pub mod availabilities;
+pub mod availability_locks;
+pub mod blackouts;
+pub mod expenses;
 pub mod fixture;
+pub mod league;
 pub mod referee;
+pub mod season;
 pub mod shared;
+pub mod standings;
 pub mod state;
 pub mod team;
 pub mod venues;
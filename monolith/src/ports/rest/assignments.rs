@@ -1,26 +1,34 @@
 use std::sync::Arc;
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
     Json,
 };
+use chrono::{Duration, Utc};
 use log::debug;
-use restinterface::{AssignmentDTO, AssignmentStagingDTO, FixtureIdDTO, RefereeIdDTO};
+use restinterface::{
+    AssignmentDTO, AssignmentProposalRequestDTO, AssignmentStagingDTO,
+    AssignmentValidationReportDTO, FixtureIdDTO, RefereeIdDTO, UnconfirmedAssignmentsFilterDTO,
+    UnconfirmedFixtureDTO,
+};
 use shared::app_error::AppError;
 
 use crate::{
     adapters::db::{
-        assignment_repo_pg::AssignmentRepositoryPg, fixture_repo_pg::FixtureRepositoryPg,
-        referee_repo_pg::RefereeRepositoryPg,
+        assignment_repo_pg::AssignmentRepositoryPg, availability_repo_pg::AvailabilityRepositoryPg,
+        fixture_repo_pg::FixtureRepositoryPg, referee_repo_pg::RefereeRepositoryPg,
     },
     application::assignment_services::{
-        commit_assignments, remove_committed_assignment, remove_staged_assignment,
-        stage_assignment, validate_assignments,
+        accept_assignment_proposal, commit_assignments, confirm_assignment, decline_assignment,
+        get_unconfirmed_assignments, propose_assignments, remove_committed_assignment,
+        remove_staged_assignment, stage_assignment, validate_assignments,
+        UNCONFIRMED_WINDOW_DEFAULT_HOURS,
     },
     domain::repositories::assignment_repo::AssignmentRepository,
 };
 
-use super::state::AppState;
+use super::{auth::verify_access_token, state::AppState};
 
 pub async fn fetch_assignments_handler(
     State(state): State<Arc<AppState>>,
@@ -104,20 +112,117 @@ pub async fn remove_committed_assignment_handler(
     Ok(Json(result))
 }
 
+pub async fn confirm_assignment_handler(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path((fixture_id, referee_id)): Path<(FixtureIdDTO, RefereeIdDTO)>,
+) -> Result<Json<AssignmentDTO>, AppError> {
+    debug!("Confirming assignment: {:?} {:?}", fixture_id, referee_id);
+
+    let access_claims = verify_access_token(&headers, &state.access_token_secret)?;
+    if !access_claims.may_act_for_referee(referee_id.0) {
+        return Err(AppError::from_error_unauthorized(
+            "Only the referee itself or an admin may confirm the assignment",
+        ));
+    }
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+    let assignment_repo = AssignmentRepositoryPg::new();
+    let result = confirm_assignment(
+        fixture_id.into(),
+        referee_id.into(),
+        &assignment_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error_with_status(&e, StatusCode::UNPROCESSABLE_ENTITY))?;
+    tx.commit().await.unwrap();
+
+    Ok(Json(result))
+}
+
+pub async fn decline_assignment_handler(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Path((fixture_id, referee_id)): Path<(FixtureIdDTO, RefereeIdDTO)>,
+) -> Result<Json<AssignmentDTO>, AppError> {
+    debug!("Declining assignment: {:?} {:?}", fixture_id, referee_id);
+
+    let access_claims = verify_access_token(&headers, &state.access_token_secret)?;
+    if !access_claims.may_act_for_referee(referee_id.0) {
+        return Err(AppError::from_error_unauthorized(
+            "Only the referee itself or an admin may decline the assignment",
+        ));
+    }
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+    let assignment_repo = AssignmentRepositoryPg::new();
+    let fixture_repo = FixtureRepositoryPg::new();
+    let result = decline_assignment(
+        fixture_id.into(),
+        referee_id.into(),
+        &assignment_repo,
+        &fixture_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error_with_status(&e, StatusCode::UNPROCESSABLE_ENTITY))?;
+    tx.commit().await.unwrap();
+
+    Ok(Json(result))
+}
+
+pub async fn fetch_unconfirmed_assignments_handler(
+    State(state): State<Arc<AppState>>,
+    Query(filter): Query<UnconfirmedAssignmentsFilterDTO>,
+) -> Result<Json<Vec<UnconfirmedFixtureDTO>>, AppError> {
+    debug!("Fetching unconfirmed assignments: {:?}", filter);
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+    let assignment_repo = AssignmentRepositoryPg::new();
+    let fixture_repo = FixtureRepositoryPg::new();
+    let result = get_unconfirmed_assignments(
+        Utc::now(),
+        Duration::hours(
+            filter
+                .within_hours
+                .unwrap_or(UNCONFIRMED_WINDOW_DEFAULT_HOURS),
+        ),
+        &assignment_repo,
+        &fixture_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e))?;
+
+    // NOTE: read-only, therefore dont commit TX
+
+    Ok(Json(result))
+}
+
 pub async fn validate_assignments_handler(
     State(state): State<Arc<AppState>>,
-) -> Result<String, AppError> {
+) -> Result<Json<AssignmentValidationReportDTO>, AppError> {
     debug!("Validating assignments");
 
     let mut tx = state.connection_pool.begin().await.unwrap();
     let assignment_repo = AssignmentRepositoryPg::new();
-    let result = validate_assignments(&assignment_repo, &mut tx)
-        .await
-        .unwrap();
+    let fixture_repo = FixtureRepositoryPg::new();
+    let referee_repo = RefereeRepositoryPg::new();
+    let availability_repo = AvailabilityRepositoryPg::new();
+    let result = validate_assignments(
+        &assignment_repo,
+        &fixture_repo,
+        &referee_repo,
+        &availability_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e))?;
 
     // NOTE: read-only, therefore dont commit TX
 
-    Ok(result)
+    Ok(Json(result))
 }
 
 pub async fn commit_assignments_handler(
@@ -129,23 +234,88 @@ pub async fn commit_assignments_handler(
     let assignment_repo = AssignmentRepositoryPg::new();
     let fixture_repo = FixtureRepositoryPg::new();
     let referee_repo = RefereeRepositoryPg::new();
-    let result = commit_assignments(&assignment_repo, &fixture_repo, &referee_repo, &mut tx)
-        .await
-        .unwrap();
+    let availability_repo = AvailabilityRepositoryPg::new();
+    let result = commit_assignments(
+        &assignment_repo,
+        &fixture_repo,
+        &referee_repo,
+        &availability_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error_with_status(&e, StatusCode::UNPROCESSABLE_ENTITY))?;
     tx.commit().await.unwrap();
 
     Ok(result)
 }
 
+pub async fn propose_assignments_handler(
+    State(state): State<Arc<AppState>>,
+    Json(proposal_request): Json<AssignmentProposalRequestDTO>,
+) -> Result<Json<Vec<AssignmentStagingDTO>>, AppError> {
+    debug!("Proposing assignments: {:?}", proposal_request);
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+    let assignment_repo = AssignmentRepositoryPg::new();
+    let fixture_repo = FixtureRepositoryPg::new();
+    let referee_repo = RefereeRepositoryPg::new();
+    let availability_repo = AvailabilityRepositoryPg::new();
+    let proposal = propose_assignments(
+        proposal_request.from,
+        proposal_request.to,
+        &assignment_repo,
+        &fixture_repo,
+        &referee_repo,
+        &availability_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e))?;
+
+    // NOTE: read-only, therefore dont commit TX
+
+    Ok(Json(proposal))
+}
+
+pub async fn accept_assignment_proposal_handler(
+    State(state): State<Arc<AppState>>,
+    Json(proposal): Json<Vec<AssignmentStagingDTO>>,
+) -> Result<Json<Vec<AssignmentDTO>>, AppError> {
+    debug!("Accepting assignment proposal: {:?}", proposal);
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+    let assignment_repo = AssignmentRepositoryPg::new();
+    let fixture_repo = FixtureRepositoryPg::new();
+    let referee_repo = RefereeRepositoryPg::new();
+    let result = accept_assignment_proposal(
+        &proposal,
+        &assignment_repo,
+        &fixture_repo,
+        &referee_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e))?;
+    tx.commit().await.unwrap();
+
+    Ok(Json(result))
+}
+
 #[cfg(test)]
 mod assignments_tests {
     use restinterface::{
-        commit_assignments, fetch_assignments, fetch_fixture, remove_committed_assignment,
-        remove_staged_assignment, stage_assignment, validate_assignments, AssignmentRefereeRoleDTO,
-        AssignmentStagingDTO, AssignmentStatusDTO, RefereeCreationDTO,
+        accept_assignment_proposal, change_fixture_date, change_referee_club, commit_assignments,
+        confirm_assignment, declare_availability, decline_assignment, fetch_assignments,
+        fetch_fixture, fetch_unconfirmed_assignments, propose_assignments,
+        remove_committed_assignment, remove_staged_assignment, stage_assignment,
+        validate_assignments, AssignmentProposalRequestDTO, AssignmentRefereeRoleDTO,
+        AssignmentStagingDTO, AssignmentStatusDTO, AssignmentValidationRuleDTO, RefereeCreationDTO,
+        UnconfirmedAssignmentsFilterDTO,
     };
     use sqlx::PgPool;
 
+    use crate::ports::rest::auth::test_tokens::{admin_access_token, referee_access_token};
+
     #[tokio::test]
     async fn assignment_scenario() {
         clear_tables().await;
@@ -157,7 +327,8 @@ mod assignments_tests {
         let (_fixture_creation, fixture_dto) = restinterface::create_test_fixture().await;
         let referee_creation = RefereeCreationDTO {
             name: "John Doe".to_string(),
-            club: "Club A".to_string(),
+            club: "Club C".to_string(),
+            qualification_level: 0,
         };
         let first_referee_dto = restinterface::create_referee(&referee_creation)
             .await
@@ -165,12 +336,34 @@ mod assignments_tests {
 
         let second_referee_creation = RefereeCreationDTO {
             name: "Jane Smith".to_string(),
-            club: "Club B".to_string(),
+            club: "Club D".to_string(),
+            qualification_level: 0,
         };
         let second_referee_dto = restinterface::create_referee(&second_referee_creation)
             .await
             .unwrap();
 
+        // a referee whose club is playing in the fixture cannot be assigned to it
+        let conflicting_referee_creation = RefereeCreationDTO {
+            name: "Max Mustermann".to_string(),
+            club: "Club A".to_string(),
+            qualification_level: 0,
+        };
+        let conflicting_referee_dto = restinterface::create_referee(&conflicting_referee_creation)
+            .await
+            .unwrap();
+        let conflicting_assignment_creation = AssignmentStagingDTO {
+            fixture_id: fixture_dto.id,
+            referee_id: conflicting_referee_dto.id,
+            referee_role: AssignmentRefereeRoleDTO::First,
+        };
+        assert!(
+            stage_assignment(&conflicting_assignment_creation)
+                .await
+                .is_err(),
+            "Staging an assignment with a conflict of interest should fail"
+        );
+
         let first_assignment_creation = AssignmentStagingDTO {
             fixture_id: fixture_dto.id,
             referee_id: first_referee_dto.id,
@@ -252,11 +445,44 @@ mod assignments_tests {
 
         let first_ref_assignment_dto = stage_assignment(&first_assignment_creation).await.unwrap();
 
-        // no conflicts, so validate_assignments() should return an empty string
-        let result = validate_assignments().await.unwrap();
+        // referees have not declared their availability yet, so validation reports errors
+        let report = validate_assignments().await.unwrap();
+        assert!(
+            report.has_errors(),
+            "Validation report should contain errors"
+        );
         assert_eq!(
-            result, "Assignments validated",
-            "Validate assignments should return an empty string"
+            report.violations.len(),
+            2,
+            "Validation report should contain 2 violations"
+        );
+        assert!(
+            report
+                .violations
+                .iter()
+                .all(|v| v.rule == AssignmentValidationRuleDTO::RefereeNotAvailable),
+            "Violations should be due to missing availabilities"
+        );
+
+        // committing the assignments should be refused
+        let result = commit_assignments().await.unwrap();
+        assert!(
+            result.contains("Assignments invalid"),
+            "Commit assignments should be refused"
+        );
+
+        declare_availability(fixture_dto.id, first_referee_dto.id)
+            .await
+            .unwrap();
+        declare_availability(fixture_dto.id, second_referee_dto.id)
+            .await
+            .unwrap();
+
+        // no conflicts, so validate_assignments() should return an empty report
+        let report = validate_assignments().await.unwrap();
+        assert!(
+            report.violations.is_empty(),
+            "Validation report should be empty"
         );
 
         // committing the assignments should work
@@ -313,9 +539,217 @@ mod assignments_tests {
         );
         assert_eq!(
             fixture_dto.second_referee,
-            Some(second_referee_dto),
+            Some(second_referee_dto.clone()),
             "Fixture second_referee should be the same"
         );
+
+        // changing the club of the remaining referee to a playing club flags their committed assignment
+        change_referee_club(second_referee_dto.id, "Club B")
+            .await
+            .unwrap();
+        let assignments = fetch_assignments().await;
+        assert!(
+            assignments[0].review_reason.is_some(),
+            "Assignment should be flagged for review"
+        );
+    }
+
+    #[tokio::test]
+    async fn assignment_proposal_scenario() {
+        clear_tables().await;
+
+        let (_fixture_creation, fixture_dto) = restinterface::create_test_fixture().await;
+
+        let mut referee_dtos = Vec::new();
+        for (name, club) in [
+            ("John Doe", "Club C"),
+            ("Jane Smith", "Club D"),
+            ("Max Mustermann", "Club E"),
+        ] {
+            let referee_creation = RefereeCreationDTO {
+                name: name.to_string(),
+                club: club.to_string(),
+                qualification_level: 0,
+            };
+            let referee_dto = restinterface::create_referee(&referee_creation)
+                .await
+                .unwrap();
+            referee_dtos.push(referee_dto);
+        }
+
+        // only the first two referees are available
+        declare_availability(fixture_dto.id, referee_dtos[0].id)
+            .await
+            .unwrap();
+        declare_availability(fixture_dto.id, referee_dtos[1].id)
+            .await
+            .unwrap();
+
+        let proposal_request = AssignmentProposalRequestDTO {
+            from: fixture_dto.date - chrono::Duration::days(1),
+            to: fixture_dto.date + chrono::Duration::days(1),
+        };
+        let proposal = propose_assignments(&proposal_request).await.unwrap();
+        assert_eq!(proposal.len(), 2, "Proposal should have 2 elements");
+        assert!(
+            proposal.iter().all(|a| a.fixture_id == fixture_dto.id),
+            "Proposal should only contain the fixture"
+        );
+        assert!(
+            proposal
+                .iter()
+                .any(|a| a.referee_role == AssignmentRefereeRoleDTO::First),
+            "Proposal should fill the first referee role"
+        );
+        assert!(
+            proposal
+                .iter()
+                .any(|a| a.referee_role == AssignmentRefereeRoleDTO::Second),
+            "Proposal should fill the second referee role"
+        );
+        assert!(
+            proposal.iter().all(|a| a.referee_id != referee_dtos[2].id),
+            "Proposal should not contain the unavailable referee"
+        );
+        assert_ne!(
+            proposal[0].referee_id, proposal[1].referee_id,
+            "Proposal should not assign the same referee twice"
+        );
+
+        // accepting the proposal stages all assignments
+        let assignments = accept_assignment_proposal(&proposal).await.unwrap();
+        assert_eq!(assignments.len(), 2, "Assignments should have 2 elements");
+        assert!(
+            assignments
+                .iter()
+                .all(|a| a.status == AssignmentStatusDTO::Staged),
+            "Assignments should be staged"
+        );
+
+        // all roles are filled now, so there is nothing left to propose
+        let proposal = propose_assignments(&proposal_request).await.unwrap();
+        assert!(proposal.is_empty(), "Proposal should be empty");
+
+        let report = validate_assignments().await.unwrap();
+        assert!(
+            report.violations.is_empty(),
+            "Validation report should be empty"
+        );
+    }
+
+    #[tokio::test]
+    async fn assignment_confirmation_scenario() {
+        clear_tables().await;
+
+        let (_fixture_creation, fixture_dto) = restinterface::create_test_fixture().await;
+        // NOTE: the fixture needs to be close to game day, but not in the past
+        change_fixture_date(
+            fixture_dto.id,
+            chrono::Utc::now() + chrono::Duration::days(1),
+        )
+        .await
+        .unwrap();
+
+        let mut referee_dtos = Vec::new();
+        for (name, club) in [("John Doe", "Club C"), ("Jane Smith", "Club D")] {
+            let referee_creation = RefereeCreationDTO {
+                name: name.to_string(),
+                club: club.to_string(),
+                qualification_level: 0,
+            };
+            let referee_dto = restinterface::create_referee(&referee_creation)
+                .await
+                .unwrap();
+            declare_availability(fixture_dto.id, referee_dto.id)
+                .await
+                .unwrap();
+            referee_dtos.push(referee_dto);
+        }
+
+        for (referee_dto, referee_role) in referee_dtos.iter().zip([
+            AssignmentRefereeRoleDTO::First,
+            AssignmentRefereeRoleDTO::Second,
+        ]) {
+            stage_assignment(&AssignmentStagingDTO {
+                fixture_id: fixture_dto.id,
+                referee_id: referee_dto.id,
+                referee_role,
+            })
+            .await
+            .unwrap();
+        }
+
+        // staged assignments cannot be confirmed yet
+        assert!(
+            confirm_assignment(fixture_dto.id, referee_dtos[0].id, &admin_access_token())
+                .await
+                .is_err(),
+            "Confirming a staged assignment should fail"
+        );
+
+        commit_assignments().await.unwrap();
+
+        // both committed assignments are still unconfirmed close to game day
+        let unconfirmed =
+            fetch_unconfirmed_assignments(&UnconfirmedAssignmentsFilterDTO::default())
+                .await
+                .unwrap();
+        assert_eq!(unconfirmed.len(), 1, "Fixture should be unconfirmed");
+        assert_eq!(unconfirmed[0].fixture.id, fixture_dto.id);
+        assert_eq!(
+            unconfirmed[0].unconfirmed_assignments.len(),
+            2,
+            "Both assignments should be unconfirmed"
+        );
+
+        // a referee cannot confirm on behalf of another referee
+        assert!(
+            confirm_assignment(
+                fixture_dto.id,
+                referee_dtos[0].id,
+                &referee_access_token(referee_dtos[1].id.0),
+            )
+            .await
+            .is_err(),
+            "Confirming the assignment of another referee should fail"
+        );
+
+        let confirmed = confirm_assignment(
+            fixture_dto.id,
+            referee_dtos[0].id,
+            &referee_access_token(referee_dtos[0].id.0),
+        )
+        .await
+        .unwrap();
+        assert_eq!(confirmed.status, AssignmentStatusDTO::Confirmed);
+
+        let declined = decline_assignment(
+            fixture_dto.id,
+            referee_dtos[1].id,
+            &referee_access_token(referee_dtos[1].id.0),
+        )
+        .await
+        .unwrap();
+        assert_eq!(declined.status, AssignmentStatusDTO::Declined);
+
+        // declining frees the role of the referee in the fixture
+        let fixture_dto = fetch_fixture(fixture_dto.id).await.unwrap();
+        assert_eq!(
+            fixture_dto.first_referee.map(|r| r.id),
+            Some(referee_dtos[0].id),
+            "Fixture first_referee should still be set"
+        );
+        assert_eq!(
+            fixture_dto.second_referee, None,
+            "Fixture second_referee should be None"
+        );
+
+        // nothing is left to confirm
+        let unconfirmed =
+            fetch_unconfirmed_assignments(&UnconfirmedAssignmentsFilterDTO::default())
+                .await
+                .unwrap();
+        assert!(unconfirmed.is_empty(), "No fixture should be unconfirmed");
     }
 
     async fn clear_tables() {
@@ -0,0 +1,126 @@
+use axum::http::{header::COOKIE, HeaderMap};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use restinterface::ACCESS_TOKEN_COOKIE;
+use serde::{Deserialize, Serialize};
+use shared::app_error::AppError;
+use uuid::Uuid;
+
+// NOTE: the monolith has no Idp, therefore its access tokens are signed (HS256) with a secret
+// it shares with the issuer of the tokens
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessClaims {
+    pub sub: String,
+    pub exp: u64,
+    pub roles: Vec<String>,
+    // only accounts of referees carry the id of their referee
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub referee_id: Option<Uuid>,
+}
+
+impl AccessClaims {
+    pub fn is_admin(&self) -> bool {
+        self.roles.iter().any(|role| role.to_lowercase() == "admin")
+    }
+
+    /// Only the referee itself or an admin may act on behalf of a referee
+    pub fn may_act_for_referee(&self, referee_id: Uuid) -> bool {
+        self.referee_id == Some(referee_id) || self.is_admin()
+    }
+}
+
+/// Verifies the signature and expiry of the access token carried in the cookie header of a request
+pub fn verify_access_token(headers: &HeaderMap, secret: &str) -> Result<AccessClaims, AppError> {
+    let access_token_encoded = headers
+        .get(COOKIE)
+        .and_then(|cookie_header| cookie_header.to_str().ok())
+        .and_then(extract_access_token_from_cookie_header)
+        .ok_or(AppError::from_error_unauthorized(
+            "No access token found in cookie",
+        ))?;
+
+    let mut validation = Validation::new(Algorithm::HS256);
+    validation.set_required_spec_claims(&["exp"]);
+
+    let access_token = jsonwebtoken::decode::<AccessClaims>(
+        &access_token_encoded,
+        &DecodingKey::from_secret(secret.as_bytes()),
+        &validation,
+    )
+    .map_err(|e| {
+        AppError::from_error_unauthorized(&format!(
+            "failed to verify access token with error {}",
+            e
+        ))
+    })?;
+
+    Ok(access_token.claims)
+}
+
+fn extract_access_token_from_cookie_header(cookie_header: &str) -> Option<String> {
+    cookie_header.split("; ").find_map(|cookie| {
+        cookie
+            .split_once('=')
+            .filter(|(name, _)| *name == ACCESS_TOKEN_COOKIE)
+            .map(|(_, value)| value.to_string())
+    })
+}
+
+#[cfg(test)]
+pub mod test_tokens {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use jsonwebtoken::{Algorithm, EncodingKey, Header};
+    use uuid::Uuid;
+
+    use crate::config::get_from_env_or_panic;
+
+    use super::AccessClaims;
+
+    pub fn admin_access_token() -> String {
+        sign_access_token(vec!["admin".to_string()], None)
+    }
+
+    pub fn referee_access_token(referee_id: Uuid) -> String {
+        sign_access_token(vec!["referee".to_string()], Some(referee_id))
+    }
+
+    /// An admin token which is not signed with the secret of the monolith
+    pub fn forged_admin_access_token() -> String {
+        encode_access_token(
+            &access_claims(vec!["admin".to_string()], None),
+            "not-the-secret",
+        )
+        .unwrap()
+    }
+
+    fn sign_access_token(roles: Vec<String>, referee_id: Option<Uuid>) -> String {
+        encode_access_token(
+            &access_claims(roles, referee_id),
+            &get_from_env_or_panic("ACCESS_TOKEN_SECRET"),
+        )
+        .unwrap()
+    }
+
+    fn encode_access_token(claims: &AccessClaims, secret: &str) -> Result<String, String> {
+        jsonwebtoken::encode(
+            &Header::new(Algorithm::HS256),
+            claims,
+            &EncodingKey::from_secret(secret.as_bytes()),
+        )
+        .map_err(|e| format!("failed to encode access token with error {}", e))
+    }
+
+    fn access_claims(roles: Vec<String>, referee_id: Option<Uuid>) -> AccessClaims {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        AccessClaims {
+            sub: Uuid::new_v4().to_string(),
+            exp: now + 3600,
+            roles,
+            referee_id,
+        }
+    }
+}
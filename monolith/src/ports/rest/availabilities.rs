@@ -3,14 +3,18 @@ use std::sync::Arc;
 use axum::extract::{Path, State};
 use axum::Json;
 use log::debug;
-use restinterface::{FixtureIdDTO, RefereeIdDTO};
+use restinterface::{AvailabilityBulkDeclarationDTO, FixtureIdDTO, RefereeIdDTO};
 use shared::app_error::AppError;
 
+use crate::adapters::db::availability_lock_repo_pg::AvailabilityLockRepositoryPg;
 use crate::adapters::db::availability_repo_pg::AvailabilityRepositoryPg;
+use crate::adapters::db::blackout_repo_pg::BlackoutRepositoryPg;
+use crate::adapters::db::declaration_deadline_repo_pg::DeclarationDeadlineRepositoryPg;
 use crate::adapters::db::fixture_repo_pg::FixtureRepositoryPg;
 use crate::adapters::db::referee_repo_pg::RefereeRepositoryPg;
 use crate::application::availability_services::{
-    declare_availability, get_availabilities_for_referee, withdraw_availability,
+    declare_availabilities_bulk, declare_availability, get_availabilities_for_referee,
+    withdraw_availability, AvailabilityBulkDeclaration,
 };
 
 use super::state::AppState;
@@ -29,6 +33,9 @@ pub async fn declare_availability_handler(
     let fixture_repo = FixtureRepositoryPg::new();
     let referee_repo = RefereeRepositoryPg::new();
     let availability_repo = AvailabilityRepositoryPg::new();
+    let availability_lock_repo = AvailabilityLockRepositoryPg::new();
+    let declaration_deadline_repo = DeclarationDeadlineRepositoryPg::new();
+    let blackout_repo = BlackoutRepositoryPg::new();
 
     declare_availability(
         fixture_id.into(),
@@ -36,6 +43,9 @@ pub async fn declare_availability_handler(
         &fixture_repo,
         &referee_repo,
         &availability_repo,
+        &availability_lock_repo,
+        &declaration_deadline_repo,
+        &blackout_repo,
         &mut tx,
     )
     .await
@@ -48,6 +58,57 @@ pub async fn declare_availability_handler(
     Ok(Json(()))
 }
 
+pub async fn declare_availabilities_bulk_handler(
+    State(state): State<Arc<AppState>>,
+    Path(referee_id): Path<RefereeIdDTO>,
+    Json(bulk_declaration): Json<AvailabilityBulkDeclarationDTO>,
+) -> Result<Json<Vec<FixtureIdDTO>>, AppError> {
+    debug!(
+        "Declaring availabilities in bulk for referee: {:?} with {:?}",
+        referee_id, bulk_declaration
+    );
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let fixture_repo = FixtureRepositoryPg::new();
+    let referee_repo = RefereeRepositoryPg::new();
+    let availability_repo = AvailabilityRepositoryPg::new();
+    let availability_lock_repo = AvailabilityLockRepositoryPg::new();
+    let declaration_deadline_repo = DeclarationDeadlineRepositoryPg::new();
+    let blackout_repo = BlackoutRepositoryPg::new();
+
+    let bulk_declaration = AvailabilityBulkDeclaration {
+        from: bulk_declaration.from,
+        to: bulk_declaration.to,
+        venue_ids: bulk_declaration
+            .venue_ids
+            .into_iter()
+            .map(|v| v.into())
+            .collect(),
+        weekdays: bulk_declaration.weekdays,
+    };
+
+    let declared = declare_availabilities_bulk(
+        referee_id.into(),
+        &bulk_declaration,
+        &fixture_repo,
+        &referee_repo,
+        &availability_repo,
+        &availability_lock_repo,
+        &declaration_deadline_repo,
+        &blackout_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(declared.into_iter().map(|f| f.into()).collect()))
+}
+
 pub async fn withdraw_availability_handler(
     State(state): State<Arc<AppState>>,
     Path((fixture_id, referee_id)): Path<(FixtureIdDTO, RefereeIdDTO)>,
@@ -62,6 +123,8 @@ pub async fn withdraw_availability_handler(
     let fixture_repo = FixtureRepositoryPg::new();
     let referee_repo = RefereeRepositoryPg::new();
     let availability_repo = AvailabilityRepositoryPg::new();
+    let availability_lock_repo = AvailabilityLockRepositoryPg::new();
+    let declaration_deadline_repo = DeclarationDeadlineRepositoryPg::new();
 
     withdraw_availability(
         fixture_id.into(),
@@ -69,6 +132,8 @@ pub async fn withdraw_availability_handler(
         &fixture_repo,
         &referee_repo,
         &availability_repo,
+        &availability_lock_repo,
+        &declaration_deadline_repo,
         &mut tx,
     )
     .await
@@ -128,6 +193,7 @@ mod availabilities_tests {
         let referee_creation = RefereeCreationDTO {
             name: "John Doe".to_string(),
             club: "Club A".to_string(),
+            qualification_level: 0,
         };
         let referee_dto = restinterface::create_referee(&referee_creation)
             .await
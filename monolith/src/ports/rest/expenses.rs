@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::IntoResponse,
+    Json,
+};
+use log::debug;
+use restinterface::{ExpenseStatementDTO, RefereeIdDTO};
+use shared::app_error::AppError;
+
+use crate::{
+    adapters::db::{
+        assignment_repo_pg::AssignmentRepositoryPg, fixture_repo_pg::FixtureRepositoryPg,
+        referee_repo_pg::RefereeRepositoryPg,
+    },
+    application,
+};
+
+use super::state::AppState;
+
+pub async fn get_expense_statement_handler(
+    State(state): State<Arc<AppState>>,
+    Path((referee_id, year, month)): Path<(RefereeIdDTO, i32, u32)>,
+) -> Result<Json<ExpenseStatementDTO>, AppError> {
+    debug!(
+        "Getting expense statement of referee {} for {}-{}",
+        referee_id.0, year, month
+    );
+
+    let statement = get_expense_statement(&state, referee_id, year, month).await?;
+
+    Ok(Json(statement))
+}
+
+pub async fn get_expense_statement_csv_handler(
+    State(state): State<Arc<AppState>>,
+    Path((referee_id, year, month)): Path<(RefereeIdDTO, i32, u32)>,
+) -> Result<impl IntoResponse, AppError> {
+    debug!(
+        "Exporting expense statement of referee {} for {}-{} as CSV",
+        referee_id.0, year, month
+    );
+
+    let statement = get_expense_statement(&state, referee_id, year, month).await?;
+    let csv = application::expense_services::render_expense_statement_csv(&statement);
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"expenses_{}_{}-{:02}.csv\"",
+                    referee_id.0, year, month
+                ),
+            ),
+        ],
+        csv,
+    ))
+}
+
+async fn get_expense_statement(
+    state: &AppState,
+    referee_id: RefereeIdDTO,
+    year: i32,
+    month: u32,
+) -> Result<ExpenseStatementDTO, AppError> {
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let referee_repo = RefereeRepositoryPg::new();
+    let assignment_repo = AssignmentRepositoryPg::new();
+    let fixture_repo = FixtureRepositoryPg::new();
+
+    application::expense_services::get_expense_statement(
+        referee_id.into(),
+        year,
+        month,
+        &state.expense_rates,
+        &referee_repo,
+        &assignment_repo,
+        &fixture_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e.to_string()))
+}
+
+#[cfg(test)]
+mod expenses_tests {
+    use chrono::{Datelike, Utc};
+    use restinterface::{
+        change_referee_home, create_referee, fetch_expense_statement, fetch_expense_statement_csv,
+        GeoLocationDTO, RefereeCreationDTO,
+    };
+
+    #[tokio::test]
+    async fn given_referee_without_assignments_when_fetching_expense_statement_then_empty_statement_is_returned(
+    ) {
+        let referee = create_referee(&RefereeCreationDTO {
+            name: "Expense Referee".to_string(),
+            club: "Club E".to_string(),
+            qualification_level: 1,
+        })
+        .await
+        .unwrap();
+        change_referee_home(
+            referee.id,
+            &GeoLocationDTO {
+                latitude: 48.2082,
+                longitude: 16.3738,
+            },
+        )
+        .await
+        .unwrap();
+
+        let now = Utc::now();
+        let statement = fetch_expense_statement(referee.id, now.year(), now.month())
+            .await
+            .unwrap();
+        assert_eq!(statement.referee.id, referee.id);
+        assert!(statement.items.is_empty());
+        assert_eq!(statement.total_cents, 0);
+
+        let csv = fetch_expense_statement_csv(referee.id, now.year(), now.month())
+            .await
+            .unwrap();
+        assert_eq!(
+            csv,
+            "date,home,away,venue,role,distance_km,travel,fee,total\r\n,,,,,,,Total,0.00\r\n"
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use log::debug;
+use restinterface::{LeagueCreationDTO, LeagueDTO, LeagueIdDTO};
+use shared::app_error::AppError;
+
+use crate::{
+    adapters::db::league_repo_pg::LeagueRepositoryPg, application::league_services::create_league,
+    domain::repositories::league_repo::LeagueRepository,
+};
+
+use super::state::AppState;
+
+pub async fn create_league_handler(
+    State(state): State<Arc<AppState>>,
+    Json(league_creation): Json<LeagueCreationDTO>,
+) -> Result<Json<LeagueDTO>, AppError> {
+    debug!("Creating league: {:?}", league_creation);
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let repo = LeagueRepositoryPg::new();
+
+    let league = create_league(&league_creation.name, &repo, &mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(league.into()))
+}
+
+pub async fn get_league_by_id_handler(
+    State(state): State<Arc<AppState>>,
+    Path(league_id): Path<LeagueIdDTO>,
+) -> Result<Json<Option<LeagueDTO>>, AppError> {
+    debug!("Fetching league by id: {:?}", league_id.0);
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let repo = LeagueRepositoryPg::new();
+
+    let league = repo
+        .find_by_id(league_id.into(), &mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e))?;
+
+    Ok(Json(league.map(|l| l.into())))
+}
+
+pub async fn get_all_leagues_handler(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<LeagueDTO>>, AppError> {
+    debug!("Fetching all leagues");
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let repo = LeagueRepositoryPg::new();
+
+    let leagues = repo
+        .get_all(&mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e))?;
+
+    Ok(Json(leagues.into_iter().map(|l| l.into()).collect()))
+}
@@ -1,16 +1,24 @@
 use std::sync::Arc;
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
+    http::header,
+    response::IntoResponse,
     Json,
 };
 use log::debug;
-use restinterface::{RefereeCreationDTO, RefereeDTO, RefereeIdDTO};
+use restinterface::{
+    ArchivedFilterDTO, GeoLocationDTO, RefereeCreationDTO, RefereeDTO, RefereeIdDTO,
+};
 use shared::app_error::AppError;
 
 use crate::{
-    adapters::db::referee_repo_pg::RefereeRepositoryPg, application,
-    domain::repositories::referee_repo::RefereeRepository,
+    adapters::db::{
+        assignment_repo_pg::AssignmentRepositoryPg, availability_repo_pg::AvailabilityRepositoryPg,
+        fixture_repo_pg::FixtureRepositoryPg, referee_repo_pg::RefereeRepositoryPg,
+    },
+    application,
+    domain::{geo_location::GeoLocation, repositories::referee_repo::RefereeRepository},
 };
 
 use super::state::AppState;
@@ -28,6 +36,7 @@ pub async fn create_referee_handler(
     let referee = application::referee_services::create_referee(
         &ref_creation.name,
         &ref_creation.club,
+        ref_creation.qualification_level,
         &repo,
         &mut tx,
     )
@@ -68,8 +77,9 @@ pub async fn get_referee_by_id_handler(
 
 pub async fn get_all_referees_handler(
     State(state): State<Arc<AppState>>,
+    Query(filter): Query<ArchivedFilterDTO>,
 ) -> Result<Json<Vec<RefereeDTO>>, AppError> {
-    debug!("Getting all referees");
+    debug!("Getting all referees: {:?}", filter);
 
     let mut tx = state.connection_pool.begin().await.unwrap();
 
@@ -81,7 +91,15 @@ pub async fn get_all_referees_handler(
         .await
         .map_err(|e| AppError::from_error(&e.to_string()))?;
 
-    Ok(Json(referees.into_iter().map(|r| r.into()).collect()))
+    let include_archived = filter.include_archived.unwrap_or(false);
+
+    Ok(Json(
+        referees
+            .into_iter()
+            .filter(|r| include_archived || !r.is_archived())
+            .map(|r| r.into())
+            .collect(),
+    ))
 }
 
 pub async fn update_referee_club_handler(
@@ -104,18 +122,127 @@ pub async fn update_referee_club_handler(
     .await
     .map_err(|e| AppError::from_error(&e.to_string()))?;
 
+    let assignment_repo = AssignmentRepositoryPg::new();
+    let fixture_repo = FixtureRepositoryPg::new();
+
+    let flagged_assignments = application::assignment_services::flag_conflicts_of_interest(
+        referee_id.into(),
+        &assignment_repo,
+        &fixture_repo,
+        &repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e.to_string()))?;
+
     tx.commit()
         .await
         .map_err(|e| AppError::from_error(&e.to_string()))?;
 
     debug!("Referee club changed: {:?}", result);
+    debug!(
+        "Assignments flagged for conflict of interest: {:?}",
+        flagged_assignments
+    );
 
     Ok(Json(club))
 }
 
+pub async fn update_referee_home_handler(
+    State(state): State<Arc<AppState>>,
+    Path(referee_id): Path<RefereeIdDTO>,
+    Json(home): Json<GeoLocationDTO>,
+) -> Result<Json<RefereeDTO>, AppError> {
+    debug!("Updating referee home: {}", referee_id.0);
+
+    let home = GeoLocation::try_from(home).map_err(|e| AppError::from_error(&e))?;
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let repo = RefereeRepositoryPg::new();
+
+    let referee =
+        application::referee_services::update_referee_home(referee_id.into(), home, &repo, &mut tx)
+            .await
+            .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    debug!("Referee home changed: {:?}", referee);
+
+    Ok(Json(referee.into()))
+}
+
+pub async fn archive_referee_handler(
+    State(state): State<Arc<AppState>>,
+    Path(referee_id): Path<RefereeIdDTO>,
+) -> Result<Json<RefereeDTO>, AppError> {
+    debug!("Archiving referee: {}", referee_id.0);
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let referee_repo = RefereeRepositoryPg::new();
+    let assignment_repo = AssignmentRepositoryPg::new();
+    let availability_repo = AvailabilityRepositoryPg::new();
+    let fixture_repo = FixtureRepositoryPg::new();
+
+    let referee = application::referee_services::archive_referee(
+        referee_id.into(),
+        &referee_repo,
+        &assignment_repo,
+        &availability_repo,
+        &fixture_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    debug!("Referee archived: {:?}", referee);
+
+    Ok(Json(referee.into()))
+}
+
+pub async fn get_referee_calendar_handler(
+    State(state): State<Arc<AppState>>,
+    Path(referee_id): Path<RefereeIdDTO>,
+) -> Result<impl IntoResponse, AppError> {
+    debug!("Getting calendar of referee: {}", referee_id.0);
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let referee_repo = RefereeRepositoryPg::new();
+    let assignment_repo = AssignmentRepositoryPg::new();
+    let fixture_repo = FixtureRepositoryPg::new();
+
+    let calendar = application::calendar_services::get_referee_calendar(
+        referee_id.into(),
+        &referee_repo,
+        &assignment_repo,
+        &fixture_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok((
+        [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+        calendar,
+    ))
+}
+
 #[cfg(test)]
 mod referee_tests {
-    use restinterface::{change_referee_club, fetch_referees, RefereeCreationDTO};
+    use restinterface::{
+        archive_referee, change_referee_club, change_referee_home, fetch_referee_calendar,
+        fetch_referees, fetch_referees_filtered, ArchivedFilterDTO, GeoLocationDTO,
+        RefereeCreationDTO,
+    };
     use sqlx::PgPool;
 
     #[tokio::test]
@@ -133,6 +260,7 @@ mod referee_tests {
         let referee_creation = RefereeCreationDTO {
             name: "John Doe".to_string(),
             club: "Club A".to_string(),
+            qualification_level: 2,
         };
 
         let referee_dto = restinterface::create_referee(&referee_creation).await;
@@ -146,6 +274,10 @@ mod referee_tests {
             "Referee name should be John Doe"
         );
         assert_eq!(referees[0].club, "Club A", "Referee club should be Club A");
+        assert_eq!(
+            referees[0].qualification_level, 2,
+            "Referee qualification level should be 2"
+        );
     }
 
     #[tokio::test]
@@ -155,6 +287,7 @@ mod referee_tests {
         let referee_creation = RefereeCreationDTO {
             name: "John Doe".to_string(),
             club: "Club A".to_string(),
+            qualification_level: 0,
         };
 
         let referee_dto = restinterface::create_referee(&referee_creation).await;
@@ -177,6 +310,113 @@ mod referee_tests {
         );
     }
 
+    #[tokio::test]
+    async fn given_referee_when_updating_home_then_home_is_updated() {
+        clear_referee_table().await;
+
+        let referee_dto = restinterface::create_referee(&RefereeCreationDTO {
+            name: "John Doe".to_string(),
+            club: "Club A".to_string(),
+            qualification_level: 0,
+        })
+        .await
+        .unwrap();
+        assert_eq!(referee_dto.home, None, "Referee should have no home");
+
+        let home = GeoLocationDTO {
+            latitude: 48.2082,
+            longitude: 16.3738,
+        };
+        let updated_referee_dto = change_referee_home(referee_dto.id, &home).await.unwrap();
+        assert_eq!(
+            updated_referee_dto.home,
+            Some(home),
+            "Referee home should be updated"
+        );
+
+        let referee_dto = restinterface::fetch_referee(referee_dto.id).await.unwrap();
+        assert_eq!(
+            referee_dto.home,
+            Some(home),
+            "Referee home should be updated"
+        );
+
+        // coordinates out of range are refused
+        let invalid_home = GeoLocationDTO {
+            latitude: 91.0,
+            longitude: 16.3738,
+        };
+        assert!(
+            change_referee_home(referee_dto.id, &invalid_home)
+                .await
+                .is_err(),
+            "Referee home should not be updated"
+        );
+    }
+
+    #[tokio::test]
+    async fn given_referee_without_assignments_when_fetching_calendar_then_empty_calendar_is_returned(
+    ) {
+        clear_referee_table().await;
+
+        let referee_dto = restinterface::create_referee(&RefereeCreationDTO {
+            name: "John Doe".to_string(),
+            club: "Club A".to_string(),
+            qualification_level: 0,
+        })
+        .await
+        .unwrap();
+
+        let calendar = fetch_referee_calendar(referee_dto.id).await.unwrap();
+        assert!(
+            calendar.starts_with("BEGIN:VCALENDAR\r\n"),
+            "Calendar should start with BEGIN:VCALENDAR"
+        );
+        assert!(
+            calendar.ends_with("END:VCALENDAR\r\n"),
+            "Calendar should end with END:VCALENDAR"
+        );
+        assert!(
+            !calendar.contains("BEGIN:VEVENT"),
+            "Calendar should not contain events"
+        );
+    }
+
+    #[tokio::test]
+    async fn given_referee_when_archiving_then_referee_is_only_listed_when_including_archived() {
+        clear_referee_table().await;
+
+        let referee_dto = restinterface::create_referee(&RefereeCreationDTO {
+            name: "John Doe".to_string(),
+            club: "Club A".to_string(),
+            qualification_level: 0,
+        })
+        .await
+        .unwrap();
+        assert!(!referee_dto.archived, "Referee should not be archived");
+
+        let archived_referee_dto = archive_referee(referee_dto.id).await.unwrap();
+        assert!(archived_referee_dto.archived, "Referee should be archived");
+
+        let referees = fetch_referees().await;
+        assert!(referees.is_empty(), "Archived referee should be hidden");
+
+        let referees = fetch_referees_filtered(&ArchivedFilterDTO {
+            include_archived: Some(true),
+        })
+        .await;
+        assert_eq!(
+            referees,
+            vec![archived_referee_dto],
+            "Archived referee should be listed when including archived"
+        );
+
+        assert!(
+            archive_referee(referee_dto.id).await.is_err(),
+            "Referee should not be archived twice"
+        );
+    }
+
     async fn clear_referee_table() {
         let db_url = std::env::var("DB_URL").expect("DB_URL not set");
         let connection_pool = PgPool::connect(&db_url).await.unwrap();
@@ -0,0 +1,173 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use log::debug;
+use restinterface::{SeasonCreationDTO, SeasonDTO, SeasonIdDTO};
+use shared::app_error::AppError;
+
+use crate::{
+    adapters::db::season_repo_pg::SeasonRepositoryPg,
+    application::season_services::{close_season, create_season},
+    domain::repositories::season_repo::SeasonRepository,
+};
+
+use super::state::AppState;
+
+pub async fn create_season_handler(
+    State(state): State<Arc<AppState>>,
+    Json(season_creation): Json<SeasonCreationDTO>,
+) -> Result<Json<SeasonDTO>, AppError> {
+    debug!("Creating season: {:?}", season_creation);
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let repo = SeasonRepositoryPg::new();
+
+    let season = create_season(&season_creation.name, &repo, &mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(season.into()))
+}
+
+pub async fn get_season_by_id_handler(
+    State(state): State<Arc<AppState>>,
+    Path(season_id): Path<SeasonIdDTO>,
+) -> Result<Json<Option<SeasonDTO>>, AppError> {
+    debug!("Fetching season by id: {:?}", season_id.0);
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let repo = SeasonRepositoryPg::new();
+
+    let season = repo
+        .find_by_id(season_id.into(), &mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e))?;
+
+    Ok(Json(season.map(|s| s.into())))
+}
+
+pub async fn get_all_seasons_handler(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<SeasonDTO>>, AppError> {
+    debug!("Fetching all seasons");
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let repo = SeasonRepositoryPg::new();
+
+    let seasons = repo
+        .get_all(&mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e))?;
+
+    Ok(Json(seasons.into_iter().map(|s| s.into()).collect()))
+}
+
+pub async fn close_season_handler(
+    State(state): State<Arc<AppState>>,
+    Path(season_id): Path<SeasonIdDTO>,
+) -> Result<Json<SeasonDTO>, AppError> {
+    debug!("Closing season: {:?}", season_id.0);
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let repo = SeasonRepositoryPg::new();
+
+    let season = close_season(season_id.into(), &repo, &mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(season.into()))
+}
+
+#[cfg(test)]
+mod season_tests {
+    use chrono::Utc;
+    use restinterface::{
+        cancel_fixture, change_fixture_date, close_season, create_season, create_test_fixture,
+        fetch_fixture, fetch_seasons, FixtureStatusDTO, SeasonCreationDTO,
+    };
+    use sqlx::PgPool;
+
+    #[tokio::test]
+    async fn given_empty_db_when_creating_season_then_open_season_is_returned() {
+        clear_tables().await;
+
+        let season = create_season(&SeasonCreationDTO {
+            name: "Season 2024/25".to_string(),
+        })
+        .await;
+        assert!(season.is_ok(), "Season should be created");
+
+        let season = season.unwrap();
+        assert_eq!(season.name, "Season 2024/25", "Season name should match");
+        assert!(!season.closed, "Season should be open");
+
+        let seasons = fetch_seasons().await;
+        assert_eq!(seasons.len(), 1, "There should be 1 season");
+        assert_eq!(seasons[0].id, season.id, "Season id should match");
+    }
+
+    #[tokio::test]
+    async fn given_closed_season_when_changing_fixture_then_fixture_is_read_only() {
+        clear_tables().await;
+
+        let (_fixture_creation, fixture_dto) = create_test_fixture().await;
+
+        let season = close_season(fixture_dto.season.id).await;
+        assert!(season.is_ok(), "Season should be closed");
+        assert!(season.unwrap().closed, "Season should be closed");
+
+        let result = change_fixture_date(fixture_dto.id, Utc::now()).await;
+        assert!(result.is_err(), "Fixture date change should fail");
+
+        let result = cancel_fixture(fixture_dto.id).await;
+        assert!(result.is_err(), "Fixture cancellation should fail");
+
+        let fixture = fetch_fixture(fixture_dto.id).await.unwrap();
+        assert!(fixture.season.closed, "Fixture season should be closed");
+        assert_eq!(
+            fixture.status,
+            FixtureStatusDTO::Scheduled,
+            "Fixture status should not have changed"
+        );
+
+        let result = close_season(fixture_dto.season.id).await;
+        assert!(result.is_err(), "Closing a closed season should fail");
+    }
+
+    async fn clear_tables() {
+        // NOTE: need to clear also fixtures and their dependants, otherwise the foreign key constraint will prevent the deletion
+        let db_url = std::env::var("DB_URL").expect("DB_URL not set");
+        let pool = PgPool::connect(&db_url).await.unwrap();
+        sqlx::query!("DELETE FROM rustddd.assignments")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query!("DELETE FROM rustddd.availabilities")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query!("DELETE FROM rustddd.fixtures")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query!("DELETE FROM rustddd.seasons")
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+}
@@ -1,16 +1,45 @@
 use restinterface::{
-    AssignmentDTO, AssignmentRefereeRoleDTO, AssignmentStatusDTO, FixtureDTO, FixtureIdDTO,
-    FixtureStatusDTO, RefereeDTO, RefereeIdDTO, TeamDTO, TeamIdDTO, VenueDTO, VenueIdDTO,
+    AssignmentDTO, AssignmentRefereeRoleDTO, AssignmentStatusDTO, AvailabilityLockDTO,
+    AvailabilityLockIdDTO, AvailabilityLockScopeDTO, BlackoutDTO, BlackoutIdDTO,
+    DeclarationDeadlineDTO, FixtureDTO, FixtureIdDTO, FixtureResultDTO, FixtureStatusDTO,
+    GeoLocationDTO, LeagueDTO, LeagueIdDTO, RefereeDTO, RefereeIdDTO, SeasonDTO, SeasonIdDTO,
+    SetScoreDTO, StandingDTO, TeamDTO, TeamIdDTO, VenueDTO, VenueIdDTO,
 };
 
-use crate::domain::aggregates::{
-    assignment::{Assignment, AssignmentRefereeRole, AssignmentStatus},
-    fixture::{Fixture, FixtureId, FixtureStatus},
-    referee::{Referee, RefereeId},
-    team::{Team, TeamId},
-    venue::{Venue, VenueId},
+use crate::domain::{
+    aggregates::{
+        assignment::{Assignment, AssignmentRefereeRole, AssignmentStatus},
+        availability_lock::{AvailabilityLock, AvailabilityLockId, AvailabilityLockScope},
+        blackout::{Blackout, BlackoutId},
+        declaration_deadline::DeclarationDeadline,
+        fixture::{Fixture, FixtureId, FixtureResult, FixtureStatus, SetScore},
+        league::{League, LeagueId},
+        referee::{Referee, RefereeId},
+        season::{Season, SeasonId},
+        standing::Standing,
+        team::{Team, TeamId},
+        venue::{Venue, VenueId},
+    },
+    geo_location::GeoLocation,
 };
 
+impl From<GeoLocation> for GeoLocationDTO {
+    fn from(location: GeoLocation) -> Self {
+        GeoLocationDTO {
+            latitude: location.latitude(),
+            longitude: location.longitude(),
+        }
+    }
+}
+
+impl TryFrom<GeoLocationDTO> for GeoLocation {
+    type Error = String;
+
+    fn try_from(location: GeoLocationDTO) -> Result<Self, Self::Error> {
+        GeoLocation::new(location.latitude, location.longitude)
+    }
+}
+
 impl From<VenueIdDTO> for VenueId {
     fn from(value: VenueIdDTO) -> Self {
         Self(value.0)
@@ -33,6 +62,8 @@ impl From<Venue> for VenueDTO {
             city: venue.city().to_string(),
             telephone: venue.telephone(),
             email: venue.email(),
+            location: venue.location().map(|l| l.into()),
+            archived: venue.is_archived(),
         }
     }
 }
@@ -41,22 +72,56 @@ impl From<FixtureStatus> for FixtureStatusDTO {
     fn from(status: FixtureStatus) -> Self {
         match status {
             FixtureStatus::Scheduled => FixtureStatusDTO::Scheduled,
+            FixtureStatus::Postponed => FixtureStatusDTO::Postponed,
+            FixtureStatus::Played => FixtureStatusDTO::Played,
             FixtureStatus::Cancelled => FixtureStatusDTO::Cancelled,
         }
     }
 }
 
+impl From<SetScoreDTO> for SetScore {
+    fn from(set_score: SetScoreDTO) -> Self {
+        SetScore {
+            home: set_score.home,
+            away: set_score.away,
+        }
+    }
+}
+
+impl From<SetScore> for SetScoreDTO {
+    fn from(set_score: SetScore) -> Self {
+        SetScoreDTO {
+            home: set_score.home,
+            away: set_score.away,
+        }
+    }
+}
+
+impl From<FixtureResult> for FixtureResultDTO {
+    fn from(result: FixtureResult) -> Self {
+        FixtureResultDTO {
+            sets: result.sets().iter().map(|s| (*s).into()).collect(),
+            winner: result.winner().into(),
+        }
+    }
+}
+
 impl From<Fixture> for FixtureDTO {
     fn from(fixture: Fixture) -> Self {
         FixtureDTO {
             id: fixture.id().into(),
             date: fixture.date().clone(),
+            league: fixture.league().clone().into(),
+            season: fixture.season().clone().into(),
             venue: fixture.venue().clone().into(),
             team_home: fixture.team_home().clone().into(),
             team_away: fixture.team_away().clone().into(),
             status: fixture.status().clone().into(),
+            result: fixture.result().map(|r| r.clone().into()),
             first_referee: fixture.first_referee().map(|r| r.clone().into()),
             second_referee: fixture.second_referee().map(|r| r.clone().into()),
+            first_referee_min_level: fixture.first_referee_min_level(),
+            second_referee_min_level: fixture.second_referee_min_level(),
         }
     }
 }
@@ -67,6 +132,27 @@ impl From<Referee> for RefereeDTO {
             id: referee.id().into(),
             name: referee.name().to_string(),
             club: referee.club().to_string(),
+            qualification_level: referee.qualification_level(),
+            home: referee.home().map(|h| h.into()),
+            archived: referee.is_archived(),
+        }
+    }
+}
+
+impl From<Standing> for StandingDTO {
+    fn from(standing: Standing) -> Self {
+        StandingDTO {
+            team: standing.team().clone().into(),
+            played: standing.played(),
+            wins: standing.wins(),
+            losses: standing.losses(),
+            sets_won: standing.sets_won(),
+            sets_lost: standing.sets_lost(),
+            set_ratio: standing.set_ratio(),
+            points_won: standing.points_won(),
+            points_lost: standing.points_lost(),
+            points_ratio: standing.points_ratio(),
+            table_points: standing.table_points(),
         }
     }
 }
@@ -77,10 +163,103 @@ impl From<Team> for TeamDTO {
             id: team.id().into(),
             name: team.name().to_string(),
             club: team.club().to_string(),
+            archived: team.is_archived(),
+        }
+    }
+}
+
+impl From<League> for LeagueDTO {
+    fn from(league: League) -> Self {
+        Self {
+            id: league.id().into(),
+            name: league.name().to_string(),
+        }
+    }
+}
+
+impl From<Season> for SeasonDTO {
+    fn from(season: Season) -> Self {
+        Self {
+            id: season.id().into(),
+            name: season.name().to_string(),
+            closed: season.is_closed(),
+        }
+    }
+}
+
+impl From<AvailabilityLockScopeDTO> for AvailabilityLockScope {
+    fn from(scope: AvailabilityLockScopeDTO) -> Self {
+        match scope {
+            AvailabilityLockScopeDTO::Fixture(fixture_id) => Self::Fixture(fixture_id.into()),
+            AvailabilityLockScopeDTO::DateRange { from, to } => Self::DateRange { from, to },
         }
     }
 }
 
+impl From<AvailabilityLockScope> for AvailabilityLockScopeDTO {
+    fn from(scope: AvailabilityLockScope) -> Self {
+        match scope {
+            AvailabilityLockScope::Fixture(fixture_id) => Self::Fixture(fixture_id.into()),
+            AvailabilityLockScope::DateRange { from, to } => Self::DateRange { from, to },
+        }
+    }
+}
+
+impl From<AvailabilityLock> for AvailabilityLockDTO {
+    fn from(lock: AvailabilityLock) -> Self {
+        Self {
+            id: lock.id().into(),
+            scope: lock.scope().into(),
+        }
+    }
+}
+
+impl From<DeclarationDeadline> for DeclarationDeadlineDTO {
+    fn from(deadline: DeclarationDeadline) -> Self {
+        Self {
+            fixture_id: deadline.fixture_id().into(),
+            deadline: *deadline.deadline(),
+            // NOTE: the monolith closes declarations as soon as the deadline passed
+            closed: deadline.is_closed(&chrono::Utc::now()),
+        }
+    }
+}
+
+impl From<AvailabilityLockIdDTO> for AvailabilityLockId {
+    fn from(id: AvailabilityLockIdDTO) -> Self {
+        Self(id.0)
+    }
+}
+
+impl From<AvailabilityLockId> for AvailabilityLockIdDTO {
+    fn from(id: AvailabilityLockId) -> Self {
+        Self(id.0)
+    }
+}
+
+impl From<Blackout> for BlackoutDTO {
+    fn from(blackout: Blackout) -> Self {
+        Self {
+            id: blackout.id().into(),
+            referee_id: blackout.referee_id().into(),
+            from: *blackout.from(),
+            to: *blackout.to(),
+        }
+    }
+}
+
+impl From<BlackoutIdDTO> for BlackoutId {
+    fn from(id: BlackoutIdDTO) -> Self {
+        Self(id.0)
+    }
+}
+
+impl From<BlackoutId> for BlackoutIdDTO {
+    fn from(id: BlackoutId) -> Self {
+        Self(id.0)
+    }
+}
+
 impl From<RefereeIdDTO> for RefereeId {
     fn from(value: RefereeIdDTO) -> Self {
         Self(value.0)
@@ -93,6 +272,18 @@ impl From<TeamIdDTO> for TeamId {
     }
 }
 
+impl From<LeagueIdDTO> for LeagueId {
+    fn from(id: LeagueIdDTO) -> Self {
+        Self(id.0)
+    }
+}
+
+impl From<SeasonIdDTO> for SeasonId {
+    fn from(id: SeasonIdDTO) -> Self {
+        Self(id.0)
+    }
+}
+
 impl From<FixtureIdDTO> for FixtureId {
     fn from(id: FixtureIdDTO) -> Self {
         Self(id.0)
@@ -111,6 +302,18 @@ impl From<TeamId> for TeamIdDTO {
     }
 }
 
+impl From<LeagueId> for LeagueIdDTO {
+    fn from(id: LeagueId) -> Self {
+        LeagueIdDTO(id.0)
+    }
+}
+
+impl From<SeasonId> for SeasonIdDTO {
+    fn from(id: SeasonId) -> Self {
+        SeasonIdDTO(id.0)
+    }
+}
+
 impl From<FixtureId> for FixtureIdDTO {
     fn from(id: FixtureId) -> Self {
         FixtureIdDTO(id.0)
@@ -140,6 +343,8 @@ impl From<AssignmentStatus> for AssignmentStatusDTO {
         match status {
             AssignmentStatus::Committed => AssignmentStatusDTO::Committed,
             AssignmentStatus::Staged => AssignmentStatusDTO::Staged,
+            AssignmentStatus::Confirmed => AssignmentStatusDTO::Confirmed,
+            AssignmentStatus::Declined => AssignmentStatusDTO::Declined,
         }
     }
 }
@@ -151,6 +356,7 @@ impl From<Assignment> for AssignmentDTO {
             fixture_id: assignment.fixture_id().into(),
             referee_id: assignment.referee_id().into(),
             referee_role: assignment.referee_role().into(),
+            review_reason: assignment.review_reason().map(|r| r.to_string()),
         }
     }
 }
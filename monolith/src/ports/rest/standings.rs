@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use log::debug;
+use restinterface::{LeagueIdDTO, SeasonIdDTO, StandingDTO};
+use shared::app_error::AppError;
+
+use crate::{
+    adapters::db::fixture_repo_pg::FixtureRepositoryPg,
+    application::standing_services::get_standings,
+};
+
+use super::state::AppState;
+
+pub async fn get_standings_handler(
+    State(state): State<Arc<AppState>>,
+    Path((league_id, season_id)): Path<(LeagueIdDTO, SeasonIdDTO)>,
+) -> Result<Json<Vec<StandingDTO>>, AppError> {
+    debug!(
+        "Fetching standings for league {:?} and season {:?}",
+        league_id.0, season_id.0
+    );
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let fixture_repo = FixtureRepositoryPg::new();
+
+    let standings = get_standings(league_id.into(), season_id.into(), &fixture_repo, &mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e))?;
+
+    Ok(Json(standings.into_iter().map(|s| s.into()).collect()))
+}
+
+#[cfg(test)]
+mod standings_tests {
+    use restinterface::{
+        create_test_fixture, fetch_standings, record_fixture_result, FixtureResultRecordingDTO,
+        SetScoreDTO,
+    };
+    use sqlx::PgPool;
+
+    use crate::ports::rest::auth::test_tokens::admin_access_token;
+
+    #[tokio::test]
+    async fn given_played_fixture_when_fetching_standings_then_winner_leads_with_three_points() {
+        clear_tables().await;
+
+        let (fixture_creation, fixture_dto) = create_test_fixture().await;
+
+        let result_recording = FixtureResultRecordingDTO {
+            sets: vec![
+                SetScoreDTO { home: 25, away: 20 },
+                SetScoreDTO { home: 23, away: 25 },
+                SetScoreDTO { home: 25, away: 18 },
+                SetScoreDTO { home: 25, away: 21 },
+            ],
+        };
+        record_fixture_result(fixture_dto.id, &result_recording, &admin_access_token())
+            .await
+            .unwrap();
+
+        let standings = fetch_standings(fixture_creation.league_id, fixture_creation.season_id)
+            .await
+            .unwrap();
+
+        assert_eq!(standings.len(), 2, "Both teams should have a standing");
+        assert_eq!(standings[0].team.id, fixture_creation.team_home_id);
+        assert_eq!(standings[0].table_points, 3);
+        assert_eq!(standings[0].sets_won, 3);
+        assert_eq!(standings[0].sets_lost, 1);
+        assert_eq!(standings[0].points_won, 98);
+        assert_eq!(standings[0].points_lost, 84);
+        assert_eq!(standings[1].team.id, fixture_creation.team_away_id);
+        assert_eq!(standings[1].table_points, 0);
+        assert_eq!(standings[1].losses, 1);
+    }
+
+    async fn clear_tables() {
+        let db_url = std::env::var("DB_URL").expect("DB_URL not set");
+        let pool = PgPool::connect(&db_url).await.unwrap();
+        sqlx::query!("DELETE FROM rustddd.assignments")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query!("DELETE FROM rustddd.availabilities")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query!("DELETE FROM rustddd.fixtures")
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+}
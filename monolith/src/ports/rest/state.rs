@@ -1,5 +1,9 @@
 use sqlx::PgPool;
 
+use crate::application::expense_services::ExpenseRates;
+
 pub struct AppState {
     pub connection_pool: PgPool,
+    pub expense_rates: ExpenseRates,
+    pub access_token_secret: String,
 }
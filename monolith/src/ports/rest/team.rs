@@ -1,15 +1,16 @@
 use std::sync::Arc;
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     Json,
 };
 use log::debug;
-use restinterface::{TeamCreationDTO, TeamDTO, TeamIdDTO};
+use restinterface::{ArchivedFilterDTO, TeamCreationDTO, TeamDTO, TeamIdDTO};
 use shared::app_error::AppError;
 
 use crate::{
-    adapters::db::team_repo_pg::TeamRepositoryPg, application::team_services::create_team,
+    adapters::db::{fixture_repo_pg::FixtureRepositoryPg, team_repo_pg::TeamRepositoryPg},
+    application::team_services::{archive_team, create_team, update_team_club, update_team_name},
     domain::repositories::team_repo::TeamRepository,
 };
 
@@ -56,8 +57,9 @@ pub async fn get_team_by_id_handler(
 
 pub async fn get_all_teams_handler(
     State(state): State<Arc<AppState>>,
+    Query(filter): Query<ArchivedFilterDTO>,
 ) -> Result<Json<Vec<TeamDTO>>, AppError> {
-    debug!("Fetching all teams");
+    debug!("Fetching all teams: {:?}", filter);
 
     let mut tx = state.connection_pool.begin().await.unwrap();
 
@@ -68,12 +70,89 @@ pub async fn get_all_teams_handler(
         .await
         .map_err(|e| AppError::from_error(&e))?;
 
-    Ok(Json(teams.into_iter().map(|t| t.into()).collect()))
+    let include_archived = filter.include_archived.unwrap_or(false);
+
+    Ok(Json(
+        teams
+            .into_iter()
+            .filter(|t| include_archived || !t.is_archived())
+            .map(|t| t.into())
+            .collect(),
+    ))
+}
+
+pub async fn update_team_name_handler(
+    State(state): State<Arc<AppState>>,
+    Path(team_id): Path<TeamIdDTO>,
+    Json(name): Json<String>,
+) -> Result<Json<TeamDTO>, AppError> {
+    debug!("Updating team name: {:?}", team_id.0);
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let repo = TeamRepositoryPg::new();
+
+    let team = update_team_name(team_id.into(), &name, &repo, &mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(team.into()))
+}
+
+pub async fn update_team_club_handler(
+    State(state): State<Arc<AppState>>,
+    Path(team_id): Path<TeamIdDTO>,
+    Json(club): Json<String>,
+) -> Result<Json<TeamDTO>, AppError> {
+    debug!("Updating team club: {:?}", team_id.0);
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let repo = TeamRepositoryPg::new();
+
+    let team = update_team_club(team_id.into(), &club, &repo, &mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(team.into()))
+}
+
+pub async fn archive_team_handler(
+    State(state): State<Arc<AppState>>,
+    Path(team_id): Path<TeamIdDTO>,
+) -> Result<Json<TeamDTO>, AppError> {
+    debug!("Archiving team: {:?}", team_id.0);
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let team_repo = TeamRepositoryPg::new();
+    let fixture_repo = FixtureRepositoryPg::new();
+
+    let team = archive_team(team_id.into(), &team_repo, &fixture_repo, &mut tx)
+        .await
+        .map_err(|e| AppError::from_error(&e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(team.into()))
 }
 
 #[cfg(test)]
 mod team_tests {
-    use restinterface::{create_team, fetch_team, fetch_teams, TeamCreationDTO};
+    use restinterface::{
+        archive_team, change_team_club, change_team_name, create_team, fetch_team, fetch_teams,
+        fetch_teams_filtered, ArchivedFilterDTO, TeamCreationDTO,
+    };
     use sqlx::PgPool;
 
     #[tokio::test]
@@ -113,6 +192,69 @@ mod team_tests {
         assert_eq!(fetched_team.club, "Club A", "Team club should be 'Club A'");
     }
 
+    #[tokio::test]
+    async fn given_team_when_updating_name_and_club_then_team_is_updated() {
+        clear_tables().await;
+
+        let team = create_team(&TeamCreationDTO {
+            name: "Team A".to_string(),
+            club: "Club A".to_string(),
+        })
+        .await
+        .unwrap();
+
+        let team = change_team_name(team.id, "Team B").await.unwrap();
+        assert_eq!(team.name, "Team B", "Team name should be 'Team B'");
+        assert_eq!(team.club, "Club A", "Team club should be unchanged");
+
+        let team = change_team_club(team.id, "Club B").await.unwrap();
+        assert_eq!(team.name, "Team B", "Team name should be unchanged");
+        assert_eq!(team.club, "Club B", "Team club should be 'Club B'");
+
+        let fetched_team = fetch_team(team.id).await.unwrap();
+        assert_eq!(
+            fetched_team, team,
+            "Fetched team should be the updated team"
+        );
+
+        let teams = fetch_teams().await;
+        assert_eq!(teams.len(), 1, "Updating must not create another team");
+    }
+
+    #[tokio::test]
+    async fn given_team_when_archiving_then_team_is_only_listed_when_including_archived() {
+        clear_tables().await;
+
+        let team = create_team(&TeamCreationDTO {
+            name: "Team A".to_string(),
+            club: "Club A".to_string(),
+        })
+        .await
+        .unwrap();
+        assert!(!team.archived, "Team should not be archived");
+
+        let archived_team = archive_team(team.id).await.unwrap();
+        assert!(archived_team.archived, "Team should be archived");
+
+        let teams = fetch_teams().await;
+        assert!(teams.is_empty(), "Archived team should be hidden");
+
+        let teams = fetch_teams_filtered(&ArchivedFilterDTO {
+            include_archived: Some(true),
+        })
+        .await;
+        assert_eq!(
+            teams,
+            vec![archived_team],
+            "Archived team should be listed when including archived"
+        );
+
+        assert!(
+            archive_team(team.id).await.is_err(),
+            "Team should not be archived twice"
+        );
+    }
+
     async fn clear_tables() {
         // NOTE: need to clear also fixtures, otherwise the foreign key constraint will prevent the deletion
         let db_url = std::env::var("DB_URL").expect("DB_URL not set");
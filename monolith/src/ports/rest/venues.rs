@@ -1,16 +1,19 @@
 use std::sync::Arc;
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     Json,
 };
 use log::debug;
-use restinterface::{VenueCreationDTO, VenueDTO, VenueIdDTO};
+use restinterface::{
+    ArchivedFilterDTO, VenueAddressDTO, VenueContactDTO, VenueCreationDTO, VenueDTO, VenueIdDTO,
+};
 use shared::app_error::AppError;
 
 use crate::{
-    adapters::db::venue_repo_pg::VenueRepositoryPg, application,
-    domain::repositories::venue_repo::VenueRepository,
+    adapters::db::{fixture_repo_pg::FixtureRepositoryPg, venue_repo_pg::VenueRepositoryPg},
+    application,
+    domain::{geo_location::GeoLocation, repositories::venue_repo::VenueRepository},
 };
 
 use super::state::AppState;
@@ -24,7 +27,13 @@ pub async fn create_venue_handler(
     let mut tx: sqlx::Transaction<'static, sqlx::Postgres> =
         state.connection_pool.begin().await.unwrap();
 
-    let mut repo: VenueRepositoryPg = VenueRepositoryPg::new();
+    let location = venue_creation
+        .location
+        .map(GeoLocation::try_from)
+        .transpose()
+        .map_err(|e| AppError::from_error(&e))?;
+
+    let repo: VenueRepositoryPg = VenueRepositoryPg::new();
     let venue = application::venue_services::create_venue(
         &venue_creation.name,
         &venue_creation.street,
@@ -32,7 +41,8 @@ pub async fn create_venue_handler(
         &venue_creation.city,
         venue_creation.telephone,
         venue_creation.email,
-        &mut repo,
+        location,
+        &repo,
         &mut tx,
     )
     .await
@@ -75,8 +85,9 @@ pub async fn get_venue_by_id_handler(
 
 pub async fn get_all_venues_handler(
     State(state): State<Arc<AppState>>,
+    Query(filter): Query<ArchivedFilterDTO>,
 ) -> Result<Json<Vec<VenueDTO>>, AppError> {
-    debug!("Getting all venues");
+    debug!("Getting all venues: {:?}", filter);
 
     let mut tx = state.connection_pool.begin().await.unwrap();
 
@@ -92,17 +103,117 @@ pub async fn get_all_venues_handler(
         .await
         .map_err(|e| AppError::from_error(&e.to_string()))?;
 
+    let include_archived = filter.include_archived.unwrap_or(false);
+
     Ok(Json::from(
         venues
             .into_iter()
+            .filter(|v| include_archived || !v.is_archived())
             .map(|v| v.into())
             .collect::<Vec<VenueDTO>>(),
     ))
 }
 
+pub async fn update_venue_address_handler(
+    State(state): State<Arc<AppState>>,
+    Path(venue_id): Path<VenueIdDTO>,
+    Json(address): Json<VenueAddressDTO>,
+) -> Result<Json<VenueDTO>, AppError> {
+    debug!("Updating venue address: {}", venue_id.0);
+
+    let location = address
+        .location
+        .map(GeoLocation::try_from)
+        .transpose()
+        .map_err(|e| AppError::from_error(&e))?;
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let repo = VenueRepositoryPg::new();
+
+    let venue = application::venue_services::update_venue_address(
+        venue_id.into(),
+        &address.street,
+        &address.zip,
+        &address.city,
+        location,
+        &repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(venue.into()))
+}
+
+pub async fn update_venue_contact_handler(
+    State(state): State<Arc<AppState>>,
+    Path(venue_id): Path<VenueIdDTO>,
+    Json(contact): Json<VenueContactDTO>,
+) -> Result<Json<VenueDTO>, AppError> {
+    debug!("Updating venue contact: {}", venue_id.0);
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let repo = VenueRepositoryPg::new();
+
+    let venue = application::venue_services::update_venue_contact(
+        venue_id.into(),
+        contact.telephone,
+        contact.email,
+        &repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(venue.into()))
+}
+
+pub async fn archive_venue_handler(
+    State(state): State<Arc<AppState>>,
+    Path(venue_id): Path<VenueIdDTO>,
+) -> Result<Json<VenueDTO>, AppError> {
+    debug!("Archiving venue: {}", venue_id.0);
+
+    let mut tx = state.connection_pool.begin().await.unwrap();
+
+    let venue_repo = VenueRepositoryPg::new();
+    let fixture_repo = FixtureRepositoryPg::new();
+
+    let venue = application::venue_services::archive_venue(
+        venue_id.into(),
+        &venue_repo,
+        &fixture_repo,
+        &mut tx,
+    )
+    .await
+    .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| AppError::from_error(&e.to_string()))?;
+
+    Ok(Json(venue.into()))
+}
+
 #[cfg(test)]
 mod venues_tests {
-    use restinterface::{create_venue, fetch_venue, fetch_venues, VenueCreationDTO};
+    use chrono::{Duration, Utc};
+    use restinterface::{
+        archive_venue, cancel_fixture, change_fixture_date, change_venue_address,
+        change_venue_contact, create_test_fixture, create_venue, fetch_venue, fetch_venues,
+        fetch_venues_filtered, ArchivedFilterDTO, GeoLocationDTO, VenueAddressDTO, VenueContactDTO,
+        VenueCreationDTO,
+    };
     use sqlx::PgPool;
 
     #[tokio::test]
@@ -124,6 +235,10 @@ mod venues_tests {
             city: "City A".to_string(),
             telephone: Some("1234567890".to_string()),
             email: Some("email@example.com".to_string()),
+            location: Some(GeoLocationDTO {
+                latitude: 48.2022,
+                longitude: 16.3347,
+            }),
         };
 
         let venue = create_venue(&venue_creation).await;
@@ -148,6 +263,11 @@ mod venues_tests {
             "Venue email should be 'email@example.com'"
         );
 
+        assert_eq!(
+            venue.location, venue_creation.location,
+            "Venue location should be the same"
+        );
+
         let fetched_venue = fetch_venue(venue.id.into()).await;
         assert!(fetched_venue.is_ok(), "Venue should be fetched");
 
@@ -175,6 +295,10 @@ mod venues_tests {
             Some("email@example.com".to_string()),
             "Venue email should be 'email@example.com'"
         );
+        assert_eq!(
+            fetched_venue.location, venue_creation.location,
+            "Venue location should be the same"
+        );
 
         let all_venues = fetch_venues().await;
         assert_eq!(all_venues.len(), 1, "There should be 1 venue");
@@ -193,6 +317,106 @@ mod venues_tests {
         );
     }
 
+    #[tokio::test]
+    async fn given_venue_when_updating_address_and_contact_then_venue_is_updated() {
+        clear_tables().await;
+
+        let venue = create_venue(&VenueCreationDTO {
+            name: "Venue A".to_string(),
+            street: "Street A".to_string(),
+            zip: "12345".to_string(),
+            city: "City A".to_string(),
+            telephone: Some("1234567890".to_string()),
+            email: None,
+            location: None,
+        })
+        .await
+        .unwrap();
+
+        let address = VenueAddressDTO {
+            street: "Street B".to_string(),
+            zip: "23456".to_string(),
+            city: "City B".to_string(),
+            location: Some(GeoLocationDTO {
+                latitude: 48.2022,
+                longitude: 16.3347,
+            }),
+        };
+        let venue = change_venue_address(venue.id, &address).await.unwrap();
+        assert_eq!(venue.name, "Venue A", "Venue name should be unchanged");
+        assert_eq!(
+            venue.street, "Street B",
+            "Venue street should be 'Street B'"
+        );
+        assert_eq!(venue.zip, "23456", "Venue zip should be '23456'");
+        assert_eq!(venue.city, "City B", "Venue city should be 'City B'");
+        assert_eq!(
+            venue.location, address.location,
+            "Venue location should be changed"
+        );
+
+        let contact = VenueContactDTO {
+            telephone: None,
+            email: Some("venue@example.com".to_string()),
+        };
+        let venue = change_venue_contact(venue.id, &contact).await.unwrap();
+        assert_eq!(venue.telephone, None, "Venue telephone should be removed");
+        assert_eq!(
+            venue.email,
+            Some("venue@example.com".to_string()),
+            "Venue email should be 'venue@example.com'"
+        );
+        assert_eq!(
+            venue.street, "Street B",
+            "Venue address should be unchanged"
+        );
+
+        let fetched_venue = fetch_venue(venue.id).await.unwrap();
+        assert_eq!(
+            fetched_venue, venue,
+            "Fetched venue should be the updated venue"
+        );
+
+        let venues = fetch_venues().await;
+        assert_eq!(venues.len(), 1, "Updating must not create another venue");
+    }
+
+    #[tokio::test]
+    async fn given_venue_of_upcoming_fixture_when_archiving_then_archiving_fails_until_fixture_is_cancelled(
+    ) {
+        clear_tables().await;
+
+        let (_, fixture) = create_test_fixture().await;
+        change_fixture_date(fixture.id, Utc::now() + Duration::days(7))
+            .await
+            .unwrap();
+
+        assert!(
+            archive_venue(fixture.venue.id).await.is_err(),
+            "Venue of an upcoming fixture should not be archived"
+        );
+        let venues = fetch_venues().await;
+        assert_eq!(venues.len(), 1, "Venue should still be listed");
+
+        cancel_fixture(fixture.id).await.unwrap();
+
+        let venue = archive_venue(fixture.venue.id).await.unwrap();
+        assert!(venue.archived, "Venue should be archived");
+
+        let venues = fetch_venues().await;
+        assert!(venues.is_empty(), "Archived venue should be hidden");
+
+        let venues = fetch_venues_filtered(&ArchivedFilterDTO {
+            include_archived: Some(true),
+        })
+        .await;
+        assert_eq!(
+            venues,
+            vec![venue],
+            "Archived venue should be listed when including archived"
+        );
+    }
+
     async fn clear_tables() {
         // NOTE: need to clear also fixtures, otherwise the foreign key constraint will prevent the deletion
         let db_url = std::env::var("DB_URL").expect("DB_URL not set");
@@ -1,10 +1,13 @@
-use chrono::{DateTime, Utc};
+use std::fmt::Display;
+
+use chrono::{DateTime, Utc, Weekday};
 use log::info;
-use reqwest::Url;
+use reqwest::{header::COOKIE, Url};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 pub const REFEREES_SERVICE_HOST: &str = "http://localhost:3000";
+pub const ACCESS_TOKEN_COOKIE: &str = "rust-ddd-access";
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RefereeIdDTO(pub Uuid);
@@ -18,6 +21,24 @@ pub struct VenueIdDTO(pub Uuid);
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TeamIdDTO(pub Uuid);
 
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeagueIdDTO(pub Uuid);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeasonIdDTO(pub Uuid);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AvailabilityLockIdDTO(pub Uuid);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlackoutIdDTO(pub Uuid);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotificationIdDTO(pub Uuid);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommitSagaIdDTO(pub Uuid);
+
 impl ToString for RefereeIdDTO {
     fn to_string(&self) -> String {
         self.0.to_string()
@@ -42,20 +63,66 @@ impl ToString for TeamIdDTO {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+impl Display for LeagueIdDTO {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Display for SeasonIdDTO {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Display for AvailabilityLockIdDTO {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Display for BlackoutIdDTO {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Display for NotificationIdDTO {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Display for CommitSagaIdDTO {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct GeoLocationDTO {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct RefereeDTO {
     pub id: RefereeIdDTO,
     pub name: String,
     pub club: String,
+    pub qualification_level: i32,
+    pub home: Option<GeoLocationDTO>,
+    pub archived: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct RefereeCreationDTO {
     pub name: String,
     pub club: String,
+    pub qualification_level: i32,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct VenueDTO {
     pub id: VenueIdDTO,
     pub name: String,
@@ -64,9 +131,11 @@ pub struct VenueDTO {
     pub city: String,
     pub telephone: Option<String>,
     pub email: Option<String>,
+    pub location: Option<GeoLocationDTO>,
+    pub archived: bool,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct VenueCreationDTO {
     pub name: String,
     pub street: String,
@@ -74,6 +143,22 @@ pub struct VenueCreationDTO {
     pub city: String,
     pub telephone: Option<String>,
     pub email: Option<String>,
+    pub location: Option<GeoLocationDTO>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct VenueAddressDTO {
+    pub street: String,
+    pub zip: String,
+    pub city: String,
+    // NOTE: a new address usually means the venue is somewhere else, therefore the location is changed along with it
+    pub location: Option<GeoLocationDTO>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct VenueContactDTO {
+    pub telephone: Option<String>,
+    pub email: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -81,6 +166,7 @@ pub struct TeamDTO {
     pub id: TeamIdDTO,
     pub name: String,
     pub club: String,
+    pub archived: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -89,36 +175,240 @@ pub struct TeamCreationDTO {
     pub club: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct LeagueDTO {
+    pub id: LeagueIdDTO,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct LeagueCreationDTO {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SeasonDTO {
+    pub id: SeasonIdDTO,
+    pub name: String,
+    pub closed: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SeasonCreationDTO {
+    pub name: String,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum FixtureStatusDTO {
     Scheduled,
+    Postponed,
+    Played,
     Cancelled,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetScoreDTO {
+    pub home: i32,
+    pub away: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FixtureResultDTO {
+    pub sets: Vec<SetScoreDTO>,
+    pub winner: TeamIdDTO,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FixtureResultRecordingDTO {
+    pub sets: Vec<SetScoreDTO>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct FixtureDTO {
     pub id: FixtureIdDTO,
+    pub league: LeagueDTO,
+    pub season: SeasonDTO,
     pub team_home: TeamDTO,
     pub team_away: TeamDTO,
     pub venue: VenueDTO,
     pub date: DateTime<Utc>,
     pub status: FixtureStatusDTO,
+    pub result: Option<FixtureResultDTO>,
     pub first_referee: Option<RefereeDTO>,
     pub second_referee: Option<RefereeDTO>,
+    pub first_referee_min_level: i32,
+    pub second_referee_min_level: i32,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct FixtureCreationDTO {
+    pub league_id: LeagueIdDTO,
+    pub season_id: SeasonIdDTO,
     pub team_home_id: TeamIdDTO,
     pub team_away_id: TeamIdDTO,
     pub venue_id: VenueIdDTO,
     pub date: DateTime<Utc>,
+    pub first_referee_min_level: i32,
+    pub second_referee_min_level: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ArchivedFilterDTO {
+    // NOTE: if not given, archived referees, teams and venues are hidden
+    pub include_archived: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct FixtureFilterDTO {
+    pub league_id: Option<LeagueIdDTO>,
+    pub season_id: Option<SeasonIdDTO>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScheduleTeamDTO {
+    pub team_id: TeamIdDTO,
+    pub home_venue_id: VenueIdDTO,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleGenerationDTO {
+    pub league_id: LeagueIdDTO,
+    pub season_id: SeasonIdDTO,
+    pub teams: Vec<ScheduleTeamDTO>,
+    pub start_date: DateTime<Utc>,
+    pub weekdays: Vec<Weekday>,
+    pub first_referee_min_level: i32,
+    pub second_referee_min_level: i32,
+}
+
+/// The CSV holds one fixture per row with the columns date (YYYY-MM-DD), time
+/// (HH:MM, UTC), home team, away team and venue, teams and venues are referenced
+/// by their name
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FixtureImportDTO {
+    pub league_id: LeagueIdDTO,
+    pub season_id: SeasonIdDTO,
+    pub csv: String,
+    pub first_referee_min_level: i32,
+    pub second_referee_min_level: i32,
+    pub dry_run: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FixtureImportRowDTO {
+    pub line: usize,
+    pub fixture: Option<FixtureDTO>,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StandingDTO {
+    pub team: TeamDTO,
+    pub played: i32,
+    pub wins: i32,
+    pub losses: i32,
+    pub sets_won: i32,
+    pub sets_lost: i32,
+    pub set_ratio: f64,
+    pub points_won: i32,
+    pub points_lost: i32,
+    pub points_ratio: f64,
+    pub table_points: i32,
+}
+
+/// A lock either covers a single fixture or all fixtures within a date range
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvailabilityLockScopeDTO {
+    Fixture(FixtureIdDTO),
+    DateRange {
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AvailabilityLockDTO {
+    pub id: AvailabilityLockIdDTO,
+    pub scope: AvailabilityLockScopeDTO,
+}
+
+/// Selects the fixtures to declare availability for, all given criteria have to
+/// match, an empty list of venues or weekdays matches every venue or weekday
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct AvailabilityBulkDeclarationDTO {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub venue_ids: Vec<VenueIdDTO>,
+    pub weekdays: Vec<Weekday>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlackoutDTO {
+    pub id: BlackoutIdDTO,
+    pub referee_id: RefereeIdDTO,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlackoutCreationDTO {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationKindDTO {
+    FirstRefereeAssigned,
+    SecondRefereeAssigned,
+    AssignmentRemoved,
+    FixtureDateChanged,
+    FixtureVenueChanged,
+    FixtureCancelled,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationStatusDTO {
+    Pending,
+    Sent,
+    Failed,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct NotificationDTO {
+    pub id: NotificationIdDTO,
+    pub referee_id: RefereeIdDTO,
+    pub fixture_id: FixtureIdDTO,
+    pub kind: NotificationKindDTO,
+    pub recipient: String,
+    pub subject: String,
+    pub body: String,
+    pub status: NotificationStatusDTO,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub sent_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct NotificationRecipientDTO {
+    pub referee_id: RefereeIdDTO,
+    pub email: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeclarationDeadlineDTO {
+    pub fixture_id: FixtureIdDTO,
+    pub deadline: DateTime<Utc>,
+    pub closed: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AssignmentStatusDTO {
     Committed,
     Staged,
+    Suspended,
+    Confirmed,
+    Declined,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
@@ -133,6 +423,7 @@ pub struct AssignmentDTO {
     pub referee_role: AssignmentRefereeRoleDTO,
     pub fixture_id: FixtureIdDTO,
     pub referee_id: RefereeIdDTO,
+    pub review_reason: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -142,6 +433,128 @@ pub struct AssignmentStagingDTO {
     pub referee_role: AssignmentRefereeRoleDTO,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitSagaStatusDTO {
+    Running,
+    Completed,
+    Compensating,
+    Compensated,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitSagaStepStatusDTO {
+    Pending,
+    Accepted,
+    Rejected,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CommitSagaStepDTO {
+    pub fixture_id: FixtureIdDTO,
+    pub referee_id: RefereeIdDTO,
+    pub referee_role: AssignmentRefereeRoleDTO,
+    pub status: CommitSagaStepStatusDTO,
+    pub reason: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CommitSagaDTO {
+    pub id: CommitSagaIdDTO,
+    pub status: CommitSagaStatusDTO,
+    pub created_at: DateTime<Utc>,
+    pub steps: Vec<CommitSagaStepDTO>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UnconfirmedAssignmentsFilterDTO {
+    // NOTE: if not given, a default window close to game day is used
+    pub within_hours: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UnconfirmedFixtureDTO {
+    pub fixture: FixtureDTO,
+    pub unconfirmed_assignments: Vec<AssignmentDTO>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AssignmentProposalRequestDTO {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssignmentValidationRuleDTO {
+    FixtureNotScheduled,
+    FixtureReadOnly,
+    RefereeNotAvailable,
+    RefereeAssignedAtSameTime,
+    RefereeAssignedToCloseFixture,
+    RefereeAssignedToCloseFixtureAtSameVenue,
+    RefereeConflictOfInterest,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssignmentValidationSeverityDTO {
+    Error,
+    Warning,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AssignmentValidationViolationDTO {
+    pub fixture_id: FixtureIdDTO,
+    pub referee_id: RefereeIdDTO,
+    pub rule: AssignmentValidationRuleDTO,
+    pub severity: AssignmentValidationSeverityDTO,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AssignmentValidationReportDTO {
+    pub violations: Vec<AssignmentValidationViolationDTO>,
+}
+
+impl FixtureDTO {
+    /// A fixture is upcoming if it is scheduled in the future, or postponed and thus still waiting for its new date
+    pub fn is_upcoming(&self, now: &DateTime<Utc>) -> bool {
+        self.status == FixtureStatusDTO::Postponed
+            || (self.status == FixtureStatusDTO::Scheduled && self.date > *now)
+    }
+}
+
+impl AssignmentValidationReportDTO {
+    pub fn has_errors(&self) -> bool {
+        self.violations
+            .iter()
+            .any(|v| v.severity == AssignmentValidationSeverityDTO::Error)
+    }
+}
+
+/// A single committed assignment of an expense statement; the distance is missing if the venue or
+/// the referee has no location, in which case only the flat fee is reimbursed
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ExpenseItemDTO {
+    pub fixture_id: FixtureIdDTO,
+    pub date: DateTime<Utc>,
+    pub team_home_name: String,
+    pub team_away_name: String,
+    pub venue_name: String,
+    pub referee_role: AssignmentRefereeRoleDTO,
+    pub distance_km: Option<f64>,
+    pub travel_cents: i64,
+    pub fee_cents: i64,
+    pub total_cents: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ExpenseStatementDTO {
+    pub referee: RefereeDTO,
+    pub year: i32,
+    pub month: u32,
+    pub items: Vec<ExpenseItemDTO>,
+    pub total_cents: i64,
+}
+
 impl From<String> for RefereeIdDTO {
     fn from(value: String) -> Self {
         RefereeIdDTO(Uuid::parse_str(&value).unwrap())
@@ -166,6 +579,42 @@ impl From<String> for TeamIdDTO {
     }
 }
 
+impl From<String> for LeagueIdDTO {
+    fn from(value: String) -> Self {
+        LeagueIdDTO(Uuid::parse_str(&value).unwrap())
+    }
+}
+
+impl From<String> for SeasonIdDTO {
+    fn from(value: String) -> Self {
+        SeasonIdDTO(Uuid::parse_str(&value).unwrap())
+    }
+}
+
+impl From<String> for AvailabilityLockIdDTO {
+    fn from(value: String) -> Self {
+        AvailabilityLockIdDTO(Uuid::parse_str(&value).unwrap())
+    }
+}
+
+impl From<String> for BlackoutIdDTO {
+    fn from(value: String) -> Self {
+        BlackoutIdDTO(Uuid::parse_str(&value).unwrap())
+    }
+}
+
+impl From<String> for NotificationIdDTO {
+    fn from(value: String) -> Self {
+        NotificationIdDTO(Uuid::parse_str(&value).unwrap())
+    }
+}
+
+impl From<String> for CommitSagaIdDTO {
+    fn from(value: String) -> Self {
+        CommitSagaIdDTO(Uuid::parse_str(&value).unwrap())
+    }
+}
+
 impl From<Uuid> for RefereeIdDTO {
     fn from(value: Uuid) -> Self {
         RefereeIdDTO(value)
@@ -190,12 +639,58 @@ impl From<Uuid> for TeamIdDTO {
     }
 }
 
+impl From<Uuid> for LeagueIdDTO {
+    fn from(value: Uuid) -> Self {
+        LeagueIdDTO(value)
+    }
+}
+
+impl From<Uuid> for SeasonIdDTO {
+    fn from(value: Uuid) -> Self {
+        SeasonIdDTO(value)
+    }
+}
+
+impl From<Uuid> for AvailabilityLockIdDTO {
+    fn from(value: Uuid) -> Self {
+        AvailabilityLockIdDTO(value)
+    }
+}
+
+impl From<Uuid> for BlackoutIdDTO {
+    fn from(value: Uuid) -> Self {
+        BlackoutIdDTO(value)
+    }
+}
+
+impl From<Uuid> for NotificationIdDTO {
+    fn from(value: Uuid) -> Self {
+        NotificationIdDTO(value)
+    }
+}
+
+impl From<Uuid> for CommitSagaIdDTO {
+    fn from(value: Uuid) -> Self {
+        CommitSagaIdDTO(value)
+    }
+}
+
 pub async fn fetch_referees() -> Vec<RefereeDTO> {
     let url = Url::parse(&format!("{}/referees/all", REFEREES_SERVICE_HOST));
     let response = reqwest::Client::new().get(url.unwrap()).send().await;
     response.unwrap().json().await.unwrap()
 }
 
+pub async fn fetch_referees_filtered(filter: &ArchivedFilterDTO) -> Vec<RefereeDTO> {
+    let url = Url::parse(&format!("{}/referees/all", REFEREES_SERVICE_HOST));
+    let response = reqwest::Client::new()
+        .get(url.unwrap())
+        .query(filter)
+        .send()
+        .await;
+    response.unwrap().json().await.unwrap()
+}
+
 pub async fn create_referee(
     ref_creation: &RefereeCreationDTO,
 ) -> Result<RefereeDTO, reqwest::Error> {
@@ -217,6 +712,15 @@ pub async fn fetch_referee(referee_id: RefereeIdDTO) -> Result<RefereeDTO, reqwe
     response.json().await
 }
 
+pub async fn fetch_referee_calendar(referee_id: RefereeIdDTO) -> Result<String, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/referees/{}/assignments.ics",
+        REFEREES_SERVICE_HOST, referee_id.0
+    ));
+    let response = reqwest::Client::new().get(url.unwrap()).send().await?;
+    response.text().await
+}
+
 pub async fn change_referee_club(
     referee_id: RefereeIdDTO,
     club: &str,
@@ -233,32 +737,134 @@ pub async fn change_referee_club(
     response.json().await
 }
 
-pub async fn fetch_venues() -> Vec<VenueDTO> {
-    let url = Url::parse(&format!("{}/venues/all", REFEREES_SERVICE_HOST));
-    let response = reqwest::Client::new().get(url.unwrap()).send().await;
-    response.unwrap().json().await.unwrap()
-}
-
-pub async fn create_venue(venue_creation: &VenueCreationDTO) -> Result<VenueDTO, reqwest::Error> {
-    let url = Url::parse(&format!("{}/venues", REFEREES_SERVICE_HOST));
+pub async fn change_referee_home(
+    referee_id: RefereeIdDTO,
+    home: &GeoLocationDTO,
+) -> Result<RefereeDTO, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/referees/{}/home",
+        REFEREES_SERVICE_HOST, referee_id.0
+    ));
     let response = reqwest::Client::new()
         .post(url.unwrap())
-        .json(&venue_creation)
+        .json(home)
         .send()
         .await?;
     response.json().await
 }
 
-pub async fn fetch_venue(venue_id: VenueIdDTO) -> Result<VenueDTO, reqwest::Error> {
-    let url = Url::parse(&format!("{}/venues/{}", REFEREES_SERVICE_HOST, venue_id.0));
-    let response = reqwest::Client::new().get(url.unwrap()).send().await?;
+pub async fn archive_referee(referee_id: RefereeIdDTO) -> Result<RefereeDTO, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/referees/{}/archive",
+        REFEREES_SERVICE_HOST, referee_id.0
+    ));
+    let response = reqwest::Client::new().post(url.unwrap()).send().await?;
     response.json().await
 }
 
-pub async fn fetch_teams() -> Vec<TeamDTO> {
-    let url = Url::parse(&format!("{}/teams/all", REFEREES_SERVICE_HOST));
-
-    info!("Fetching teams from {:?}", url);
+pub async fn fetch_expense_statement(
+    referee_id: RefereeIdDTO,
+    year: i32,
+    month: u32,
+) -> Result<ExpenseStatementDTO, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/expenses/referee/{}/{}/{}",
+        REFEREES_SERVICE_HOST, referee_id.0, year, month
+    ));
+    let response = reqwest::Client::new().get(url.unwrap()).send().await?;
+    response.json().await
+}
+
+pub async fn fetch_expense_statement_csv(
+    referee_id: RefereeIdDTO,
+    year: i32,
+    month: u32,
+) -> Result<String, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/expenses/referee/{}/{}/{}/statement.csv",
+        REFEREES_SERVICE_HOST, referee_id.0, year, month
+    ));
+    let response = reqwest::Client::new().get(url.unwrap()).send().await?;
+    response.text().await
+}
+
+pub async fn fetch_venues() -> Vec<VenueDTO> {
+    let url = Url::parse(&format!("{}/venues/all", REFEREES_SERVICE_HOST));
+    let response = reqwest::Client::new().get(url.unwrap()).send().await;
+    response.unwrap().json().await.unwrap()
+}
+
+pub async fn fetch_venues_filtered(filter: &ArchivedFilterDTO) -> Vec<VenueDTO> {
+    let url = Url::parse(&format!("{}/venues/all", REFEREES_SERVICE_HOST));
+    let response = reqwest::Client::new()
+        .get(url.unwrap())
+        .query(filter)
+        .send()
+        .await;
+    response.unwrap().json().await.unwrap()
+}
+
+pub async fn create_venue(venue_creation: &VenueCreationDTO) -> Result<VenueDTO, reqwest::Error> {
+    let url = Url::parse(&format!("{}/venues", REFEREES_SERVICE_HOST));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .json(&venue_creation)
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn fetch_venue(venue_id: VenueIdDTO) -> Result<VenueDTO, reqwest::Error> {
+    let url = Url::parse(&format!("{}/venues/{}", REFEREES_SERVICE_HOST, venue_id.0));
+    let response = reqwest::Client::new().get(url.unwrap()).send().await?;
+    response.json().await
+}
+
+pub async fn change_venue_address(
+    venue_id: VenueIdDTO,
+    address: &VenueAddressDTO,
+) -> Result<VenueDTO, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/venues/{}/address",
+        REFEREES_SERVICE_HOST, venue_id.0
+    ));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .json(&address)
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn change_venue_contact(
+    venue_id: VenueIdDTO,
+    contact: &VenueContactDTO,
+) -> Result<VenueDTO, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/venues/{}/contact",
+        REFEREES_SERVICE_HOST, venue_id.0
+    ));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .json(&contact)
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn archive_venue(venue_id: VenueIdDTO) -> Result<VenueDTO, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/venues/{}/archive",
+        REFEREES_SERVICE_HOST, venue_id.0
+    ));
+    let response = reqwest::Client::new().post(url.unwrap()).send().await?;
+    response.json().await
+}
+
+pub async fn fetch_teams() -> Vec<TeamDTO> {
+    let url = Url::parse(&format!("{}/teams/all", REFEREES_SERVICE_HOST));
+
+    info!("Fetching teams from {:?}", url);
 
     let builder = reqwest::Client::new().get(url.unwrap());
     //.fetch_mode_no_cors();
@@ -268,6 +874,16 @@ pub async fn fetch_teams() -> Vec<TeamDTO> {
     response.unwrap().json().await.unwrap()
 }
 
+pub async fn fetch_teams_filtered(filter: &ArchivedFilterDTO) -> Vec<TeamDTO> {
+    let url = Url::parse(&format!("{}/teams/all", REFEREES_SERVICE_HOST));
+    let response = reqwest::Client::new()
+        .get(url.unwrap())
+        .query(filter)
+        .send()
+        .await;
+    response.unwrap().json().await.unwrap()
+}
+
 pub async fn fetch_team(team_id: TeamIdDTO) -> Result<TeamDTO, reqwest::Error> {
     let url = Url::parse(&format!("{}/teams/{}", REFEREES_SERVICE_HOST, team_id.0));
     let response = reqwest::Client::new().get(url.unwrap()).send().await?;
@@ -284,12 +900,102 @@ pub async fn create_team(team_creation: &TeamCreationDTO) -> Result<TeamDTO, req
     response.json().await
 }
 
+pub async fn change_team_name(team_id: TeamIdDTO, name: &str) -> Result<TeamDTO, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/teams/{}/name",
+        REFEREES_SERVICE_HOST, team_id.0
+    ));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .json(&name)
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn change_team_club(team_id: TeamIdDTO, club: &str) -> Result<TeamDTO, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/teams/{}/club",
+        REFEREES_SERVICE_HOST, team_id.0
+    ));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .json(&club)
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn archive_team(team_id: TeamIdDTO) -> Result<TeamDTO, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/teams/{}/archive",
+        REFEREES_SERVICE_HOST, team_id.0
+    ));
+    let response = reqwest::Client::new().post(url.unwrap()).send().await?;
+    response.json().await
+}
+
+pub async fn fetch_leagues() -> Vec<LeagueDTO> {
+    let url = Url::parse(&format!("{}/leagues/all", REFEREES_SERVICE_HOST));
+    let response = reqwest::Client::new().get(url.unwrap()).send().await;
+    response.unwrap().json().await.unwrap()
+}
+
+pub async fn create_league(
+    league_creation: &LeagueCreationDTO,
+) -> Result<LeagueDTO, reqwest::Error> {
+    let url = Url::parse(&format!("{}/leagues", REFEREES_SERVICE_HOST));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .json(&league_creation)
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn fetch_seasons() -> Vec<SeasonDTO> {
+    let url = Url::parse(&format!("{}/seasons/all", REFEREES_SERVICE_HOST));
+    let response = reqwest::Client::new().get(url.unwrap()).send().await;
+    response.unwrap().json().await.unwrap()
+}
+
+pub async fn create_season(
+    season_creation: &SeasonCreationDTO,
+) -> Result<SeasonDTO, reqwest::Error> {
+    let url = Url::parse(&format!("{}/seasons", REFEREES_SERVICE_HOST));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .json(&season_creation)
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn close_season(season_id: SeasonIdDTO) -> Result<SeasonDTO, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/seasons/{}/close",
+        REFEREES_SERVICE_HOST, season_id.0
+    ));
+    let response = reqwest::Client::new().post(url.unwrap()).send().await?;
+    response.json().await
+}
+
 pub async fn fetch_fixtures() -> Vec<FixtureDTO> {
     let url = Url::parse(&format!("{}/fixtures/all", REFEREES_SERVICE_HOST));
     let response = reqwest::Client::new().get(url.unwrap()).send().await;
     response.unwrap().json().await.unwrap()
 }
 
+pub async fn fetch_fixtures_filtered(filter: &FixtureFilterDTO) -> Vec<FixtureDTO> {
+    let url = Url::parse(&format!("{}/fixtures", REFEREES_SERVICE_HOST));
+    let response = reqwest::Client::new()
+        .get(url.unwrap())
+        .query(filter)
+        .send()
+        .await;
+    response.unwrap().json().await.unwrap()
+}
+
 pub async fn create_fixture(
     fixture_creation: &FixtureCreationDTO,
 ) -> Result<FixtureDTO, reqwest::Error> {
@@ -302,6 +1008,45 @@ pub async fn create_fixture(
     response.json().await
 }
 
+pub async fn preview_schedule(
+    schedule_generation: &ScheduleGenerationDTO,
+) -> Result<Vec<FixtureCreationDTO>, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/fixtures/schedule/preview",
+        REFEREES_SERVICE_HOST
+    ));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .json(schedule_generation)
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn generate_schedule(
+    schedule_generation: &ScheduleGenerationDTO,
+) -> Result<Vec<FixtureDTO>, reqwest::Error> {
+    let url = Url::parse(&format!("{}/fixtures/schedule", REFEREES_SERVICE_HOST));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .json(schedule_generation)
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn import_fixtures(
+    fixture_import: &FixtureImportDTO,
+) -> Result<Vec<FixtureImportRowDTO>, reqwest::Error> {
+    let url = Url::parse(&format!("{}/fixtures/import", REFEREES_SERVICE_HOST));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .json(fixture_import)
+        .send()
+        .await?;
+    response.json().await
+}
+
 pub async fn fetch_fixture(fixture_id: FixtureIdDTO) -> Result<FixtureDTO, reqwest::Error> {
     let url = Url::parse(&format!(
         "{}/fixtures/{}",
@@ -352,6 +1097,61 @@ pub async fn cancel_fixture(fixture_id: FixtureIdDTO) -> Result<(), reqwest::Err
     response.json().await
 }
 
+pub async fn record_fixture_result(
+    fixture_id: FixtureIdDTO,
+    result_recording: &FixtureResultRecordingDTO,
+    access_token: &str,
+) -> Result<FixtureDTO, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/fixtures/{}/result",
+        REFEREES_SERVICE_HOST, fixture_id.0
+    ));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .header(COOKIE, format!("{}={}", ACCESS_TOKEN_COOKIE, access_token))
+        .json(result_recording)
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn postpone_fixture(fixture_id: FixtureIdDTO) -> Result<(), reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/fixtures/{}/postpone",
+        REFEREES_SERVICE_HOST, fixture_id.0
+    ));
+    let response = reqwest::Client::new().post(url.unwrap()).send().await?;
+    response.json().await
+}
+
+pub async fn reschedule_fixture(
+    fixture_id: FixtureIdDTO,
+    date: DateTime<Utc>,
+) -> Result<(), reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/fixtures/{}/reschedule",
+        REFEREES_SERVICE_HOST, fixture_id.0
+    ));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .json(&date)
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn fetch_standings(
+    league_id: LeagueIdDTO,
+    season_id: SeasonIdDTO,
+) -> Result<Vec<StandingDTO>, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/leagues/{}/seasons/{}/standings",
+        REFEREES_SERVICE_HOST, league_id.0, season_id.0
+    ));
+    let response = reqwest::Client::new().get(url.unwrap()).send().await?;
+    response.json().await
+}
+
 pub async fn fetch_availabilities_for_referee(
     referee_id: RefereeIdDTO,
 ) -> Result<Vec<FixtureIdDTO>, reqwest::Error> {
@@ -387,6 +1187,128 @@ pub async fn withdraw_availability(
     response.json().await
 }
 
+pub async fn declare_availabilities_bulk(
+    referee_id: RefereeIdDTO,
+    bulk_declaration: &AvailabilityBulkDeclarationDTO,
+) -> Result<Vec<FixtureIdDTO>, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/availabilities/declare/referee/{}",
+        REFEREES_SERVICE_HOST, referee_id.0
+    ));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .json(&bulk_declaration)
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn fetch_blackouts_for_referee(
+    referee_id: RefereeIdDTO,
+) -> Result<Vec<BlackoutDTO>, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/availabilities/blackouts/referee/{}",
+        REFEREES_SERVICE_HOST, referee_id.0
+    ));
+    let response = reqwest::Client::new().get(url.unwrap()).send().await?;
+    response.json().await
+}
+
+pub async fn declare_blackout(
+    referee_id: RefereeIdDTO,
+    blackout_creation: &BlackoutCreationDTO,
+) -> Result<BlackoutDTO, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/availabilities/blackouts/referee/{}",
+        REFEREES_SERVICE_HOST, referee_id.0
+    ));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .json(&blackout_creation)
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn remove_blackout(blackout_id: BlackoutIdDTO) -> Result<(), reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/availabilities/blackouts/{}",
+        REFEREES_SERVICE_HOST, blackout_id.0
+    ));
+    let response = reqwest::Client::new().delete(url.unwrap()).send().await?;
+    response.json().await
+}
+
+pub async fn fetch_notifications_for_referee(
+    referee_id: RefereeIdDTO,
+) -> Result<Vec<NotificationDTO>, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/notifications/referee/{}",
+        REFEREES_SERVICE_HOST, referee_id.0
+    ));
+    let response = reqwest::Client::new().get(url.unwrap()).send().await?;
+    response.json().await
+}
+
+pub async fn set_notification_recipient(
+    referee_id: RefereeIdDTO,
+    email: &str,
+) -> Result<NotificationRecipientDTO, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/notifications/recipients/referee/{}",
+        REFEREES_SERVICE_HOST, referee_id.0
+    ));
+    let response = reqwest::Client::new()
+        .put(url.unwrap())
+        .json(&email)
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn fetch_availability_locks() -> Result<Vec<AvailabilityLockDTO>, reqwest::Error> {
+    let url = Url::parse(&format!("{}/availabilities/locks", REFEREES_SERVICE_HOST));
+    let response = reqwest::Client::new().get(url.unwrap()).send().await?;
+    response.json().await
+}
+
+pub async fn lock_availabilities(
+    scope: &AvailabilityLockScopeDTO,
+) -> Result<AvailabilityLockDTO, reqwest::Error> {
+    let url = Url::parse(&format!("{}/availabilities/locks", REFEREES_SERVICE_HOST));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .json(&scope)
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn unlock_availabilities(lock_id: AvailabilityLockIdDTO) -> Result<(), reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/availabilities/locks/{}",
+        REFEREES_SERVICE_HOST, lock_id.0
+    ));
+    let response = reqwest::Client::new().delete(url.unwrap()).send().await?;
+    response.json().await
+}
+
+pub async fn set_declaration_deadline(
+    fixture_id: FixtureIdDTO,
+    deadline: DateTime<Utc>,
+) -> Result<DeclarationDeadlineDTO, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/availabilities/deadlines/fixture/{}",
+        REFEREES_SERVICE_HOST, fixture_id.0
+    ));
+    let response = reqwest::Client::new()
+        .put(url.unwrap())
+        .json(&deadline)
+        .send()
+        .await?;
+    response.json().await
+}
+
 pub async fn fetch_assignments() -> Vec<AssignmentDTO> {
     let url = Url::parse(&format!("{}/assignments", REFEREES_SERVICE_HOST));
     let response = reqwest::Client::new().get(url.unwrap()).send().await;
@@ -413,6 +1335,61 @@ pub async fn remove_committed_assignment(assignment: &AssignmentDTO) -> Result<(
     let response = reqwest::Client::new().delete(url.unwrap()).send().await?;
     response.json().await
 }
+pub async fn confirm_assignment(
+    fixture_id: FixtureIdDTO,
+    referee_id: RefereeIdDTO,
+    access_token: &str,
+) -> Result<AssignmentDTO, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/assignments/committed/{}/{}/confirm",
+        REFEREES_SERVICE_HOST, fixture_id.0, referee_id.0
+    ));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .header(COOKIE, format!("{}={}", ACCESS_TOKEN_COOKIE, access_token))
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn decline_assignment(
+    fixture_id: FixtureIdDTO,
+    referee_id: RefereeIdDTO,
+    access_token: &str,
+) -> Result<AssignmentDTO, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/assignments/committed/{}/{}/decline",
+        REFEREES_SERVICE_HOST, fixture_id.0, referee_id.0
+    ));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .header(COOKIE, format!("{}={}", ACCESS_TOKEN_COOKIE, access_token))
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn fetch_unconfirmed_assignments(
+    filter: &UnconfirmedAssignmentsFilterDTO,
+) -> Result<Vec<UnconfirmedFixtureDTO>, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/assignments/unconfirmed",
+        REFEREES_SERVICE_HOST
+    ));
+    let response = reqwest::Client::new()
+        .get(url.unwrap())
+        .query(filter)
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn fetch_assignments_for_review() -> Result<Vec<AssignmentDTO>, reqwest::Error> {
+    let url = Url::parse(&format!("{}/assignments/review", REFEREES_SERVICE_HOST));
+    let response = reqwest::Client::new().get(url.unwrap()).send().await?;
+    response.json().await
+}
+
 pub async fn remove_staged_assignment(assignment: &AssignmentDTO) -> Result<(), reqwest::Error> {
     let url = Url::parse(&format!(
         "{}/assignments/staged/{}/{}",
@@ -421,10 +1398,10 @@ pub async fn remove_staged_assignment(assignment: &AssignmentDTO) -> Result<(),
     let response = reqwest::Client::new().delete(url.unwrap()).send().await?;
     response.json().await
 }
-pub async fn validate_assignments() -> Result<String, reqwest::Error> {
+pub async fn validate_assignments() -> Result<AssignmentValidationReportDTO, reqwest::Error> {
     let url = Url::parse(&format!("{}/assignments/validate", REFEREES_SERVICE_HOST));
     let response = reqwest::Client::new().post(url.unwrap()).send().await?;
-    response.text().await
+    response.json().await
 }
 
 pub async fn commit_assignments() -> Result<String, reqwest::Error> {
@@ -433,6 +1410,50 @@ pub async fn commit_assignments() -> Result<String, reqwest::Error> {
     response.text().await
 }
 
+pub async fn fetch_commit_sagas() -> Result<Vec<CommitSagaDTO>, reqwest::Error> {
+    let url = Url::parse(&format!("{}/assignments/sagas", REFEREES_SERVICE_HOST));
+    let response = reqwest::Client::new().get(url.unwrap()).send().await?;
+    response.json().await
+}
+
+pub async fn fetch_commit_saga(
+    saga_id: CommitSagaIdDTO,
+) -> Result<Option<CommitSagaDTO>, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/assignments/sagas/{}",
+        REFEREES_SERVICE_HOST, saga_id.0
+    ));
+    let response = reqwest::Client::new().get(url.unwrap()).send().await?;
+    response.json().await
+}
+
+pub async fn propose_assignments(
+    proposal_request: &AssignmentProposalRequestDTO,
+) -> Result<Vec<AssignmentStagingDTO>, reqwest::Error> {
+    let url = Url::parse(&format!("{}/assignments/proposal", REFEREES_SERVICE_HOST));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .json(&proposal_request)
+        .send()
+        .await?;
+    response.json().await
+}
+
+pub async fn accept_assignment_proposal(
+    proposal: &[AssignmentStagingDTO],
+) -> Result<Vec<AssignmentDTO>, reqwest::Error> {
+    let url = Url::parse(&format!(
+        "{}/assignments/proposal/accept",
+        REFEREES_SERVICE_HOST
+    ));
+    let response = reqwest::Client::new()
+        .post(url.unwrap())
+        .json(&proposal)
+        .send()
+        .await?;
+    response.json().await
+}
+
 #[allow(dead_code)]
 // NOTE: only used for testing
 pub async fn create_test_fixture() -> (FixtureCreationDTO, FixtureDTO) {
@@ -457,15 +1478,35 @@ pub async fn create_test_fixture() -> (FixtureCreationDTO, FixtureDTO) {
         city: "City A".to_string(),
         telephone: Some("1234567890".to_string()),
         email: Some("email@example.com".to_string()),
+        location: Some(GeoLocationDTO {
+            latitude: 48.2022,
+            longitude: 16.3347,
+        }),
+    })
+    .await
+    .unwrap();
+
+    let league = create_league(&LeagueCreationDTO {
+        name: "League A".to_string(),
+    })
+    .await
+    .unwrap();
+
+    let season = create_season(&SeasonCreationDTO {
+        name: "Season 2024/25".to_string(),
     })
     .await
     .unwrap();
 
     let fixture_creation = FixtureCreationDTO {
+        league_id: league.id,
+        season_id: season.id,
         date: Utc::now(),
         venue_id: venue.id,
         team_home_id: team_home.id,
         team_away_id: team_away.id,
+        first_referee_min_level: 0,
+        second_referee_min_level: 0,
     };
 
     let fixture_dto = create_fixture(&fixture_creation).await;
@@ -0,0 +1,117 @@
+use chrono::{DateTime, Utc};
+use restinterface::{
+    AssignmentValidationRuleDTO, AssignmentValidationSeverityDTO, AssignmentValidationViolationDTO,
+    FixtureIdDTO, RefereeIdDTO, VenueIdDTO,
+};
+
+// NOTE: a game-hour is the time window in which a referee cannot officiate two different fixtures at different venues
+pub const GAME_HOUR_MINUTES: i64 = 60;
+
+/// When and where a fixture is played
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixtureSlot {
+    pub date: DateTime<Utc>,
+    pub venue_id: VenueIdDTO,
+}
+
+impl FixtureSlot {
+    /// Two fixtures are at the same time if they start at the exact same date and time
+    pub fn is_at_same_time(&self, other: &FixtureSlot) -> bool {
+        self.date == other.date
+    }
+
+    /// Two fixtures are "close" if they start within the same game-hour
+    pub fn is_close_to(&self, other: &FixtureSlot) -> bool {
+        (self.date - other.date).num_minutes().abs() < GAME_HOUR_MINUTES
+    }
+
+    /// A referee cannot officiate two fixtures which are at the same time, or close to each other at different venues
+    pub fn is_in_conflict_with(&self, other: &FixtureSlot) -> bool {
+        self.is_at_same_time(other) || (self.is_close_to(other) && self.venue_id != other.venue_id)
+    }
+}
+
+/// A fixture a referee is booked for by a staged or committed assignment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RefereeBooking {
+    pub fixture_id: FixtureIdDTO,
+    pub referee_id: RefereeIdDTO,
+    pub slot: FixtureSlot,
+    pub staged: bool,
+}
+
+/// Checks each staged booking against all other bookings of the same referee, both staged and committed.
+/// Conflicts between two staged bookings are only reported once, for the first of them.
+pub fn booking_violations(bookings: &[RefereeBooking]) -> Vec<AssignmentValidationViolationDTO> {
+    let mut violations = Vec::new();
+
+    for (idx, booking) in bookings.iter().enumerate() {
+        if !booking.staged {
+            continue;
+        }
+
+        for (other_idx, other_booking) in bookings.iter().enumerate() {
+            if other_booking.referee_id != booking.referee_id
+                || other_booking.fixture_id == booking.fixture_id
+            {
+                continue;
+            }
+
+            if other_booking.staged && other_idx < idx {
+                continue;
+            }
+
+            if booking.slot.is_at_same_time(&other_booking.slot) {
+                violations.push(violation(
+                    booking,
+                    AssignmentValidationRuleDTO::RefereeAssignedAtSameTime,
+                    AssignmentValidationSeverityDTO::Error,
+                    format!(
+                        "Referee {} is assigned to fixtures {} and {} at the same time",
+                        booking.referee_id.0, booking.fixture_id.0, other_booking.fixture_id.0
+                    ),
+                ));
+            } else if booking.slot.is_close_to(&other_booking.slot) {
+                // NOTE: close fixtures at the same venue are allowed, but we still report them as a warning
+                if booking.slot.venue_id == other_booking.slot.venue_id {
+                    violations.push(violation(
+                        booking,
+                        AssignmentValidationRuleDTO::RefereeAssignedToCloseFixtureAtSameVenue,
+                        AssignmentValidationSeverityDTO::Warning,
+                        format!(
+                            "Referee {} is assigned to fixtures {} and {} at the same venue within the same game-hour",
+                            booking.referee_id.0, booking.fixture_id.0, other_booking.fixture_id.0
+                        ),
+                    ));
+                } else {
+                    violations.push(violation(
+                        booking,
+                        AssignmentValidationRuleDTO::RefereeAssignedToCloseFixture,
+                        AssignmentValidationSeverityDTO::Error,
+                        format!(
+                            "Referee {} is assigned to fixtures {} and {} at different venues within the same game-hour",
+                            booking.referee_id.0, booking.fixture_id.0, other_booking.fixture_id.0
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+
+    violations
+}
+
+fn violation(
+    booking: &RefereeBooking,
+    rule: AssignmentValidationRuleDTO,
+    severity: AssignmentValidationSeverityDTO,
+    message: String,
+) -> AssignmentValidationViolationDTO {
+    AssignmentValidationViolationDTO {
+        fixture_id: booking.fixture_id,
+        referee_id: booking.referee_id,
+        rule,
+        severity,
+        message,
+    }
+}
@@ -1 +1,2 @@
 pub mod app_error;
+pub mod booking;